@@ -0,0 +1,67 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+use peko_crypto::hash::{HashFunction, Keccak256, SHA256Backend, SHA256};
+
+/// Number of messages in each batch.
+const BATCH_SIZE: usize = 1000;
+
+/// Size of each message in the batch.
+const MESSAGE_SIZE: usize = 64;
+
+/// Hash a batch of short messages one at a time and with `hash_batch`, using each SHA-256
+/// backend available on this CPU.
+fn bench_sha256(c: &mut Criterion) {
+    let messages = vec![[0x5au8; MESSAGE_SIZE]; BATCH_SIZE];
+    let messages: Vec<&[u8]> = messages.iter().map(|message| &message[..]).collect();
+
+    let mut group = c.benchmark_group("batch/sha256");
+    group.throughput(Throughput::Bytes((BATCH_SIZE * MESSAGE_SIZE) as u64));
+
+    for &backend in SHA256Backend::ALL.iter().filter(|b| b.is_available()) {
+        group.bench_with_input(
+            BenchmarkId::new("loop", format!("{:?}", backend)),
+            &backend,
+            |b, &backend| {
+                b.iter(|| {
+                    messages
+                        .iter()
+                        .map(|message| {
+                            let mut hasher = SHA256::with_backend(backend);
+                            hasher.update(message);
+                            hasher.digest()
+                        })
+                        .collect::<Vec<_>>()
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("batch", format!("{:?}", backend)),
+            &backend,
+            |b, &backend| b.iter(|| SHA256::hash_batch_with_backend(backend, &messages)),
+        );
+    }
+
+    group.finish();
+}
+
+/// Hash a batch of short messages one at a time and with `hash_batch`, using Keccak-256.
+fn bench_keccak256(c: &mut Criterion) {
+    let messages = vec![[0x5au8; MESSAGE_SIZE]; BATCH_SIZE];
+    let messages: Vec<&[u8]> = messages.iter().map(|message| &message[..]).collect();
+
+    let mut group = c.benchmark_group("batch/keccak256");
+    group.throughput(Throughput::Bytes((BATCH_SIZE * MESSAGE_SIZE) as u64));
+    group.bench_function("loop", |b| {
+        b.iter(|| {
+            messages
+                .iter()
+                .map(|message| Keccak256::hash(message))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("batch", |b| b.iter(|| Keccak256::hash_batch(&messages)));
+    group.finish();
+}
+
+criterion_group!(benches, bench_sha256, bench_keccak256);
+criterion_main!(benches);
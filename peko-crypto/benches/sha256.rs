@@ -0,0 +1,75 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+use peko_crypto::hash::{HashFunction, SHA256Backend, SHA256};
+
+/// Size of the message hashed by each benchmark.
+const MESSAGE_SIZE: usize = 1 << 20;
+
+/// Hash a 1 MiB message, fed to the hasher in updates of various sizes. Small updates stress
+/// the buffering of partial blocks, large ones the compression function.
+fn bench_update_size(c: &mut Criterion) {
+    let message = vec![0x5au8; MESSAGE_SIZE];
+
+    let mut group = c.benchmark_group("sha256");
+    group.throughput(Throughput::Bytes(MESSAGE_SIZE as u64));
+
+    for update_size in [1, 8, 63, 64, 1024, MESSAGE_SIZE].iter() {
+        group.bench_with_input(
+            BenchmarkId::new("update", update_size),
+            update_size,
+            |b, &update_size| {
+                b.iter(|| {
+                    let mut hasher = SHA256::new();
+                    for chunk in message.chunks(update_size) {
+                        hasher.update(chunk);
+                    }
+                    hasher.digest()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+/// Hash a 1 MiB message with each backend available on this CPU.
+fn bench_backends(c: &mut Criterion) {
+    let message = vec![0x5au8; MESSAGE_SIZE];
+
+    let mut group = c.benchmark_group("sha256");
+    group.throughput(Throughput::Bytes(MESSAGE_SIZE as u64));
+
+    for &backend in SHA256Backend::ALL.iter().filter(|b| b.is_available()) {
+        group.bench_with_input(
+            BenchmarkId::new("backend", format!("{:?}", backend)),
+            &backend,
+            |b, &backend| {
+                b.iter(|| {
+                    let mut hasher = SHA256::with_backend(backend);
+                    hasher.update(&message);
+                    hasher.digest()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+/// Hash many short messages, as done for Merkle trees and transaction hashes.
+fn bench_short_messages(c: &mut Criterion) {
+    let message = [0x5au8; 64];
+
+    let mut group = c.benchmark_group("sha256");
+    group.throughput(Throughput::Bytes(message.len() as u64));
+    group.bench_function("hash/64", |b| b.iter(|| SHA256::hash(&message)));
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_update_size,
+    bench_backends,
+    bench_short_messages
+);
+criterion_main!(benches);
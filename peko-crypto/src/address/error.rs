@@ -0,0 +1,34 @@
+//! Errors returned when parsing an address.
+
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Result type returned when parsing an address.
+///
+/// In essence the Error type is fixed to [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors returned when parsing an address.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The address is not 40 hexadecimal digits long, excluding the optional `0x` prefix.
+    InvalidLength,
+
+    /// The address contains a character which is not a hexadecimal digit.
+    InvalidCharacter,
+
+    /// The address is in mixed case, but the case of the letters doesn't match the checksum.
+    InvalidChecksum,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidLength => f.write_str("invalid address length"),
+            Error::InvalidCharacter => f.write_str("invalid character in address"),
+            Error::InvalidChecksum => f.write_str("invalid address checksum"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
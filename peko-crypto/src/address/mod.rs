@@ -0,0 +1,265 @@
+//! Ethereum addresses.
+//!
+//! An address is the last 20 bytes of the Keccak-256 hash of a public key, or of the data
+//! identifying a contract creation. Addresses are displayed with the mixed-case checksum of
+//! [EIP-55](https://eips.ethereum.org/EIPS/eip-55), or optionally the chain-aware checksum of
+//! [EIP-1191](https://eips.ethereum.org/EIPS/eip-1191).
+mod error;
+
+pub use error::{Error, Result};
+
+use crate::hash::{HashFunction, Keccak256, H256};
+use crate::secp256k1::PublicKey;
+use crate::util::{hex_decode, hex_encode};
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use std::convert::TryInto;
+use std::fmt;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+/// Size of an address, in bytes.
+pub const ADDRESS_SIZE: usize = 20;
+
+/// An Ethereum address.
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::address::Address;
+/// use peko_crypto::secp256k1::SecretKey;
+///
+/// let secret_key = SecretKey::from_bytes(&hex!(
+///     "289c2857d4598e37fb9647507e47a309d6133539bf21a8b9cb6df88fd5232032"
+/// ))
+/// .unwrap();
+/// let address = Address::from_public_key(&secret_key.public_key());
+///
+/// assert_eq!(
+///     address.to_string(),
+///     "0x970E8128AB834E8EAC17Ab8E3812F010678CF791"
+/// );
+/// assert_eq!(
+///     "0x970e8128ab834e8eac17ab8e3812f010678cf791".parse(),
+///     Ok(address)
+/// );
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Address([u8; ADDRESS_SIZE]);
+
+impl Address {
+    /// Create an address from its bytes.
+    pub fn new(bytes: [u8; ADDRESS_SIZE]) -> Address {
+        Address(bytes)
+    }
+
+    /// Return the bytes of the address.
+    pub fn as_bytes(&self) -> &[u8; ADDRESS_SIZE] {
+        &self.0
+    }
+
+    /// Derive the address of an account from its public key.
+    pub fn from_public_key(public_key: &PublicKey) -> Address {
+        Address::from_hash(&Keccak256::hash(&public_key.to_bytes()))
+    }
+
+    /// Compute the address of a contract created by `sender` with the `CREATE` opcode, or by a
+    /// contract creation transaction. This is the hash of the RLP-encoded list
+    /// `[sender, nonce]`.
+    pub fn create(sender: &Address, nonce: u64) -> Address {
+        let encoded = peko_rlp::to_bytes(&(sender, nonce)).expect("Addresses can be RLP-encoded");
+
+        Address::from_hash(&Keccak256::hash(&encoded))
+    }
+
+    /// Compute the address of a contract created by `sender` with the `CREATE2` opcode, as
+    /// specified by [EIP-1014](https://eips.ethereum.org/EIPS/eip-1014).
+    ///
+    /// # Example
+    /// ```
+    /// use hex_literal::hex;
+    /// use peko_crypto::address::Address;
+    ///
+    /// let sender = Address::new(hex!("deadbeef00000000000000000000000000000000"));
+    /// let address = Address::create2(&sender, &[0u8; 32], &hex!("00"));
+    ///
+    /// assert_eq!(
+    ///     address.to_string(),
+    ///     "0xB928f69Bb1D91Cd65274e3c79d8986362984fDA3"
+    /// );
+    /// ```
+    pub fn create2(sender: &Address, salt: &[u8; 32], init_code: &[u8]) -> Address {
+        let mut hasher = Keccak256::new();
+        hasher.update(&[0xff]);
+        hasher.update(&sender.0);
+        hasher.update(salt);
+        hasher.update(&Keccak256::hash(init_code));
+
+        Address::from_hash(&hasher.digest())
+    }
+
+    /// Return the address with the EIP-55 checksum, prefixed with `0x`. This is the same as
+    /// [`Address::to_string`].
+    pub fn to_checksum(&self) -> String {
+        self.checksum(None)
+    }
+
+    /// Return the address with the EIP-1191 checksum for the given chain ID, prefixed with
+    /// `0x`.
+    ///
+    /// Note that the checksum is only meaningful to chains which adopted EIP-1191; for other
+    /// chains, use [`Address::to_checksum`].
+    pub fn to_checksum_with_chain_id(&self, chain_id: u64) -> String {
+        self.checksum(Some(chain_id))
+    }
+
+    /// Parse an address with the EIP-1191 checksum for the given chain ID. Like
+    /// [`Address::from_str`], the `0x` prefix is optional and the checksum is only verified if
+    /// the address is in mixed case.
+    pub fn from_checksum_with_chain_id(address: &str, chain_id: u64) -> Result<Address> {
+        Address::parse(address, Some(chain_id))
+    }
+
+    /// Take the address from the last 20 bytes of a hash.
+    fn from_hash(hash: &H256) -> Address {
+        Address(
+            hash[32 - ADDRESS_SIZE..]
+                .try_into()
+                .expect("Address must be 20 bytes long"),
+        )
+    }
+
+    /// Return the checksum hash of the lowercase hexadecimal address. EIP-1191 prefixes the
+    /// address with the chain ID and `0x` before hashing.
+    fn checksum_hash(lowercase_hex: &[u8], chain_id: Option<u64>) -> H256 {
+        let mut hasher = Keccak256::new();
+        if let Some(chain_id) = chain_id {
+            hasher.update(format!("{}0x", chain_id).as_bytes());
+        }
+        hasher.update(lowercase_hex);
+
+        hasher.digest()
+    }
+
+    /// Return the address with the checksum, prefixed with `0x`. A letter is uppercased if the
+    /// corresponding nibble of the checksum hash is at least 8.
+    fn checksum(&self, chain_id: Option<u64>) -> String {
+        let mut hex = hex_encode(&self.0).into_bytes();
+        let hash = Address::checksum_hash(&hex, chain_id);
+
+        for (i, digit) in hex.iter_mut().enumerate() {
+            let nibble = (hash[i / 2] >> (4 * (1 - i % 2))) & 0xf;
+            if nibble >= 8 {
+                digit.make_ascii_uppercase();
+            }
+        }
+
+        format!(
+            "0x{}",
+            std::str::from_utf8(&hex).expect("Hex digits are ASCII")
+        )
+    }
+
+    /// Parse an address, verifying the checksum if the address is in mixed case.
+    fn parse(address: &str, chain_id: Option<u64>) -> Result<Address> {
+        let digits = address.strip_prefix("0x").unwrap_or(address);
+        if digits.len() != ADDRESS_SIZE * 2 {
+            return Err(Error::InvalidLength);
+        }
+
+        let bytes = hex_decode(digits).ok_or(Error::InvalidCharacter)?;
+        let address = Address(bytes[..].try_into().expect("Address must be 20 bytes long"));
+
+        let is_lowercase = !digits.bytes().any(|c| c.is_ascii_uppercase());
+        let is_uppercase = !digits.bytes().any(|c| c.is_ascii_lowercase());
+        if !is_lowercase && !is_uppercase && address.checksum(chain_id)[2..] != *digits {
+            return Err(Error::InvalidChecksum);
+        }
+
+        Ok(address)
+    }
+}
+
+impl From<[u8; ADDRESS_SIZE]> for Address {
+    fn from(bytes: [u8; ADDRESS_SIZE]) -> Address {
+        Address(bytes)
+    }
+}
+
+impl AsRef<[u8]> for Address {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Address {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Address(0x{})", hex_encode(&self.0))
+    }
+}
+
+impl fmt::Display for Address {
+    /// Format the address with the EIP-55 checksum, prefixed with `0x`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_checksum())
+    }
+}
+
+impl FromStr for Address {
+    type Err = Error;
+
+    /// Parse an address with the EIP-55 checksum. The `0x` prefix is optional. All-lowercase
+    /// and all-uppercase addresses carry no checksum, and are accepted as is.
+    fn from_str(address: &str) -> Result<Address> {
+        Address::parse(address, None)
+    }
+}
+
+impl Serialize for Address {
+    /// Serialize the address with the EIP-55 checksum in human-readable formats such as JSON,
+    /// and as a byte array otherwise, as in RLP-encoded transactions.
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+}
+
+/// Visitor accepting either an address string, whose checksum is verified if it is in mixed
+/// case, or exactly 20 bytes.
+struct AddressVisitor;
+
+impl<'de> Visitor<'de> for AddressVisitor {
+    type Value = Address;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "an address of {} bytes", ADDRESS_SIZE)
+    }
+
+    fn visit_str<E: de::Error>(self, address: &str) -> std::result::Result<Address, E> {
+        address.parse().map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> std::result::Result<Address, E> {
+        bytes
+            .try_into()
+            .map(Address)
+            .map_err(|_| E::invalid_length(bytes.len(), &self))
+    }
+}
+
+impl<'de> Deserialize<'de> for Address {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Address, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(AddressVisitor)
+        } else {
+            deserializer.deserialize_bytes(AddressVisitor)
+        }
+    }
+}
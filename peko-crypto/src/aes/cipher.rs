@@ -0,0 +1,190 @@
+use crate::aes::{BlockCipher, BLOCK_SIZE};
+use crate::util::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+/// Round constants used by the key expansion.
+const RCON: [u8; 10] = [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1b, 0x36];
+
+/// Multiply by x in GF(2^8), modulo the AES polynomial x^8 + x^4 + x^3 + x + 1.
+fn xtime(x: u8) -> u8 {
+    (x << 1) ^ ((x >> 7) * 0x1b)
+}
+
+/// Multiply in GF(2^8), without branches or table lookups depending on the operands.
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+    for _ in 0..8 {
+        product ^= a & (b & 1).wrapping_neg();
+        a = xtime(a);
+        b >>= 1;
+    }
+    product
+}
+
+/// The substitution box used by SubBytes and the key expansion: the inverse in GF(2^8), with 0
+/// mapped to itself, followed by an affine transformation.
+///
+/// It is computed rather than looked up in a table, so that the memory accesses and thus the
+/// cache timing do not depend on the key or the data.
+fn sub_byte(x: u8) -> u8 {
+    // x^254 is the inverse of x, computed with 7 squarings and 4 multiplications.
+    let x2 = gf_mul(x, x);
+    let x3 = gf_mul(x2, x);
+    let x6 = gf_mul(x3, x3);
+    let x12 = gf_mul(x6, x6);
+    let x15 = gf_mul(x12, x3);
+    let x30 = gf_mul(x15, x15);
+    let x60 = gf_mul(x30, x30);
+    let x120 = gf_mul(x60, x60);
+    let x240 = gf_mul(x120, x120);
+    let x252 = gf_mul(x240, x12);
+    let inverse = gf_mul(x252, x2);
+
+    inverse
+        ^ inverse.rotate_left(1)
+        ^ inverse.rotate_left(2)
+        ^ inverse.rotate_left(3)
+        ^ inverse.rotate_left(4)
+        ^ 0x63
+}
+
+/// Expand the key into `ROUND_KEYS` round keys.
+fn expand_key<const ROUND_KEYS: usize>(key: &[u8]) -> [[u8; BLOCK_SIZE]; ROUND_KEYS] {
+    let key_words = key.len() / 4;
+    // The words are the round keys themselves, so they are wiped once copied out.
+    let mut words = Zeroizing::new(vec![[0u8; 4]; ROUND_KEYS * 4]);
+
+    for (word, bytes) in words.iter_mut().zip(key.chunks_exact(4)) {
+        word.copy_from_slice(bytes);
+    }
+
+    for i in key_words..words.len() {
+        let mut temp = words[i - 1];
+
+        if i % key_words == 0 {
+            // RotWord, SubWord, then add the round constant.
+            temp = [
+                sub_byte(temp[1]) ^ RCON[i / key_words - 1],
+                sub_byte(temp[2]),
+                sub_byte(temp[3]),
+                sub_byte(temp[0]),
+            ];
+        } else if key_words > 6 && i % key_words == 4 {
+            temp = [
+                sub_byte(temp[0]),
+                sub_byte(temp[1]),
+                sub_byte(temp[2]),
+                sub_byte(temp[3]),
+            ];
+        }
+
+        for j in 0..4 {
+            words[i][j] = words[i - key_words][j] ^ temp[j];
+        }
+    }
+
+    let mut round_keys = [[0u8; BLOCK_SIZE]; ROUND_KEYS];
+    for (round_key, round_words) in round_keys.iter_mut().zip(words.chunks_exact(4)) {
+        for (bytes, word) in round_key.chunks_exact_mut(4).zip(round_words) {
+            bytes.copy_from_slice(word);
+        }
+    }
+
+    round_keys
+}
+
+/// Encrypt a block with the expanded key. The state is kept in column-major order, which is
+/// the order of the bytes in the block.
+fn encrypt_block(round_keys: &[[u8; BLOCK_SIZE]], block: &mut [u8; BLOCK_SIZE]) {
+    let rounds = round_keys.len() - 1;
+
+    add_round_key(block, &round_keys[0]);
+
+    for (round, round_key) in round_keys.iter().enumerate().skip(1) {
+        // SubBytes.
+        for byte in block.iter_mut() {
+            *byte = sub_byte(*byte);
+        }
+
+        // ShiftRows: row r is rotated left by r columns.
+        let state = *block;
+        for column in 0..4 {
+            for row in 1..4 {
+                block[column * 4 + row] = state[((column + row) % 4) * 4 + row];
+            }
+        }
+
+        // MixColumns, skipped in the last round.
+        if round != rounds {
+            for column in block.chunks_exact_mut(4) {
+                let [a0, a1, a2, a3] = [column[0], column[1], column[2], column[3]];
+                let all = a0 ^ a1 ^ a2 ^ a3;
+                column[0] ^= all ^ xtime(a0 ^ a1);
+                column[1] ^= all ^ xtime(a1 ^ a2);
+                column[2] ^= all ^ xtime(a2 ^ a3);
+                column[3] ^= all ^ xtime(a3 ^ a0);
+            }
+        }
+
+        add_round_key(block, round_key);
+    }
+}
+
+/// XOR the round key into the state.
+fn add_round_key(block: &mut [u8; BLOCK_SIZE], round_key: &[u8; BLOCK_SIZE]) {
+    for (byte, key) in block.iter_mut().zip(round_key.iter()) {
+        *byte ^= key;
+    }
+}
+
+macro_rules! define_aes {
+    ($name: ident, $bits: expr, $rounds: expr, $doc: expr) => {
+        #[doc = $doc]
+        #[derive(Clone)]
+        pub struct $name {
+            round_keys: [[u8; BLOCK_SIZE]; $rounds + 1],
+        }
+
+        impl Drop for $name {
+            fn drop(&mut self) {
+                self.round_keys.zeroize();
+            }
+        }
+
+        impl ZeroizeOnDrop for $name {}
+
+        impl $name {
+            /// Create a new instance with the given key.
+            pub fn new(key: &[u8; $bits / 8]) -> $name {
+                $name {
+                    round_keys: expand_key(key),
+                }
+            }
+        }
+
+        impl BlockCipher for $name {
+            fn encrypt_block(&self, block: &mut [u8; BLOCK_SIZE]) {
+                encrypt_block(&self.round_keys, block);
+            }
+        }
+    };
+}
+
+define_aes!(
+    AES128,
+    128,
+    10,
+    r#"AES with a 128-bit key.
+
+# Example encrypting a single block, as in ECB mode
+```
+use hex_literal::hex;
+use peko_crypto::aes::{BlockCipher, AES128};
+
+let cipher = AES128::new(&hex!("000102030405060708090a0b0c0d0e0f"));
+let mut block = hex!("00112233445566778899aabbccddeeff");
+cipher.encrypt_block(&mut block);
+
+assert_eq!(block, hex!("69c4e0d86a7b0430d8cdb78070b4c55a"));
+```"#
+);
+define_aes!(AES256, 256, 14, "AES with a 256-bit key.");
@@ -0,0 +1,70 @@
+//! Counter mode, as specified in section 6.5 of
+//! [NIST SP 800-38A](https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38a.pdf).
+
+use crate::aes::{BlockCipher, BLOCK_SIZE};
+use crate::util::{Zeroize, ZeroizeOnDrop};
+
+/// A block cipher in counter mode, producing a keystream that is XOR-ed with the data.
+///
+/// Encryption and decryption are the same operation. The keystream continues across calls to
+/// [`Ctr::apply_keystream`], so data can be processed in pieces of any size. The whole initial
+/// counter block is incremented as a 128-bit big-endian integer.
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::aes::{Ctr, AES128};
+///
+/// let key = hex!("2b7e151628aed2a6abf7158809cf4f3c");
+/// let iv = hex!("f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff");
+///
+/// let mut data = hex!("6bc1bee22e409f96e93d7e117393172a");
+/// Ctr::new(AES128::new(&key), &iv).apply_keystream(&mut data);
+///
+/// assert_eq!(data, hex!("874d6191b620e3261bef6864990db6ce"));
+/// ```
+#[derive(Clone)]
+pub struct Ctr<C: BlockCipher> {
+    cipher: C,
+    counter: u128,
+    keystream: [u8; BLOCK_SIZE],
+    position: usize,
+}
+
+impl<C: BlockCipher> Ctr<C> {
+    /// Create a new instance, starting with `iv` as the initial counter block.
+    pub fn new(cipher: C, iv: &[u8; BLOCK_SIZE]) -> Ctr<C> {
+        Ctr {
+            cipher,
+            counter: u128::from_be_bytes(*iv),
+            keystream: [0u8; BLOCK_SIZE],
+            // No keystream is available until the first block is encrypted.
+            position: BLOCK_SIZE,
+        }
+    }
+
+    /// XOR the next bytes of the keystream with `data`, in place.
+    pub fn apply_keystream(&mut self, data: &mut [u8]) {
+        for byte in data.iter_mut() {
+            if self.position == BLOCK_SIZE {
+                self.keystream = self.counter.to_be_bytes();
+                self.cipher.encrypt_block(&mut self.keystream);
+                self.counter = self.counter.wrapping_add(1);
+                self.position = 0;
+            }
+
+            *byte ^= self.keystream[self.position];
+            self.position += 1;
+        }
+    }
+}
+
+impl<C: BlockCipher> Drop for Ctr<C> {
+    fn drop(&mut self) {
+        // The cipher zeroizes its own key; the keystream would reveal the rest of the message.
+        self.keystream.zeroize();
+    }
+}
+
+/// The cipher must also zeroize its key on drop.
+impl<C: BlockCipher + ZeroizeOnDrop> ZeroizeOnDrop for Ctr<C> {}
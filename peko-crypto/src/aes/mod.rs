@@ -0,0 +1,21 @@
+//! The AES block cipher, as specified in
+//! [FIPS 197](https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.197.pdf), and its modes of
+//! operation.
+//!
+//! The S-box is computed arithmetically instead of being looked up in a table, so the cipher
+//! makes no memory accesses that depend on the key or the data, and is not exposed to
+//! cache-timing attacks. This makes it slower than table-based implementations.
+mod cipher;
+mod ctr;
+
+pub use cipher::{AES128, AES256};
+pub use ctr::Ctr;
+
+/// Size of each block in bytes.
+pub const BLOCK_SIZE: usize = 128 / 8;
+
+/// Trait for a block cipher operating on 128-bit blocks.
+pub trait BlockCipher {
+    /// Encrypt a single block in place. On its own, this is encryption in ECB mode.
+    fn encrypt_block(&self, block: &mut [u8; BLOCK_SIZE]);
+}
@@ -0,0 +1,34 @@
+//! Errors returned by bignum operations.
+
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Result type returned by bignum operations.
+///
+/// In essence the Error type is fixed to [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors returned by bignum operations.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The modulus is so long that the gas cost of the operation saturates.
+    ModulusTooLong,
+
+    /// The string contains a character which is not a digit, or no digits at all.
+    InvalidDigit,
+
+    /// The integer doesn't fit in the target type.
+    Overflow,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::ModulusTooLong => f.write_str("modulus too long"),
+            Error::InvalidDigit => f.write_str("invalid digit"),
+            Error::Overflow => f.write_str("integer overflow"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,199 @@
+//! 256-bit two's complement signed integers with the semantics of the signed EVM instructions.
+
+use crate::bignum::U256;
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::Formatter;
+use std::ops::{Add, Div, Mul, Neg, Rem, Shr, Sub};
+
+/// A 256-bit signed integer: the bits of a [`U256`], interpreted in two's complement as the
+/// signed instructions of the EVM do.
+///
+/// Conversions from and to [`U256`] keep the bits as they are. Addition, subtraction and
+/// multiplication wrap and give the same bits as for [`U256`]; division rounds towards zero,
+/// the remainder has the sign of the dividend, and both give zero for a zero divisor. The
+/// quotient of [`I256::MIN`] by -1 wraps to [`I256::MIN`].
+///
+/// # Example
+/// ```
+/// use peko_crypto::bignum::{I256, U256};
+///
+/// let minus_seven = I256::from(-7i64);
+///
+/// assert_eq!(U256::from(minus_seven), U256::MAX - U256::from(6u64));
+/// assert_eq!(minus_seven / I256::from(2i64), I256::from(-3i64));
+/// assert_eq!(minus_seven % I256::from(2i64), I256::from(-1i64));
+/// assert_eq!(minus_seven >> 1, I256::from(-4i64));
+/// assert_eq!(I256::MIN / I256::MINUS_ONE, I256::MIN);
+/// assert!(minus_seven < I256::ZERO);
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct I256(U256);
+
+impl I256 {
+    pub const ZERO: I256 = I256(U256::ZERO);
+    pub const ONE: I256 = I256(U256::ONE);
+    pub const MINUS_ONE: I256 = I256(U256::MAX);
+    pub const MIN: I256 = I256(U256::from_limbs([0, 0, 0, 1 << 63]));
+    pub const MAX: I256 = I256(U256::from_limbs([
+        u64::MAX,
+        u64::MAX,
+        u64::MAX,
+        u64::MAX >> 1,
+    ]));
+
+    pub fn is_negative(&self) -> bool {
+        self.0.bit(255)
+    }
+
+    /// Return the absolute value as an unsigned integer, which holds 2^255 for [`I256::MIN`].
+    pub fn unsigned_abs(self) -> U256 {
+        if self.is_negative() {
+            (-self).0
+        } else {
+            self.0
+        }
+    }
+
+    /// Return the quotient and the remainder, which are both zero for a zero divisor: the
+    /// instructions `SDIV` and `SMOD`.
+    pub fn div_rem(self, divisor: I256) -> (I256, I256) {
+        let (quotient, remainder) = self.unsigned_abs().div_rem(divisor.unsigned_abs());
+        let (quotient, remainder) = (I256(quotient), I256(remainder));
+
+        (
+            if self.is_negative() != divisor.is_negative() {
+                -quotient
+            } else {
+                quotient
+            },
+            if self.is_negative() {
+                -remainder
+            } else {
+                remainder
+            },
+        )
+    }
+}
+
+impl From<U256> for I256 {
+    fn from(value: U256) -> I256 {
+        I256(value)
+    }
+}
+
+impl From<I256> for U256 {
+    fn from(value: I256) -> U256 {
+        value.0
+    }
+}
+
+impl From<i64> for I256 {
+    fn from(value: i64) -> I256 {
+        I256::from(value as i128)
+    }
+}
+
+impl From<i128> for I256 {
+    fn from(value: i128) -> I256 {
+        let magnitude = I256(U256::from(value.unsigned_abs()));
+        if value < 0 {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+impl PartialOrd for I256 {
+    fn partial_cmp(&self, other: &I256) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for I256 {
+    /// Compare as signed integers: the instructions `SLT` and `SGT`.
+    fn cmp(&self, other: &I256) -> Ordering {
+        other
+            .is_negative()
+            .cmp(&self.is_negative())
+            .then_with(|| self.0.cmp(&other.0))
+    }
+}
+
+impl Neg for I256 {
+    type Output = I256;
+
+    /// Negate, wrapping [`I256::MIN`] to itself.
+    fn neg(self) -> I256 {
+        I256(!self.0 + U256::ONE)
+    }
+}
+
+impl Add for I256 {
+    type Output = I256;
+
+    fn add(self, other: I256) -> I256 {
+        I256(self.0 + other.0)
+    }
+}
+
+impl Sub for I256 {
+    type Output = I256;
+
+    fn sub(self, other: I256) -> I256 {
+        I256(self.0 - other.0)
+    }
+}
+
+impl Mul for I256 {
+    type Output = I256;
+
+    fn mul(self, other: I256) -> I256 {
+        I256(self.0 * other.0)
+    }
+}
+
+impl Div for I256 {
+    type Output = I256;
+
+    fn div(self, other: I256) -> I256 {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem for I256 {
+    type Output = I256;
+
+    fn rem(self, other: I256) -> I256 {
+        self.div_rem(other).1
+    }
+}
+
+impl Shr<u32> for I256 {
+    type Output = I256;
+
+    /// Shift right, copying the sign bit: the instruction `SAR`. Shifts of 255 bits or more give
+    /// zero or -1.
+    fn shr(self, shift: u32) -> I256 {
+        if self.is_negative() {
+            I256(!(!self.0 >> shift))
+        } else {
+            I256(self.0 >> shift)
+        }
+    }
+}
+
+impl fmt::Display for I256 {
+    /// Format the integer in decimal.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad_integral(!self.is_negative(), "", &self.unsigned_abs().to_string())
+    }
+}
+
+impl fmt::Debug for I256 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "I256({})", self)
+    }
+}
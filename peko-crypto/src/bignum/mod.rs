@@ -0,0 +1,17 @@
+//! Arbitrary-precision arithmetic, the precompiled contract `MODEXP` built on it, and the
+//! 256-bit words of the EVM.
+//!
+//! Integers are converted from and to bytes with the same big-endian conventions as the
+//! integers of [`peko_rlp`], which [`BigUint`] and [`U256`] implement
+//! [`peko_rlp::MinBigEndian`] for.
+mod error;
+mod i256;
+mod modexp;
+mod u256;
+mod uint;
+
+pub use error::{Error, Result};
+pub use i256::I256;
+pub use modexp::{modexp, modexp_gas};
+pub use u256::U256;
+pub use uint::BigUint;
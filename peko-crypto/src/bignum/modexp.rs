@@ -0,0 +1,134 @@
+//! The precompiled contract `MODEXP`, with the gas pricing of EIP-2565.
+
+use crate::bignum::{BigUint, Error, Result};
+
+use peko_rlp::MinBigEndian;
+
+use std::convert::TryFrom;
+
+/// Size of each of the three length fields at the start of the input.
+const LENGTH_SIZE: usize = 32;
+
+/// Minimum gas cost of a call.
+const MIN_GAS: u64 = 200;
+
+/// Length of the longest modulus accepted by [`modexp`]. Any longer modulus has more than
+/// 2^33 64-bit words, which alone saturates the gas cost.
+const MAX_MODULUS_LENGTH: u64 = 1 << 36;
+
+/// Read `length` bytes of `input` starting at `offset`, right-padded with zeros.
+fn read_padded(input: &[u8], offset: u64, length: usize) -> Vec<u8> {
+    let mut output = vec![0u8; length];
+    if let Ok(offset) = usize::try_from(offset) {
+        if offset < input.len() {
+            let available = (input.len() - offset).min(length);
+            output[..available].copy_from_slice(&input[offset..offset + available]);
+        }
+    }
+    output
+}
+
+/// Read the lengths of the base, the exponent and the modulus, saturated to `u64::MAX`.
+fn read_lengths(input: &[u8]) -> (u64, u64, u64) {
+    let read_length = |index: usize| {
+        let bytes = read_padded(input, (index * LENGTH_SIZE) as u64, LENGTH_SIZE);
+        let start = bytes
+            .iter()
+            .position(|&byte| byte != 0)
+            .unwrap_or(LENGTH_SIZE);
+        u64::try_from_min_be(&bytes[start..]).unwrap_or(u64::MAX)
+    };
+
+    (read_length(0), read_length(1), read_length(2))
+}
+
+/// Compute `base^exponent mod modulus`: the precompiled contract `MODEXP` at address `0x05`,
+/// specified by [EIP-198](https://eips.ethereum.org/EIPS/eip-198).
+///
+/// The input is the lengths of the base, the exponent and the modulus as 32-byte big-endian
+/// integers, followed by the three operands as big-endian integers of these lengths. The input
+/// is right-padded with zeros as needed; anything beyond the operands is ignored. The output is
+/// the result as a big-endian integer of the same length as the modulus, which is zero if the
+/// modulus is zero.
+///
+/// The output is allocated with the length of the modulus, so the call must be paid for with
+/// [`modexp_gas`] first, which grows with it. Moduli whose cost saturates are rejected with
+/// [`Error::ModulusTooLong`]. The base and the exponent are never longer than the input when
+/// they are read: if they extend past it, so does the modulus, which is then zero.
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::bignum;
+///
+/// // 3^(p - 1) mod p, with p the field modulus of secp256k1.
+/// let input = hex!(
+///     "0000000000000000000000000000000000000000000000000000000000000001"
+///     "0000000000000000000000000000000000000000000000000000000000000020"
+///     "0000000000000000000000000000000000000000000000000000000000000020"
+///     "03"
+///     "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e"
+///     "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f"
+/// );
+///
+/// assert_eq!(
+///     bignum::modexp(&input).unwrap(),
+///     hex!("0000000000000000000000000000000000000000000000000000000000000001")
+/// );
+/// assert_eq!(bignum::modexp_gas(&input), 1360);
+/// ```
+pub fn modexp(input: &[u8]) -> Result<Vec<u8>> {
+    let (base_length, exponent_length, modulus_length) = read_lengths(input);
+    if modulus_length == 0 {
+        return Ok(vec![]);
+    }
+    if modulus_length > MAX_MODULUS_LENGTH {
+        return Err(Error::ModulusTooLong);
+    }
+
+    let base_offset = 3 * LENGTH_SIZE as u64;
+    let exponent_offset = base_offset.saturating_add(base_length);
+    let modulus_offset = exponent_offset.saturating_add(exponent_length);
+
+    let modulus_length = usize::try_from(modulus_length).map_err(|_| Error::ModulusTooLong)?;
+    let modulus = BigUint::from_be_bytes(&read_padded(input, modulus_offset, modulus_length));
+    if modulus.is_zero() {
+        return Ok(vec![0u8; modulus_length]);
+    }
+
+    // The modulus starts within the input, so both lengths are less than the input length.
+    let base = read_padded(input, base_offset, base_length as usize);
+    let exponent = read_padded(input, exponent_offset, exponent_length as usize);
+    let result = BigUint::from_be_bytes(&base).modpow(&BigUint::from_be_bytes(&exponent), &modulus);
+
+    Ok(result
+        .to_be_bytes_padded(modulus_length)
+        .expect("Result must be less than the modulus"))
+}
+
+/// Compute the gas cost of [`modexp`] for the same input, as specified by
+/// [EIP-2565](https://eips.ethereum.org/EIPS/eip-2565).
+///
+/// The cost is saturated to `u64::MAX`, which is more than any block can pay for.
+pub fn modexp_gas(input: &[u8]) -> u64 {
+    let (base_length, exponent_length, modulus_length) = read_lengths(input);
+
+    // The cost of one multiplication grows quadratically with the number of 64-bit words.
+    let max_length = base_length.max(modulus_length) as u128;
+    let words = max_length.div_ceil(8);
+    let multiplication_complexity = words * words;
+
+    // The number of squarings, from the position of the highest set bit in the exponent. Only
+    // its first 32 bytes are read; each byte after them counts for 8 squarings.
+    let exponent_offset = (3 * LENGTH_SIZE as u64).saturating_add(base_length);
+    let head_length = exponent_length.min(32) as usize;
+    let head = BigUint::from_be_bytes(&read_padded(input, exponent_offset, head_length));
+    let mut iteration_count = exponent_length.saturating_sub(32) as u128 * 8;
+    if head.bits() > 0 {
+        iteration_count += head.bits() as u128 - 1;
+    }
+    let iteration_count = iteration_count.max(1);
+
+    let gas = multiplication_complexity.saturating_mul(iteration_count) / 3;
+    u64::try_from(gas).unwrap_or(u64::MAX).max(MIN_GAS)
+}
@@ -0,0 +1,582 @@
+//! 256-bit unsigned integers with the wrapping semantics of the EVM.
+
+use crate::bignum::{BigUint, Error, Result};
+
+use peko_rlp::MinBigEndian;
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use std::cmp::Ordering;
+use std::convert::{TryFrom, TryInto};
+use std::fmt;
+use std::fmt::Formatter;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Not, Rem, Shl, Shr, Sub};
+use std::str::FromStr;
+
+/// Number of 64-bit limbs in a 256-bit integer.
+const LIMBS: usize = 4;
+
+/// The largest power of ten which fits in a limb, used to format and parse decimal digits.
+const TEN_POW_19: u64 = 10_000_000_000_000_000_000;
+
+/// A 256-bit unsigned integer, the word of the EVM.
+///
+/// Arithmetic follows the semantics of the EVM instead of those of the primitive integers:
+/// addition, subtraction, multiplication and exponentiation wrap modulo 2^256, division and
+/// remainder by zero give zero, and shifts by 256 bits or more give zero. [`I256`] interprets
+/// the same bits as a signed integer.
+///
+/// Formatted in decimal, or hexadecimal with `{:x}`. Serialized as a `0x`-prefixed hexadecimal
+/// string without leading zeros in human-readable formats such as JSON, and as a big-endian
+/// byte array without leading zeros otherwise, which is how RLP encodes integers.
+///
+/// [`I256`]: crate::bignum::I256
+///
+/// # Example
+/// ```
+/// use peko_crypto::bignum::U256;
+///
+/// let max = U256::MAX;
+///
+/// assert_eq!(max + U256::ONE, U256::ZERO);
+/// assert_eq!(U256::ZERO - U256::ONE, max);
+/// assert_eq!(max / U256::ZERO, U256::ZERO);
+/// assert_eq!(U256::from(2u64).pow(U256::from(255u64)) >> 255, U256::ONE);
+/// assert_eq!(
+///     max.to_string(),
+///     "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+/// );
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct U256([u64; LIMBS]);
+
+impl U256 {
+    pub const ZERO: U256 = U256([0; LIMBS]);
+    pub const ONE: U256 = U256([1, 0, 0, 0]);
+    pub const MAX: U256 = U256([u64::MAX; LIMBS]);
+
+    /// Create an integer from little-endian 64-bit limbs.
+    pub const fn from_limbs(limbs: [u64; LIMBS]) -> U256 {
+        U256(limbs)
+    }
+
+    /// Return the little-endian 64-bit limbs of the integer.
+    pub const fn as_limbs(&self) -> &[u64; LIMBS] {
+        &self.0
+    }
+
+    /// Decode a 32-byte big-endian integer.
+    pub fn from_be_bytes(bytes: [u8; 32]) -> U256 {
+        let mut limbs = [0u64; LIMBS];
+        for (limb, chunk) in limbs.iter_mut().rev().zip(bytes.chunks_exact(8)) {
+            *limb = u64::from_be_bytes(chunk.try_into().expect("Limb must be 8 bytes long"));
+        }
+        U256(limbs)
+    }
+
+    /// Encode to a 32-byte big-endian integer.
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (chunk, limb) in bytes.chunks_exact_mut(8).zip(self.0.iter().rev()) {
+            chunk.copy_from_slice(&limb.to_be_bytes());
+        }
+        bytes
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == [0; LIMBS]
+    }
+
+    /// Return the number of bits needed to represent the integer, which is zero for zero.
+    pub fn bits(&self) -> u32 {
+        256 - self.leading_zeros()
+    }
+
+    pub fn leading_zeros(&self) -> u32 {
+        let mut zeros = 0;
+        for limb in self.0.iter().rev() {
+            zeros += limb.leading_zeros();
+            if *limb != 0 {
+                break;
+            }
+        }
+        zeros
+    }
+
+    /// Return the bit at position `index`, counting from the least significant bit.
+    pub fn bit(&self, index: u32) -> bool {
+        index < 256 && (self.0[index as usize / 64] >> (index % 64)) & 1 == 1
+    }
+
+    /// Return the sum and whether it overflowed.
+    pub fn overflowing_add(self, other: U256) -> (U256, bool) {
+        let mut limbs = [0u64; LIMBS];
+        let mut carry = false;
+        for (limb, (a, b)) in limbs.iter_mut().zip(self.0.iter().zip(other.0.iter())) {
+            let (sum, carry_a) = a.overflowing_add(*b);
+            let (sum, carry_b) = sum.overflowing_add(carry as u64);
+            *limb = sum;
+            carry = carry_a || carry_b;
+        }
+        (U256(limbs), carry)
+    }
+
+    /// Return the difference and whether it underflowed.
+    pub fn overflowing_sub(self, other: U256) -> (U256, bool) {
+        let mut limbs = [0u64; LIMBS];
+        let mut borrow = false;
+        for (limb, (a, b)) in limbs.iter_mut().zip(self.0.iter().zip(other.0.iter())) {
+            let (difference, borrow_a) = a.overflowing_sub(*b);
+            let (difference, borrow_b) = difference.overflowing_sub(borrow as u64);
+            *limb = difference;
+            borrow = borrow_a || borrow_b;
+        }
+        (U256(limbs), borrow)
+    }
+
+    /// Return the product and whether it overflowed.
+    pub fn overflowing_mul(self, other: U256) -> (U256, bool) {
+        let mut limbs = [0u64; 2 * LIMBS];
+        for (i, &a) in self.0.iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, &b) in other.0.iter().enumerate() {
+                let product = a as u128 * b as u128 + limbs[i + j] as u128 + carry;
+                limbs[i + j] = product as u64;
+                carry = product >> 64;
+            }
+            limbs[i + LIMBS] = carry as u64;
+        }
+
+        let overflow = limbs[LIMBS..].iter().any(|&limb| limb != 0);
+        (
+            U256(limbs[..LIMBS].try_into().expect("Low half must be 4 limbs")),
+            overflow,
+        )
+    }
+
+    /// Return `self^exponent`, wrapping modulo 2^256: the instruction `EXP`.
+    pub fn pow(self, exponent: U256) -> U256 {
+        let mut result = U256::ONE;
+        for index in (0..exponent.bits()).rev() {
+            result = result * result;
+            if exponent.bit(index) {
+                result = result * self;
+            }
+        }
+        result
+    }
+
+    /// Return the quotient and the remainder, which are both zero for a zero divisor.
+    pub fn div_rem(self, divisor: U256) -> (U256, U256) {
+        if divisor.is_zero() {
+            return (U256::ZERO, U256::ZERO);
+        }
+        if self < divisor {
+            return (U256::ZERO, self);
+        }
+
+        let (quotient, remainder) = BigUint::from(self).div_rem(&BigUint::from(divisor));
+        (
+            U256::try_from(quotient).expect("Quotient must fit in 256 bits"),
+            U256::try_from(remainder).expect("Remainder must fit in 256 bits"),
+        )
+    }
+
+    /// Return `(self + other) mod modulus` without wrapping the sum, or zero for a zero
+    /// modulus: the instruction `ADDMOD`.
+    pub fn add_mod(self, other: U256, modulus: U256) -> U256 {
+        if modulus.is_zero() {
+            return U256::ZERO;
+        }
+
+        // Both terms are less than the modulus after reducing them, so the sum is less than
+        // twice the modulus, and subtracting it once is enough.
+        let (sum, overflow) = (self % modulus).overflowing_add(other % modulus);
+        if overflow || sum >= modulus {
+            sum - modulus
+        } else {
+            sum
+        }
+    }
+
+    /// Return `(self * other) mod modulus` without wrapping the product, or zero for a zero
+    /// modulus: the instruction `MULMOD`.
+    pub fn mul_mod(self, other: U256, modulus: U256) -> U256 {
+        if modulus.is_zero() {
+            return U256::ZERO;
+        }
+
+        let product = &BigUint::from(self) * &BigUint::from(other);
+        U256::try_from(&product % &BigUint::from(modulus)).expect("Remainder must fit in 256 bits")
+    }
+
+    /// Return the byte at position `index`, counting from the most significant byte, or zero if
+    /// `index` is 32 or more: the instruction `BYTE`.
+    pub fn byte(&self, index: usize) -> u8 {
+        if index >= 32 {
+            return 0;
+        }
+        self.to_be_bytes()[index]
+    }
+
+    /// Extend the sign of the two's complement integer in the `byte + 1` least significant
+    /// bytes to the whole word, or return the integer as is if `byte` is 31 or more: the
+    /// instruction `SIGNEXTEND`.
+    pub fn sign_extend(self, byte: usize) -> U256 {
+        if byte >= 31 {
+            return self;
+        }
+
+        let sign_bit = 8 * byte as u32 + 7;
+        let mask = (U256::ONE << (sign_bit + 1)) - U256::ONE;
+        if self.bit(sign_bit) {
+            self | !mask
+        } else {
+            self & mask
+        }
+    }
+
+    /// Multiply by a limb and add another, returning the carried limb.
+    fn mul_add_limb(self, factor: u64, term: u64) -> (U256, u64) {
+        let mut limbs = [0u64; LIMBS];
+        let mut carry = term as u128;
+        for (limb, &a) in limbs.iter_mut().zip(self.0.iter()) {
+            let product = a as u128 * factor as u128 + carry;
+            *limb = product as u64;
+            carry = product >> 64;
+        }
+        (U256(limbs), carry as u64)
+    }
+
+    /// Divide by a non-zero limb, returning the quotient and the remainder.
+    fn div_rem_limb(self, divisor: u64) -> (U256, u64) {
+        let mut limbs = [0u64; LIMBS];
+        let mut remainder = 0u128;
+        for (limb, &a) in limbs.iter_mut().zip(self.0.iter()).rev() {
+            let dividend = (remainder << 64) | a as u128;
+            *limb = (dividend / divisor as u128) as u64;
+            remainder = dividend % divisor as u128;
+        }
+        (U256(limbs), remainder as u64)
+    }
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &U256) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for U256 {
+    fn cmp(&self, other: &U256) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl Add for U256 {
+    type Output = U256;
+
+    /// Add, wrapping modulo 2^256.
+    fn add(self, other: U256) -> U256 {
+        self.overflowing_add(other).0
+    }
+}
+
+impl Sub for U256 {
+    type Output = U256;
+
+    /// Subtract, wrapping modulo 2^256.
+    fn sub(self, other: U256) -> U256 {
+        self.overflowing_sub(other).0
+    }
+}
+
+impl Mul for U256 {
+    type Output = U256;
+
+    /// Multiply, wrapping modulo 2^256.
+    fn mul(self, other: U256) -> U256 {
+        self.overflowing_mul(other).0
+    }
+}
+
+impl Div for U256 {
+    type Output = U256;
+
+    /// Divide, rounding down, with a zero divisor giving zero.
+    fn div(self, other: U256) -> U256 {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem for U256 {
+    type Output = U256;
+
+    /// Return the remainder of the division, with a zero divisor giving zero.
+    fn rem(self, other: U256) -> U256 {
+        self.div_rem(other).1
+    }
+}
+
+impl Not for U256 {
+    type Output = U256;
+
+    fn not(self) -> U256 {
+        U256([!self.0[0], !self.0[1], !self.0[2], !self.0[3]])
+    }
+}
+
+impl BitAnd for U256 {
+    type Output = U256;
+
+    fn bitand(self, other: U256) -> U256 {
+        let mut limbs = self.0;
+        limbs
+            .iter_mut()
+            .zip(other.0.iter())
+            .for_each(|(a, b)| *a &= b);
+        U256(limbs)
+    }
+}
+
+impl BitOr for U256 {
+    type Output = U256;
+
+    fn bitor(self, other: U256) -> U256 {
+        let mut limbs = self.0;
+        limbs
+            .iter_mut()
+            .zip(other.0.iter())
+            .for_each(|(a, b)| *a |= b);
+        U256(limbs)
+    }
+}
+
+impl BitXor for U256 {
+    type Output = U256;
+
+    fn bitxor(self, other: U256) -> U256 {
+        let mut limbs = self.0;
+        limbs
+            .iter_mut()
+            .zip(other.0.iter())
+            .for_each(|(a, b)| *a ^= b);
+        U256(limbs)
+    }
+}
+
+impl Shl<u32> for U256 {
+    type Output = U256;
+
+    /// Shift left, with shifts of 256 bits or more giving zero: the instruction `SHL`.
+    fn shl(self, shift: u32) -> U256 {
+        if shift >= 256 {
+            return U256::ZERO;
+        }
+
+        let (limb_shift, bit_shift) = ((shift / 64) as usize, shift % 64);
+        let mut limbs = [0u64; LIMBS];
+        for (index, limb) in limbs.iter_mut().enumerate().skip(limb_shift) {
+            *limb = self.0[index - limb_shift] << bit_shift;
+            if bit_shift > 0 && index > limb_shift {
+                *limb |= self.0[index - limb_shift - 1] >> (64 - bit_shift);
+            }
+        }
+        U256(limbs)
+    }
+}
+
+impl Shr<u32> for U256 {
+    type Output = U256;
+
+    /// Shift right, with shifts of 256 bits or more giving zero: the instruction `SHR`.
+    fn shr(self, shift: u32) -> U256 {
+        if shift >= 256 {
+            return U256::ZERO;
+        }
+
+        let (limb_shift, bit_shift) = ((shift / 64) as usize, shift % 64);
+        let mut limbs = [0u64; LIMBS];
+        for (index, limb) in limbs.iter_mut().enumerate().take(LIMBS - limb_shift) {
+            *limb = self.0[index + limb_shift] >> bit_shift;
+            if bit_shift > 0 && index + limb_shift + 1 < LIMBS {
+                *limb |= self.0[index + limb_shift + 1] << (64 - bit_shift);
+            }
+        }
+        U256(limbs)
+    }
+}
+
+impl From<u64> for U256 {
+    fn from(value: u64) -> U256 {
+        U256([value, 0, 0, 0])
+    }
+}
+
+impl From<u128> for U256 {
+    fn from(value: u128) -> U256 {
+        U256([value as u64, (value >> 64) as u64, 0, 0])
+    }
+}
+
+impl TryFrom<U256> for u64 {
+    type Error = Error;
+
+    fn try_from(value: U256) -> Result<u64> {
+        match value.0 {
+            [low, 0, 0, 0] => Ok(low),
+            _ => Err(Error::Overflow),
+        }
+    }
+}
+
+impl From<U256> for BigUint {
+    fn from(value: U256) -> BigUint {
+        BigUint::from_be_bytes(&value.to_be_bytes())
+    }
+}
+
+impl TryFrom<BigUint> for U256 {
+    type Error = Error;
+
+    fn try_from(value: BigUint) -> Result<U256> {
+        U256::try_from_min_be(&value.to_be_bytes()).ok_or(Error::Overflow)
+    }
+}
+
+impl MinBigEndian for U256 {
+    fn to_min_be(&self) -> Vec<u8> {
+        let bytes = self.to_be_bytes();
+        let start = bytes.iter().position(|&byte| byte != 0).unwrap_or(32);
+        bytes[start..].to_vec()
+    }
+
+    fn try_from_min_be(input: &[u8]) -> Option<U256> {
+        if input.len() > 32 {
+            return None;
+        }
+
+        let mut bytes = [0u8; 32];
+        bytes[32 - input.len()..].copy_from_slice(input);
+        Some(U256::from_be_bytes(bytes))
+    }
+}
+
+impl fmt::Display for U256 {
+    /// Format the integer in decimal.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // Split into groups of 19 digits, from the least significant one.
+        let mut groups = vec![];
+        let mut rest = *self;
+        loop {
+            let (quotient, group) = rest.div_rem_limb(TEN_POW_19);
+            groups.push(group);
+            rest = quotient;
+            if rest.is_zero() {
+                break;
+            }
+        }
+
+        let mut digits = groups
+            .pop()
+            .expect("There must be at least one group")
+            .to_string();
+        for group in groups.iter().rev() {
+            digits.push_str(&format!("{:019}", group));
+        }
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+impl fmt::LowerHex for U256 {
+    /// Format the integer as lowercase hexadecimal digits without leading zeros, with the `0x`
+    /// prefix only in the alternate form `{:#x}`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut digits = String::new();
+        for limb in self.0.iter().rev() {
+            if digits.is_empty() {
+                if *limb != 0 {
+                    digits = format!("{:x}", limb);
+                }
+            } else {
+                digits.push_str(&format!("{:016x}", limb));
+            }
+        }
+        if digits.is_empty() {
+            digits.push('0');
+        }
+        f.pad_integral(true, "0x", &digits)
+    }
+}
+
+impl fmt::Debug for U256 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "U256({})", self)
+    }
+}
+
+impl FromStr for U256 {
+    type Err = Error;
+
+    /// Parse `0x`-prefixed hexadecimal digits in either case, or decimal digits otherwise.
+    /// Leading zeros are accepted.
+    fn from_str(value: &str) -> Result<U256> {
+        let (digits, radix) = match value.strip_prefix("0x") {
+            Some(digits) => (digits, 16),
+            None => (value, 10),
+        };
+        if digits.is_empty() {
+            return Err(Error::InvalidDigit);
+        }
+
+        let mut result = U256::ZERO;
+        for digit in digits.chars() {
+            let digit = digit.to_digit(radix).ok_or(Error::InvalidDigit)?;
+            let (product, carry) = result.mul_add_limb(radix as u64, digit as u64);
+            if carry != 0 {
+                return Err(Error::Overflow);
+            }
+            result = product;
+        }
+        Ok(result)
+    }
+}
+
+impl Serialize for U256 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&format_args!("{:#x}", self))
+        } else {
+            serializer.serialize_bytes(&self.to_min_be())
+        }
+    }
+}
+
+/// Visitor accepting either a string or a big-endian byte array of at most 32 bytes.
+struct U256Visitor;
+
+impl<'de> Visitor<'de> for U256Visitor {
+    type Value = U256;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("a 256-bit unsigned integer")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> std::result::Result<U256, E> {
+        value.parse().map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> std::result::Result<U256, E> {
+        U256::try_from_min_be(bytes).ok_or_else(|| E::invalid_length(bytes.len(), &self))
+    }
+}
+
+impl<'de> Deserialize<'de> for U256 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<U256, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(U256Visitor)
+        } else {
+            deserializer.deserialize_bytes(U256Visitor)
+        }
+    }
+}
@@ -0,0 +1,321 @@
+//! Arbitrary-precision unsigned integers.
+
+use peko_rlp::MinBigEndian;
+
+use std::cmp::Ordering;
+use std::ops::{Div, Mul, Rem};
+
+/// An unsigned integer of arbitrary size.
+///
+/// Conversions from and to bytes follow the big-endian conventions of RLP integers: see
+/// [`MinBigEndian`].
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::bignum::BigUint;
+///
+/// let a = BigUint::from_be_bytes(&hex!("0123456789abcdef0123456789abcdef"));
+/// let b = BigUint::from_be_bytes(&hex!("fedcba9876543210"));
+///
+/// assert_eq!(&(&a * &b) / &b, a);
+/// assert_eq!((&a % &b).to_be_bytes(), hex!("7f598f328cc265bf"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    /// Little-endian 64-bit limbs, without trailing zero limbs. Zero has no limbs.
+    limbs: Vec<u64>,
+}
+
+impl BigUint {
+    /// Return zero.
+    pub fn zero() -> BigUint {
+        BigUint { limbs: vec![] }
+    }
+
+    /// Return one.
+    pub fn one() -> BigUint {
+        BigUint { limbs: vec![1] }
+    }
+
+    fn from_limbs(mut limbs: Vec<u64>) -> BigUint {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        BigUint { limbs }
+    }
+
+    /// Decode a big-endian integer of any length, possibly with leading zeros.
+    pub fn from_be_bytes(bytes: &[u8]) -> BigUint {
+        let limbs = bytes
+            .rchunks(8)
+            .map(|chunk| {
+                let mut limb = [0u8; 8];
+                limb[8 - chunk.len()..].copy_from_slice(chunk);
+                u64::from_be_bytes(limb)
+            })
+            .collect();
+        BigUint::from_limbs(limbs)
+    }
+
+    /// Encode to big-endian bytes, without leading zeros. Zero is encoded as an empty array.
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        let bytes: Vec<u8> = self
+            .limbs
+            .iter()
+            .rev()
+            .flat_map(|limb| limb.to_be_bytes())
+            .collect();
+
+        match bytes.iter().position(|&byte| byte != 0) {
+            Some(start) => bytes[start..].to_vec(),
+            None => vec![],
+        }
+    }
+
+    /// Encode to exactly `length` big-endian bytes, left-padded with zeros.
+    ///
+    /// Returns `None` if the integer doesn't fit.
+    pub fn to_be_bytes_padded(&self, length: usize) -> Option<Vec<u8>> {
+        let bytes = self.to_be_bytes();
+        if bytes.len() > length {
+            return None;
+        }
+
+        let mut padded = vec![0u8; length];
+        padded[length - bytes.len()..].copy_from_slice(&bytes);
+        Some(padded)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Return the number of bits needed to represent the integer, which is zero for zero.
+    pub fn bits(&self) -> usize {
+        match self.limbs.last() {
+            Some(last) => self.limbs.len() * 64 - last.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// Return the bit at position `index`, counting from the least significant bit.
+    pub fn bit(&self, index: usize) -> bool {
+        match self.limbs.get(index / 64) {
+            Some(limb) => (limb >> (index % 64)) & 1 == 1,
+            None => false,
+        }
+    }
+
+    /// Return `self^exponent mod modulus`.
+    ///
+    /// Panics if `modulus` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use peko_crypto::bignum::BigUint;
+    ///
+    /// let base = BigUint::from_be_bytes(&[3]);
+    /// let exponent = BigUint::from_be_bytes(&[200]);
+    /// let modulus = BigUint::from_be_bytes(&[1, 0, 1]);
+    ///
+    /// // 3 is a generator of the multiplicative group modulo the prime 65537.
+    /// assert_ne!(base.modpow(&exponent, &modulus), BigUint::one());
+    /// assert_eq!(
+    ///     base.modpow(&BigUint::from_be_bytes(&[1, 0, 0]), &modulus),
+    ///     BigUint::one()
+    /// );
+    /// ```
+    pub fn modpow(&self, exponent: &BigUint, modulus: &BigUint) -> BigUint {
+        assert!(!modulus.is_zero(), "Modulus must not be zero");
+
+        let base = self % modulus;
+        let mut result = &BigUint::one() % modulus;
+        for index in (0..exponent.bits()).rev() {
+            result = &(&result * &result) % modulus;
+            if exponent.bit(index) {
+                result = &(&result * &base) % modulus;
+            }
+        }
+        result
+    }
+
+    /// Shift left by less than 64 bits, into a new vector of limbs with one more limb.
+    fn shl_limbs(&self, shift: u32) -> Vec<u64> {
+        let mut limbs = Vec::with_capacity(self.limbs.len() + 1);
+        let mut carry = 0;
+        for &limb in &self.limbs {
+            limbs.push((limb << shift) | carry);
+            carry = if shift == 0 { 0 } else { limb >> (64 - shift) };
+        }
+        limbs.push(carry);
+        limbs
+    }
+
+    /// Divide by a single limb, returning the quotient and the remainder.
+    fn div_rem_limb(&self, divisor: u64) -> (BigUint, u64) {
+        let mut quotient = vec![0u64; self.limbs.len()];
+        let mut remainder = 0u128;
+        for (index, &limb) in self.limbs.iter().enumerate().rev() {
+            let dividend = (remainder << 64) | limb as u128;
+            quotient[index] = (dividend / divisor as u128) as u64;
+            remainder = dividend % divisor as u128;
+        }
+        (BigUint::from_limbs(quotient), remainder as u64)
+    }
+
+    /// Return the quotient and the remainder of the division by `divisor`.
+    ///
+    /// Panics if `divisor` is zero.
+    pub fn div_rem(&self, divisor: &BigUint) -> (BigUint, BigUint) {
+        assert!(!divisor.is_zero(), "Divisor must not be zero");
+
+        if self < divisor {
+            return (BigUint::zero(), self.clone());
+        }
+        if divisor.limbs.len() == 1 {
+            let (quotient, remainder) = self.div_rem_limb(divisor.limbs[0]);
+            return (quotient, BigUint::from_limbs(vec![remainder]));
+        }
+
+        // Algorithm D of Knuth, The Art of Computer Programming, volume 2, section 4.3.1. Both
+        // operands are normalized so that the top limb of the divisor has its top bit set, which
+        // makes each estimated quotient limb off by at most two.
+        let shift = divisor
+            .limbs
+            .last()
+            .expect("Divisor must not be zero")
+            .leading_zeros();
+        let mut u = self.shl_limbs(shift);
+        let mut v = divisor.shl_limbs(shift);
+        v.pop();
+
+        let n = v.len();
+        let mut quotient = vec![0u64; u.len() - n];
+        for j in (0..quotient.len()).rev() {
+            let numerator = ((u[j + n] as u128) << 64) | u[j + n - 1] as u128;
+            let mut q = numerator / v[n - 1] as u128;
+            let mut r = numerator % v[n - 1] as u128;
+            while q >> 64 != 0 || q * v[n - 2] as u128 > ((r << 64) | u[j + n - 2] as u128) {
+                q -= 1;
+                r += v[n - 1] as u128;
+                if r >> 64 != 0 {
+                    break;
+                }
+            }
+
+            // Subtract q * v from the current window of u.
+            let mut borrow = 0i128;
+            let mut carry = 0u128;
+            for i in 0..n {
+                let product = q * v[i] as u128 + carry;
+                carry = product >> 64;
+                let difference = u[i + j] as i128 - borrow - (product as u64) as i128;
+                u[i + j] = difference as u64;
+                borrow = (difference < 0) as i128;
+            }
+            let difference = u[j + n] as i128 - borrow - carry as i128;
+            u[j + n] = difference as u64;
+
+            // The estimate was one too large: add v back.
+            if difference < 0 {
+                q -= 1;
+                let mut carry = 0u128;
+                for i in 0..n {
+                    let sum = u[i + j] as u128 + v[i] as u128 + carry;
+                    u[i + j] = sum as u64;
+                    carry = sum >> 64;
+                }
+                u[j + n] = u[j + n].wrapping_add(carry as u64);
+            }
+
+            quotient[j] = q as u64;
+        }
+
+        // Undo the normalization of the remainder.
+        let mut remainder = vec![0u64; n];
+        for i in 0..n {
+            remainder[i] = if shift == 0 {
+                u[i]
+            } else {
+                (u[i] >> shift) | (u[i + 1] << (64 - shift))
+            };
+        }
+
+        (
+            BigUint::from_limbs(quotient),
+            BigUint::from_limbs(remainder),
+        )
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &BigUint) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &BigUint) -> Ordering {
+        // Without trailing zero limbs, a longer integer is a larger one.
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        if self.is_zero() || other.is_zero() {
+            return BigUint::zero();
+        }
+
+        let mut limbs = vec![0u64; self.limbs.len() + other.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, &b) in other.limbs.iter().enumerate() {
+                let product = a as u128 * b as u128 + limbs[i + j] as u128 + carry;
+                limbs[i + j] = product as u64;
+                carry = product >> 64;
+            }
+            limbs[i + other.limbs.len()] = carry as u64;
+        }
+        BigUint::from_limbs(limbs)
+    }
+}
+
+impl Div for &BigUint {
+    type Output = BigUint;
+
+    fn div(self, other: &BigUint) -> BigUint {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem for &BigUint {
+    type Output = BigUint;
+
+    fn rem(self, other: &BigUint) -> BigUint {
+        self.div_rem(other).1
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> BigUint {
+        BigUint::from_limbs(vec![value])
+    }
+}
+
+impl MinBigEndian for BigUint {
+    fn to_min_be(&self) -> Vec<u8> {
+        self.to_be_bytes()
+    }
+
+    /// Decode big-endian bytes. Any length fits, so this never fails.
+    fn try_from_min_be(input: &[u8]) -> Option<BigUint> {
+        Some(BigUint::from_be_bytes(input))
+    }
+}
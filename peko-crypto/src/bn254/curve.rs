@@ -0,0 +1,290 @@
+//! The groups G1 and G2: points on y^2 = x^3 + 3 over Fp, and on its sextic twist
+//! y^2 = x^3 + 3 / ξ over Fp2.
+
+use crate::bn254::fp::Fp;
+use crate::bn254::fp2::Fp2;
+use crate::bn254::{Error, Result};
+
+use std::convert::TryInto;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// The order r of G1 and G2, as little-endian 64-bit limbs.
+const ORDER: [u64; 4] = [
+    0x43e1f593f0000001,
+    0x2833e84879b97091,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+];
+
+/// Arithmetic shared by Fp and Fp2, over which the points of G1 and G2 are defined.
+pub(crate) trait Field:
+    Copy + Eq + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// The constant b of the curve equation y^2 = x^3 + b.
+    const B: Self;
+
+    fn is_zero(self) -> bool;
+    fn square(self) -> Self;
+    fn double(self) -> Self;
+    fn invert(self) -> Self;
+}
+
+impl Field for Fp {
+    const ZERO: Fp = Fp::ZERO;
+    const ONE: Fp = Fp::ONE;
+    const B: Fp = Fp::from_raw([3, 0, 0, 0]);
+
+    fn is_zero(self) -> bool {
+        self.is_zero()
+    }
+
+    fn square(self) -> Fp {
+        self.square()
+    }
+
+    fn double(self) -> Fp {
+        self.double()
+    }
+
+    fn invert(self) -> Fp {
+        self.invert()
+    }
+}
+
+impl Field for Fp2 {
+    const ZERO: Fp2 = Fp2::ZERO;
+    const ONE: Fp2 = Fp2::ONE;
+    // 3 / (9 + u).
+    const B: Fp2 = Fp2::new(
+        Fp::from_raw([
+            0x3267e6dc24a138e5,
+            0xb5b4c5e559dbefa3,
+            0x81be18991be06ac3,
+            0x2b149d40ceb8aaae,
+        ]),
+        Fp::from_raw([
+            0xe4a2bd0685c315d2,
+            0xa74fa084e52d1852,
+            0xcd2cafadeed8fdf4,
+            0x009713b03af0fed4,
+        ]),
+    );
+
+    fn is_zero(self) -> bool {
+        self.is_zero()
+    }
+
+    fn square(self) -> Fp2 {
+        self.square()
+    }
+
+    fn double(self) -> Fp2 {
+        self.double()
+    }
+
+    fn invert(self) -> Fp2 {
+        self.invert()
+    }
+}
+
+/// A point in affine coordinates, which is either the point at infinity or satisfies the curve
+/// equation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Affine<F> {
+    Infinity,
+    Point { x: F, y: F },
+}
+
+/// A point in Jacobian coordinates: (X, Y, Z) represents (X / Z^2, Y / Z^3), with Z = 0 for the
+/// point at infinity.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Jacobian<F> {
+    x: F,
+    y: F,
+    z: F,
+}
+
+pub(crate) type G1 = Affine<Fp>;
+pub(crate) type G2 = Affine<Fp2>;
+
+impl<F: Field> Affine<F> {
+    /// Create a point, checking that it is on the curve.
+    fn new(x: F, y: F) -> Result<Affine<F>> {
+        if y.square() != x.square() * x + F::B {
+            return Err(Error::PointNotOnCurve);
+        }
+
+        Ok(Affine::Point { x, y })
+    }
+
+    pub(crate) fn to_jacobian(self) -> Jacobian<F> {
+        match self {
+            Affine::Infinity => Jacobian {
+                x: F::ONE,
+                y: F::ONE,
+                z: F::ZERO,
+            },
+            Affine::Point { x, y } => Jacobian { x, y, z: F::ONE },
+        }
+    }
+}
+
+impl<F: Field> Neg for Affine<F> {
+    type Output = Affine<F>;
+
+    fn neg(self) -> Affine<F> {
+        match self {
+            Affine::Infinity => Affine::Infinity,
+            Affine::Point { x, y } => Affine::Point { x, y: -y },
+        }
+    }
+}
+
+impl<F: Field> Jacobian<F> {
+    pub(crate) fn is_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    pub(crate) fn to_affine(self) -> Affine<F> {
+        if self.is_infinity() {
+            return Affine::Infinity;
+        }
+
+        let z_inverse = self.z.invert();
+        let z_inverse_squared = z_inverse.square();
+        Affine::Point {
+            x: self.x * z_inverse_squared,
+            y: self.y * z_inverse_squared * z_inverse,
+        }
+    }
+
+    /// Return `2 * self`, with the formulas "dbl-2009-l" of the Explicit-Formulas Database.
+    pub(crate) fn double(&self) -> Jacobian<F> {
+        if self.is_infinity() {
+            return *self;
+        }
+
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = b.square();
+        let d = ((self.x + b).square() - a - c).double();
+        let e = a.double() + a;
+        let f = e.square();
+
+        let x = f - d.double();
+        Jacobian {
+            x,
+            y: e * (d - x) - c.double().double().double(),
+            z: (self.y * self.z).double(),
+        }
+    }
+
+    /// Return `self + other`, with the formulas "add-2007-bl" of the Explicit-Formulas Database.
+    pub(crate) fn add(&self, other: &Jacobian<F>) -> Jacobian<F> {
+        if self.is_infinity() {
+            return *other;
+        }
+        if other.is_infinity() {
+            return *self;
+        }
+
+        let z1z1 = self.z.square();
+        let z2z2 = other.z.square();
+        let u1 = self.x * z2z2;
+        let u2 = other.x * z1z1;
+        let s1 = self.y * other.z * z2z2;
+        let s2 = other.y * self.z * z1z1;
+
+        let h = u2 - u1;
+        let r = (s2 - s1).double();
+        if h.is_zero() {
+            // Same x coordinate: the points are either equal or opposite.
+            return if r.is_zero() {
+                self.double()
+            } else {
+                Affine::Infinity.to_jacobian()
+            };
+        }
+
+        let i = h.double().square();
+        let j = h * i;
+        let v = u1 * i;
+
+        let x = r.square() - j - v.double();
+        Jacobian {
+            x,
+            y: r * (v - x) - (s1 * j).double(),
+            z: ((self.z + other.z).square() - z1z1 - z2z2) * h,
+        }
+    }
+
+    /// Return `scalar * self`, with the scalar given as little-endian 64-bit limbs.
+    pub(crate) fn mul(&self, scalar: &[u64]) -> Jacobian<F> {
+        let mut result = Affine::Infinity.to_jacobian();
+        for limb in scalar.iter().rev() {
+            for bit in (0..64).rev() {
+                result = result.double();
+                if (limb >> bit) & 1 == 1 {
+                    result = result.add(self);
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Parse 32 big-endian bytes into an element of Fp.
+fn parse_fp(bytes: &[u8]) -> Result<Fp> {
+    Fp::from_bytes(
+        bytes
+            .try_into()
+            .expect("Field element must be 32 bytes long"),
+    )
+}
+
+impl G1 {
+    /// Parse a point encoded as `x || y`, with (0, 0) being the point at infinity.
+    pub(crate) fn from_bytes(bytes: &[u8; 64]) -> Result<G1> {
+        let x = parse_fp(&bytes[..32])?;
+        let y = parse_fp(&bytes[32..])?;
+
+        if x.is_zero() && y.is_zero() {
+            Ok(Affine::Infinity)
+        } else {
+            Affine::new(x, y)
+        }
+    }
+
+    pub(crate) fn to_bytes(self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        if let Affine::Point { x, y } = self {
+            bytes[..32].copy_from_slice(&x.to_bytes());
+            bytes[32..].copy_from_slice(&y.to_bytes());
+        }
+        bytes
+    }
+}
+
+impl G2 {
+    /// Parse a point encoded as `x.c1 || x.c0 || y.c1 || y.c0`, with (0, 0) being the point at
+    /// infinity. The point must also be in the subgroup of order r.
+    pub(crate) fn from_bytes(bytes: &[u8; 128]) -> Result<G2> {
+        let x = Fp2::new(parse_fp(&bytes[32..64])?, parse_fp(&bytes[..32])?);
+        let y = Fp2::new(parse_fp(&bytes[96..])?, parse_fp(&bytes[64..96])?);
+
+        if x.is_zero() && y.is_zero() {
+            return Ok(Affine::Infinity);
+        }
+
+        // Unlike G1, the twist has points outside of the subgroup of order r.
+        let point = Affine::new(x, y)?;
+        if !point.to_jacobian().mul(&ORDER).is_infinity() {
+            return Err(Error::PointNotInSubgroup);
+        }
+
+        Ok(point)
+    }
+}
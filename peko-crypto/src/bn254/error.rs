@@ -0,0 +1,38 @@
+//! Errors returned by alt_bn128 operations.
+
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Result type returned by alt_bn128 operations.
+///
+/// In essence the Error type is fixed to [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors returned by alt_bn128 operations. Precompiled contracts fail on any of them.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A coordinate is not less than the field modulus.
+    InvalidFieldElement,
+
+    /// The point is neither on the curve nor the point at infinity.
+    PointNotOnCurve,
+
+    /// The G2 point is on the twist, but not in the subgroup of the same order as G1.
+    PointNotInSubgroup,
+
+    /// The input of the pairing check is not a multiple of 192 bytes.
+    InvalidInputLength,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidFieldElement => f.write_str("invalid field element"),
+            Error::PointNotOnCurve => f.write_str("point not on curve"),
+            Error::PointNotInSubgroup => f.write_str("point not in subgroup"),
+            Error::InvalidInputLength => f.write_str("invalid input length"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
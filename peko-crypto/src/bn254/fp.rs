@@ -0,0 +1,241 @@
+//! The base field of alt_bn128, with elements kept in Montgomery form.
+
+use crate::bn254::{Error, Result};
+
+use std::convert::TryInto;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// The field modulus p, as little-endian 64-bit limbs.
+pub(crate) const MODULUS: [u64; 4] = [
+    0x3c208c16d87cfd47,
+    0x97816a916871ca8d,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+];
+
+/// -p^-1 mod 2^64, for Montgomery reduction.
+const INV: u64 = 0x87d20782e4866389;
+
+/// 2^512 mod p, for converting into Montgomery form.
+const R2: [u64; 4] = [
+    0xf32cfc5b538afa89,
+    0xb5e71911d44501fb,
+    0x47ab1eff0a417ff6,
+    0x06d89f71cab8351f,
+];
+
+/// 2^256 mod p, one in Montgomery form.
+const R: [u64; 4] = [
+    0xd35d438dc58f0d9d,
+    0x0a78eb28f5c70b3d,
+    0x666ea36f7879462c,
+    0x0e0a77c19a07df2f,
+];
+
+/// Return `a + b + carry` and the carry out.
+const fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let result = a as u128 + b as u128 + carry as u128;
+    (result as u64, (result >> 64) as u64)
+}
+
+/// Return `a - b - borrow` and the borrow out, which is 1 on underflow.
+const fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let result = (a as u128).wrapping_sub(b as u128 + borrow as u128);
+    (result as u64, (result >> 127) as u64)
+}
+
+/// Return `a + b * c + carry` and the carry out.
+const fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let result = a as u128 + (b as u128) * (c as u128) + carry as u128;
+    (result as u64, (result >> 64) as u64)
+}
+
+/// Subtract the modulus if `value` is not less than it. `value` must be less than 2p.
+const fn subtract_modulus(value: [u64; 4]) -> [u64; 4] {
+    let mut result = [0u64; 4];
+    let mut borrow = 0;
+    let mut i = 0;
+    while i < 4 {
+        let (limb, b) = sbb(value[i], MODULUS[i], borrow);
+        result[i] = limb;
+        borrow = b;
+        i += 1;
+    }
+
+    if borrow == 0 {
+        result
+    } else {
+        value
+    }
+}
+
+/// Montgomery multiplication: `a * b / 2^256 mod p`, with the coarsely integrated operand
+/// scanning method.
+const fn montgomery_mul(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let mut t = [0u64; 6];
+
+    let mut i = 0;
+    while i < 4 {
+        let mut carry = 0;
+        let mut j = 0;
+        while j < 4 {
+            let (limb, c) = mac(t[j], a[j], b[i], carry);
+            t[j] = limb;
+            carry = c;
+            j += 1;
+        }
+        let (limb, c) = adc(t[4], carry, 0);
+        t[4] = limb;
+        t[5] = c;
+
+        // Add a multiple of the modulus making the lowest limb zero, then shift it out.
+        let m = t[0].wrapping_mul(INV);
+        let (_, mut carry) = mac(t[0], m, MODULUS[0], 0);
+        let mut j = 1;
+        while j < 4 {
+            let (limb, c) = mac(t[j], m, MODULUS[j], carry);
+            t[j - 1] = limb;
+            carry = c;
+            j += 1;
+        }
+        let (limb, c) = adc(t[4], carry, 0);
+        t[3] = limb;
+        t[4] = t[5] + c;
+
+        i += 1;
+    }
+
+    // The modulus is less than 2^254, so the result is less than 2p and fits in 4 limbs.
+    subtract_modulus([t[0], t[1], t[2], t[3]])
+}
+
+/// An element of the base field, in Montgomery form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Fp([u64; 4]);
+
+impl Fp {
+    pub(crate) const ZERO: Fp = Fp([0; 4]);
+    pub(crate) const ONE: Fp = Fp(R);
+
+    /// Create an element from an integer less than the modulus, as little-endian limbs.
+    pub(crate) const fn from_raw(limbs: [u64; 4]) -> Fp {
+        Fp(montgomery_mul(&limbs, &R2))
+    }
+
+    /// Parse a 32-byte big-endian integer, which must be less than the modulus.
+    pub(crate) fn from_bytes(bytes: &[u8; 32]) -> Result<Fp> {
+        let mut limbs = [0u64; 4];
+        for (limb, word) in limbs.iter_mut().rev().zip(bytes.chunks_exact(8)) {
+            *limb = u64::from_be_bytes(word.try_into().expect("Limb must be 8 bytes long"));
+        }
+
+        if subtract_modulus(limbs) != limbs {
+            return Err(Error::InvalidFieldElement);
+        }
+
+        Ok(Fp::from_raw(limbs))
+    }
+
+    /// Return the 32-byte big-endian representation.
+    pub(crate) fn to_bytes(self) -> [u8; 32] {
+        let limbs = montgomery_mul(&self.0, &[1, 0, 0, 0]);
+
+        let mut bytes = [0u8; 32];
+        for (word, limb) in bytes.chunks_exact_mut(8).zip(limbs.iter().rev()) {
+            word.copy_from_slice(&limb.to_be_bytes());
+        }
+        bytes
+    }
+
+    pub(crate) fn is_zero(self) -> bool {
+        self == Fp::ZERO
+    }
+
+    pub(crate) fn square(self) -> Fp {
+        self * self
+    }
+
+    pub(crate) fn double(self) -> Fp {
+        self + self
+    }
+
+    /// Raise to the power `exponent`, given as little-endian limbs.
+    pub(crate) fn pow(self, exponent: &[u64]) -> Fp {
+        let mut result = Fp::ONE;
+        for limb in exponent.iter().rev() {
+            for bit in (0..64).rev() {
+                result = result.square();
+                if (limb >> bit) & 1 == 1 {
+                    result = result * self;
+                }
+            }
+        }
+        result
+    }
+
+    /// Return the multiplicative inverse, or zero for zero.
+    pub(crate) fn invert(self) -> Fp {
+        // Fermat's little theorem: a^(p - 2) = a^-1.
+        let (exponent, _) = sbb(MODULUS[0], 2, 0);
+        self.pow(&[exponent, MODULUS[1], MODULUS[2], MODULUS[3]])
+    }
+}
+
+impl Add for Fp {
+    type Output = Fp;
+
+    fn add(self, other: Fp) -> Fp {
+        let mut result = [0u64; 4];
+        let mut carry = 0;
+        for (i, limb) in result.iter_mut().enumerate() {
+            let (sum, c) = adc(self.0[i], other.0[i], carry);
+            *limb = sum;
+            carry = c;
+        }
+
+        // Both operands are less than p < 2^254, so the sum never carries out.
+        Fp(subtract_modulus(result))
+    }
+}
+
+impl Sub for Fp {
+    type Output = Fp;
+
+    fn sub(self, other: Fp) -> Fp {
+        let mut result = [0u64; 4];
+        let mut borrow = 0;
+        for (i, limb) in result.iter_mut().enumerate() {
+            let (difference, b) = sbb(self.0[i], other.0[i], borrow);
+            *limb = difference;
+            borrow = b;
+        }
+
+        // Add the modulus back on underflow.
+        if borrow == 1 {
+            let mut carry = 0;
+            for (limb, modulus) in result.iter_mut().zip(MODULUS.iter()) {
+                let (sum, c) = adc(*limb, *modulus, carry);
+                *limb = sum;
+                carry = c;
+            }
+        }
+
+        Fp(result)
+    }
+}
+
+impl Mul for Fp {
+    type Output = Fp;
+
+    fn mul(self, other: Fp) -> Fp {
+        Fp(montgomery_mul(&self.0, &other.0))
+    }
+}
+
+impl Neg for Fp {
+    type Output = Fp;
+
+    fn neg(self) -> Fp {
+        Fp::ZERO - self
+    }
+}
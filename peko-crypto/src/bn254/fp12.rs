@@ -0,0 +1,192 @@
+//! The quadratic extension Fp12 = Fp6[w] / (w^2 - v), the target group of the pairing.
+
+use crate::bn254::fp::Fp;
+use crate::bn254::fp2::Fp2;
+use crate::bn254::fp6::Fp6;
+
+use std::ops::Mul;
+
+/// The curve parameter x, from which the field modulus and the group order are derived.
+pub(crate) const X: u64 = 4965661367192848881;
+
+/// ξ^(k (p - 1) / 6) for k from 1 to 5: the factor by which the Frobenius endomorphism
+/// multiplies the coefficient of w^k, after conjugating it.
+pub(crate) const FROBENIUS_COEFFICIENTS: [Fp2; 5] = [
+    Fp2::new(
+        Fp::from_raw([
+            0xd60b35dadcc9e470,
+            0x5c521e08292f2176,
+            0xe8b99fdd76e68b60,
+            0x1284b71c2865a7df,
+        ]),
+        Fp::from_raw([
+            0xca5cf05f80f362ac,
+            0x747992778eeec7e5,
+            0xa6327cfe12150b8e,
+            0x246996f3b4fae7e6,
+        ]),
+    ),
+    Fp2::new(
+        Fp::from_raw([
+            0x99e39557176f553d,
+            0xb78cc310c2c3330c,
+            0x4c0bec3cf559b143,
+            0x2fb347984f7911f7,
+        ]),
+        Fp::from_raw([
+            0x1665d51c640fcba2,
+            0x32ae2a1d0b7c9dce,
+            0x4ba4cc8bd75a0794,
+            0x16c9e55061ebae20,
+        ]),
+    ),
+    Fp2::new(
+        Fp::from_raw([
+            0xdc54014671a0135a,
+            0xdbaae0eda9c95998,
+            0xdc5ec698b6e2f9b9,
+            0x063cf305489af5dc,
+        ]),
+        Fp::from_raw([
+            0x82d37f632623b0e3,
+            0x21807dc98fa25bd2,
+            0x0704b5a7ec796f2b,
+            0x07c03cbcac41049a,
+        ]),
+    ),
+    Fp2::new(
+        Fp::from_raw([
+            0x848a1f55921ea762,
+            0xd33365f7be94ec72,
+            0x80f3c0b75a181e84,
+            0x05b54f5e64eea801,
+        ]),
+        Fp::from_raw([
+            0xc13b4711cd2b8126,
+            0x3685d2ea1bdec763,
+            0x9f3a80b03b0b1c92,
+            0x2c145edbe7fd8aee,
+        ]),
+    ),
+    Fp2::new(
+        Fp::from_raw([
+            0x2ea2c810eab7692f,
+            0x425c459b55aa1bd3,
+            0xe93a3661a4353ff4,
+            0x0183c1e74f798649,
+        ]),
+        Fp::from_raw([
+            0x24c6b8ee6e0c2c4b,
+            0xb080cb99678e2ac0,
+            0xa27fb246c7729f7d,
+            0x12acf2ca76fd0675,
+        ]),
+    ),
+];
+
+/// An element `c0 + c1 * w` of Fp12.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Fp12 {
+    pub(crate) c0: Fp6,
+    pub(crate) c1: Fp6,
+}
+
+impl Fp12 {
+    pub(crate) const ONE: Fp12 = Fp12::new(Fp6::ONE, Fp6::ZERO);
+
+    pub(crate) const fn new(c0: Fp6, c1: Fp6) -> Fp12 {
+        Fp12 { c0, c1 }
+    }
+
+    pub(crate) fn square(self) -> Fp12 {
+        self * self
+    }
+
+    /// Return the conjugate `c0 - c1 * w`, which is `self^(p^6)`. For elements of the
+    /// cyclotomic subgroup, such as the results of the easy part of the final exponentiation,
+    /// it is also the inverse.
+    pub(crate) fn conjugate(self) -> Fp12 {
+        Fp12::new(self.c0, -self.c1)
+    }
+
+    /// Return the multiplicative inverse, or zero for zero.
+    pub(crate) fn invert(self) -> Fp12 {
+        // 1 / (c0 + c1 w) = (c0 - c1 w) / (c0^2 - c1^2 v).
+        let norm = (self.c0.square() - self.c1.square().mul_by_nonresidue()).invert();
+        Fp12::new(self.c0 * norm, -(self.c1 * norm))
+    }
+
+    /// Return `self^p`.
+    pub(crate) fn frobenius(self) -> Fp12 {
+        let [w1, w2, w3, w4, w5] = FROBENIUS_COEFFICIENTS;
+
+        Fp12::new(
+            Fp6::new(
+                self.c0.c0.conjugate(),
+                self.c0.c1.conjugate() * w2,
+                self.c0.c2.conjugate() * w4,
+            ),
+            Fp6::new(
+                self.c1.c0.conjugate() * w1,
+                self.c1.c1.conjugate() * w3,
+                self.c1.c2.conjugate() * w5,
+            ),
+        )
+    }
+
+    /// Return `self^-x` for an element of the cyclotomic subgroup.
+    fn pow_negative_x(self) -> Fp12 {
+        let mut result = Fp12::ONE;
+        for bit in (0..64 - X.leading_zeros()).rev() {
+            result = result.square();
+            if (X >> bit) & 1 == 1 {
+                result = result * self;
+            }
+        }
+        result.conjugate()
+    }
+
+    /// Raise the output of the Miller loop to the power `2x(6x^2 + 3x + 1) (p^12 - 1) / r`,
+    /// mapping it to the group of r-th roots of unity.
+    ///
+    /// The extra factor is coprime to r, so the result is one exactly when the reduced pairing
+    /// is, and the pairing stays bilinear and non-degenerate.
+    pub(crate) fn final_exponentiation(self) -> Fp12 {
+        // Easy part: f^((p^6 - 1)(p^2 + 1)).
+        let f = self.conjugate() * self.invert();
+        let f = f.frobenius().frobenius() * f;
+
+        // Hard part: f^(2x(6x^2 + 3x + 1) (p^4 - p^2 + 1) / r). Expanded in base p, the
+        // exponent has coefficients that are small polynomials in x, so it only takes three
+        // exponentiations by x.
+        let a = f.pow_negative_x();
+        let b = a.square();
+        let c = b.square();
+        let d = c * b;
+        let e = d.pow_negative_x();
+        let g = e.square().pow_negative_x();
+        let k = g.conjugate() * e * d.conjugate();
+        let l = k * b;
+        let m = k * e;
+        let n = f * m;
+        let o = l.frobenius() * n;
+        let q = k.frobenius().frobenius() * o;
+        let t = f.conjugate() * l;
+        t.frobenius().frobenius().frobenius() * q
+    }
+}
+
+impl Mul for Fp12 {
+    type Output = Fp12;
+
+    fn mul(self, other: Fp12) -> Fp12 {
+        // Karatsuba, with w^2 = v.
+        let t0 = self.c0 * other.c0;
+        let t1 = self.c1 * other.c1;
+
+        Fp12::new(
+            t0 + t1.mul_by_nonresidue(),
+            (self.c0 + self.c1) * (other.c0 + other.c1) - t0 - t1,
+        )
+    }
+}
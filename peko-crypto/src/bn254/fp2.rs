@@ -0,0 +1,99 @@
+//! The quadratic extension Fp2 = Fp[u] / (u^2 + 1).
+
+use crate::bn254::fp::Fp;
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// An element `c0 + c1 * u` of Fp2.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Fp2 {
+    pub(crate) c0: Fp,
+    pub(crate) c1: Fp,
+}
+
+impl Fp2 {
+    pub(crate) const ZERO: Fp2 = Fp2::new(Fp::ZERO, Fp::ZERO);
+    pub(crate) const ONE: Fp2 = Fp2::new(Fp::ONE, Fp::ZERO);
+
+    pub(crate) const fn new(c0: Fp, c1: Fp) -> Fp2 {
+        Fp2 { c0, c1 }
+    }
+
+    pub(crate) fn is_zero(self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+
+    pub(crate) fn square(self) -> Fp2 {
+        // (c0 + c1 u)^2 = (c0 + c1)(c0 - c1) + 2 c0 c1 u.
+        Fp2::new(
+            (self.c0 + self.c1) * (self.c0 - self.c1),
+            (self.c0 * self.c1).double(),
+        )
+    }
+
+    pub(crate) fn double(self) -> Fp2 {
+        self + self
+    }
+
+    /// Return the conjugate `c0 - c1 * u`, which is also the Frobenius endomorphism.
+    pub(crate) fn conjugate(self) -> Fp2 {
+        Fp2::new(self.c0, -self.c1)
+    }
+
+    /// Multiply by the non-residue ξ = 9 + u defining Fp6.
+    pub(crate) fn mul_by_nonresidue(self) -> Fp2 {
+        let nine_c0 = self.c0.double().double().double() + self.c0;
+        let nine_c1 = self.c1.double().double().double() + self.c1;
+        Fp2::new(nine_c0 - self.c1, self.c0 + nine_c1)
+    }
+
+    /// Multiply both coefficients by an element of the base field.
+    pub(crate) fn scale(self, factor: Fp) -> Fp2 {
+        Fp2::new(self.c0 * factor, self.c1 * factor)
+    }
+
+    /// Return the multiplicative inverse, or zero for zero.
+    pub(crate) fn invert(self) -> Fp2 {
+        // 1 / (c0 + c1 u) = (c0 - c1 u) / (c0^2 + c1^2).
+        let norm = (self.c0.square() + self.c1.square()).invert();
+        Fp2::new(self.c0 * norm, -(self.c1 * norm))
+    }
+}
+
+impl Add for Fp2 {
+    type Output = Fp2;
+
+    fn add(self, other: Fp2) -> Fp2 {
+        Fp2::new(self.c0 + other.c0, self.c1 + other.c1)
+    }
+}
+
+impl Sub for Fp2 {
+    type Output = Fp2;
+
+    fn sub(self, other: Fp2) -> Fp2 {
+        Fp2::new(self.c0 - other.c0, self.c1 - other.c1)
+    }
+}
+
+impl Mul for Fp2 {
+    type Output = Fp2;
+
+    fn mul(self, other: Fp2) -> Fp2 {
+        // Karatsuba, with u^2 = -1.
+        let v0 = self.c0 * other.c0;
+        let v1 = self.c1 * other.c1;
+        Fp2::new(
+            v0 - v1,
+            (self.c0 + self.c1) * (other.c0 + other.c1) - v0 - v1,
+        )
+    }
+}
+
+impl Neg for Fp2 {
+    type Output = Fp2;
+
+    fn neg(self) -> Fp2 {
+        Fp2::new(-self.c0, -self.c1)
+    }
+}
@@ -0,0 +1,82 @@
+//! The cubic extension Fp6 = Fp2[v] / (v^3 - ξ), with ξ = 9 + u.
+
+use crate::bn254::fp2::Fp2;
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// An element `c0 + c1 * v + c2 * v^2` of Fp6.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Fp6 {
+    pub(crate) c0: Fp2,
+    pub(crate) c1: Fp2,
+    pub(crate) c2: Fp2,
+}
+
+impl Fp6 {
+    pub(crate) const ZERO: Fp6 = Fp6::new(Fp2::ZERO, Fp2::ZERO, Fp2::ZERO);
+    pub(crate) const ONE: Fp6 = Fp6::new(Fp2::ONE, Fp2::ZERO, Fp2::ZERO);
+
+    pub(crate) const fn new(c0: Fp2, c1: Fp2, c2: Fp2) -> Fp6 {
+        Fp6 { c0, c1, c2 }
+    }
+
+    pub(crate) fn square(self) -> Fp6 {
+        self * self
+    }
+
+    /// Multiply by `v`, the non-residue defining Fp12.
+    pub(crate) fn mul_by_nonresidue(self) -> Fp6 {
+        Fp6::new(self.c2.mul_by_nonresidue(), self.c0, self.c1)
+    }
+
+    /// Return the multiplicative inverse, or zero for zero.
+    pub(crate) fn invert(self) -> Fp6 {
+        let a = self.c0.square() - (self.c1 * self.c2).mul_by_nonresidue();
+        let b = self.c2.square().mul_by_nonresidue() - self.c0 * self.c1;
+        let c = self.c1.square() - self.c0 * self.c2;
+
+        let norm = (self.c0 * a + (self.c2 * b + self.c1 * c).mul_by_nonresidue()).invert();
+        Fp6::new(a * norm, b * norm, c * norm)
+    }
+}
+
+impl Add for Fp6 {
+    type Output = Fp6;
+
+    fn add(self, other: Fp6) -> Fp6 {
+        Fp6::new(self.c0 + other.c0, self.c1 + other.c1, self.c2 + other.c2)
+    }
+}
+
+impl Sub for Fp6 {
+    type Output = Fp6;
+
+    fn sub(self, other: Fp6) -> Fp6 {
+        Fp6::new(self.c0 - other.c0, self.c1 - other.c1, self.c2 - other.c2)
+    }
+}
+
+impl Mul for Fp6 {
+    type Output = Fp6;
+
+    fn mul(self, other: Fp6) -> Fp6 {
+        // Karatsuba, with v^3 = ξ.
+        let t0 = self.c0 * other.c0;
+        let t1 = self.c1 * other.c1;
+        let t2 = self.c2 * other.c2;
+
+        Fp6::new(
+            t0 + ((self.c1 + self.c2) * (other.c1 + other.c2) - t1 - t2).mul_by_nonresidue(),
+            (self.c0 + self.c1) * (other.c0 + other.c1) - t0 - t1 + t2.mul_by_nonresidue(),
+            (self.c0 + self.c2) * (other.c0 + other.c2) - t0 - t2 + t1,
+        )
+    }
+}
+
+impl Neg for Fp6 {
+    type Output = Fp6;
+
+    fn neg(self) -> Fp6 {
+        Fp6::new(-self.c0, -self.c1, -self.c2)
+    }
+}
@@ -0,0 +1,155 @@
+//! Arithmetic on the alt_bn128 (also known as BN254) pairing-friendly curve, for the precompiled
+//! contracts of [EIP-196](https://eips.ethereum.org/EIPS/eip-196) and
+//! [EIP-197](https://eips.ethereum.org/EIPS/eip-197).
+//!
+//! The functions here take and return the exact byte encodings of the precompiled contracts.
+//! Field elements are 32-byte big-endian integers, which must be less than the field modulus. A
+//! G1 point is `x || y`, and a G2 point is `x.c1 || x.c0 || y.c1 || y.c0` with `c0 + c1 * u`
+//! an element of Fp2 = Fp[u] / (u^2 + 1). The point at infinity is encoded as all zeros.
+mod curve;
+mod error;
+mod fp;
+mod fp12;
+mod fp2;
+mod fp6;
+mod pairing;
+
+pub use error::{Error, Result};
+
+use curve::{G1, G2};
+
+use std::convert::TryInto;
+
+/// Size of an encoded G1 point.
+const G1_SIZE: usize = 64;
+
+/// Size of an encoded G2 point.
+const G2_SIZE: usize = 128;
+
+/// Copy `input` into an array of `N` bytes, padded with zeros or truncated.
+fn padded<const N: usize>(input: &[u8]) -> [u8; N] {
+    let mut result = [0u8; N];
+    let length = input.len().min(N);
+    result[..length].copy_from_slice(&input[..length]);
+    result
+}
+
+/// Add two G1 points: the precompiled contract `ECADD` at address `0x06`.
+///
+/// The input is two encoded points, right-padded with zeros to 128 bytes; anything beyond is
+/// ignored. The output is the encoded sum.
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::bn254;
+///
+/// // The generator (1, 2) added to itself.
+/// let input = hex!(
+///     "0000000000000000000000000000000000000000000000000000000000000001"
+///     "0000000000000000000000000000000000000000000000000000000000000002"
+///     "0000000000000000000000000000000000000000000000000000000000000001"
+///     "0000000000000000000000000000000000000000000000000000000000000002"
+/// );
+///
+/// assert_eq!(
+///     bn254::add(&input).unwrap()[..],
+///     hex!(
+///         "030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3"
+///         "15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4"
+///     )[..]
+/// );
+/// ```
+pub fn add(input: &[u8]) -> Result<[u8; 64]> {
+    let input: [u8; 2 * G1_SIZE] = padded(input);
+    let a = G1::from_bytes(
+        input[..G1_SIZE]
+            .try_into()
+            .expect("Point must be 64 bytes long"),
+    )?;
+    let b = G1::from_bytes(
+        input[G1_SIZE..]
+            .try_into()
+            .expect("Point must be 64 bytes long"),
+    )?;
+
+    Ok(a.to_jacobian().add(&b.to_jacobian()).to_affine().to_bytes())
+}
+
+/// Multiply a G1 point by a scalar: the precompiled contract `ECMUL` at address `0x07`.
+///
+/// The input is an encoded point followed by a 32-byte big-endian scalar, right-padded with
+/// zeros to 96 bytes; anything beyond is ignored. The scalar may be greater than the order of
+/// the group. The output is the encoded product.
+pub fn mul(input: &[u8]) -> Result<[u8; 64]> {
+    let input: [u8; G1_SIZE + 32] = padded(input);
+    let point = G1::from_bytes(
+        input[..G1_SIZE]
+            .try_into()
+            .expect("Point must be 64 bytes long"),
+    )?;
+
+    let mut scalar = [0u64; 4];
+    for (limb, bytes) in scalar
+        .iter_mut()
+        .rev()
+        .zip(input[G1_SIZE..].chunks_exact(8))
+    {
+        *limb = u64::from_be_bytes(bytes.try_into().expect("Limb must be 8 bytes long"));
+    }
+
+    Ok(point.to_jacobian().mul(&scalar).to_affine().to_bytes())
+}
+
+/// Check whether the product of the pairings of pairs of points is one: the precompiled
+/// contract `ECPAIRING` at address `0x08`.
+///
+/// The input is a sequence of pairs, each an encoded G1 point followed by an encoded G2 point,
+/// so its length must be a multiple of 192 bytes. G2 points must be in the subgroup of order
+/// r. The output is 1 if the check succeeds and 0 otherwise, as a 32-byte big-endian integer;
+/// an empty input succeeds.
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::bn254;
+///
+/// // e(G1, G2) is not one.
+/// let input = hex!(
+///     "0000000000000000000000000000000000000000000000000000000000000001"
+///     "0000000000000000000000000000000000000000000000000000000000000002"
+///     "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"
+///     "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"
+///     "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"
+///     "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"
+/// );
+///
+/// assert_eq!(bn254::pairing(&input).unwrap(), [0u8; 32]);
+/// assert_eq!(bn254::pairing(&[]).unwrap()[31], 1);
+/// ```
+pub fn pairing(input: &[u8]) -> Result<[u8; 32]> {
+    if !input.len().is_multiple_of(G1_SIZE + G2_SIZE) {
+        return Err(Error::InvalidInputLength);
+    }
+
+    let pairs = input
+        .chunks_exact(G1_SIZE + G2_SIZE)
+        .map(|pair| {
+            let p = G1::from_bytes(
+                pair[..G1_SIZE]
+                    .try_into()
+                    .expect("Point must be 64 bytes long"),
+            )?;
+            let q = G2::from_bytes(
+                pair[G1_SIZE..]
+                    .try_into()
+                    .expect("Point must be 128 bytes long"),
+            )?;
+            Ok((p, q))
+        })
+        .collect::<Result<Vec<(G1, G2)>>>()?;
+
+    let mut result = [0u8; 32];
+    result[31] = pairing::pairing_check(&pairs) as u8;
+    Ok(result)
+}
@@ -0,0 +1,100 @@
+//! The optimal ate pairing e: G1 x G2 -> Fp12.
+
+use crate::bn254::curve::{Affine, G1, G2};
+use crate::bn254::fp::Fp;
+use crate::bn254::fp12::{Fp12, FROBENIUS_COEFFICIENTS, X};
+use crate::bn254::fp2::Fp2;
+use crate::bn254::fp6::Fp6;
+
+/// 6x + 2, the parameter of the Miller loop.
+const ATE_LOOP_COUNT: u128 = 6 * X as u128 + 2;
+
+/// Apply the Frobenius endomorphism to a point of the twist, as (x^p, y^p) on the curve over
+/// Fp12.
+fn frobenius(point: G2) -> G2 {
+    match point {
+        Affine::Infinity => Affine::Infinity,
+        Affine::Point { x, y } => Affine::Point {
+            x: x.conjugate() * FROBENIUS_COEFFICIENTS[1],
+            y: y.conjugate() * FROBENIUS_COEFFICIENTS[2],
+        },
+    }
+}
+
+/// Evaluate at `(px, py)` the line through `t` and `q`, or the tangent at `t` if they are
+/// equal. Return the value of the line and `t + q`.
+///
+/// With the twist mapping (x, y) to (x w^2, y w^3), the line with slope λ through t evaluates
+/// to `py - λ px w + (λ x_t - y_t) w^3`. Vertical lines evaluate to an element of Fp6, which the
+/// final exponentiation maps to one, so they are skipped.
+fn line(t: G2, q: G2, px: Fp, py: Fp) -> (Fp12, G2) {
+    let ((tx, ty), (qx, qy)) = match (t, q) {
+        (Affine::Point { x: tx, y: ty }, Affine::Point { x: qx, y: qy }) => ((tx, ty), (qx, qy)),
+        (Affine::Infinity, _) => return (Fp12::ONE, q),
+        (_, Affine::Infinity) => return (Fp12::ONE, t),
+    };
+
+    let slope = if tx != qx {
+        (qy - ty) * (qx - tx).invert()
+    } else if ty == qy {
+        let tx_squared = tx.square();
+        (tx_squared.double() + tx_squared) * ty.double().invert()
+    } else {
+        return (Fp12::ONE, Affine::Infinity);
+    };
+
+    let x = slope.square() - tx - qx;
+    let sum = Affine::Point {
+        x,
+        y: slope * (tx - x) - ty,
+    };
+
+    let value = Fp12::new(
+        Fp6::new(Fp2::new(py, Fp::ZERO), Fp2::ZERO, Fp2::ZERO),
+        Fp6::new(-slope.scale(px), slope * tx - ty, Fp2::ZERO),
+    );
+    (value, sum)
+}
+
+/// The Miller loop of the optimal ate pairing, for points other than the point at infinity.
+fn miller_loop(p: G1, q: G2) -> Fp12 {
+    let (px, py) = match p {
+        Affine::Point { x, y } => (x, y),
+        Affine::Infinity => return Fp12::ONE,
+    };
+
+    let mut f = Fp12::ONE;
+    let mut t = q;
+
+    // The most significant bit is accounted for by starting from q.
+    for bit in (0..127 - ATE_LOOP_COUNT.leading_zeros()).rev() {
+        let (value, doubled) = line(t, t, px, py);
+        f = f.square() * value;
+        t = doubled;
+
+        if (ATE_LOOP_COUNT >> bit) & 1 == 1 {
+            let (value, sum) = line(t, q, px, py);
+            f = f * value;
+            t = sum;
+        }
+    }
+
+    let q1 = frobenius(q);
+    let q2 = -frobenius(q1);
+
+    let (value, sum) = line(t, q1, px, py);
+    f = f * value;
+    let (value, _) = line(sum, q2, px, py);
+    f * value
+}
+
+/// Return whether the product of the pairings of all pairs is one. Pairs with a point at
+/// infinity contribute one.
+pub(crate) fn pairing_check(pairs: &[(G1, G2)]) -> bool {
+    let f = pairs
+        .iter()
+        .filter(|(p, q)| *p != Affine::Infinity && *q != Affine::Infinity)
+        .fold(Fp12::ONE, |f, &(p, q)| f * miller_loop(p, q));
+
+    f.final_exponentiation() == Fp12::ONE
+}
@@ -0,0 +1,35 @@
+//! Errors returned by ECIES decryption.
+
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Result type returned by ECIES decryption.
+///
+/// In essence the Error type is fixed to [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors returned by ECIES decryption.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The ciphertext is shorter than the ephemeral public key, IV and tag it must contain.
+    TooShort,
+
+    /// The ephemeral public key is not a valid uncompressed point on the curve.
+    InvalidPublicKey,
+
+    /// The tag does not match, either because the ciphertext or the shared MAC data was altered,
+    /// or because the ciphertext was encrypted for a different key.
+    InvalidTag,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::TooShort => f.write_str("ciphertext is too short"),
+            Error::InvalidPublicKey => f.write_str("invalid ephemeral public key"),
+            Error::InvalidTag => f.write_str("tag does not match"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,121 @@
+//! The Elliptic Curve Integrated Encryption Scheme, as specified by
+//! [devp2p](https://github.com/ethereum/devp2p/blob/master/rlpx.md#ecies-encryption) for the
+//! RLPx handshake.
+//!
+//! A ciphertext is `R || iv || c || d`, where `R` is the uncompressed ephemeral public key, `c`
+//! the message encrypted with AES-128-CTR and `d` the HMAC-SHA256 tag of `iv || c` followed by
+//! the shared MAC data. EIP-8 handshake messages use their 2-byte size prefix as the shared MAC
+//! data.
+mod error;
+
+pub use error::{Error, Result};
+
+use crate::aes::{Ctr, AES128};
+use crate::hash::{HashFunction, SHA256};
+use crate::kdf::concat_kdf;
+use crate::mac::Hmac;
+use crate::secp256k1::{PublicKey, SecretKey};
+use crate::util::Zeroizing;
+
+use rand_core::{OsRng, RngCore};
+
+use std::convert::TryInto;
+
+/// Size of the uncompressed ephemeral public key, including the SEC1 tag.
+const PUBLIC_KEY_SIZE: usize = 65;
+
+/// Size of the IV of AES-128-CTR.
+const IV_SIZE: usize = 16;
+
+/// Size of the HMAC-SHA256 tag.
+const TAG_SIZE: usize = 32;
+
+/// Number of bytes a ciphertext is longer than its message.
+pub const OVERHEAD: usize = PUBLIC_KEY_SIZE + IV_SIZE + TAG_SIZE;
+
+/// Derive the encryption key and the MAC key from the shared secret.
+fn derive_keys(shared_secret: &[u8; 32]) -> (Zeroizing<[u8; 16]>, Zeroizing<[u8; 32]>) {
+    let keys = concat_kdf::<SHA256>(shared_secret, &[], 32);
+
+    let encryption_key = Zeroizing::new(keys[..16].try_into().expect("Key must be 16 bytes long"));
+    let mac_key = Zeroizing::new(SHA256::hash(&keys[16..]).into_bytes());
+
+    (encryption_key, mac_key)
+}
+
+/// Compute the tag of `iv || c || shared_mac_data`.
+fn tag(mac_key: &[u8; 32], iv: &[u8], ciphertext: &[u8], shared_mac_data: &[u8]) -> Hmac<SHA256> {
+    let mut hmac = Hmac::<SHA256>::new(mac_key);
+    hmac.update(iv);
+    hmac.update(ciphertext);
+    hmac.update(shared_mac_data);
+
+    hmac
+}
+
+/// Encrypt `message` for `public_key`, with a random ephemeral key and IV.
+///
+/// The ciphertext is [`OVERHEAD`] bytes longer than the message.
+///
+/// # Example
+/// ```
+/// use peko_crypto::ecies;
+/// use peko_crypto::secp256k1::SecretKey;
+///
+/// let secret_key = SecretKey::random();
+/// let ciphertext = ecies::encrypt(&secret_key.public_key(), b"peko", b"");
+///
+/// assert_eq!(ciphertext.len(), 4 + ecies::OVERHEAD);
+/// assert_eq!(ecies::decrypt(&secret_key, &ciphertext, b""), Ok(b"peko".to_vec()));
+/// ```
+pub fn encrypt(public_key: &PublicKey, message: &[u8], shared_mac_data: &[u8]) -> Vec<u8> {
+    let ephemeral_key = SecretKey::random();
+    let mut iv = [0u8; IV_SIZE];
+    OsRng.fill_bytes(&mut iv);
+
+    let (encryption_key, mac_key) = derive_keys(&ephemeral_key.shared_secret(public_key));
+
+    let mut result = Vec::with_capacity(message.len() + OVERHEAD);
+    result.push(0x04);
+    result.extend_from_slice(&ephemeral_key.public_key().to_bytes());
+    result.extend_from_slice(&iv);
+
+    let mut ciphertext = message.to_vec();
+    Ctr::new(AES128::new(&encryption_key), &iv).apply_keystream(&mut ciphertext);
+    result.extend_from_slice(&ciphertext);
+
+    result.extend_from_slice(&tag(&mac_key, &iv, &ciphertext, shared_mac_data).digest());
+
+    result
+}
+
+/// Decrypt a ciphertext encrypted for the public key of `secret_key`.
+///
+/// The tag is checked before anything is decrypted.
+pub fn decrypt(secret_key: &SecretKey, data: &[u8], shared_mac_data: &[u8]) -> Result<Vec<u8>> {
+    if data.len() < OVERHEAD {
+        return Err(Error::TooShort);
+    }
+
+    let (public_key, rest) = data.split_at(PUBLIC_KEY_SIZE);
+    let (iv, rest) = rest.split_at(IV_SIZE);
+    let (ciphertext, tag_bytes) = rest.split_at(rest.len() - TAG_SIZE);
+
+    // Only the uncompressed encoding is allowed, so the ciphertext layout stays fixed.
+    if public_key[0] != 0x04 {
+        return Err(Error::InvalidPublicKey);
+    }
+    let public_key = PublicKey::from_bytes(public_key).map_err(|_| Error::InvalidPublicKey)?;
+
+    let (encryption_key, mac_key) = derive_keys(&secret_key.shared_secret(&public_key));
+
+    if !tag(&mac_key, iv, ciphertext, shared_mac_data).verify(tag_bytes) {
+        return Err(Error::InvalidTag);
+    }
+
+    let iv = iv.try_into().expect("IV must be 16 bytes long");
+    let mut message = ciphertext.to_vec();
+    Ctr::new(AES128::new(&encryption_key), iv).apply_keystream(&mut message);
+
+    Ok(message)
+}
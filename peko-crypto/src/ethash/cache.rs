@@ -0,0 +1,87 @@
+use crate::ethash::{self, fnv, keccak512, keccak512_node, Node, HASH_BYTES, HASH_WORDS};
+
+/// Number of rounds of RandMemoHash over the cache.
+const CACHE_ROUNDS: usize = 3;
+
+/// Number of cache items mixed into each dataset item.
+const DATASET_PARENTS: u32 = 256;
+
+/// The cache of an epoch, from which the items of the dataset are computed.
+///
+/// # Example
+/// ```
+/// use peko_crypto::ethash::{self, Cache};
+///
+/// // Generating the cache of an epoch takes a while, a small cache is used here instead.
+/// let cache = Cache::with_size(1024, &ethash::seed_hash(0));
+///
+/// assert_eq!(cache.size(), 1024);
+/// ```
+#[derive(Clone)]
+pub struct Cache {
+    nodes: Vec<Node>,
+}
+
+impl Cache {
+    /// Generate the cache of an epoch.
+    pub fn new(epoch: u64) -> Cache {
+        Cache::with_size(ethash::cache_size(epoch), &ethash::seed_hash(epoch))
+    }
+
+    /// Generate a cache of `size` bytes from a seed, which must be a non-zero multiple of 64.
+    ///
+    /// Only the caches generated by [`Cache::new`] are valid for Ethereum blocks, other sizes
+    /// are useful for tests.
+    pub fn with_size(size: usize, seed: &[u8]) -> Cache {
+        assert!(
+            size > 0 && size.is_multiple_of(HASH_BYTES),
+            "Cache size must be a non-zero multiple of 64 bytes"
+        );
+        let count = size / HASH_BYTES;
+
+        // Fill the cache with a sequence of hashes.
+        let mut nodes = Vec::with_capacity(count);
+        nodes.push(keccak512(seed));
+        for i in 1..count {
+            nodes.push(keccak512_node(&nodes[i - 1]));
+        }
+
+        // Then mix it with a few rounds of RandMemoHash.
+        for _ in 0..CACHE_ROUNDS {
+            for i in 0..count {
+                let other = nodes[i][0] as usize % count;
+                let mut node = nodes[(i + count - 1) % count];
+                for (word, &other_word) in node.iter_mut().zip(&nodes[other]) {
+                    *word ^= other_word;
+                }
+                nodes[i] = keccak512_node(&node);
+            }
+        }
+
+        Cache { nodes }
+    }
+
+    /// Return the size of the cache in bytes.
+    pub fn size(&self) -> usize {
+        self.nodes.len() * HASH_BYTES
+    }
+
+    /// Compute an item of the dataset.
+    pub(crate) fn dataset_item(&self, index: u32) -> Node {
+        let count = self.nodes.len();
+
+        let mut mix = self.nodes[index as usize % count];
+        mix[0] ^= index;
+        let mut mix = keccak512_node(&mix);
+
+        for parent in 0..DATASET_PARENTS {
+            let word = mix[parent as usize % HASH_WORDS];
+            let node = &self.nodes[fnv(index ^ parent, word) as usize % count];
+            for (word, &node_word) in mix.iter_mut().zip(node) {
+                *word = fnv(*word, node_word);
+            }
+        }
+
+        keccak512_node(&mix)
+    }
+}
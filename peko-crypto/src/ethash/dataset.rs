@@ -0,0 +1,211 @@
+//! The full dataset, in memory or in files of the
+//! [Ethash DAG Disk Storage Format](https://eth.wiki/concepts/ethash/dag-disk-storage-format).
+
+use crate::ethash::hashimoto::hashimoto;
+use crate::ethash::{
+    self, bytes_to_node, is_valid_dataset_size, words_to_bytes, Cache, HashimotoOutput, HASH_BYTES,
+};
+use crate::hash::H256;
+use crate::util::hex_encode;
+
+use memmap2::{Mmap, MmapMut};
+use rayon::prelude::*;
+
+use std::convert::TryFrom;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// Revision of the algorithm, which is part of the name of dataset files.
+const REVISION: u32 = 23;
+
+/// The magic number at the start of dataset files.
+const MAGIC: u64 = 0xfee1deadbaddcafe;
+
+/// Size of the magic number.
+const MAGIC_SIZE: usize = 8;
+
+enum Storage {
+    Memory(Vec<u8>),
+    Mapped(Mmap),
+}
+
+/// The full dataset of an epoch, which makes hashimoto much faster than computing its items
+/// from the cache.
+///
+/// The dataset is either held in memory, or in a file of the Ethash DAG Disk Storage Format
+/// which is mapped into memory: the magic number `0xfee1deadbaddcafe`, followed by the items,
+/// all in little-endian. The items are generated in parallel, on all the threads of the global
+/// [`rayon`] thread pool.
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::ethash::{self, Cache, Dataset};
+/// use peko_crypto::hash::H256;
+///
+/// let cache = Cache::with_size(1024, &ethash::seed_hash(0));
+/// let dataset = Dataset::with_size(&cache, 32 * 1024);
+/// let header_hash = H256::new(hex!(
+///     "c9149cc0386e689d789a1c2f3d5d169a61a6218ed30e74414dc736e442ef3d1f"
+/// ));
+///
+/// assert_eq!(
+///     ethash::hashimoto_full(&dataset, &header_hash, 0),
+///     ethash::hashimoto_light(&cache, 32 * 1024, &header_hash, 0)
+/// );
+/// ```
+pub struct Dataset {
+    storage: Storage,
+}
+
+impl Dataset {
+    /// Generate the dataset of an epoch from its cache, in memory.
+    pub fn new(cache: &Cache, epoch: u64) -> Dataset {
+        Dataset::with_size(cache, ethash::dataset_size(epoch))
+    }
+
+    /// Generate a dataset of `size` bytes from a cache in memory. The size must be a non-zero
+    /// multiple of 128.
+    pub fn with_size(cache: &Cache, size: u64) -> Dataset {
+        let mut data = vec![0u8; checked_size(size)];
+        generate(cache, &mut data);
+
+        Dataset {
+            storage: Storage::Memory(data),
+        }
+    }
+
+    /// Generate the dataset of an epoch from its cache into the file at `path`, which is
+    /// created or truncated, and map it into memory.
+    ///
+    /// The name of the file is usually [`dataset_file_name`].
+    pub fn create<P: AsRef<Path>>(cache: &Cache, epoch: u64, path: P) -> io::Result<Dataset> {
+        Dataset::create_with_size(cache, ethash::dataset_size(epoch), path)
+    }
+
+    /// Generate a dataset of `size` bytes from a cache into the file at `path`, which is
+    /// created or truncated, and map it into memory. The size must be a non-zero multiple of
+    /// 128.
+    pub fn create_with_size<P: AsRef<Path>>(
+        cache: &Cache,
+        size: u64,
+        path: P,
+    ) -> io::Result<Dataset> {
+        let size = checked_size(size);
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        file.set_len((MAGIC_SIZE + size) as u64)?;
+
+        // The file was just created with the right length, and is only written through this
+        // mapping.
+        let mut mmap = unsafe { MmapMut::map_mut(&file)? };
+        mmap[..MAGIC_SIZE].copy_from_slice(&MAGIC.to_le_bytes());
+        generate(cache, &mut mmap[MAGIC_SIZE..]);
+        mmap.flush()?;
+
+        Ok(Dataset {
+            storage: Storage::Mapped(mmap.make_read_only()?),
+        })
+    }
+
+    /// Map the dataset file at `path` into memory.
+    ///
+    /// The file must have been written by [`Dataset::create`] or [`Dataset::save`], or by
+    /// another implementation of the format. An error of kind [`io::ErrorKind::InvalidData`]
+    /// is returned if it does not start with the magic number, or if its items do not fill a
+    /// whole number of mixes or are more than 256 GiB. The items themselves are not checked,
+    /// and the file must not be modified while it is mapped.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Dataset> {
+        let file = File::open(path)?;
+        // The file is only read, and must not be modified while it is mapped, as documented
+        // above.
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        if mmap.len() < MAGIC_SIZE || mmap[..MAGIC_SIZE] != MAGIC.to_le_bytes() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "dataset file does not start with the magic number",
+            ));
+        }
+        if !is_valid_dataset_size((mmap.len() - MAGIC_SIZE) as u64) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid dataset file length",
+            ));
+        }
+
+        Ok(Dataset {
+            storage: Storage::Mapped(mmap),
+        })
+    }
+
+    /// Write the dataset into the file at `path` in the Ethash DAG Disk Storage Format. The
+    /// file is created or truncated.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(&MAGIC.to_le_bytes())?;
+        writer.write_all(self.data())?;
+        writer.flush()
+    }
+
+    /// Return the size of the dataset in bytes, without the magic number of its file.
+    pub fn size(&self) -> u64 {
+        self.data().len() as u64
+    }
+
+    /// Return the items of the dataset.
+    fn data(&self) -> &[u8] {
+        match &self.storage {
+            Storage::Memory(data) => data,
+            Storage::Mapped(mmap) => &mmap[MAGIC_SIZE..],
+        }
+    }
+}
+
+/// Check that `size` is a valid dataset size for this platform.
+fn checked_size(size: u64) -> usize {
+    assert!(
+        is_valid_dataset_size(size),
+        "Dataset size must be a non-zero multiple of 128 bytes of at most 256 GiB"
+    );
+    usize::try_from(size).expect("Dataset size must fit in memory")
+}
+
+/// Fill `data` with the items of the dataset, in parallel.
+fn generate(cache: &Cache, data: &mut [u8]) {
+    data.par_chunks_exact_mut(HASH_BYTES)
+        .enumerate()
+        .for_each(|(index, item)| words_to_bytes(&cache.dataset_item(index as u32), item));
+}
+
+/// Return the usual name of the dataset file of an epoch: `full-R23-` followed by the first 8
+/// bytes of the seed in hexadecimal.
+///
+/// # Example
+/// ```
+/// use peko_crypto::ethash;
+///
+/// assert_eq!(ethash::dataset_file_name(0), "full-R23-0000000000000000");
+/// assert_eq!(ethash::dataset_file_name(1), "full-R23-290decd9548b62a8");
+/// ```
+pub fn dataset_file_name(epoch: u64) -> String {
+    let seed = ethash::seed_hash(epoch);
+    format!("full-R{}-{}", REVISION, hex_encode(&seed[..8]))
+}
+
+/// Run hashimoto for a header hash and a nonce over the full dataset.
+///
+/// The output is the same as [`hashimoto_light`](crate::ethash::hashimoto_light) with the cache
+/// the dataset was generated from.
+pub fn hashimoto_full(dataset: &Dataset, header_hash: &H256, nonce: u64) -> HashimotoOutput {
+    let data = dataset.data();
+    hashimoto(dataset.size(), header_hash, nonce, |index| {
+        let offset = index as usize * HASH_BYTES;
+        bytes_to_node(&data[offset..offset + HASH_BYTES])
+    })
+}
@@ -0,0 +1,39 @@
+//! Errors returned by Ethash operations.
+
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Result type returned by Ethash operations.
+///
+/// In essence the Error type is fixed to [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors returned by Ethash operations. A block is invalid on any of them, except
+/// [`Error::InvalidDatasetSize`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The dataset size is not a non-zero multiple of 128 bytes of at most 256 GiB.
+    InvalidDatasetSize,
+
+    /// The difficulty is zero, so there is no boundary to check against.
+    ZeroDifficulty,
+
+    /// The mix digest of the header does not match the one computed from the nonce.
+    InvalidMixDigest,
+
+    /// The result is above the boundary set by the difficulty.
+    InsufficientDifficulty,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidDatasetSize => f.write_str("invalid dataset size"),
+            Error::ZeroDifficulty => f.write_str("zero difficulty"),
+            Error::InvalidMixDigest => f.write_str("invalid mix digest"),
+            Error::InsufficientDifficulty => f.write_str("insufficient difficulty"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,177 @@
+use crate::bignum::{BigUint, U256};
+use crate::ethash::{
+    fnv, is_valid_dataset_size, keccak512, words_to_bytes, Cache, Error, Node, Result, HASH_BYTES,
+    HASH_WORDS, MIX_BYTES,
+};
+use crate::hash::{HashFunction, Keccak256, H256};
+
+use std::convert::TryInto;
+
+/// Number of 32-bit words in the mix.
+const MIX_WORDS: usize = MIX_BYTES / 4;
+
+/// Number of items in the mix.
+const MIX_NODES: usize = MIX_BYTES / HASH_BYTES;
+
+/// Number of accesses to the dataset.
+const ACCESSES: u32 = 64;
+
+/// The output of hashimoto for a header and a nonce.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HashimotoOutput {
+    /// Digest of the mix, stored in the header next to the nonce.
+    pub mix_digest: H256,
+
+    /// The value checked against the difficulty boundary.
+    pub result: H256,
+}
+
+/// Run hashimoto over a dataset of `dataset_size` bytes, whose items are returned by `lookup`.
+///
+/// The size must be valid, see [`is_valid_dataset_size`].
+pub(crate) fn hashimoto<F>(
+    dataset_size: u64,
+    header_hash: &H256,
+    nonce: u64,
+    lookup: F,
+) -> HashimotoOutput
+where
+    F: Fn(u32) -> Node,
+{
+    let rows = (dataset_size / MIX_BYTES as u64) as u32;
+
+    let mut seed_input = [0u8; 40];
+    seed_input[..32].copy_from_slice(header_hash.as_bytes());
+    seed_input[32..].copy_from_slice(&nonce.to_le_bytes());
+    let seed = keccak512(&seed_input);
+
+    // The mix starts as the seed, repeated to fill it.
+    let mut mix = [0u32; MIX_WORDS];
+    for chunk in mix.chunks_exact_mut(HASH_WORDS) {
+        chunk.copy_from_slice(&seed);
+    }
+
+    for i in 0..ACCESSES {
+        let row = fnv(i ^ seed[0], mix[i as usize % MIX_WORDS]) % rows;
+        for (j, chunk) in mix.chunks_exact_mut(HASH_WORDS).enumerate() {
+            let node = lookup(row * MIX_NODES as u32 + j as u32);
+            for (word, &node_word) in chunk.iter_mut().zip(&node) {
+                *word = fnv(*word, node_word);
+            }
+        }
+    }
+
+    // Compress the mix into 8 words.
+    let mut compressed = [0u32; MIX_WORDS / 4];
+    for (word, chunk) in compressed.iter_mut().zip(mix.chunks_exact(4)) {
+        *word = fnv(fnv(fnv(chunk[0], chunk[1]), chunk[2]), chunk[3]);
+    }
+
+    let mut result_input = [0u8; HASH_BYTES + 32];
+    words_to_bytes(&seed, &mut result_input[..HASH_BYTES]);
+    words_to_bytes(&compressed, &mut result_input[HASH_BYTES..]);
+
+    HashimotoOutput {
+        mix_digest: H256::new(
+            result_input[HASH_BYTES..]
+                .try_into()
+                .expect("Mix digest must be 32 bytes long"),
+        ),
+        result: Keccak256::hash(&result_input),
+    }
+}
+
+/// Run hashimoto for a header hash and a nonce, computing the items of a dataset of
+/// `dataset_size` bytes from the cache as they are needed. The size must be a non-zero multiple
+/// of 128 of at most 256 GiB.
+///
+/// The header hash is the Keccak-256 hash of the RLP encoding of the header, without its mix
+/// digest and nonce. The output is the same as with the full dataset, while only 128 of its
+/// items are computed.
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::ethash::{self, Cache};
+/// use peko_crypto::hash::H256;
+///
+/// let cache = Cache::with_size(1024, &ethash::seed_hash(0));
+/// let header_hash = H256::new(hex!(
+///     "c9149cc0386e689d789a1c2f3d5d169a61a6218ed30e74414dc736e442ef3d1f"
+/// ));
+/// let output = ethash::hashimoto_light(&cache, 32 * 1024, &header_hash, 0);
+///
+/// assert_eq!(
+///     output.mix_digest,
+///     hex!("e4073cffaef931d37117cefd9afd27ea0f1cad6a981dd2605c4a1ac97c519800")
+/// );
+/// assert_eq!(
+///     output.result,
+///     hex!("d3539235ee2e6f8db665c0a72169f55b7f6c605712330b778ec3944f0eb5a557")
+/// );
+/// ```
+pub fn hashimoto_light(
+    cache: &Cache,
+    dataset_size: u64,
+    header_hash: &H256,
+    nonce: u64,
+) -> HashimotoOutput {
+    assert!(
+        is_valid_dataset_size(dataset_size),
+        "Dataset size must be a non-zero multiple of 128 bytes of at most 256 GiB"
+    );
+    hashimoto(dataset_size, header_hash, nonce, |index| {
+        cache.dataset_item(index)
+    })
+}
+
+/// Check a result of hashimoto against the boundary set by the difficulty: the result, as a
+/// big-endian integer, must not be greater than 2^256 / difficulty.
+///
+/// Any result is above the boundary of a zero difficulty.
+pub fn check_difficulty(result: &H256, difficulty: &U256) -> bool {
+    if difficulty.is_zero() {
+        return false;
+    }
+
+    // result <= 2^256 / difficulty if and only if result * difficulty <= 2^256, as the result is
+    // an integer.
+    let mut boundary = [0u8; 33];
+    boundary[0] = 1;
+    let product =
+        &BigUint::from(U256::from_be_bytes(result.into_bytes())) * &BigUint::from(*difficulty);
+
+    product <= BigUint::from_be_bytes(&boundary)
+}
+
+/// Verify the proof of work of a header from the cache of its epoch: the mix digest must match
+/// the one computed from the nonce, and the result must be within the difficulty boundary.
+///
+/// `dataset_size` is the size of the dataset of the epoch, returned by
+/// [`ethash::dataset_size`](crate::ethash::dataset_size). [`Error::InvalidDatasetSize`] is
+/// returned if it is not a non-zero multiple of 128 bytes of at most 256 GiB.
+pub fn verify_light(
+    cache: &Cache,
+    dataset_size: u64,
+    header_hash: &H256,
+    nonce: u64,
+    mix_digest: &H256,
+    difficulty: &U256,
+) -> Result<()> {
+    if !is_valid_dataset_size(dataset_size) {
+        return Err(Error::InvalidDatasetSize);
+    }
+    if difficulty.is_zero() {
+        return Err(Error::ZeroDifficulty);
+    }
+
+    let output = hashimoto_light(cache, dataset_size, header_hash, nonce);
+    if output.mix_digest != *mix_digest {
+        return Err(Error::InvalidMixDigest);
+    }
+    if !check_difficulty(&output.result, difficulty) {
+        return Err(Error::InsufficientDifficulty);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+//! Ethash, the proof of work of Ethereum, as specified in the
+//! [Ethash wiki page](https://eth.wiki/en/concepts/ethash/ethash).
+//!
+//! Every epoch of [`EPOCH_LENGTH`] blocks has a seed, from which a cache of a few dozen
+//! megabytes is generated. Each item of the dataset of a few gigabytes is computed from the
+//! cache, so a block can be verified from the cache alone with [`hashimoto_light`], which
+//! computes the few items it needs on the fly. The full dataset is generated by `Dataset`,
+//! behind the feature `full-dag`, which can also store it in and load it from files.
+//!
+//! Items of the cache and the dataset are 64 bytes long, and are seen as sixteen little-endian
+//! 32-bit words.
+mod cache;
+#[cfg(feature = "full-dag")]
+mod dataset;
+mod error;
+mod hashimoto;
+
+pub use cache::Cache;
+#[cfg(feature = "full-dag")]
+pub use dataset::{dataset_file_name, hashimoto_full, Dataset};
+pub use error::{Error, Result};
+pub use hashimoto::{check_difficulty, hashimoto_light, verify_light, HashimotoOutput};
+
+use crate::hash::{HashFunction, Keccak256, Keccak512, H256};
+
+/// Number of blocks in an epoch.
+pub const EPOCH_LENGTH: u64 = 30000;
+
+/// Size of the dataset of the first epoch, before it is rounded down.
+const DATASET_BYTES_INIT: u64 = 1 << 30;
+
+/// Growth of the size of the dataset in each epoch.
+const DATASET_BYTES_GROWTH: u64 = 1 << 23;
+
+/// Size of the cache of the first epoch, before it is rounded down.
+const CACHE_BYTES_INIT: u64 = 1 << 24;
+
+/// Growth of the size of the cache in each epoch.
+const CACHE_BYTES_GROWTH: u64 = 1 << 17;
+
+/// Size of an item of the cache or the dataset.
+const HASH_BYTES: usize = 64;
+
+/// Number of 32-bit words in an item.
+const HASH_WORDS: usize = HASH_BYTES / 4;
+
+/// Size of the mix of hashimoto, which is made of two consecutive items of the dataset.
+const MIX_BYTES: usize = 128;
+
+/// An item of the cache or the dataset.
+type Node = [u32; HASH_WORDS];
+
+/// Return the epoch of a block.
+pub fn epoch(block_number: u64) -> u64 {
+    block_number / EPOCH_LENGTH
+}
+
+/// Return the seed of an epoch: Keccak-256 applied `epoch` times to 32 zero bytes.
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::ethash;
+///
+/// assert_eq!(ethash::seed_hash(0), [0u8; 32]);
+/// assert_eq!(
+///     ethash::seed_hash(1),
+///     hex!("290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563")
+/// );
+/// ```
+pub fn seed_hash(epoch: u64) -> H256 {
+    let mut seed = H256::default();
+    for _ in 0..epoch {
+        seed = Keccak256::hash(&seed);
+    }
+    seed
+}
+
+/// Return the size of the cache of an epoch, in bytes: the largest size below the linear
+/// growth such that the number of items is prime.
+pub fn cache_size(epoch: u64) -> usize {
+    let mut size = CACHE_BYTES_INIT + CACHE_BYTES_GROWTH * epoch - HASH_BYTES as u64;
+    while !is_prime(size / HASH_BYTES as u64) {
+        size -= 2 * HASH_BYTES as u64;
+    }
+    size as usize
+}
+
+/// Return the size of the dataset of an epoch, in bytes: the largest size below the linear
+/// growth such that the number of mixes is prime.
+pub fn dataset_size(epoch: u64) -> u64 {
+    let mut size = DATASET_BYTES_INIT + DATASET_BYTES_GROWTH * epoch - MIX_BYTES as u64;
+    while !is_prime(size / MIX_BYTES as u64) {
+        size -= 2 * MIX_BYTES as u64;
+    }
+    size
+}
+
+/// Check that a dataset of `size` bytes can be used by hashimoto: it must be a non-zero multiple
+/// of 128 bytes, and the index of each of its items must fit in 32 bits, which bounds it to
+/// 256 GiB.
+fn is_valid_dataset_size(size: u64) -> bool {
+    size > 0
+        && size.is_multiple_of(MIX_BYTES as u64)
+        && size / HASH_BYTES as u64 <= u32::MAX as u64 + 1
+}
+
+fn is_prime(number: u64) -> bool {
+    if number < 2 {
+        return false;
+    }
+    (2..)
+        .take_while(|divisor| divisor * divisor <= number)
+        .all(|divisor| !number.is_multiple_of(divisor))
+}
+
+/// The non-associative replacement of XOR used to mix items, after the FNV hash function.
+fn fnv(a: u32, b: u32) -> u32 {
+    a.wrapping_mul(0x01000193) ^ b
+}
+
+fn words_to_bytes(words: &[u32], bytes: &mut [u8]) {
+    for (chunk, word) in bytes.chunks_exact_mut(4).zip(words) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+}
+
+/// Read an item from the first 64 bytes of `bytes`.
+fn bytes_to_node(bytes: &[u8]) -> Node {
+    let mut node = [0u32; HASH_WORDS];
+    for (word, chunk) in node.iter_mut().zip(bytes.chunks_exact(4)) {
+        *word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+    node
+}
+
+/// Hash `data` with Keccak-512 into an item.
+fn keccak512(data: &[u8]) -> Node {
+    bytes_to_node(&Keccak512::hash(data))
+}
+
+/// Hash an item with Keccak-512.
+fn keccak512_node(node: &Node) -> Node {
+    let mut bytes = [0u8; HASH_BYTES];
+    words_to_bytes(node, &mut bytes);
+    keccak512(&bytes)
+}
@@ -0,0 +1,307 @@
+//! BLAKE2b, as specified in [RFC 7693](https://tools.ietf.org/html/rfc7693).
+
+use crate::hash::{Digest, HashFunction};
+use crate::util::{Zeroize, ZeroizeOnDrop};
+
+use std::convert::TryInto;
+
+/// Initialization vector, identical to the initial digest of SHA-512.
+const IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+/// Message word permutations. Round `i` uses permutation `i % 10`.
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+/// Number of rounds used by the BLAKE2b hash function.
+const ROUNDS: u32 = 12;
+
+/// Size of each message block in bytes.
+const BLOCK_SIZE: usize = 1024 / 8;
+
+/// Maximum size of the output and the key, in bytes.
+const MAX_SIZE: usize = 512 / 8;
+
+/// The mixing function G, mixing two message words into four words of the working vector.
+fn g(v: &mut [u64; 16], a: usize, b: usize, c: usize, d: usize, x: u64, y: u64) {
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
+
+/// The BLAKE2b compression function F, with a caller-chosen number of rounds.
+///
+/// `h` is the state vector, updated in place, `m` the message block, `t` the offset counter
+/// (the number of bytes hashed so far, including this block) as two little-endian words, and
+/// `final_block` indicates whether this is the last block. The BLAKE2b hash function uses 12
+/// rounds; the precompiled contract at address `0x09` (EIP-152) lets the caller choose.
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::hash::blake2b_f;
+///
+/// // Compress the only block of the message "abc", using the initial state of BLAKE2b-512.
+/// let mut h = [
+///     0x6a09e667f2bdc948,
+///     0xbb67ae8584caa73b,
+///     0x3c6ef372fe94f82b,
+///     0xa54ff53a5f1d36f1,
+///     0x510e527fade682d1,
+///     0x9b05688c2b3e6c1f,
+///     0x1f83d9abfb41bd6b,
+///     0x5be0cd19137e2179,
+/// ];
+/// let mut m = [0u64; 16];
+/// m[0] = 0x636261;
+///
+/// blake2b_f(12, &mut h, &m, [3, 0], true);
+///
+/// let digest: Vec<u8> = h.iter().flat_map(|word| word.to_le_bytes().to_vec()).collect();
+/// assert_eq!(
+///     digest,
+///     hex!(
+///         "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1"
+///         "7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+///     )
+///     .to_vec()
+/// );
+/// ```
+pub fn blake2b_f(rounds: u32, h: &mut [u64; 8], m: &[u64; 16], t: [u64; 2], final_block: bool) {
+    let mut v = [0u64; 16];
+    v[..8].copy_from_slice(h);
+    v[8..].copy_from_slice(&IV);
+
+    v[12] ^= t[0];
+    v[13] ^= t[1];
+    if final_block {
+        v[14] = !v[14];
+    }
+
+    for round in 0..rounds as usize {
+        let s = &SIGMA[round % 10];
+
+        g(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+        g(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+        g(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+        g(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+
+        g(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+        g(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+        g(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+        g(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+    }
+
+    for i in 0..8 {
+        h[i] ^= v[i] ^ v[i + 8];
+    }
+}
+
+/// BLAKE2b with a variable output size of 1 to 64 bytes, optionally keyed.
+///
+/// For the common fixed output sizes, [`BLAKE2b256`] and [`BLAKE2b512`] implement
+/// [`HashFunction`].
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::hash::BLAKE2b;
+///
+/// let mut hasher = BLAKE2b::new(20, b"key");
+/// hasher.update(b"The quick brown fox jumps over the lazy dog");
+///
+/// assert_eq!(
+///     hasher.digest(),
+///     hex!("6dc7bc109586c90d88d501dc74207680dee0b56f").to_vec()
+/// );
+/// ```
+#[derive(Clone)]
+pub struct BLAKE2b {
+    h: [u64; 8],
+    counter: u128,
+    buffer: [u8; BLOCK_SIZE],
+    buffer_length: usize,
+    output_size: usize,
+}
+
+impl Drop for BLAKE2b {
+    fn drop(&mut self) {
+        // A keyed instance holds the key in its buffer until the first block is compressed.
+        self.h.zeroize();
+        self.counter.zeroize();
+        self.buffer.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for BLAKE2b {}
+
+impl BLAKE2b {
+    /// Create a new instance producing `output_size` bytes of output. `key` turns BLAKE2b into a
+    /// MAC, and may be empty for unkeyed hashing.
+    ///
+    /// Panics if `output_size` is not between 1 and 64, or `key` is longer than 64 bytes.
+    pub fn new(output_size: usize, key: &[u8]) -> BLAKE2b {
+        assert!(
+            (1..=MAX_SIZE).contains(&output_size),
+            "Output size must be between 1 and 64 bytes"
+        );
+        assert!(key.len() <= MAX_SIZE, "Key must be at most 64 bytes long");
+
+        // The first word of the state is mixed with the parameter block: output size, key size,
+        // fanout = 1 and depth = 1.
+        let mut h = IV;
+        h[0] ^= 0x01010000 ^ ((key.len() as u64) << 8) ^ (output_size as u64);
+
+        let mut hasher = BLAKE2b {
+            h,
+            counter: 0,
+            buffer: [0u8; BLOCK_SIZE],
+            buffer_length: 0,
+            output_size,
+        };
+
+        // The key, padded with zeros, is processed as the first block.
+        if !key.is_empty() {
+            hasher.buffer[..key.len()].copy_from_slice(key);
+            hasher.buffer_length = BLOCK_SIZE;
+        }
+
+        hasher
+    }
+
+    /// Compress a block, with the counter already including the block.
+    fn compress(h: &mut [u64; 8], block: &[u8; BLOCK_SIZE], counter: u128, final_block: bool) {
+        let mut m = [0u64; 16];
+        for (i, word) in block.chunks_exact(8).enumerate() {
+            m[i] = u64::from_le_bytes(word.try_into().expect("Word must be 8 bytes long"));
+        }
+
+        blake2b_f(
+            ROUNDS,
+            h,
+            &m,
+            [counter as u64, (counter >> 64) as u64],
+            final_block,
+        );
+    }
+
+    /// Update, or add more data to the current hash.
+    pub fn update(&mut self, data: &[u8]) {
+        let mut data = data;
+
+        while !data.is_empty() {
+            // The last block must be compressed with the final flag set, so a full buffer is
+            // only compressed once more data arrives.
+            if self.buffer_length == BLOCK_SIZE {
+                self.counter += BLOCK_SIZE as u128;
+                BLAKE2b::compress(&mut self.h, &self.buffer, self.counter, false);
+                self.buffer_length = 0;
+            }
+
+            let length = data.len().min(BLOCK_SIZE - self.buffer_length);
+            self.buffer[self.buffer_length..self.buffer_length + length]
+                .copy_from_slice(&data[..length]);
+            self.buffer_length += length;
+            data = &data[length..];
+        }
+    }
+
+    /// Return the hash digest of all data that have been added so far.
+    pub fn digest(&self) -> Vec<u8> {
+        let mut h = self.h;
+
+        let mut block = self.buffer;
+        for byte in block[self.buffer_length..].iter_mut() {
+            *byte = 0;
+        }
+
+        BLAKE2b::compress(
+            &mut h,
+            &block,
+            self.counter + self.buffer_length as u128,
+            true,
+        );
+
+        h.iter()
+            .flat_map(|word| word.to_le_bytes().to_vec())
+            .take(self.output_size)
+            .collect()
+    }
+}
+
+macro_rules! define_blake2b {
+    ($name: ident, $bits: expr, $doc: expr) => {
+        #[doc = $doc]
+        #[derive(Clone)]
+        pub struct $name {
+            hasher: BLAKE2b,
+        }
+
+        impl ZeroizeOnDrop for $name {}
+
+        impl HashFunction for $name {
+            type Output = Digest<{ $bits / 8 }>;
+            const BLOCK_SIZE: usize = BLOCK_SIZE;
+
+            fn new() -> $name {
+                $name {
+                    hasher: BLAKE2b::new($bits / 8, &[]),
+                }
+            }
+
+            fn update(&mut self, data: &[u8]) {
+                self.hasher.update(data);
+            }
+
+            fn digest(&self) -> Self::Output {
+                let mut result = [0u8; $bits / 8];
+                result.copy_from_slice(&self.hasher.digest());
+
+                Digest::new(result)
+            }
+        }
+    };
+}
+
+define_blake2b!(BLAKE2b256, 256, "Unkeyed BLAKE2b with 256 bits of output.");
+define_blake2b!(
+    BLAKE2b512,
+    512,
+    r#"Unkeyed BLAKE2b with 512 bits of output.
+
+# Example
+```
+use hex_literal::hex;
+use peko_crypto::hash::{HashFunction, BLAKE2b512};
+
+let correct_digest = hex!(
+    "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1"
+    "7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+);
+
+assert_eq!(BLAKE2b512::hash(b"abc"), correct_digest);
+```"#
+);
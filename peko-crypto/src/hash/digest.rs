@@ -0,0 +1,223 @@
+//! Fixed-size hash digests.
+
+use crate::hash::{Error, Result};
+use crate::util::{constant_time_eq, hex_decode, hex_encode};
+
+use zeroize::DefaultIsZeroes;
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::TryInto;
+use std::fmt;
+use std::fmt::Formatter;
+use std::hash::{Hash, Hasher};
+use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
+
+/// The output of a hash function: `N` bytes, formatted as `0x`-prefixed hexadecimal.
+///
+/// Comparisons take the same time regardless of where the digests differ, so checking a MAC or
+/// a commitment against a received digest does not leak how much of it is correct.
+///
+/// Serialized as a hexadecimal string in human-readable formats such as JSON, and as a byte
+/// array otherwise, e.g. a fixed-size byte string in RLP.
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::hash::{HashFunction, Keccak256, H256};
+///
+/// let digest = Keccak256::hash(b"");
+/// let hex = "0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470";
+///
+/// assert_eq!(digest.to_string(), hex);
+/// assert_eq!(hex.parse::<H256>().unwrap(), digest);
+/// assert_eq!(
+///     digest,
+///     hex!("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+/// );
+/// ```
+#[derive(Clone, Copy)]
+pub struct Digest<const N: usize>([u8; N]);
+
+/// A 256-bit digest, such as the output of SHA-256 and Keccak-256, and the block and
+/// transaction hashes of Ethereum.
+pub type H256 = Digest<32>;
+
+impl<const N: usize> Digest<N> {
+    /// Create a digest from its bytes.
+    pub fn new(bytes: [u8; N]) -> Digest<N> {
+        Digest(bytes)
+    }
+
+    /// Return the bytes of the digest.
+    pub fn as_bytes(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    /// Return the bytes of the digest, consuming it.
+    pub fn into_bytes(self) -> [u8; N] {
+        self.0
+    }
+}
+
+impl<const N: usize> Default for Digest<N> {
+    fn default() -> Digest<N> {
+        Digest([0u8; N])
+    }
+}
+
+/// Digests derived from secrets, such as HKDF pseudorandom keys, can be wiped with
+/// [`Zeroize`](crate::util::Zeroize).
+impl<const N: usize> DefaultIsZeroes for Digest<N> {}
+
+impl<const N: usize> From<[u8; N]> for Digest<N> {
+    fn from(bytes: [u8; N]) -> Digest<N> {
+        Digest(bytes)
+    }
+}
+
+impl<const N: usize> From<Digest<N>> for [u8; N] {
+    fn from(digest: Digest<N>) -> [u8; N] {
+        digest.0
+    }
+}
+
+impl<const N: usize> Deref for Digest<N> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> DerefMut for Digest<N> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for Digest<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> AsMut<[u8]> for Digest<N> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl<const N: usize> PartialEq for Digest<N> {
+    fn eq(&self, other: &Digest<N>) -> bool {
+        constant_time_eq(&self.0, &other.0)
+    }
+}
+
+impl<const N: usize> Eq for Digest<N> {}
+
+impl<const N: usize> PartialEq<[u8; N]> for Digest<N> {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        constant_time_eq(&self.0, other)
+    }
+}
+
+impl<const N: usize> PartialEq<Digest<N>> for [u8; N] {
+    fn eq(&self, other: &Digest<N>) -> bool {
+        constant_time_eq(self, &other.0)
+    }
+}
+
+impl<const N: usize> Hash for Digest<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<const N: usize> fmt::LowerHex for Digest<N> {
+    /// Format the digest as lowercase hexadecimal digits, with the `0x` prefix only in the
+    /// alternate form `{:#x}`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        f.write_str(&hex_encode(&self.0))
+    }
+}
+
+impl<const N: usize> fmt::Display for Digest<N> {
+    /// Format the digest as `0x`-prefixed lowercase hexadecimal digits.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self)
+    }
+}
+
+impl<const N: usize> fmt::Debug for Digest<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Digest({:#x})", self)
+    }
+}
+
+impl<const N: usize> FromStr for Digest<N> {
+    type Err = Error;
+
+    /// Parse `2 * N` hexadecimal digits in either case, with an optional `0x` prefix.
+    fn from_str(digest: &str) -> Result<Digest<N>> {
+        let digits = digest.strip_prefix("0x").unwrap_or(digest);
+        if digits.len() != N * 2 {
+            return Err(Error::InvalidLength);
+        }
+
+        let bytes = hex_decode(digits).ok_or(Error::InvalidCharacter)?;
+
+        Ok(Digest(
+            bytes[..].try_into().expect("Digest must be N bytes long"),
+        ))
+    }
+}
+
+impl<const N: usize> Serialize for Digest<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+}
+
+/// Visitor accepting either a hexadecimal string or exactly `N` bytes.
+struct DigestVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for DigestVisitor<N> {
+    type Value = Digest<N>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "a digest of {} bytes", N)
+    }
+
+    fn visit_str<E: de::Error>(self, digest: &str) -> std::result::Result<Digest<N>, E> {
+        digest.parse().map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> std::result::Result<Digest<N>, E> {
+        bytes
+            .try_into()
+            .map(Digest)
+            .map_err(|_| E::invalid_length(bytes.len(), &self))
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for Digest<N> {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Digest<N>, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(DigestVisitor)
+        } else {
+            deserializer.deserialize_bytes(DigestVisitor)
+        }
+    }
+}
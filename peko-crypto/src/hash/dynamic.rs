@@ -0,0 +1,116 @@
+//! Hash functions chosen at runtime.
+
+use crate::hash::{
+    BLAKE2b256, BLAKE2b512, HashFunction, Keccak256, Keccak512, RIPEMD160, SHA224, SHA256, SHA384,
+    SHA3_224, SHA3_256, SHA3_384, SHA3_512, SHA512, SHA512_224, SHA512_256,
+};
+
+/// Object-safe version of [`HashFunction`], for hash functions chosen at runtime.
+///
+/// Every [`HashFunction`] implements this trait, so any of them can be put behind a
+/// `Box<dyn DynHash>`. The digest is returned as a `Vec<u8>` since its size is only known at
+/// runtime.
+///
+/// Since both traits have methods with the same names, only import the one in use, or call the
+/// methods with the trait name, e.g. `DynHash::update(&mut hasher, data)`.
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::hash::{self, DynHash};
+///
+/// // The name could come from a command line flag.
+/// let mut hasher: Box<dyn DynHash> = hash::from_name("sha256").unwrap();
+/// hasher.update(b"The quick brown fox jumps over the lazy dog");
+///
+/// assert_eq!(hasher.output_size(), 32);
+/// assert_eq!(
+///     hasher.digest(),
+///     hex!("d7a8fbb307d7809469ca9abcb0082e4f8d5651e46d3cdb762d02d0bf37c9e592").to_vec()
+/// );
+/// ```
+pub trait DynHash {
+    /// Update, or add more data to the current hash.
+    fn update(&mut self, data: &[u8]);
+
+    /// Return the hash digest of all data that have been added so far.
+    fn digest(&self) -> Vec<u8>;
+
+    /// Discard all data that have been added so far, starting over with an empty message.
+    fn reset(&mut self);
+
+    /// Size of the output in bytes.
+    fn output_size(&self) -> usize;
+
+    /// Size of the blocks the hash function processes the message in, in bytes.
+    fn block_size(&self) -> usize;
+
+    /// Return a boxed copy of this hasher, including all data that have been added so far.
+    fn box_clone(&self) -> Box<dyn DynHash>;
+}
+
+impl<H> DynHash for H
+where
+    H: HashFunction + Clone + 'static,
+{
+    fn update(&mut self, data: &[u8]) {
+        HashFunction::update(self, data);
+    }
+
+    fn digest(&self) -> Vec<u8> {
+        HashFunction::digest(self).as_ref().to_vec()
+    }
+
+    fn reset(&mut self) {
+        *self = H::new();
+    }
+
+    fn output_size(&self) -> usize {
+        H::OUTPUT_SIZE
+    }
+
+    fn block_size(&self) -> usize {
+        H::BLOCK_SIZE
+    }
+
+    fn box_clone(&self) -> Box<dyn DynHash> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn DynHash> {
+    fn clone(&self) -> Box<dyn DynHash> {
+        self.box_clone()
+    }
+}
+
+/// Create a hasher from the name of its hash function, or return `None` if the name is
+/// unknown.
+///
+/// Names follow [Python's hashlib](https://docs.python.org/3/library/hashlib.html), in lowercase:
+/// `sha224`, `sha256`, `sha384`, `sha512`, `sha512_224`, `sha512_256`, `sha3_224`, `sha3_256`,
+/// `sha3_384`, `sha3_512`, `ripemd160` and `blake2b`, the latter with 512 bits of output. On top
+/// of those, `blake2b256` is BLAKE2b with 256 bits of output, and `keccak256` and `keccak512`
+/// are the Keccak functions used by Ethereum.
+pub fn from_name(name: &str) -> Option<Box<dyn DynHash>> {
+    let hasher: Box<dyn DynHash> = match name {
+        "sha224" => Box::new(SHA224::new()),
+        "sha256" => Box::new(SHA256::new()),
+        "sha384" => Box::new(SHA384::new()),
+        "sha512" => Box::new(SHA512::new()),
+        "sha512_224" => Box::new(SHA512_224::new()),
+        "sha512_256" => Box::new(SHA512_256::new()),
+        "sha3_224" => Box::new(SHA3_224::new()),
+        "sha3_256" => Box::new(SHA3_256::new()),
+        "sha3_384" => Box::new(SHA3_384::new()),
+        "sha3_512" => Box::new(SHA3_512::new()),
+        "ripemd160" => Box::new(RIPEMD160::new()),
+        "blake2b" => Box::new(BLAKE2b512::new()),
+        "blake2b256" => Box::new(BLAKE2b256::new()),
+        "keccak256" => Box::new(Keccak256::new()),
+        "keccak512" => Box::new(Keccak512::new()),
+        _ => return None,
+    };
+
+    Some(hasher)
+}
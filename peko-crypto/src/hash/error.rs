@@ -0,0 +1,39 @@
+//! Errors returned when parsing a digest or restoring the state of a hash function.
+
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Result type returned when parsing a digest or restoring the state of a hash function.
+///
+/// In essence the Error type is fixed to [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors returned when parsing a digest or restoring the state of a hash function.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The digest is not twice as many hexadecimal digits as its size in bytes, excluding the
+    /// optional `0x` prefix.
+    InvalidLength,
+
+    /// The digest contains a character which is not a hexadecimal digit.
+    InvalidCharacter,
+
+    /// The state is not as long as expected from the number of bytes hashed so far.
+    InvalidStateLength,
+
+    /// The state contains a value which is out of range for the hash function.
+    InvalidState,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidLength => f.write_str("invalid digest length"),
+            Error::InvalidCharacter => f.write_str("invalid character in digest"),
+            Error::InvalidStateLength => f.write_str("invalid hash state length"),
+            Error::InvalidState => f.write_str("invalid hash state"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
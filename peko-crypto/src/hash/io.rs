@@ -0,0 +1,155 @@
+//! Adapters connecting hash functions to [`std::io`].
+
+use crate::hash::HashFunction;
+
+use std::io::{self, Read, Write};
+
+/// A [`Write`] sink feeding everything written to it into a hash function.
+///
+/// Writes never fail and always consume the whole buffer, so data can be streamed into the hash
+/// function with [`io::copy`].
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::hash::{HashWriter, SHA256};
+///
+/// let mut file: &[u8] = b"The quick brown fox jumps over the lazy dog";
+/// let mut writer = HashWriter::<SHA256>::new();
+/// std::io::copy(&mut file, &mut writer).unwrap();
+///
+/// assert_eq!(
+///     writer.digest(),
+///     hex!("d7a8fbb307d7809469ca9abcb0082e4f8d5651e46d3cdb762d02d0bf37c9e592")
+/// );
+/// ```
+#[derive(Clone)]
+pub struct HashWriter<H>
+where
+    H: HashFunction,
+{
+    hasher: H,
+}
+
+impl<H> HashWriter<H>
+where
+    H: HashFunction,
+{
+    /// Create a new writer, starting from an empty message.
+    pub fn new() -> HashWriter<H> {
+        HashWriter::with_hasher(H::new())
+    }
+
+    /// Create a new writer continuing from the data already added to `hasher`.
+    pub fn with_hasher(hasher: H) -> HashWriter<H> {
+        HashWriter { hasher }
+    }
+
+    /// Return the hash digest of all data that have been written so far.
+    pub fn digest(&self) -> H::Output {
+        self.hasher.digest()
+    }
+
+    /// Return the underlying hash function.
+    pub fn into_inner(self) -> H {
+        self.hasher
+    }
+}
+
+impl<H> Default for HashWriter<H>
+where
+    H: HashFunction,
+{
+    fn default() -> HashWriter<H> {
+        HashWriter::new()
+    }
+}
+
+impl<H> Write for HashWriter<H>
+where
+    H: HashFunction,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.hasher.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// A [`Read`] adapter hashing everything read through it, for verifying data while streaming it.
+///
+/// Only the bytes actually returned by the inner reader are hashed, so the digest covers exactly
+/// the data the caller has seen.
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::hash::{HashReader, SHA256};
+///
+/// let download: &[u8] = b"The quick brown fox jumps over the lazy dog";
+/// let mut reader = HashReader::<_, SHA256>::new(download);
+///
+/// let mut contents = Vec::new();
+/// std::io::copy(&mut reader, &mut contents).unwrap();
+///
+/// assert_eq!(contents, download);
+/// assert_eq!(
+///     reader.digest(),
+///     hex!("d7a8fbb307d7809469ca9abcb0082e4f8d5651e46d3cdb762d02d0bf37c9e592")
+/// );
+/// ```
+#[derive(Clone)]
+pub struct HashReader<R, H>
+where
+    H: HashFunction,
+{
+    inner: R,
+    hasher: H,
+}
+
+impl<R, H> HashReader<R, H>
+where
+    H: HashFunction,
+{
+    /// Create a new reader hashing the data read from `inner`.
+    pub fn new(inner: R) -> HashReader<R, H> {
+        HashReader::with_hasher(inner, H::new())
+    }
+
+    /// Create a new reader hashing the data read from `inner`, continuing from the data already
+    /// added to `hasher`.
+    pub fn with_hasher(inner: R, hasher: H) -> HashReader<R, H> {
+        HashReader { inner, hasher }
+    }
+
+    /// Return the hash digest of all data that have been read so far.
+    pub fn digest(&self) -> H::Output {
+        self.hasher.digest()
+    }
+
+    /// Return a reference to the inner reader. Reading from it directly bypasses the hash.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Return the inner reader and the hash function.
+    pub fn into_inner(self) -> (R, H) {
+        (self.inner, self.hasher)
+    }
+}
+
+impl<R, H> Read for HashReader<R, H>
+where
+    R: Read,
+    H: HashFunction,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let length = self.inner.read(buf)?;
+        self.hasher.update(&buf[..length]);
+
+        Ok(length)
+    }
+}
@@ -0,0 +1,84 @@
+use crate::hash::sponge::{self, Sponge, STATE_SIZE};
+use crate::hash::{Digest, HashFunction, Result, ResumableHash, XofReader};
+use crate::util::ZeroizeOnDrop;
+
+/// Domain separation bits and the first padding bit of the original Keccak submission.
+const KECCAK_DOMAIN: u8 = 0x01;
+
+macro_rules! define_keccak {
+    ($name: ident, $bits: expr, $doc: expr) => {
+        #[doc = $doc]
+        #[derive(Clone)]
+        pub struct $name {
+            sponge: Sponge,
+        }
+
+        impl ZeroizeOnDrop for $name {}
+
+        impl HashFunction for $name {
+            type Output = Digest<{ $bits / 8 }>;
+            // Capacity is twice the output size.
+            const BLOCK_SIZE: usize = STATE_SIZE - 2 * ($bits / 8);
+
+            fn new() -> $name {
+                $name {
+                    sponge: Sponge::new(Self::BLOCK_SIZE),
+                }
+            }
+
+            fn update(&mut self, data: &[u8]) {
+                self.sponge.absorb(data);
+            }
+
+            fn digest(&self) -> Self::Output {
+                let mut result = [0u8; $bits / 8];
+                self.sponge
+                    .clone()
+                    .finalize(KECCAK_DOMAIN)
+                    .read(&mut result);
+
+                Digest::new(result)
+            }
+
+            fn hash_batch(messages: &[&[u8]]) -> Vec<Self::Output> {
+                sponge::hash_batch(Self::BLOCK_SIZE, KECCAK_DOMAIN, messages)
+            }
+        }
+
+        impl ResumableHash for $name {
+            fn export_state(&self) -> Vec<u8> {
+                self.sponge.export()
+            }
+
+            fn import_state(state: &[u8]) -> Result<$name> {
+                Ok($name {
+                    sponge: Sponge::import(Self::BLOCK_SIZE, state)?,
+                })
+            }
+        }
+    };
+}
+
+define_keccak!(
+    Keccak256,
+    256,
+    r#"Keccak-256, as used throughout Ethereum.
+
+This is the original Keccak submission to the SHA-3 competition, which differs from the
+standardized SHA3-256 only in its padding.
+
+# Example
+```
+use hex_literal::hex;
+use peko_crypto::hash::{HashFunction, Keccak256};
+
+let correct_digest = hex!("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470");
+
+assert_eq!(Keccak256::hash(b""), correct_digest);
+```"#
+);
+define_keccak!(
+    Keccak512,
+    512,
+    "Keccak-512, the original Keccak submission with 512 bits of output, as used by Ethash."
+);
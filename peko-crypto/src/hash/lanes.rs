@@ -0,0 +1,113 @@
+//! Vectors of words processed in lockstep, one independent message per lane.
+//!
+//! Batch hashing runs the scalar algorithms on these types instead of single words. Every
+//! operation is a loop over the lanes, which the compiler turns into SIMD instructions; the
+//! batch functions are compiled a second time with AVX2 enabled, and pick that version at
+//! runtime when the CPU supports it.
+
+use std::ops::{BitAnd, BitXor, Not, Shr};
+
+macro_rules! define_lanes {
+    ($name: ident, $word: ty, $lanes: expr, $doc: expr) => {
+        #[doc = $doc]
+        #[derive(Clone, Copy)]
+        pub(crate) struct $name(pub(crate) [$word; $lanes]);
+
+        // Not every word size needs every operation.
+        #[allow(dead_code)]
+        impl $name {
+            /// Number of lanes.
+            pub(crate) const LANES: usize = $lanes;
+
+            /// Create a vector with the same word in every lane.
+            #[inline(always)]
+            pub(crate) fn splat(word: $word) -> $name {
+                $name([word; $lanes])
+            }
+
+            #[inline(always)]
+            fn map(self, f: impl Fn($word) -> $word) -> $name {
+                let mut result = self.0;
+                for word in result.iter_mut() {
+                    *word = f(*word);
+                }
+
+                $name(result)
+            }
+
+            #[inline(always)]
+            fn zip(self, other: $name, f: impl Fn($word, $word) -> $word) -> $name {
+                let mut result = self.0;
+                for (word, &other) in result.iter_mut().zip(other.0.iter()) {
+                    *word = f(*word, other);
+                }
+
+                $name(result)
+            }
+
+            #[inline(always)]
+            pub(crate) fn wrapping_add(self, other: $name) -> $name {
+                self.zip(other, <$word>::wrapping_add)
+            }
+
+            #[inline(always)]
+            pub(crate) fn rotate_left(self, n: u32) -> $name {
+                self.map(|word| word.rotate_left(n))
+            }
+
+            #[inline(always)]
+            pub(crate) fn rotate_right(self, n: u32) -> $name {
+                self.map(|word| word.rotate_right(n))
+            }
+        }
+
+        impl BitXor for $name {
+            type Output = $name;
+
+            #[inline(always)]
+            fn bitxor(self, other: $name) -> $name {
+                self.zip(other, |a, b| a ^ b)
+            }
+        }
+
+        impl BitAnd for $name {
+            type Output = $name;
+
+            #[inline(always)]
+            fn bitand(self, other: $name) -> $name {
+                self.zip(other, |a, b| a & b)
+            }
+        }
+
+        impl Not for $name {
+            type Output = $name;
+
+            #[inline(always)]
+            fn not(self) -> $name {
+                self.map(|word| !word)
+            }
+        }
+
+        impl Shr<u32> for $name {
+            type Output = $name;
+
+            #[inline(always)]
+            fn shr(self, n: u32) -> $name {
+                self.map(|word| word >> n)
+            }
+        }
+    };
+}
+
+define_lanes!(
+    U32x8,
+    u32,
+    8,
+    "Eight 32-bit words, filling a 256-bit AVX2 register."
+);
+define_lanes!(
+    U64x4,
+    u64,
+    4,
+    "Four 64-bit words, filling a 256-bit AVX2 register."
+);
@@ -0,0 +1,177 @@
+//! The Merkle-Damgård construction shared by the SHA-2 family and RIPEMD-160.
+//!
+//! All of these hash functions buffer and pad the message the same way. They only differ in the
+//! compression function, the initial digest, the byte order of the message length in the
+//! padding, and how much of the final digest is output.
+
+use crate::hash::{Error, Result};
+use crate::util::Zeroize;
+
+use std::convert::TryInto;
+
+/// The internal digest of a hash function, together with the compression function updating it.
+/// `CHUNK_SIZE` is the size of each message chunk in bytes.
+pub(crate) trait CompressionFunction<const CHUNK_SIZE: usize>: Copy + Zeroize {
+    /// Size of the digest in bytes, as written by [`CompressionFunction::write_bytes`].
+    const DIGEST_SIZE: usize;
+
+    /// Size of the total message length appended at the end of the padding, in bytes.
+    const LENGTH_SIZE: usize;
+
+    /// Whether the total message length is appended in little-endian instead of big-endian.
+    const LITTLE_ENDIAN_LENGTH: bool = false;
+
+    /// Produce a new digest by compressing the message chunk, and adding it to this digest.
+    fn process_chunk(&self, chunk: &[u8; CHUNK_SIZE]) -> Self;
+
+    /// Write the digest as bytes into `output`, in the byte order of the hash function. If
+    /// `output` is shorter than the digest, the digest is truncated.
+    fn write_bytes(&self, output: &mut [u8]);
+
+    /// Return a digest with the value read from `DIGEST_SIZE` bytes of `input`, in the byte
+    /// order of the hash function. Anything else in this digest is kept.
+    fn read_bytes(&self, input: &[u8]) -> Self;
+}
+
+/// State of a hash: the internal digest, and a partial chunk of message data which has not been
+/// compressed yet.
+///
+/// Full chunks are compressed directly from the data passed to [`MerkleDamgard::update`]; only
+/// the trailing partial chunk is copied into the fixed-size buffer, so updating never
+/// allocates.
+#[derive(Clone)]
+pub(crate) struct MerkleDamgard<D: Zeroize, const CHUNK_SIZE: usize> {
+    digest: D,
+    message_length: u128,
+    buffer: [u8; CHUNK_SIZE],
+    buffer_length: usize,
+}
+
+impl<D: CompressionFunction<CHUNK_SIZE>, const CHUNK_SIZE: usize> MerkleDamgard<D, CHUNK_SIZE> {
+    /// Create a new state starting from the initial digest of a hash function.
+    pub(crate) fn new(initial_digest: D) -> MerkleDamgard<D, CHUNK_SIZE> {
+        MerkleDamgard {
+            digest: initial_digest,
+            message_length: 0,
+            buffer: [0u8; CHUNK_SIZE],
+            buffer_length: 0,
+        }
+    }
+
+    /// Restore a state exported by [`MerkleDamgard::export`]. `initial_digest` only provides
+    /// what is kept by [`CompressionFunction::read_bytes`].
+    pub(crate) fn import(initial_digest: D, state: &[u8]) -> Result<MerkleDamgard<D, CHUNK_SIZE>> {
+        let header_size = D::DIGEST_SIZE + D::LENGTH_SIZE;
+        if state.len() < header_size {
+            return Err(Error::InvalidStateLength);
+        }
+
+        let mut length_bytes = [0u8; 16];
+        length_bytes[16 - D::LENGTH_SIZE..].copy_from_slice(&state[D::DIGEST_SIZE..header_size]);
+        let message_length = u128::from_be_bytes(length_bytes);
+
+        let buffered = &state[header_size..];
+        if buffered.len() as u128 != message_length % CHUNK_SIZE as u128 {
+            return Err(Error::InvalidStateLength);
+        }
+
+        let mut buffer = [0u8; CHUNK_SIZE];
+        buffer[..buffered.len()].copy_from_slice(buffered);
+
+        Ok(MerkleDamgard {
+            digest: initial_digest.read_bytes(&state[..D::DIGEST_SIZE]),
+            message_length,
+            buffer,
+            buffer_length: buffered.len(),
+        })
+    }
+
+    /// Export the state in the format described in [`ResumableHash`](crate::hash::ResumableHash).
+    pub(crate) fn export(&self) -> Vec<u8> {
+        let header_size = D::DIGEST_SIZE + D::LENGTH_SIZE;
+        let mut state = vec![0u8; header_size];
+        self.digest.write_bytes(&mut state[..D::DIGEST_SIZE]);
+
+        let length_bytes = self.message_length.to_be_bytes();
+        state[D::DIGEST_SIZE..].copy_from_slice(&length_bytes[16 - D::LENGTH_SIZE..]);
+
+        state.extend_from_slice(&self.buffer[..self.buffer_length]);
+        state
+    }
+
+    /// Compress as much data as possible, keeping the remainder for the next call.
+    pub(crate) fn update(&mut self, mut data: &[u8]) {
+        self.message_length += data.len() as u128;
+
+        // Complete the buffered chunk first.
+        if self.buffer_length > 0 {
+            let length = data.len().min(CHUNK_SIZE - self.buffer_length);
+            self.buffer[self.buffer_length..self.buffer_length + length]
+                .copy_from_slice(&data[..length]);
+            self.buffer_length += length;
+            data = &data[length..];
+
+            if self.buffer_length < CHUNK_SIZE {
+                return;
+            }
+
+            self.digest = self.digest.process_chunk(&self.buffer);
+            self.buffer_length = 0;
+        }
+
+        let mut chunks = data.chunks_exact(CHUNK_SIZE);
+        for chunk in &mut chunks {
+            let chunk = chunk
+                .try_into()
+                .expect("Chunk must be CHUNK_SIZE bytes long");
+            self.digest = self.digest.process_chunk(chunk);
+        }
+
+        let remainder = chunks.remainder();
+        self.buffer[..remainder.len()].copy_from_slice(remainder);
+        self.buffer_length = remainder.len();
+    }
+
+    /// Finalize the hash by hashing the remaining messages and any required paddings.
+    pub(crate) fn finalize(&self) -> D {
+        let mut digest = self.digest;
+        let mut chunk = self.buffer;
+
+        // First byte in the padding is 0b10000000, followed by 0x00 until the end of the chunk.
+        chunk[self.buffer_length] = 0b10000000;
+        for byte in chunk[self.buffer_length + 1..].iter_mut() {
+            *byte = 0x00;
+        }
+
+        // If there's no room left for the total message length, it goes into an extra chunk
+        // of padding.
+        if self.buffer_length + 1 > CHUNK_SIZE - D::LENGTH_SIZE {
+            digest = digest.process_chunk(&chunk);
+            chunk = [0u8; CHUNK_SIZE];
+        }
+
+        // The last LENGTH_SIZE bytes is the total message length in bits.
+        let final_length = self.message_length.wrapping_mul(8);
+        let length_bytes = &mut chunk[CHUNK_SIZE - D::LENGTH_SIZE..];
+        if D::LITTLE_ENDIAN_LENGTH {
+            length_bytes.copy_from_slice(&final_length.to_le_bytes()[..D::LENGTH_SIZE]);
+        } else {
+            let final_length_bytes = final_length.to_be_bytes();
+            length_bytes
+                .copy_from_slice(&final_length_bytes[final_length_bytes.len() - D::LENGTH_SIZE..]);
+        }
+
+        let digest = digest.process_chunk(&chunk);
+        chunk.zeroize();
+
+        digest
+    }
+}
+
+impl<D: Zeroize, const CHUNK_SIZE: usize> Drop for MerkleDamgard<D, CHUNK_SIZE> {
+    fn drop(&mut self) {
+        self.digest.zeroize();
+        self.message_length.zeroize();
+        self.buffer.zeroize();
+    }
+}
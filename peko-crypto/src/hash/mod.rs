@@ -1,13 +1,39 @@
 //! Cryptographic hash functions.
+mod blake2b;
+mod digest;
+mod dynamic;
+mod error;
+mod io;
+mod keccak;
+mod lanes;
+mod merkle_damgard;
+mod ripemd160;
 mod sha256;
+mod sha3;
+mod sha512;
+mod sponge;
+
+use crate::util::Zeroize;
+
+use std::fmt;
+use std::mem;
 
 /// Trait for a hash function.
 ///
 /// This trait is inspired by
-/// [Python's hashlib API](https://docs.python.org/3/library/hashlib.html).
-pub trait HashFunction<const OUTPUT_SIZE: usize> {
-    /// Type of the output. Must be set to `[u8; OUTPUT_SIZE]` in the implementation.
-    type Output;
+/// [Python's hashlib API](https://docs.python.org/3/library/hashlib.html). To pick a hash
+/// function at runtime, use [`DynHash`] instead.
+pub trait HashFunction {
+    /// Type of the output, a [`Digest`] of [`HashFunction::OUTPUT_SIZE`] bytes.
+    type Output: AsRef<[u8]> + AsMut<[u8]> + Copy + Eq + fmt::Debug + Zeroize;
+
+    /// Size of the output in bytes. Derived from [`HashFunction::Output`], so implementations
+    /// never need to set it.
+    const OUTPUT_SIZE: usize = mem::size_of::<Self::Output>();
+
+    /// Size of the blocks the hash function processes the message in, in bytes. Constructions
+    /// built on top of a hash function, such as HMAC, depend on it.
+    const BLOCK_SIZE: usize;
 
     /// Create a new instance of the hash function.
     fn new() -> Self;
@@ -16,15 +42,125 @@ pub trait HashFunction<const OUTPUT_SIZE: usize> {
     fn update(&mut self, data: &[u8]);
 
     /// Return the hash digest of all data that have been added so far.
-    fn digest(&self) -> [u8; OUTPUT_SIZE];
+    fn digest(&self) -> Self::Output;
 
     /// Calculate the hash digest of an array of bytes. This function is a shortcut to the
     /// new-update-digest process.
-    fn hash(data: &[u8]) -> [u8; OUTPUT_SIZE] {
+    fn hash(data: &[u8]) -> Self::Output
+    where
+        Self: Sized,
+    {
         let mut hasher = Self::new();
         hasher.update(data);
         hasher.digest()
     }
+
+    /// Calculate the hash digests of many independent messages, in the same order.
+    ///
+    /// This gives the same result as calling [`HashFunction::hash`] on every message, which is
+    /// what the default implementation does. Hash functions with a multi-buffer implementation,
+    /// such as SHA-256 and the Keccak family, hash several messages at once in SIMD lanes, which
+    /// is much faster for many short messages.
+    fn hash_batch(messages: &[&[u8]]) -> Vec<Self::Output>
+    where
+        Self: Sized,
+    {
+        messages.iter().map(|message| Self::hash(message)).collect()
+    }
+}
+
+/// Trait for a hash function whose state can be saved as bytes and restored later, so that
+/// hashing a long message can resume where it stopped.
+///
+/// For hash functions built on the Merkle-Damgård construction, the state is the chaining value
+/// in the byte order of the digest, followed by the number of bytes hashed so far as a
+/// big-endian integer as wide as the length in the padding, followed by the bytes of the partial
+/// block which has not been compressed yet. Since the chaining value of a finished hash is its
+/// digest, this also allows length extension and other midstate constructions.
+///
+/// For the Keccak family, the state is the 200-byte Keccak state with its lanes in
+/// little-endian order, followed by a single byte holding the position in the current block.
+///
+/// The state is only meaningful to the hash function which exported it, and since the partial
+/// block is part of the message, it is as sensitive as the message itself.
+///
+/// # Example
+/// ```
+/// use peko_crypto::hash::{HashFunction, ResumableHash, SHA256};
+///
+/// let mut hasher = SHA256::new();
+/// hasher.update(b"The quick brown fox ");
+/// let state = hasher.export_state();
+///
+/// let mut resumed = SHA256::import_state(&state).unwrap();
+/// resumed.update(b"jumps over the lazy dog");
+///
+/// assert_eq!(
+///     resumed.digest(),
+///     SHA256::hash(b"The quick brown fox jumps over the lazy dog")
+/// );
+/// ```
+pub trait ResumableHash: Sized {
+    /// Return the state of all data that have been added so far.
+    fn export_state(&self) -> Vec<u8>;
+
+    /// Create a new instance continuing from a state returned by
+    /// [`ResumableHash::export_state`].
+    fn import_state(state: &[u8]) -> Result<Self>;
+}
+
+/// Trait for an extendable-output function (XOF), a hash function whose output can be extended
+/// to any desired length.
+///
+/// Absorbing data follows the same new-update flow as [`HashFunction`]. Output is then squeezed
+/// through a [`XofReader`], as many bytes at a time as needed.
+pub trait ExtendableOutputFunction {
+    /// Type of the reader squeezing the output.
+    type Reader: XofReader;
+
+    /// Create a new instance of the function.
+    fn new() -> Self;
+
+    /// Update, or add more data to the current hash.
+    fn update(&mut self, data: &[u8]);
+
+    /// Return a reader squeezing the output for all data that have been added so far.
+    fn finalize_xof(&self) -> Self::Reader;
+
+    /// Return the first `length` bytes of output for all data that have been added so far.
+    fn digest(&self, length: usize) -> Vec<u8> {
+        let mut result = vec![0u8; length];
+        self.finalize_xof().read(&mut result);
+        result
+    }
+
+    /// Calculate the first `length` bytes of output for an array of bytes. This function is a
+    /// shortcut to the new-update-digest process.
+    fn hash(data: &[u8], length: usize) -> Vec<u8>
+    where
+        Self: Sized,
+    {
+        let mut xof = Self::new();
+        xof.update(data);
+        xof.digest(length)
+    }
+}
+
+/// Reader squeezing the output of an [`ExtendableOutputFunction`].
+pub trait XofReader {
+    /// Fill `output` with the next bytes of output. Consecutive calls continue where the previous
+    /// one stopped, so reading 32 bytes twice gives the same result as reading 64 bytes once.
+    fn read(&mut self, output: &mut [u8]);
 }
 
-pub use sha256::SHA256;
+pub use blake2b::{blake2b_f, BLAKE2b, BLAKE2b256, BLAKE2b512};
+pub use digest::{Digest, H256};
+pub use dynamic::{from_name, DynHash};
+pub use error::{Error, Result};
+pub use io::{HashReader, HashWriter};
+pub use keccak::{Keccak256, Keccak512};
+pub use ripemd160::RIPEMD160;
+pub use sha256::{SHA256Backend, SHA224, SHA256};
+pub use sha3::{SHA3_224, SHA3_256, SHA3_384, SHA3_512, SHAKE128, SHAKE256};
+pub use sha512::{SHA384, SHA512, SHA512_224, SHA512_256};
+pub use sponge::{Sponge, SpongeReader};
@@ -0,0 +1,203 @@
+use crate::hash::merkle_damgard::{CompressionFunction, MerkleDamgard};
+use crate::hash::{Digest, HashFunction, Result, ResumableHash};
+use crate::util::ZeroizeOnDrop;
+
+use std::convert::TryInto;
+
+/// The internal RIPEMD-160 digest. On output this internal digest will be converted to an
+/// array of u8s.
+type InternalDigest = [u32; 5];
+
+/// Initial digest value.
+const H: InternalDigest = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+/// Constants added in each round of the left line.
+const K_LEFT: [u32; 5] = [0x00000000, 0x5a827999, 0x6ed9eba1, 0x8f1bbcdc, 0xa953fd4e];
+
+/// Constants added in each round of the right line.
+const K_RIGHT: [u32; 5] = [0x50a28be6, 0x5c4dd124, 0x6d703ef3, 0x7a6d76e9, 0x00000000];
+
+/// Message word selected in each step of the left line.
+const R_LEFT: [usize; 80] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, //
+    7, 4, 13, 1, 10, 6, 15, 3, 12, 0, 9, 5, 2, 14, 11, 8, //
+    3, 10, 14, 4, 9, 15, 8, 1, 2, 7, 0, 6, 13, 11, 5, 12, //
+    1, 9, 11, 10, 0, 8, 12, 4, 13, 3, 7, 15, 14, 5, 6, 2, //
+    4, 0, 5, 9, 7, 12, 2, 10, 14, 1, 3, 8, 11, 6, 15, 13,
+];
+
+/// Message word selected in each step of the right line.
+const R_RIGHT: [usize; 80] = [
+    5, 14, 7, 0, 9, 2, 11, 4, 13, 6, 15, 8, 1, 10, 3, 12, //
+    6, 11, 3, 7, 0, 13, 5, 10, 14, 15, 8, 12, 4, 9, 1, 2, //
+    15, 5, 1, 3, 7, 14, 6, 9, 11, 8, 12, 2, 10, 0, 4, 13, //
+    8, 6, 4, 1, 3, 11, 15, 0, 5, 12, 2, 13, 9, 7, 10, 14, //
+    12, 15, 10, 4, 1, 5, 8, 7, 6, 2, 13, 14, 0, 3, 9, 11,
+];
+
+/// Left rotation applied in each step of the left line.
+const S_LEFT: [u32; 80] = [
+    11, 14, 15, 12, 5, 8, 7, 9, 11, 13, 14, 15, 6, 7, 9, 8, //
+    7, 6, 8, 13, 11, 9, 7, 15, 7, 12, 15, 9, 11, 7, 13, 12, //
+    11, 13, 6, 7, 14, 9, 13, 15, 14, 8, 13, 6, 5, 12, 7, 5, //
+    11, 12, 14, 15, 14, 15, 9, 8, 9, 14, 5, 6, 8, 6, 5, 12, //
+    9, 15, 5, 11, 6, 8, 13, 12, 5, 12, 13, 14, 11, 8, 5, 6,
+];
+
+/// Left rotation applied in each step of the right line.
+const S_RIGHT: [u32; 80] = [
+    8, 9, 9, 11, 13, 15, 15, 5, 7, 7, 8, 11, 14, 14, 12, 6, //
+    9, 13, 15, 7, 12, 8, 9, 11, 7, 7, 12, 7, 6, 15, 13, 11, //
+    9, 7, 15, 11, 8, 6, 6, 14, 12, 13, 5, 14, 13, 13, 7, 5, //
+    15, 5, 8, 11, 14, 14, 6, 14, 6, 9, 12, 9, 12, 5, 15, 8, //
+    8, 5, 12, 9, 12, 5, 14, 6, 8, 13, 6, 5, 15, 13, 11, 11,
+];
+
+/// Size of each message chunk in bytes.
+const CHUNK_SIZE: usize = 512 / 8;
+
+/// Type of each message chunk to be processed.
+type MessageChunk = [u8; CHUNK_SIZE];
+
+/// The non-linear function used in each round. The left line uses them in the order 0 to 4,
+/// while the right line uses them in reverse order.
+fn f(round: usize, x: u32, y: u32, z: u32) -> u32 {
+    match round {
+        0 => x ^ y ^ z,
+        1 => (x & y) | (!x & z),
+        2 => (x | !y) ^ z,
+        3 => (x & z) | (y & !z),
+        4 => x ^ (y | !z),
+        _ => unreachable!("RIPEMD-160 only has 5 rounds"),
+    }
+}
+
+/// Produce a new digest by compressing the message chunk, and adding it to the source digest.
+fn process_chunk(chunk: &MessageChunk, source_digest: &InternalDigest) -> InternalDigest {
+    // The chunk is interpreted as 16 little-endian words.
+    let mut x = [0u32; 16];
+    for (i, word) in chunk.chunks_exact(4).enumerate() {
+        x[i] = u32::from_le_bytes(word.try_into().expect("Word must be 4 bytes long"));
+    }
+
+    let [mut al, mut bl, mut cl, mut dl, mut el] = *source_digest;
+    let [mut ar, mut br, mut cr, mut dr, mut er] = *source_digest;
+
+    // The two lines are computed in parallel, each consisting of 5 rounds of 16 steps.
+    for j in 0..80 {
+        let round = j / 16;
+
+        let t = al
+            .wrapping_add(f(round, bl, cl, dl))
+            .wrapping_add(x[R_LEFT[j]])
+            .wrapping_add(K_LEFT[round])
+            .rotate_left(S_LEFT[j])
+            .wrapping_add(el);
+        al = el;
+        el = dl;
+        dl = cl.rotate_left(10);
+        cl = bl;
+        bl = t;
+
+        let t = ar
+            .wrapping_add(f(4 - round, br, cr, dr))
+            .wrapping_add(x[R_RIGHT[j]])
+            .wrapping_add(K_RIGHT[round])
+            .rotate_left(S_RIGHT[j])
+            .wrapping_add(er);
+        ar = er;
+        er = dr;
+        dr = cr.rotate_left(10);
+        cr = br;
+        br = t;
+    }
+
+    // Combine both lines with the source digest.
+    [
+        source_digest[1].wrapping_add(cl).wrapping_add(dr),
+        source_digest[2].wrapping_add(dl).wrapping_add(er),
+        source_digest[3].wrapping_add(el).wrapping_add(ar),
+        source_digest[4].wrapping_add(al).wrapping_add(br),
+        source_digest[0].wrapping_add(bl).wrapping_add(cr),
+    ]
+}
+
+impl CompressionFunction<CHUNK_SIZE> for InternalDigest {
+    const DIGEST_SIZE: usize = 160 / 8;
+    const LENGTH_SIZE: usize = 64 / 8;
+    const LITTLE_ENDIAN_LENGTH: bool = true;
+
+    fn process_chunk(&self, chunk: &MessageChunk) -> InternalDigest {
+        process_chunk(chunk, self)
+    }
+
+    fn write_bytes(&self, output: &mut [u8]) {
+        for (bytes, value) in output.chunks_mut(4).zip(self.iter()) {
+            bytes.copy_from_slice(&value.to_le_bytes()[..bytes.len()]);
+        }
+    }
+
+    fn read_bytes(&self, input: &[u8]) -> InternalDigest {
+        let mut digest = [0u32; 5];
+        for (value, bytes) in digest.iter_mut().zip(input.chunks_exact(4)) {
+            *value = u32::from_le_bytes(bytes.try_into().expect("Word must be 4 bytes long"));
+        }
+
+        digest
+    }
+}
+
+/// RACE Integrity Primitives Evaluation Message Digest with 160 bits of output.
+///
+/// Used by the Ethereum precompiled contract at address `0x03`.
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::hash::{HashFunction, RIPEMD160};
+///
+/// let data = b"The quick brown fox jumps over the lazy dog";
+/// let correct_digest = hex!("37f332f68db77bd9d7edd4969571ad671cf9dd3b");
+///
+/// assert_eq!(RIPEMD160::hash(data), correct_digest);
+/// ```
+#[derive(Clone)]
+pub struct RIPEMD160 {
+    state: MerkleDamgard<InternalDigest, CHUNK_SIZE>,
+}
+
+impl ZeroizeOnDrop for RIPEMD160 {}
+
+impl HashFunction for RIPEMD160 {
+    type Output = Digest<{ 160 / 8 }>;
+    const BLOCK_SIZE: usize = CHUNK_SIZE;
+
+    fn new() -> RIPEMD160 {
+        RIPEMD160 {
+            state: MerkleDamgard::new(H),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.state.update(data);
+    }
+
+    fn digest(&self) -> Self::Output {
+        let mut result = [0u8; 160 / 8];
+        self.state.finalize().write_bytes(&mut result);
+
+        Digest::new(result)
+    }
+}
+
+impl ResumableHash for RIPEMD160 {
+    fn export_state(&self) -> Vec<u8> {
+        self.state.export()
+    }
+
+    fn import_state(state: &[u8]) -> Result<RIPEMD160> {
+        Ok(RIPEMD160 {
+            state: MerkleDamgard::import(H, state)?,
+        })
+    }
+}
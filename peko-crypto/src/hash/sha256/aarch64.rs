@@ -0,0 +1,64 @@
+//! SHA-256 compression with the ARMv8 cryptographic extensions.
+//!
+//! `vsha256hq_u32` and `vsha256h2q_u32` perform four rounds at a time on the ABCD and EFGH
+//! halves of the state. The message schedule is computed four words at a time with
+//! `vsha256su0q_u32` and `vsha256su1q_u32`, in a ring of four registers.
+
+use std::arch::aarch64::*;
+
+use super::{InternalDigest, MessageChunk, K};
+
+/// Return whether the CPU supports the SHA-2 instructions.
+pub(super) fn is_available() -> bool {
+    std::arch::is_aarch64_feature_detected!("sha2")
+}
+
+/// Produce a new digest by compressing the message chunk, and adding it to the source digest.
+///
+/// # Safety
+/// The CPU must support the SHA-2 instructions, see [`is_available`].
+#[target_feature(enable = "sha2")]
+pub(super) unsafe fn process_chunk(
+    chunk: &MessageChunk,
+    source_digest: &InternalDigest,
+) -> InternalDigest {
+    let mut abcd = vld1q_u32(source_digest.as_ptr());
+    let mut efgh = vld1q_u32(source_digest[4..].as_ptr());
+
+    let abcd_save = abcd;
+    let efgh_save = efgh;
+
+    let mut w = [vdupq_n_u32(0); 4];
+    for (i, word) in w.iter_mut().enumerate() {
+        // Convert each 32-bit word from big-endian.
+        *word = vreinterpretq_u32_u8(vrev32q_u8(vld1q_u8(chunk[i * 16..].as_ptr())));
+    }
+
+    // Each iteration performs four rounds, with w[i % 4] holding the message words of the
+    // current rounds. These are then replaced with the words four iterations later.
+    for i in 0..16 {
+        let current = i % 4;
+        let msg = vaddq_u32(w[current], vld1q_u32(K[i * 4..].as_ptr()));
+
+        if i < 12 {
+            w[current] = vsha256su0q_u32(w[current], w[(i + 1) % 4]);
+        }
+
+        let abcd_previous = abcd;
+        abcd = vsha256hq_u32(abcd, efgh, msg);
+        efgh = vsha256h2q_u32(efgh, abcd_previous, msg);
+
+        if i < 12 {
+            w[current] = vsha256su1q_u32(w[current], w[(i + 2) % 4], w[(i + 3) % 4]);
+        }
+    }
+
+    abcd = vaddq_u32(abcd, abcd_save);
+    efgh = vaddq_u32(efgh, efgh_save);
+
+    let mut digest = [0u32; 8];
+    vst1q_u32(digest.as_mut_ptr(), abcd);
+    vst1q_u32(digest[4..].as_mut_ptr(), efgh);
+
+    digest
+}
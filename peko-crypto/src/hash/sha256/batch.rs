@@ -0,0 +1,173 @@
+//! Hashing many messages at once, eight at a time in the lanes of [`U32x8`].
+
+use crate::hash::lanes::U32x8;
+use crate::hash::Digest;
+
+use super::{InternalDigest, CHUNK_SIZE, K};
+
+use std::convert::TryInto;
+
+/// Return the number of chunks in the message after padding, which adds at least one byte and
+/// the 8-byte message length.
+fn chunk_count(length: usize) -> usize {
+    (length + 8) / CHUNK_SIZE + 1
+}
+
+/// Write chunk `index` of the padded message into `chunk`.
+fn padded_chunk(message: &[u8], index: usize, chunk: &mut [u8; CHUNK_SIZE]) {
+    let start = index * CHUNK_SIZE;
+    let data = message.get(start..).unwrap_or(&[]);
+    let length = data.len().min(CHUNK_SIZE);
+
+    chunk[..length].copy_from_slice(&data[..length]);
+    for byte in chunk[length..].iter_mut() {
+        *byte = 0x00;
+    }
+
+    // The padding starts with 0b10000000 right after the message, and ends with the message
+    // length in bits in the last chunk.
+    if (start..start + CHUNK_SIZE).contains(&message.len()) {
+        chunk[message.len() - start] = 0b10000000;
+    }
+    if index + 1 == chunk_count(message.len()) {
+        chunk[CHUNK_SIZE - 8..].copy_from_slice(&(message.len() as u64 * 8).to_be_bytes());
+    }
+}
+
+/// Compress one chunk into the digest of every lane. This is the same as the scalar
+/// `process_chunk`, on vectors instead of words.
+#[inline(always)]
+fn compress(digest: &mut [U32x8; 8], chunk: &[U32x8; 16]) {
+    let mut w = [U32x8::splat(0); 64];
+    w[..16].copy_from_slice(chunk);
+
+    for i in 16..64 {
+        let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+        let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+
+        w[i] = w[i - 16]
+            .wrapping_add(w[i - 7])
+            .wrapping_add(s1)
+            .wrapping_add(s0);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *digest;
+
+    for i in 0..64 {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch = (e & f) ^ ((!e) & g);
+        let temp1 = h
+            .wrapping_add(w[i])
+            .wrapping_add(U32x8::splat(K[i]))
+            .wrapping_add(s1)
+            .wrapping_add(ch);
+
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let temp2 = s0.wrapping_add(maj);
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temp1);
+        d = c;
+        c = b;
+        b = a;
+        a = temp1.wrapping_add(temp2);
+    }
+
+    for (word, value) in digest.iter_mut().zip([a, b, c, d, e, f, g, h].iter()) {
+        *word = word.wrapping_add(*value);
+    }
+}
+
+/// Hash every message, starting from `initial_digest` and truncating the output to `N` bytes.
+///
+/// Each lane works on its own message, and picks up the next one as soon as it's done, so
+/// messages of different lengths still keep all lanes busy.
+#[inline(always)]
+fn hash_batch_lanes<const N: usize>(
+    initial_digest: &InternalDigest,
+    messages: &[&[u8]],
+) -> Vec<Digest<N>> {
+    let mut digests = vec![Digest::default(); messages.len()];
+    let mut digest = [U32x8::splat(0); 8];
+
+    // The message being hashed by each lane, and the index of its next chunk.
+    let mut lanes: [Option<(usize, usize)>; U32x8::LANES] = [None; U32x8::LANES];
+    let mut next_message = 0;
+    let mut chunk = [0u8; CHUNK_SIZE];
+
+    loop {
+        let mut words = [U32x8::splat(0); 16];
+        let mut active = false;
+
+        for (lane, slot) in lanes.iter_mut().enumerate() {
+            if slot.is_none() && next_message < messages.len() {
+                *slot = Some((next_message, 0));
+                next_message += 1;
+
+                for (word, &value) in digest.iter_mut().zip(initial_digest.iter()) {
+                    word.0[lane] = value;
+                }
+            }
+
+            if let Some((message, index)) = *slot {
+                active = true;
+                padded_chunk(messages[message], index, &mut chunk);
+
+                for (word, bytes) in words.iter_mut().zip(chunk.chunks_exact(4)) {
+                    word.0[lane] =
+                        u32::from_be_bytes(bytes.try_into().expect("Word must be 4 bytes long"));
+                }
+            }
+        }
+
+        if !active {
+            break;
+        }
+
+        compress(&mut digest, &words);
+
+        for (lane, slot) in lanes.iter_mut().enumerate() {
+            if let Some((message, index)) = *slot {
+                if index + 1 < chunk_count(messages[message].len()) {
+                    *slot = Some((message, index + 1));
+                    continue;
+                }
+
+                for (bytes, word) in digests[message].chunks_mut(4).zip(digest.iter()) {
+                    bytes.copy_from_slice(&word.0[lane].to_be_bytes()[..bytes.len()]);
+                }
+                *slot = None;
+            }
+        }
+    }
+
+    digests
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "avx2")]
+unsafe fn hash_batch_avx2<const N: usize>(
+    initial_digest: &InternalDigest,
+    messages: &[&[u8]],
+) -> Vec<Digest<N>> {
+    hash_batch_lanes(initial_digest, messages)
+}
+
+/// Hash every message, starting from `initial_digest` and truncating the output to `N` bytes.
+pub(super) fn hash_batch<const N: usize>(
+    initial_digest: &InternalDigest,
+    messages: &[&[u8]],
+) -> Vec<Digest<N>> {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("avx2") {
+            // AVX2 support was just checked.
+            return unsafe { hash_batch_avx2(initial_digest, messages) };
+        }
+    }
+
+    hash_batch_lanes(initial_digest, messages)
+}
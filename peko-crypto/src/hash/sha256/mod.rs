@@ -0,0 +1,411 @@
+//! SHA-256 and SHA-224, with a portable compression function and hardware-accelerated ones
+//! selected at runtime.
+#[cfg(target_arch = "aarch64")]
+mod aarch64;
+mod batch;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86;
+
+use crate::hash::merkle_damgard::{CompressionFunction, MerkleDamgard};
+use crate::hash::{Digest, HashFunction, Result, ResumableHash};
+use crate::util::{Zeroize, ZeroizeOnDrop};
+
+use std::convert::TryInto;
+
+/// The internal SHA256 digest. On output this internal digest will be converted to an
+/// array of u8s.
+type InternalDigest = [u32; 8];
+
+/// Initial digest value.
+const H: InternalDigest = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// Initial digest value of SHA-224.
+const H224: InternalDigest = [
+    0xc1059ed8, 0x367cd507, 0x3070dd17, 0xf70e5939, 0xffc00b31, 0x68581511, 0x64f98fa7, 0xbefa4fa4,
+];
+
+/// Round constants.
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// Size of each message chunk in bytes.
+const CHUNK_SIZE: usize = 512 / 8;
+
+/// Type of each message chunk to be processed.
+type MessageChunk = [u8; CHUNK_SIZE];
+
+/// Produce a new digest by compressing the message chunk, and adding it to the source digest.
+fn process_chunk(chunk: &MessageChunk, source_digest: &InternalDigest) -> InternalDigest {
+    // Prepare the message schedule.
+    let mut w = [0u32; 64];
+
+    // Word 0..15 comes from the chunk.
+    for i in 0..16 {
+        w[i] = u32::from_be_bytes([
+            chunk[i * 4],
+            chunk[i * 4 + 1],
+            chunk[i * 4 + 2],
+            chunk[i * 4 + 3],
+        ])
+    }
+
+    // Extend the first 16 bytes to the remaining words.
+    for i in 16..64 {
+        let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+        let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+
+        w[i] = w[i - 16]
+            .wrapping_add(w[i - 7])
+            .wrapping_add(s1)
+            .wrapping_add(s0);
+    }
+
+    let mut a = source_digest[0];
+    let mut b = source_digest[1];
+    let mut c = source_digest[2];
+    let mut d = source_digest[3];
+    let mut e = source_digest[4];
+    let mut f = source_digest[5];
+    let mut g = source_digest[6];
+    let mut h = source_digest[7];
+
+    // Compression function.
+    for i in 0..64 {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch = (e & f) ^ ((!e) & g);
+        let temp1 = h
+            .wrapping_add(w[i])
+            .wrapping_add(K[i])
+            .wrapping_add(s1)
+            .wrapping_add(ch);
+
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let temp2 = s0.wrapping_add(maj);
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temp1);
+        d = c;
+        c = b;
+        b = a;
+        a = temp1.wrapping_add(temp2);
+    }
+
+    [
+        source_digest[0].wrapping_add(a),
+        source_digest[1].wrapping_add(b),
+        source_digest[2].wrapping_add(c),
+        source_digest[3].wrapping_add(d),
+        source_digest[4].wrapping_add(e),
+        source_digest[5].wrapping_add(f),
+        source_digest[6].wrapping_add(g),
+        source_digest[7].wrapping_add(h),
+    ]
+}
+
+/// Implementation of the SHA-256 compression function.
+///
+/// [`SHA256::new`] and [`SHA224::new`] pick the fastest backend the CPU supports. A specific
+/// backend can be forced with [`SHA256::with_backend`] and [`SHA224::with_backend`], e.g. to
+/// compare the output of all backends in tests.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SHA256Backend {
+    /// The portable implementation, available everywhere.
+    Portable,
+
+    /// The Intel SHA extensions (SHA-NI) on x86 and x86-64.
+    ShaNi,
+
+    /// The SHA-2 instructions of the ARMv8 cryptographic extensions on AArch64.
+    ArmV8,
+}
+
+impl SHA256Backend {
+    /// All backends, available or not.
+    pub const ALL: [SHA256Backend; 3] = [
+        SHA256Backend::Portable,
+        SHA256Backend::ShaNi,
+        SHA256Backend::ArmV8,
+    ];
+
+    /// Return the fastest backend available on this CPU.
+    pub fn detect() -> SHA256Backend {
+        if SHA256Backend::ShaNi.is_available() {
+            SHA256Backend::ShaNi
+        } else if SHA256Backend::ArmV8.is_available() {
+            SHA256Backend::ArmV8
+        } else {
+            SHA256Backend::Portable
+        }
+    }
+
+    /// Return whether the backend is supported by this CPU.
+    pub fn is_available(self) -> bool {
+        match self {
+            SHA256Backend::Portable => true,
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SHA256Backend::ShaNi => x86::is_available(),
+            #[cfg(target_arch = "aarch64")]
+            SHA256Backend::ArmV8 => aarch64::is_available(),
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+}
+
+/// The internal digest, together with the backend compressing message chunks into it.
+#[derive(Clone, Copy)]
+struct BackendDigest {
+    digest: InternalDigest,
+    backend: SHA256Backend,
+}
+
+impl BackendDigest {
+    /// Create a new digest. Panics if the backend is not available on this CPU.
+    fn new(digest: InternalDigest, backend: SHA256Backend) -> BackendDigest {
+        assert!(
+            backend.is_available(),
+            "SHA-256 backend {:?} is not available on this CPU",
+            backend
+        );
+
+        BackendDigest { digest, backend }
+    }
+}
+
+impl Zeroize for BackendDigest {
+    fn zeroize(&mut self) {
+        self.digest.zeroize();
+    }
+}
+
+impl CompressionFunction<CHUNK_SIZE> for BackendDigest {
+    const DIGEST_SIZE: usize = 256 / 8;
+    const LENGTH_SIZE: usize = 64 / 8;
+
+    fn process_chunk(&self, chunk: &MessageChunk) -> BackendDigest {
+        // The backend was checked to be available when the digest was created.
+        let digest = match self.backend {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SHA256Backend::ShaNi => unsafe { x86::process_chunk(chunk, &self.digest) },
+            #[cfg(target_arch = "aarch64")]
+            SHA256Backend::ArmV8 => unsafe { aarch64::process_chunk(chunk, &self.digest) },
+            _ => process_chunk(chunk, &self.digest),
+        };
+
+        BackendDigest {
+            digest,
+            backend: self.backend,
+        }
+    }
+
+    fn write_bytes(&self, output: &mut [u8]) {
+        for (bytes, value) in output.chunks_mut(4).zip(self.digest.iter()) {
+            bytes.copy_from_slice(&value.to_be_bytes()[..bytes.len()]);
+        }
+    }
+
+    fn read_bytes(&self, input: &[u8]) -> BackendDigest {
+        let mut digest = [0u32; 8];
+        for (value, bytes) in digest.iter_mut().zip(input.chunks_exact(4)) {
+            *value = u32::from_be_bytes(bytes.try_into().expect("Word must be 4 bytes long"));
+        }
+
+        BackendDigest {
+            digest,
+            backend: self.backend,
+        }
+    }
+}
+
+/// Secure Hashing Algorithm-2 with 256 bits of output.
+///
+/// # Example using the new-update-digest flow
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::hash::{HashFunction, SHA256};
+///
+/// let data = b"The quick brown fox jumps over the lazy dog";
+/// let correct_digest = hex!("d7a8fbb307d7809469ca9abcb0082e4f8d5651e46d3cdb762d02d0bf37c9e592");
+///
+/// let mut hasher = SHA256::new();
+/// hasher.update(data);
+/// assert_eq!(hasher.digest(), correct_digest)
+/// ```
+///
+/// # Example using the one-shot function
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::hash::{HashFunction, SHA256};
+///
+/// let data = b"The quick brown fox jumps over the lazy dog";
+/// let correct_digest = hex!("d7a8fbb307d7809469ca9abcb0082e4f8d5651e46d3cdb762d02d0bf37c9e592");
+///
+/// assert_eq!(SHA256::hash(data), correct_digest);
+/// ```
+#[derive(Clone)]
+pub struct SHA256 {
+    state: MerkleDamgard<BackendDigest, CHUNK_SIZE>,
+}
+
+impl ZeroizeOnDrop for SHA256 {}
+
+impl SHA256 {
+    /// Create a new instance using the given backend, instead of the fastest one available.
+    ///
+    /// Panics if the backend is not available on this CPU.
+    pub fn with_backend(backend: SHA256Backend) -> SHA256 {
+        SHA256 {
+            state: MerkleDamgard::new(BackendDigest::new(H, backend)),
+        }
+    }
+
+    /// Calculate the hash digests of many independent messages using the given backend,
+    /// instead of the fastest one available. The portable backend hashes eight messages at once
+    /// in SIMD lanes, while the hardware-accelerated ones hash them one after the other.
+    ///
+    /// Panics if the backend is not available on this CPU.
+    pub fn hash_batch_with_backend(
+        backend: SHA256Backend,
+        messages: &[&[u8]],
+    ) -> Vec<Digest<{ 256 / 8 }>> {
+        match backend {
+            SHA256Backend::Portable => batch::hash_batch(&H, messages),
+            _ => messages
+                .iter()
+                .map(|message| {
+                    let mut hasher = SHA256::with_backend(backend);
+                    hasher.update(message);
+                    hasher.digest()
+                })
+                .collect(),
+        }
+    }
+}
+
+impl HashFunction for SHA256 {
+    type Output = Digest<{ 256 / 8 }>;
+    const BLOCK_SIZE: usize = CHUNK_SIZE;
+
+    fn new() -> SHA256 {
+        SHA256::with_backend(SHA256Backend::detect())
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.state.update(data);
+    }
+
+    fn digest(&self) -> Self::Output {
+        let mut result = [0u8; 256 / 8];
+        self.state.finalize().write_bytes(&mut result);
+
+        Digest::new(result)
+    }
+
+    fn hash_batch(messages: &[&[u8]]) -> Vec<Self::Output> {
+        SHA256::hash_batch_with_backend(SHA256Backend::detect(), messages)
+    }
+}
+
+impl ResumableHash for SHA256 {
+    fn export_state(&self) -> Vec<u8> {
+        self.state.export()
+    }
+
+    fn import_state(state: &[u8]) -> Result<SHA256> {
+        Ok(SHA256 {
+            state: MerkleDamgard::import(BackendDigest::new(H, SHA256Backend::detect()), state)?,
+        })
+    }
+}
+
+/// Secure Hashing Algorithm-2 with 224 bits of output.
+///
+/// SHA-224 is SHA-256 with a different initial digest, truncated to 224 bits.
+#[derive(Clone)]
+pub struct SHA224 {
+    state: MerkleDamgard<BackendDigest, CHUNK_SIZE>,
+}
+
+impl ZeroizeOnDrop for SHA224 {}
+
+impl SHA224 {
+    /// Create a new instance using the given backend, instead of the fastest one available.
+    ///
+    /// Panics if the backend is not available on this CPU.
+    pub fn with_backend(backend: SHA256Backend) -> SHA224 {
+        SHA224 {
+            state: MerkleDamgard::new(BackendDigest::new(H224, backend)),
+        }
+    }
+
+    /// Calculate the hash digests of many independent messages using the given backend,
+    /// instead of the fastest one available. The portable backend hashes eight messages at once
+    /// in SIMD lanes, while the hardware-accelerated ones hash them one after the other.
+    ///
+    /// Panics if the backend is not available on this CPU.
+    pub fn hash_batch_with_backend(
+        backend: SHA256Backend,
+        messages: &[&[u8]],
+    ) -> Vec<Digest<{ 224 / 8 }>> {
+        match backend {
+            SHA256Backend::Portable => batch::hash_batch(&H224, messages),
+            _ => messages
+                .iter()
+                .map(|message| {
+                    let mut hasher = SHA224::with_backend(backend);
+                    hasher.update(message);
+                    hasher.digest()
+                })
+                .collect(),
+        }
+    }
+}
+
+impl HashFunction for SHA224 {
+    type Output = Digest<{ 224 / 8 }>;
+    const BLOCK_SIZE: usize = CHUNK_SIZE;
+
+    fn new() -> SHA224 {
+        SHA224::with_backend(SHA256Backend::detect())
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.state.update(data);
+    }
+
+    fn digest(&self) -> Self::Output {
+        let mut result = [0u8; 224 / 8];
+        self.state.finalize().write_bytes(&mut result);
+
+        Digest::new(result)
+    }
+
+    fn hash_batch(messages: &[&[u8]]) -> Vec<Self::Output> {
+        SHA224::hash_batch_with_backend(SHA256Backend::detect(), messages)
+    }
+}
+
+impl ResumableHash for SHA224 {
+    fn export_state(&self) -> Vec<u8> {
+        self.state.export()
+    }
+
+    fn import_state(state: &[u8]) -> Result<SHA224> {
+        Ok(SHA224 {
+            state: MerkleDamgard::import(BackendDigest::new(H224, SHA256Backend::detect()), state)?,
+        })
+    }
+}
@@ -0,0 +1,99 @@
+//! SHA-256 compression with the Intel SHA extensions.
+//!
+//! The SHA-NI instructions keep the state in two registers as ABEF and CDGH, and perform two
+//! rounds at a time. The message schedule is computed four words at a time with `sha256msg1` and
+//! `sha256msg2`, in a ring of four registers.
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use super::{InternalDigest, MessageChunk, K};
+
+/// Return whether the CPU supports SHA-NI, and the SSE extensions used alongside.
+pub(super) fn is_available() -> bool {
+    is_x86_feature_detected!("sha")
+        && is_x86_feature_detected!("sse2")
+        && is_x86_feature_detected!("ssse3")
+        && is_x86_feature_detected!("sse4.1")
+}
+
+/// Produce a new digest by compressing the message chunk, and adding it to the source digest.
+///
+/// # Safety
+/// The CPU must support SHA-NI, see [`is_available`].
+#[target_feature(enable = "sha,sse2,ssse3,sse4.1")]
+pub(super) unsafe fn process_chunk(
+    chunk: &MessageChunk,
+    source_digest: &InternalDigest,
+) -> InternalDigest {
+    // Shuffle mask converting each 32-bit word from big-endian.
+    let mask = _mm_set_epi64x(
+        0x0c0d_0e0f_0809_0a0b_u64 as i64,
+        0x0405_0607_0001_0203_u64 as i64,
+    );
+
+    // Rearrange the digest from ABCD, EFGH to ABEF, CDGH.
+    let abcd = _mm_shuffle_epi32(
+        _mm_loadu_si128(source_digest.as_ptr() as *const __m128i),
+        0xb1,
+    );
+    let efgh = _mm_shuffle_epi32(
+        _mm_loadu_si128(source_digest[4..].as_ptr() as *const __m128i),
+        0x1b,
+    );
+    let mut abef = _mm_alignr_epi8(abcd, efgh, 8);
+    let mut cdgh = _mm_blend_epi16(efgh, abcd, 0xf0);
+
+    let abef_save = abef;
+    let cdgh_save = cdgh;
+
+    let mut w = [_mm_setzero_si128(); 4];
+    for (i, word) in w.iter_mut().enumerate() {
+        *word = _mm_shuffle_epi8(
+            _mm_loadu_si128(chunk[i * 16..].as_ptr() as *const __m128i),
+            mask,
+        );
+    }
+
+    // Each iteration performs four rounds, with w[i % 4] holding the message words of the
+    // current rounds.
+    for i in 0..16 {
+        let msg = _mm_add_epi32(
+            w[i % 4],
+            _mm_loadu_si128(K[i * 4..].as_ptr() as *const __m128i),
+        );
+        cdgh = _mm_sha256rnds2_epu32(cdgh, abef, msg);
+
+        // Finish computing the words of the next rounds.
+        if (3..15).contains(&i) {
+            let next = (i + 1) % 4;
+            let tmp = _mm_alignr_epi8(w[i % 4], w[(i + 3) % 4], 4);
+            w[next] = _mm_sha256msg2_epu32(_mm_add_epi32(w[next], tmp), w[i % 4]);
+        }
+
+        abef = _mm_sha256rnds2_epu32(abef, cdgh, _mm_shuffle_epi32(msg, 0x0e));
+
+        // Start computing the words of the rounds after the next three.
+        if (1..13).contains(&i) {
+            let previous = (i + 3) % 4;
+            w[previous] = _mm_sha256msg1_epu32(w[previous], w[i % 4]);
+        }
+    }
+
+    abef = _mm_add_epi32(abef, abef_save);
+    cdgh = _mm_add_epi32(cdgh, cdgh_save);
+
+    // Rearrange the digest back from ABEF, CDGH to ABCD, EFGH.
+    let feba = _mm_shuffle_epi32(abef, 0x1b);
+    let dchg = _mm_shuffle_epi32(cdgh, 0xb1);
+    let abcd = _mm_blend_epi16(feba, dchg, 0xf0);
+    let efgh = _mm_alignr_epi8(dchg, feba, 8);
+
+    let mut digest = [0u32; 8];
+    _mm_storeu_si128(digest.as_mut_ptr() as *mut __m128i, abcd);
+    _mm_storeu_si128(digest[4..].as_mut_ptr() as *mut __m128i, efgh);
+
+    digest
+}
@@ -0,0 +1,146 @@
+//! The SHA-3 family of hash functions and the SHAKE extendable-output functions, as specified in
+//! [FIPS 202](https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.202.pdf).
+
+use crate::hash::sponge::{self, Sponge, SpongeReader, STATE_SIZE};
+use crate::hash::{
+    Digest, ExtendableOutputFunction, HashFunction, Result, ResumableHash, XofReader,
+};
+use crate::util::ZeroizeOnDrop;
+
+/// Domain separation bits and the first padding bit for SHA-3.
+const SHA3_DOMAIN: u8 = 0x06;
+
+/// Domain separation bits and the first padding bit for SHAKE.
+const SHAKE_DOMAIN: u8 = 0x1f;
+
+macro_rules! define_sha3 {
+    ($name: ident, $bits: expr, $doc: expr) => {
+        #[doc = $doc]
+        #[allow(non_camel_case_types)]
+        #[derive(Clone)]
+        pub struct $name {
+            sponge: Sponge,
+        }
+
+        impl ZeroizeOnDrop for $name {}
+
+        impl HashFunction for $name {
+            type Output = Digest<{ $bits / 8 }>;
+            // Capacity is twice the output size.
+            const BLOCK_SIZE: usize = STATE_SIZE - 2 * ($bits / 8);
+
+            fn new() -> $name {
+                $name {
+                    sponge: Sponge::new(Self::BLOCK_SIZE),
+                }
+            }
+
+            fn update(&mut self, data: &[u8]) {
+                self.sponge.absorb(data);
+            }
+
+            fn digest(&self) -> Self::Output {
+                let mut result = [0u8; $bits / 8];
+                self.sponge.clone().finalize(SHA3_DOMAIN).read(&mut result);
+
+                Digest::new(result)
+            }
+
+            fn hash_batch(messages: &[&[u8]]) -> Vec<Self::Output> {
+                sponge::hash_batch(Self::BLOCK_SIZE, SHA3_DOMAIN, messages)
+            }
+        }
+
+        impl ResumableHash for $name {
+            fn export_state(&self) -> Vec<u8> {
+                self.sponge.export()
+            }
+
+            fn import_state(state: &[u8]) -> Result<$name> {
+                Ok($name {
+                    sponge: Sponge::import(Self::BLOCK_SIZE, state)?,
+                })
+            }
+        }
+    };
+}
+
+macro_rules! define_shake {
+    ($name: ident, $security_bits: expr, $doc: expr) => {
+        #[doc = $doc]
+        #[derive(Clone)]
+        pub struct $name {
+            sponge: Sponge,
+        }
+
+        impl ZeroizeOnDrop for $name {}
+
+        impl ExtendableOutputFunction for $name {
+            type Reader = SpongeReader;
+
+            fn new() -> $name {
+                $name {
+                    // Capacity is twice the security level.
+                    sponge: Sponge::new(STATE_SIZE - 2 * ($security_bits / 8)),
+                }
+            }
+
+            fn update(&mut self, data: &[u8]) {
+                self.sponge.absorb(data);
+            }
+
+            fn finalize_xof(&self) -> SpongeReader {
+                self.sponge.clone().finalize(SHAKE_DOMAIN)
+            }
+        }
+    };
+}
+
+define_sha3!(SHA3_224, 224, "SHA-3 with 224 bits of output.");
+define_sha3!(
+    SHA3_256,
+    256,
+    r#"SHA-3 with 256 bits of output.
+
+# Example
+```
+use hex_literal::hex;
+use peko_crypto::hash::{HashFunction, SHA3_256};
+
+let data = b"The quick brown fox jumps over the lazy dog";
+let correct_digest = hex!("69070dda01975c8c120c3aada1b282394e7f032fa9cf32f4cb2259a0897dfc04");
+
+assert_eq!(SHA3_256::hash(data), correct_digest);
+```"#
+);
+define_sha3!(SHA3_384, 384, "SHA-3 with 384 bits of output.");
+define_sha3!(SHA3_512, 512, "SHA-3 with 512 bits of output.");
+
+define_shake!(
+    SHAKE128,
+    128,
+    r#"SHAKE128 extendable-output function, with 128 bits of security.
+
+# Example squeezing the output in several steps
+```
+use hex_literal::hex;
+use peko_crypto::hash::{ExtendableOutputFunction, XofReader, SHAKE128};
+
+let mut shake = SHAKE128::new();
+shake.update(b"The quick brown fox jumps over the lazy dog");
+
+let mut reader = shake.finalize_xof();
+let mut first = [0u8; 16];
+let mut second = [0u8; 16];
+reader.read(&mut first);
+reader.read(&mut second);
+
+assert_eq!(first, hex!("f4202e3c5852f9182a0430fd8144f0a7"));
+assert_eq!(second, hex!("4b95e7417ecae17db0f8cfeed0e3e66e"));
+```"#
+);
+define_shake!(
+    SHAKE256,
+    256,
+    "SHAKE256 extendable-output function, with 256 bits of security."
+);
@@ -0,0 +1,328 @@
+use crate::hash::merkle_damgard::{CompressionFunction, MerkleDamgard};
+use crate::hash::{Digest, HashFunction, Result, ResumableHash};
+use crate::util::ZeroizeOnDrop;
+
+use std::convert::TryInto;
+
+/// The internal SHA-512 digest, shared by all 64-bit SHA-2 variants. On output this internal
+/// digest will be converted to an array of u8s.
+type InternalDigest = [u64; 8];
+
+/// Initial digest value of SHA-512.
+const H512: InternalDigest = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+/// Initial digest value of SHA-384.
+const H384: InternalDigest = [
+    0xcbbb9d5dc1059ed8,
+    0x629a292a367cd507,
+    0x9159015a3070dd17,
+    0x152fecd8f70e5939,
+    0x67332667ffc00b31,
+    0x8eb44a8768581511,
+    0xdb0c2e0d64f98fa7,
+    0x47b5481dbefa4fa4,
+];
+
+/// Initial digest value of SHA-512/224.
+///
+/// FIPS 180-4 defines SHA-512/t for other output sizes, whose initial digests are generated
+/// from the one of SHA-512. Only SHA-512/224 and SHA-512/256 are provided, with the initial
+/// digests listed in the standard.
+const H512_224: InternalDigest = [
+    0x8c3d37c819544da2,
+    0x73e1996689dcd4d6,
+    0x1dfab7ae32ff9c82,
+    0x679dd514582f9fcf,
+    0x0f6d2b697bd44da8,
+    0x77e36f7304c48942,
+    0x3f9d85a86a1d36c8,
+    0x1112e6ad91d692a1,
+];
+
+/// Initial digest value of SHA-512/256.
+const H512_256: InternalDigest = [
+    0x22312194fc2bf72c,
+    0x9f555fa3c84c64c2,
+    0x2393b86b6f53b151,
+    0x963877195940eabd,
+    0x96283ee2a88effe3,
+    0xbe5e1e2553863992,
+    0x2b0199fc2c85b8aa,
+    0x0eb72ddc81c52ca2,
+];
+
+/// Round constants.
+const K: [u64; 80] = [
+    0x428a2f98d728ae22,
+    0x7137449123ef65cd,
+    0xb5c0fbcfec4d3b2f,
+    0xe9b5dba58189dbbc,
+    0x3956c25bf348b538,
+    0x59f111f1b605d019,
+    0x923f82a4af194f9b,
+    0xab1c5ed5da6d8118,
+    0xd807aa98a3030242,
+    0x12835b0145706fbe,
+    0x243185be4ee4b28c,
+    0x550c7dc3d5ffb4e2,
+    0x72be5d74f27b896f,
+    0x80deb1fe3b1696b1,
+    0x9bdc06a725c71235,
+    0xc19bf174cf692694,
+    0xe49b69c19ef14ad2,
+    0xefbe4786384f25e3,
+    0x0fc19dc68b8cd5b5,
+    0x240ca1cc77ac9c65,
+    0x2de92c6f592b0275,
+    0x4a7484aa6ea6e483,
+    0x5cb0a9dcbd41fbd4,
+    0x76f988da831153b5,
+    0x983e5152ee66dfab,
+    0xa831c66d2db43210,
+    0xb00327c898fb213f,
+    0xbf597fc7beef0ee4,
+    0xc6e00bf33da88fc2,
+    0xd5a79147930aa725,
+    0x06ca6351e003826f,
+    0x142929670a0e6e70,
+    0x27b70a8546d22ffc,
+    0x2e1b21385c26c926,
+    0x4d2c6dfc5ac42aed,
+    0x53380d139d95b3df,
+    0x650a73548baf63de,
+    0x766a0abb3c77b2a8,
+    0x81c2c92e47edaee6,
+    0x92722c851482353b,
+    0xa2bfe8a14cf10364,
+    0xa81a664bbc423001,
+    0xc24b8b70d0f89791,
+    0xc76c51a30654be30,
+    0xd192e819d6ef5218,
+    0xd69906245565a910,
+    0xf40e35855771202a,
+    0x106aa07032bbd1b8,
+    0x19a4c116b8d2d0c8,
+    0x1e376c085141ab53,
+    0x2748774cdf8eeb99,
+    0x34b0bcb5e19b48a8,
+    0x391c0cb3c5c95a63,
+    0x4ed8aa4ae3418acb,
+    0x5b9cca4f7763e373,
+    0x682e6ff3d6b2b8a3,
+    0x748f82ee5defb2fc,
+    0x78a5636f43172f60,
+    0x84c87814a1f0ab72,
+    0x8cc702081a6439ec,
+    0x90befffa23631e28,
+    0xa4506cebde82bde9,
+    0xbef9a3f7b2c67915,
+    0xc67178f2e372532b,
+    0xca273eceea26619c,
+    0xd186b8c721c0c207,
+    0xeada7dd6cde0eb1e,
+    0xf57d4f7fee6ed178,
+    0x06f067aa72176fba,
+    0x0a637dc5a2c898a6,
+    0x113f9804bef90dae,
+    0x1b710b35131c471b,
+    0x28db77f523047d84,
+    0x32caab7b40c72493,
+    0x3c9ebe0a15c9bebc,
+    0x431d67c49c100d4c,
+    0x4cc5d4becb3e42b6,
+    0x597f299cfc657e2a,
+    0x5fcb6fab3ad6faec,
+    0x6c44198c4a475817,
+];
+
+/// Size of each message chunk in bytes.
+const CHUNK_SIZE: usize = 1024 / 8;
+
+/// Type of each message chunk to be processed.
+type MessageChunk = [u8; CHUNK_SIZE];
+
+/// Produce a new digest by compressing the message chunk, and adding it to the source digest.
+fn process_chunk(chunk: &MessageChunk, source_digest: &InternalDigest) -> InternalDigest {
+    // Prepare the message schedule.
+    let mut w = [0u64; 80];
+
+    // Word 0..15 comes from the chunk.
+    for (i, word) in chunk.chunks_exact(8).enumerate() {
+        w[i] = u64::from_be_bytes(word.try_into().expect("Word must be 8 bytes long"));
+    }
+
+    // Extend the first 16 words to the remaining words.
+    for i in 16..80 {
+        let s0 = w[i - 15].rotate_right(1) ^ w[i - 15].rotate_right(8) ^ (w[i - 15] >> 7);
+        let s1 = w[i - 2].rotate_right(19) ^ w[i - 2].rotate_right(61) ^ (w[i - 2] >> 6);
+
+        w[i] = w[i - 16]
+            .wrapping_add(w[i - 7])
+            .wrapping_add(s1)
+            .wrapping_add(s0);
+    }
+
+    let mut a = source_digest[0];
+    let mut b = source_digest[1];
+    let mut c = source_digest[2];
+    let mut d = source_digest[3];
+    let mut e = source_digest[4];
+    let mut f = source_digest[5];
+    let mut g = source_digest[6];
+    let mut h = source_digest[7];
+
+    // Compression function.
+    for i in 0..80 {
+        let s1 = e.rotate_right(14) ^ e.rotate_right(18) ^ e.rotate_right(41);
+        let ch = (e & f) ^ ((!e) & g);
+        let temp1 = h
+            .wrapping_add(w[i])
+            .wrapping_add(K[i])
+            .wrapping_add(s1)
+            .wrapping_add(ch);
+
+        let s0 = a.rotate_right(28) ^ a.rotate_right(34) ^ a.rotate_right(39);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let temp2 = s0.wrapping_add(maj);
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temp1);
+        d = c;
+        c = b;
+        b = a;
+        a = temp1.wrapping_add(temp2);
+    }
+
+    [
+        source_digest[0].wrapping_add(a),
+        source_digest[1].wrapping_add(b),
+        source_digest[2].wrapping_add(c),
+        source_digest[3].wrapping_add(d),
+        source_digest[4].wrapping_add(e),
+        source_digest[5].wrapping_add(f),
+        source_digest[6].wrapping_add(g),
+        source_digest[7].wrapping_add(h),
+    ]
+}
+
+impl CompressionFunction<CHUNK_SIZE> for InternalDigest {
+    const DIGEST_SIZE: usize = 512 / 8;
+    const LENGTH_SIZE: usize = 128 / 8;
+
+    fn process_chunk(&self, chunk: &MessageChunk) -> InternalDigest {
+        process_chunk(chunk, self)
+    }
+
+    fn write_bytes(&self, output: &mut [u8]) {
+        for (bytes, value) in output.chunks_mut(8).zip(self.iter()) {
+            bytes.copy_from_slice(&value.to_be_bytes()[..bytes.len()]);
+        }
+    }
+
+    fn read_bytes(&self, input: &[u8]) -> InternalDigest {
+        let mut digest = [0u64; 8];
+        for (value, bytes) in digest.iter_mut().zip(input.chunks_exact(8)) {
+            *value = u64::from_be_bytes(bytes.try_into().expect("Word must be 8 bytes long"));
+        }
+
+        digest
+    }
+}
+
+macro_rules! define_sha512 {
+    ($name: ident, $initial_digest: expr, $bits: expr, $doc: expr) => {
+        #[doc = $doc]
+        #[allow(non_camel_case_types)]
+        #[derive(Clone)]
+        pub struct $name {
+            state: MerkleDamgard<InternalDigest, CHUNK_SIZE>,
+        }
+
+        impl ZeroizeOnDrop for $name {}
+
+        impl HashFunction for $name {
+            type Output = Digest<{ $bits / 8 }>;
+            const BLOCK_SIZE: usize = CHUNK_SIZE;
+
+            fn new() -> $name {
+                $name {
+                    state: MerkleDamgard::new($initial_digest),
+                }
+            }
+
+            fn update(&mut self, data: &[u8]) {
+                self.state.update(data);
+            }
+
+            fn digest(&self) -> Self::Output {
+                let mut result = [0u8; $bits / 8];
+                self.state.finalize().write_bytes(&mut result);
+
+                Digest::new(result)
+            }
+        }
+
+        impl ResumableHash for $name {
+            fn export_state(&self) -> Vec<u8> {
+                self.state.export()
+            }
+
+            fn import_state(state: &[u8]) -> Result<$name> {
+                Ok($name {
+                    state: MerkleDamgard::import($initial_digest, state)?,
+                })
+            }
+        }
+    };
+}
+
+define_sha512!(
+    SHA512,
+    H512,
+    512,
+    r#"Secure Hashing Algorithm-2 with 512 bits of output.
+
+# Example
+```
+use hex_literal::hex;
+use peko_crypto::hash::{HashFunction, SHA512};
+
+let data = b"The quick brown fox jumps over the lazy dog";
+let correct_digest = hex!(
+    "07e547d9586f6a73f73fbac0435ed76951218fb7d0c8d788a309d785436bbb64"
+    "2e93a252a954f23912547d1e8a3b5ed6e1bfd7097821233fa0538f3db854fee6"
+);
+
+assert_eq!(SHA512::hash(data), correct_digest);
+```"#
+);
+define_sha512!(
+    SHA384,
+    H384,
+    384,
+    "Secure Hashing Algorithm-2 with 384 bits of output.\n\n\
+     SHA-384 is SHA-512 with a different initial digest, truncated to 384 bits."
+);
+define_sha512!(
+    SHA512_224,
+    H512_224,
+    224,
+    "SHA-512/224: SHA-512 with a different initial digest, truncated to 224 bits."
+);
+define_sha512!(
+    SHA512_256,
+    H512_256,
+    256,
+    "SHA-512/256: SHA-512 with a different initial digest, truncated to 256 bits."
+);
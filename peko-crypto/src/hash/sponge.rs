@@ -0,0 +1,424 @@
+//! The Keccak-f[1600] permutation and the sponge construction built on it.
+//!
+//! Keccak-256, SHA-3 and SHAKE are all instances of the same sponge, differing only in
+//! their rate and domain separation bits.
+
+use crate::hash::lanes::U64x4;
+use crate::hash::{Digest, Error, Result, XofReader};
+use crate::util::{Zeroize, ZeroizeOnDrop};
+
+use std::convert::TryInto;
+
+/// Number of rounds in Keccak-f[1600].
+const ROUNDS: usize = 24;
+
+/// Round constants, XOR-ed into lane (0, 0) in the iota step.
+const RC: [u64; ROUNDS] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// Rotation offsets of the rho step, in the order the lanes are visited by the pi step.
+const RHO: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+/// Lane visiting order of the pi step.
+const PI: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+/// The Keccak state: 5x5 lanes of 64 bits each. Lane (x, y) is stored at index x + 5 * y.
+type State = [u64; 25];
+
+/// Size of the Keccak state in bytes.
+pub(crate) const STATE_SIZE: usize = 1600 / 8;
+
+/// Apply the Keccak-f[1600] permutation to the state.
+fn keccak_f(state: &mut State) {
+    for &round_constant in RC.iter() {
+        // Theta step.
+        let mut c = [0u64; 5];
+        for x in 0..5 {
+            c[x] = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                state[x + 5 * y] ^= d;
+            }
+        }
+
+        // Rho and pi steps.
+        let mut last = state[1];
+        for i in 0..24 {
+            let temp = state[PI[i]];
+            state[PI[i]] = last.rotate_left(RHO[i]);
+            last = temp;
+        }
+
+        // Chi step.
+        for y in 0..5 {
+            let row = [
+                state[5 * y],
+                state[5 * y + 1],
+                state[5 * y + 2],
+                state[5 * y + 3],
+                state[5 * y + 4],
+            ];
+
+            for x in 0..5 {
+                state[x + 5 * y] = row[x] ^ ((!row[(x + 1) % 5]) & row[(x + 2) % 5]);
+            }
+        }
+
+        // Iota step.
+        state[0] ^= round_constant;
+    }
+}
+
+/// Apply the Keccak-f[1600] permutation to the state of every lane. This is the same as
+/// [`keccak_f`], on vectors instead of words.
+#[inline(always)]
+fn keccak_f_lanes(state: &mut [U64x4; 25]) {
+    for &round_constant in RC.iter() {
+        // Theta step.
+        let mut c = [U64x4::splat(0); 5];
+        for x in 0..5 {
+            c[x] = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                state[x + 5 * y] = state[x + 5 * y] ^ d;
+            }
+        }
+
+        // Rho and pi steps.
+        let mut last = state[1];
+        for i in 0..24 {
+            let temp = state[PI[i]];
+            state[PI[i]] = last.rotate_left(RHO[i]);
+            last = temp;
+        }
+
+        // Chi step.
+        for y in 0..5 {
+            let row = [
+                state[5 * y],
+                state[5 * y + 1],
+                state[5 * y + 2],
+                state[5 * y + 3],
+                state[5 * y + 4],
+            ];
+
+            for x in 0..5 {
+                state[x + 5 * y] = row[x] ^ ((!row[(x + 1) % 5]) & row[(x + 2) % 5]);
+            }
+        }
+
+        // Iota step.
+        state[0] = state[0] ^ U64x4::splat(round_constant);
+    }
+}
+
+/// Write block `index` of the message padded with `domain` into the first `rate` bytes of
+/// `block`.
+fn padded_block(message: &[u8], index: usize, rate: usize, domain: u8, block: &mut [u8]) {
+    let start = index * rate;
+    let data = message.get(start..).unwrap_or(&[]);
+    let length = data.len().min(rate);
+
+    block[..length].copy_from_slice(&data[..length]);
+    for byte in block[length..rate].iter_mut() {
+        *byte = 0x00;
+    }
+
+    // The padding is the domain byte right after the message, and 0x80 in the last byte of the
+    // last block. Both may be the same byte.
+    if length < rate {
+        block[length] ^= domain;
+        block[rate - 1] ^= 0x80;
+    }
+}
+
+/// Hash every message with the sponge of the given rate and domain, squeezing `N` bytes each.
+///
+/// Each lane works on its own message, and picks up the next one as soon as it's done, so
+/// messages of different lengths still keep all lanes busy.
+#[inline(always)]
+fn hash_batch_lanes<const N: usize>(rate: usize, domain: u8, messages: &[&[u8]]) -> Vec<Digest<N>> {
+    let mut digests = vec![Digest::default(); messages.len()];
+    let mut state = [U64x4::splat(0); 25];
+
+    // The message being hashed by each lane, and the index of its next block.
+    let mut lanes: [Option<(usize, usize)>; U64x4::LANES] = [None; U64x4::LANES];
+    let mut next_message = 0;
+    let mut block = [0u8; STATE_SIZE];
+
+    loop {
+        let mut active = false;
+
+        for (lane, slot) in lanes.iter_mut().enumerate() {
+            if slot.is_none() && next_message < messages.len() {
+                *slot = Some((next_message, 0));
+                next_message += 1;
+
+                for word in state.iter_mut() {
+                    word.0[lane] = 0;
+                }
+            }
+
+            if let Some((message, index)) = *slot {
+                active = true;
+                padded_block(messages[message], index, rate, domain, &mut block);
+
+                for (word, bytes) in state.iter_mut().zip(block[..rate].chunks_exact(8)) {
+                    word.0[lane] ^=
+                        u64::from_le_bytes(bytes.try_into().expect("Word must be 8 bytes long"));
+                }
+            }
+        }
+
+        if !active {
+            break;
+        }
+
+        keccak_f_lanes(&mut state);
+
+        for (lane, slot) in lanes.iter_mut().enumerate() {
+            if let Some((message, index)) = *slot {
+                // The message ends in the last block, which holds at least one byte of padding.
+                if (index + 1) * rate <= messages[message].len() {
+                    *slot = Some((message, index + 1));
+                    continue;
+                }
+
+                for (bytes, word) in digests[message].chunks_mut(8).zip(state.iter()) {
+                    bytes.copy_from_slice(&word.0[lane].to_le_bytes()[..bytes.len()]);
+                }
+                *slot = None;
+            }
+        }
+    }
+
+    digests
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "avx2")]
+unsafe fn hash_batch_avx2<const N: usize>(
+    rate: usize,
+    domain: u8,
+    messages: &[&[u8]],
+) -> Vec<Digest<N>> {
+    hash_batch_lanes(rate, domain, messages)
+}
+
+/// Hash every message with the sponge of the given rate and domain, squeezing `N` bytes each.
+/// The rate must be a multiple of 8 bytes, which holds for every standard instance.
+///
+/// Panics if `N` is larger than the rate.
+pub(crate) fn hash_batch<const N: usize>(
+    rate: usize,
+    domain: u8,
+    messages: &[&[u8]],
+) -> Vec<Digest<N>> {
+    assert!(
+        N <= rate && rate < STATE_SIZE,
+        "Rate must be between the output size and 199 bytes"
+    );
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("avx2") {
+            // AVX2 support was just checked.
+            return unsafe { hash_batch_avx2(rate, domain, messages) };
+        }
+    }
+
+    hash_batch_lanes(rate, domain, messages)
+}
+
+/// The sponge construction over Keccak-f[1600], in its absorbing phase.
+///
+/// Input is XOR-ed directly into the first `rate` bytes of the state, so no separate buffer is
+/// needed for data which doesn't fill a whole block.
+///
+/// # Example
+/// Computing SHA3-256 by hand:
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::hash::{Sponge, XofReader};
+///
+/// let mut sponge = Sponge::new(136);
+/// sponge.absorb(b"");
+///
+/// let mut output = [0u8; 32];
+/// sponge.finalize(0x06).read(&mut output);
+///
+/// assert_eq!(
+///     output,
+///     hex!("a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a")
+/// );
+/// ```
+#[derive(Clone)]
+pub struct Sponge {
+    state: State,
+    rate: usize,
+    position: usize,
+}
+
+impl Drop for Sponge {
+    fn drop(&mut self) {
+        self.state.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for Sponge {}
+
+/// XOR a byte into the state at the given byte offset.
+fn xor_byte(state: &mut State, offset: usize, byte: u8) {
+    state[offset / 8] ^= (byte as u64) << (8 * (offset % 8));
+}
+
+/// Read the byte of the state at the given byte offset.
+fn read_byte(state: &State, offset: usize) -> u8 {
+    (state[offset / 8] >> (8 * (offset % 8))) as u8
+}
+
+impl Sponge {
+    /// Create a new sponge absorbing and squeezing `rate` bytes per permutation.
+    ///
+    /// The capacity, and therefore the security level, is the remaining `200 - rate` bytes.
+    pub fn new(rate: usize) -> Sponge {
+        assert!(
+            rate > 0 && rate < STATE_SIZE,
+            "Rate must be between 1 and 199 bytes"
+        );
+
+        Sponge {
+            state: [0u64; 25],
+            rate,
+            position: 0,
+        }
+    }
+
+    /// Restore a sponge with the given rate from a state exported by [`Sponge::export`].
+    pub(crate) fn import(rate: usize, state: &[u8]) -> Result<Sponge> {
+        if state.len() != STATE_SIZE + 1 {
+            return Err(Error::InvalidStateLength);
+        }
+
+        let position = state[STATE_SIZE] as usize;
+        if position >= rate {
+            return Err(Error::InvalidState);
+        }
+
+        let mut sponge = Sponge::new(rate);
+        for (word, bytes) in sponge.state.iter_mut().zip(state.chunks_exact(8)) {
+            *word = u64::from_le_bytes(bytes.try_into().expect("Word must be 8 bytes long"));
+        }
+        sponge.position = position;
+
+        Ok(sponge)
+    }
+
+    /// Export the state in the format described in [`ResumableHash`](crate::hash::ResumableHash).
+    pub(crate) fn export(&self) -> Vec<u8> {
+        let mut state: Vec<u8> = self
+            .state
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect();
+        state.push(self.position as u8);
+
+        state
+    }
+
+    /// Absorb more data into the sponge.
+    pub fn absorb(&mut self, data: &[u8]) {
+        for &byte in data {
+            xor_byte(&mut self.state, self.position, byte);
+            self.position += 1;
+
+            if self.position == self.rate {
+                keccak_f(&mut self.state);
+                self.position = 0;
+            }
+        }
+    }
+
+    /// Apply the multi-rate padding and switch to the squeezing phase.
+    ///
+    /// `domain` holds the domain separation bits followed by the first bit of the padding: the
+    /// original Keccak submission uses `0x01`, FIPS 202 uses `0x06` for SHA-3 and `0x1f` for
+    /// SHAKE.
+    pub fn finalize(mut self, domain: u8) -> SpongeReader {
+        xor_byte(&mut self.state, self.position, domain);
+        xor_byte(&mut self.state, self.rate - 1, 0x80);
+        keccak_f(&mut self.state);
+
+        SpongeReader {
+            state: self.state,
+            rate: self.rate,
+            position: 0,
+        }
+    }
+}
+
+/// The sponge construction over Keccak-f[1600], in its squeezing phase.
+///
+/// Created by [`Sponge::finalize`].
+#[derive(Clone)]
+pub struct SpongeReader {
+    state: State,
+    rate: usize,
+    position: usize,
+}
+
+impl Drop for SpongeReader {
+    fn drop(&mut self) {
+        self.state.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SpongeReader {}
+
+impl XofReader for SpongeReader {
+    fn read(&mut self, output: &mut [u8]) {
+        for byte in output.iter_mut() {
+            if self.position == self.rate {
+                keccak_f(&mut self.state);
+                self.position = 0;
+            }
+
+            *byte = read_byte(&self.state, self.position);
+            self.position += 1;
+        }
+    }
+}
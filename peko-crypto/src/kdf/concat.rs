@@ -0,0 +1,48 @@
+//! The concatenation key derivation function, as specified in section 5.8.1 of
+//! [NIST SP 800-56A](https://nvlpubs.nist.gov/nistpubs/SpecialPublications/NIST.SP.800-56Ar2.pdf).
+
+use crate::hash::HashFunction;
+use crate::util::Zeroizing;
+
+/// Derive `length` bytes from the shared secret `z` and the context `other_info`.
+///
+/// Each block of output is the hash of a 32-bit big-endian counter starting at 1, followed by
+/// `z` and `other_info`. devp2p's ECIES uses it with SHA-256 and an empty `other_info`.
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::hash::{HashFunction, SHA256};
+/// use peko_crypto::kdf::concat_kdf;
+///
+/// let z = b"shared secret";
+///
+/// // A single block is the hash of the counter 1 followed by the secret.
+/// let mut hasher = SHA256::new();
+/// hasher.update(&hex!("00000001"));
+/// hasher.update(z);
+///
+/// assert_eq!(concat_kdf::<SHA256>(z, b"", 32)[..], hasher.digest()[..]);
+/// ```
+pub fn concat_kdf<H>(z: &[u8], other_info: &[u8], length: usize) -> Zeroizing<Vec<u8>>
+where
+    H: HashFunction,
+{
+    let mut result = Zeroizing::new(Vec::with_capacity(length));
+
+    let mut counter: u32 = 1;
+
+    while result.len() < length {
+        let mut hasher = H::new();
+        hasher.update(&counter.to_be_bytes());
+        hasher.update(z);
+        hasher.update(other_info);
+
+        let needed = (length - result.len()).min(H::OUTPUT_SIZE);
+        let block = Zeroizing::new(hasher.digest());
+        result.extend_from_slice(&(*block).as_ref()[..needed]);
+        counter += 1;
+    }
+
+    result
+}
@@ -0,0 +1,81 @@
+//! HKDF, as specified in [RFC 5869](https://tools.ietf.org/html/rfc5869).
+
+use crate::hash::HashFunction;
+use crate::mac::Hmac;
+use crate::util::Zeroizing;
+
+/// Extract a pseudorandom key from the input keying material `ikm`. The key is as long as the
+/// output of the hash function.
+///
+/// An empty `salt` is equivalent to a salt of `H::OUTPUT_SIZE` zero bytes.
+pub fn hkdf_extract<H>(salt: &[u8], ikm: &[u8]) -> Zeroizing<H::Output>
+where
+    H: HashFunction + Clone,
+{
+    Zeroizing::new(Hmac::<H>::mac(salt, ikm))
+}
+
+/// Expand the pseudorandom key `prk` into `length` bytes of output keying material, bound to the
+/// context `info`.
+///
+/// Panics if `length` is greater than 255 times the output size of the hash function.
+pub fn hkdf_expand<H>(prk: &[u8], info: &[u8], length: usize) -> Zeroizing<Vec<u8>>
+where
+    H: HashFunction + Clone,
+{
+    assert!(
+        length <= 255 * H::OUTPUT_SIZE,
+        "Output must be at most 255 times the output size of the hash function"
+    );
+
+    let hmac = Hmac::<H>::new(prk);
+    let mut result = Zeroizing::new(Vec::with_capacity(length));
+    let mut previous = Zeroizing::new(Vec::new());
+
+    // T(i) = HMAC(PRK, T(i - 1) | info | i), with T(0) empty.
+    for counter in 1..=255u8 {
+        if result.len() >= length {
+            break;
+        }
+
+        let mut hmac = hmac.clone();
+        hmac.update(&previous);
+        hmac.update(info);
+        hmac.update(&[counter]);
+        *previous = hmac.digest().as_ref().to_vec();
+
+        let needed = (length - result.len()).min(H::OUTPUT_SIZE);
+        result.extend_from_slice(&previous[..needed]);
+    }
+
+    result
+}
+
+/// Derive `length` bytes of output keying material from the input keying material `ikm`, by
+/// extracting then expanding.
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::hash::SHA256;
+/// use peko_crypto::kdf::hkdf;
+///
+/// let ikm = hex!("0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b");
+/// let salt = hex!("000102030405060708090a0b0c");
+/// let info = hex!("f0f1f2f3f4f5f6f7f8f9");
+///
+/// assert_eq!(
+///     hkdf::<SHA256>(&salt, &ikm, &info, 42)[..],
+///     hex!(
+///         "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf"
+///         "34007208d5b887185865"
+///     )
+/// );
+/// ```
+pub fn hkdf<H>(salt: &[u8], ikm: &[u8], info: &[u8], length: usize) -> Zeroizing<Vec<u8>>
+where
+    H: HashFunction + Clone,
+{
+    let prk = hkdf_extract::<H>(salt, ikm);
+    hkdf_expand::<H>((*prk).as_ref(), info, length)
+}
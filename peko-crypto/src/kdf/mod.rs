@@ -0,0 +1,13 @@
+//! Key derivation functions.
+//!
+//! Derived keys are returned wrapped in [`Zeroizing`](crate::util::Zeroizing), so they are wiped
+//! from memory when dropped.
+mod concat;
+mod hkdf;
+mod pbkdf2;
+mod scrypt;
+
+pub use concat::concat_kdf;
+pub use hkdf::{hkdf, hkdf_expand, hkdf_extract};
+pub use pbkdf2::pbkdf2;
+pub use scrypt::scrypt;
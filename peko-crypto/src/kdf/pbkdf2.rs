@@ -0,0 +1,57 @@
+//! PBKDF2, as specified in [RFC 8018](https://tools.ietf.org/html/rfc8018).
+
+use crate::hash::HashFunction;
+use crate::mac::Hmac;
+use crate::util::{Zeroize, Zeroizing};
+
+/// Derive `length` bytes from `password` and `salt`, using HMAC with the given hash function as
+/// the pseudorandom function.
+///
+/// Panics if `iterations` is zero.
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::hash::SHA256;
+/// use peko_crypto::kdf::pbkdf2;
+///
+/// assert_eq!(
+///     pbkdf2::<SHA256>(b"passwd", b"salt", 1, 16)[..],
+///     hex!("55ac046e56e3089fec1691c22544b605")
+/// );
+/// ```
+pub fn pbkdf2<H>(password: &[u8], salt: &[u8], iterations: u32, length: usize) -> Zeroizing<Vec<u8>>
+where
+    H: HashFunction + Clone,
+{
+    assert!(iterations > 0, "Iteration count must be positive");
+
+    let hmac = Hmac::<H>::new(password);
+    let mut result = Zeroizing::new(vec![0u8; length]);
+
+    // Each block is T(i) = U(1) ^ U(2) ^ ... ^ U(c), with U(1) = PRF(P, S | i) and
+    // U(j) = PRF(P, U(j - 1)).
+    for (i, block) in result.chunks_mut(H::OUTPUT_SIZE).enumerate() {
+        let mut prf = hmac.clone();
+        prf.update(salt);
+        prf.update(&(i as u32 + 1).to_be_bytes());
+        let mut u = prf.digest();
+        let mut t = u;
+
+        for _ in 1..iterations {
+            let mut prf = hmac.clone();
+            prf.update(u.as_ref());
+            u = prf.digest();
+
+            for (t, u) in t.as_mut().iter_mut().zip(u.as_ref()) {
+                *t ^= u;
+            }
+        }
+
+        block.copy_from_slice(&t.as_ref()[..block.len()]);
+        u.zeroize();
+        t.zeroize();
+    }
+
+    result
+}
@@ -0,0 +1,159 @@
+//! scrypt, as specified in [RFC 7914](https://tools.ietf.org/html/rfc7914).
+
+use crate::hash::SHA256;
+use crate::kdf::pbkdf2;
+use crate::util::Zeroizing;
+
+use std::convert::TryInto;
+
+/// Number of 32-bit words in a Salsa20 block.
+const SALSA_WORDS: usize = 64 / 4;
+
+/// The Salsa20/8 core, hashing a 64-byte block in place.
+fn salsa20_8(block: &mut [u32; SALSA_WORDS]) {
+    let mut x = *block;
+
+    for _ in 0..8 / 2 {
+        // Column round.
+        x[4] ^= x[0].wrapping_add(x[12]).rotate_left(7);
+        x[8] ^= x[4].wrapping_add(x[0]).rotate_left(9);
+        x[12] ^= x[8].wrapping_add(x[4]).rotate_left(13);
+        x[0] ^= x[12].wrapping_add(x[8]).rotate_left(18);
+        x[9] ^= x[5].wrapping_add(x[1]).rotate_left(7);
+        x[13] ^= x[9].wrapping_add(x[5]).rotate_left(9);
+        x[1] ^= x[13].wrapping_add(x[9]).rotate_left(13);
+        x[5] ^= x[1].wrapping_add(x[13]).rotate_left(18);
+        x[14] ^= x[10].wrapping_add(x[6]).rotate_left(7);
+        x[2] ^= x[14].wrapping_add(x[10]).rotate_left(9);
+        x[6] ^= x[2].wrapping_add(x[14]).rotate_left(13);
+        x[10] ^= x[6].wrapping_add(x[2]).rotate_left(18);
+        x[3] ^= x[15].wrapping_add(x[11]).rotate_left(7);
+        x[7] ^= x[3].wrapping_add(x[15]).rotate_left(9);
+        x[11] ^= x[7].wrapping_add(x[3]).rotate_left(13);
+        x[15] ^= x[11].wrapping_add(x[7]).rotate_left(18);
+
+        // Row round.
+        x[1] ^= x[0].wrapping_add(x[3]).rotate_left(7);
+        x[2] ^= x[1].wrapping_add(x[0]).rotate_left(9);
+        x[3] ^= x[2].wrapping_add(x[1]).rotate_left(13);
+        x[0] ^= x[3].wrapping_add(x[2]).rotate_left(18);
+        x[6] ^= x[5].wrapping_add(x[4]).rotate_left(7);
+        x[7] ^= x[6].wrapping_add(x[5]).rotate_left(9);
+        x[4] ^= x[7].wrapping_add(x[6]).rotate_left(13);
+        x[5] ^= x[4].wrapping_add(x[7]).rotate_left(18);
+        x[11] ^= x[10].wrapping_add(x[9]).rotate_left(7);
+        x[8] ^= x[11].wrapping_add(x[10]).rotate_left(9);
+        x[9] ^= x[8].wrapping_add(x[11]).rotate_left(13);
+        x[10] ^= x[9].wrapping_add(x[8]).rotate_left(18);
+        x[12] ^= x[15].wrapping_add(x[14]).rotate_left(7);
+        x[13] ^= x[12].wrapping_add(x[15]).rotate_left(9);
+        x[14] ^= x[13].wrapping_add(x[12]).rotate_left(13);
+        x[15] ^= x[14].wrapping_add(x[13]).rotate_left(18);
+    }
+
+    for (b, x) in block.iter_mut().zip(x.iter()) {
+        *b = b.wrapping_add(*x);
+    }
+}
+
+/// The BlockMix function with Salsa20/8, mixing `2 * r` Salsa20 blocks from `input` into
+/// `output`.
+fn block_mix(input: &[u32], output: &mut [u32]) {
+    let blocks = input.len() / SALSA_WORDS;
+
+    let mut x: [u32; SALSA_WORDS] = input[input.len() - SALSA_WORDS..]
+        .try_into()
+        .expect("Block must be 16 words long");
+
+    // Even blocks go to the first half of the output, odd blocks to the second half.
+    for (i, block) in input.chunks_exact(SALSA_WORDS).enumerate() {
+        for (x, b) in x.iter_mut().zip(block) {
+            *x ^= b;
+        }
+        salsa20_8(&mut x);
+
+        let position = (i / 2 + (i % 2) * (blocks / 2)) * SALSA_WORDS;
+        output[position..position + SALSA_WORDS].copy_from_slice(&x);
+    }
+}
+
+/// The ROMix function, mixing `block` in place with a lookup table of `n` entries.
+fn ro_mix(block: &mut [u32], n: usize) {
+    let length = block.len();
+    // The lookup table is derived from the password, so it is wiped like the output.
+    let mut v = Zeroizing::new(vec![0u32; length * n]);
+    let mut scratch = Zeroizing::new(vec![0u32; length]);
+
+    for i in 0..n {
+        v[i * length..(i + 1) * length].copy_from_slice(block);
+        block_mix(block, &mut scratch);
+        block.copy_from_slice(&scratch);
+    }
+
+    for _ in 0..n {
+        // Integerify: the first word of the last Salsa20 block, modulo n.
+        let j = block[length - SALSA_WORDS] as usize & (n - 1);
+
+        for (b, v) in block.iter_mut().zip(&v[j * length..(j + 1) * length]) {
+            *b ^= v;
+        }
+        block_mix(block, &mut scratch);
+        block.copy_from_slice(&scratch);
+    }
+}
+
+/// Derive `length` bytes from `password` and `salt`, with the CPU/memory cost `n`, block size `r`
+/// and parallelization `p`.
+///
+/// Memory usage is about `128 * r * n` bytes. Web3 Secret Storage files typically use
+/// `n = 262144`, `r = 8` and `p = 1`.
+///
+/// Panics if `n` is not a power of two greater than 1, or `r` or `p` is zero.
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::kdf::scrypt;
+///
+/// assert_eq!(
+///     scrypt(b"password", b"NaCl", 1024, 8, 16, 64)[..],
+///     hex!(
+///         "fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b373162"
+///         "2eaf30d92e22a3886ff109279d9830dac727afb94a83ee6d8360cbdfa2cc0640"
+///     )
+/// );
+/// ```
+pub fn scrypt(
+    password: &[u8],
+    salt: &[u8],
+    n: usize,
+    r: usize,
+    p: usize,
+    length: usize,
+) -> Zeroizing<Vec<u8>> {
+    assert!(
+        n > 1 && n.is_power_of_two(),
+        "Cost parameter must be a power of two greater than 1"
+    );
+    assert!(r > 0, "Block size must be positive");
+    assert!(p > 0, "Parallelization must be positive");
+
+    let block_size = 128 * r;
+    let mut b = pbkdf2::<SHA256>(password, salt, 1, p * block_size);
+
+    for chunk in b.chunks_exact_mut(block_size) {
+        let mut block: Zeroizing<Vec<u32>> = chunk
+            .chunks_exact(4)
+            .map(|word| u32::from_le_bytes(word.try_into().expect("Word must be 4 bytes long")))
+            .collect::<Vec<u32>>()
+            .into();
+
+        ro_mix(&mut block, n);
+
+        for (bytes, word) in chunk.chunks_exact_mut(4).zip(block.iter()) {
+            bytes.copy_from_slice(&word.to_le_bytes());
+        }
+    }
+
+    pbkdf2::<SHA256>(password, &b, 1, length)
+}
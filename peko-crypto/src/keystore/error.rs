@@ -0,0 +1,56 @@
+//! Errors returned when reading a keystore.
+
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Result type returned when reading a keystore.
+///
+/// In essence the Error type is fixed to [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors returned when reading a keystore.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The JSON is malformed, or a required field is missing or has the wrong type. Contains the
+    /// message of the JSON parser.
+    InvalidFormat(String),
+
+    /// The keystore is not version 3.
+    UnsupportedVersion(u32),
+
+    /// The cipher is not `aes-128-ctr`.
+    UnsupportedCipher(String),
+
+    /// The pseudorandom function of PBKDF2 is not `hmac-sha256`.
+    UnsupportedPrf(String),
+
+    /// The KDF or cipher parameters are out of range, e.g. the scrypt cost is not a power of two
+    /// or would take more than 1 GiB of memory, PBKDF2 has more than 2^24 iterations, the
+    /// derived key is shorter than 32 bytes, or the IV is not 16 bytes long.
+    InvalidParameters,
+
+    /// The MAC doesn't match the derived key and the ciphertext, most likely because the password
+    /// is wrong.
+    WrongPassword,
+
+    /// The decrypted secret key is not a valid secp256k1 secret key.
+    InvalidSecretKey,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidFormat(msg) => write!(f, "invalid keystore: {}", msg),
+            Error::UnsupportedVersion(version) => {
+                write!(f, "unsupported keystore version: {}", version)
+            }
+            Error::UnsupportedCipher(cipher) => write!(f, "unsupported cipher: {}", cipher),
+            Error::UnsupportedPrf(prf) => write!(f, "unsupported PBKDF2 PRF: {}", prf),
+            Error::InvalidParameters => f.write_str("invalid KDF or cipher parameters"),
+            Error::WrongPassword => f.write_str("wrong password"),
+            Error::InvalidSecretKey => f.write_str("invalid secret key"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,20 @@
+//! Hexadecimal encoding of the byte arrays in a keystore, usable with `#[serde(with = "hex")]`.
+
+use crate::util::{hex_decode, hex_encode};
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Decode hexadecimal digits in either case, with an optional `0x` prefix.
+pub(super) fn decode(hex: &str) -> Option<Vec<u8>> {
+    hex_decode(hex.strip_prefix("0x").unwrap_or(hex))
+}
+
+pub(super) fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&hex_encode(bytes))
+}
+
+pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    let hex = String::deserialize(deserializer)?;
+    decode(&hex).ok_or_else(|| D::Error::custom(format!("invalid hex string: {}", hex)))
+}
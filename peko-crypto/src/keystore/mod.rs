@@ -0,0 +1,348 @@
+//! Encrypted key files, as specified by
+//! [Web3 Secret Storage](https://github.com/ethereum/wiki/wiki/Web3-Secret-Storage-Definition)
+//! version 3 and written by go-ethereum and most other clients.
+//!
+//! The secret key is encrypted with AES-128-CTR, using the first half of a key derived from the
+//! password with scrypt or PBKDF2-HMAC-SHA256. The MAC is the Keccak-256 hash of the second
+//! half of the derived key followed by the ciphertext, which lets a wrong password be detected
+//! before the secret key is decrypted.
+mod error;
+mod hex;
+
+pub use error::{Error, Result};
+
+use crate::address::Address;
+use crate::aes::{Ctr, AES128};
+use crate::hash::{HashFunction, Keccak256, H256, SHA256};
+use crate::kdf::{pbkdf2, scrypt};
+use crate::secp256k1::SecretKey;
+use crate::util::{constant_time_eq, hex_encode, Zeroizing};
+
+use rand_core::{OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+use std::convert::TryInto;
+
+/// The only supported version of the format.
+const VERSION: u32 = 3;
+
+/// The only supported cipher.
+const CIPHER: &str = "aes-128-ctr";
+
+/// The only supported pseudorandom function of PBKDF2.
+const PRF: &str = "hmac-sha256";
+
+/// Length of the derived key: the first half is the encryption key, the second the MAC key.
+const DERIVED_KEY_SIZE: usize = 32;
+
+/// Length of the random salt of new keystores.
+const SALT_SIZE: usize = 32;
+
+/// Longest derived key accepted. Only the first 32 bytes are used.
+const MAX_DERIVED_KEY_SIZE: usize = 1024;
+
+/// Largest scrypt CPU/memory cost accepted.
+const MAX_SCRYPT_N: usize = 1 << 24;
+
+/// Largest scrypt block size accepted.
+const MAX_SCRYPT_R: usize = 1 << 10;
+
+/// Largest scrypt parallelization accepted.
+const MAX_SCRYPT_P: usize = 1 << 4;
+
+/// Largest number of PBKDF2 iterations accepted, so that a crafted keystore can't keep the CPU
+/// busy for hours. This is 64 times what the keystores of go-ethereum use.
+const MAX_PBKDF2_ITERATIONS: u32 = 1 << 24;
+
+/// Most memory scrypt may use, `128 * r * n` bytes, so that a crafted keystore can't exhaust
+/// it. This is 4 times what the standard parameters of go-ethereum use.
+const MAX_SCRYPT_MEMORY: usize = 1 << 30;
+
+/// Key derivation function used to encrypt a new keystore.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kdf {
+    /// scrypt with the CPU/memory cost `n`, block size `r` and parallelization `p`.
+    Scrypt { n: usize, r: usize, p: usize },
+
+    /// PBKDF2-HMAC-SHA256 with the given number of iterations.
+    Pbkdf2 { iterations: u32 },
+}
+
+impl Kdf {
+    /// The scrypt parameters go-ethereum uses by default, taking about 256 MiB of memory.
+    pub const STANDARD_SCRYPT: Kdf = Kdf::Scrypt {
+        n: 1 << 18,
+        r: 8,
+        p: 1,
+    };
+
+    /// The scrypt parameters go-ethereum uses with `--lightkdf`, taking about 4 MiB of memory.
+    pub const LIGHT_SCRYPT: Kdf = Kdf::Scrypt {
+        n: 1 << 12,
+        r: 8,
+        p: 6,
+    };
+}
+
+/// Parameters of the key derivation function, as stored in the `kdf` and `kdfparams` fields.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "kdf", content = "kdfparams", rename_all = "lowercase")]
+enum KdfParams {
+    Scrypt {
+        dklen: usize,
+        n: usize,
+        p: usize,
+        r: usize,
+        #[serde(with = "hex")]
+        salt: Vec<u8>,
+    },
+    Pbkdf2 {
+        c: u32,
+        dklen: usize,
+        prf: String,
+        #[serde(with = "hex")]
+        salt: Vec<u8>,
+    },
+}
+
+impl KdfParams {
+    /// Derive the key from the password, after checking the parameters.
+    fn derive_key(&self, password: &[u8]) -> Result<Zeroizing<Vec<u8>>> {
+        match self {
+            KdfParams::Scrypt {
+                dklen,
+                n,
+                p,
+                r,
+                salt,
+            } => {
+                if !(DERIVED_KEY_SIZE..=MAX_DERIVED_KEY_SIZE).contains(dklen)
+                    || !(2..=MAX_SCRYPT_N).contains(n)
+                    || !n.is_power_of_two()
+                    || !(1..=MAX_SCRYPT_R).contains(r)
+                    || !(1..=MAX_SCRYPT_P).contains(p)
+                {
+                    return Err(Error::InvalidParameters);
+                }
+                let memory = r
+                    .checked_mul(*n)
+                    .and_then(|blocks| blocks.checked_mul(128))
+                    .ok_or(Error::InvalidParameters)?;
+                if memory > MAX_SCRYPT_MEMORY {
+                    return Err(Error::InvalidParameters);
+                }
+
+                Ok(scrypt(password, salt, *n, *r, *p, *dklen))
+            }
+            KdfParams::Pbkdf2 {
+                c,
+                dklen,
+                prf,
+                salt,
+            } => {
+                if prf != PRF {
+                    return Err(Error::UnsupportedPrf(prf.clone()));
+                }
+                if !(DERIVED_KEY_SIZE..=MAX_DERIVED_KEY_SIZE).contains(dklen)
+                    || !(1..=MAX_PBKDF2_ITERATIONS).contains(c)
+                {
+                    return Err(Error::InvalidParameters);
+                }
+
+                Ok(pbkdf2::<SHA256>(password, salt, *c, *dklen))
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct CipherParams {
+    #[serde(with = "hex")]
+    iv: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Crypto {
+    cipher: String,
+    cipherparams: CipherParams,
+    #[serde(with = "hex")]
+    ciphertext: Vec<u8>,
+    #[serde(flatten)]
+    kdf: KdfParams,
+    #[serde(with = "hex")]
+    mac: Vec<u8>,
+}
+
+/// A version 3 keystore, holding a secret key encrypted with a password.
+///
+/// # Example
+/// ```
+/// use peko_crypto::keystore::{Error, Kdf, Keystore};
+/// use peko_crypto::secp256k1::SecretKey;
+///
+/// let secret_key = SecretKey::random();
+/// let keystore =
+///     Keystore::encrypt(&secret_key, b"password", Kdf::Pbkdf2 { iterations: 1024 }).unwrap();
+///
+/// let keystore = Keystore::from_json(&keystore.to_json()).unwrap();
+/// assert_eq!(
+///     keystore.decrypt(b"password").unwrap().to_bytes(),
+///     secret_key.to_bytes()
+/// );
+/// assert_eq!(keystore.decrypt(b"wrong").err(), Some(Error::WrongPassword));
+/// ```
+#[derive(Serialize, Deserialize)]
+pub struct Keystore {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    address: Option<String>,
+    // Some older clients capitalize the field.
+    #[serde(alias = "Crypto")]
+    crypto: Crypto,
+    id: String,
+    version: u32,
+}
+
+impl Keystore {
+    /// Encrypt `secret_key` with `password`, using a random salt and IV.
+    ///
+    /// Returns [`Error::InvalidParameters`] if the KDF parameters would be rejected when
+    /// decrypting, e.g. a scrypt cost which is not a power of two.
+    pub fn encrypt(secret_key: &SecretKey, password: &[u8], kdf: Kdf) -> Result<Keystore> {
+        let mut salt = vec![0u8; SALT_SIZE];
+        OsRng.fill_bytes(&mut salt);
+
+        let mut iv = [0u8; 16];
+        OsRng.fill_bytes(&mut iv);
+
+        let kdf = match kdf {
+            Kdf::Scrypt { n, r, p } => KdfParams::Scrypt {
+                dklen: DERIVED_KEY_SIZE,
+                n,
+                p,
+                r,
+                salt,
+            },
+            Kdf::Pbkdf2 { iterations } => KdfParams::Pbkdf2 {
+                c: iterations,
+                dklen: DERIVED_KEY_SIZE,
+                prf: PRF.to_string(),
+                salt,
+            },
+        };
+
+        let derived_key = kdf.derive_key(password)?;
+
+        let mut ciphertext = secret_key.to_bytes().to_vec();
+        apply_cipher(&derived_key, &iv, &mut ciphertext);
+
+        let address = Address::from_public_key(&secret_key.public_key());
+
+        Ok(Keystore {
+            address: Some(hex_encode(address.as_bytes())),
+            crypto: Crypto {
+                cipher: CIPHER.to_string(),
+                cipherparams: CipherParams { iv: iv.to_vec() },
+                mac: mac(&derived_key, &ciphertext).to_vec(),
+                ciphertext,
+                kdf,
+            },
+            id: random_uuid(),
+            version: VERSION,
+        })
+    }
+
+    /// Parse a keystore from JSON. Only the structure is checked here; the parameters are
+    /// checked by [`Keystore::decrypt`].
+    pub fn from_json(json: &str) -> Result<Keystore> {
+        let keystore: Keystore =
+            serde_json::from_str(json).map_err(|e| Error::InvalidFormat(e.to_string()))?;
+
+        if keystore.version != VERSION {
+            return Err(Error::UnsupportedVersion(keystore.version));
+        }
+
+        Ok(keystore)
+    }
+
+    /// Serialize the keystore to JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Keystores can be serialized to JSON")
+    }
+
+    /// Decrypt the secret key with `password`.
+    ///
+    /// Returns [`Error::WrongPassword`] if the MAC doesn't match, in which case nothing is
+    /// decrypted.
+    pub fn decrypt(&self, password: &[u8]) -> Result<SecretKey> {
+        let crypto = &self.crypto;
+
+        if crypto.cipher != CIPHER {
+            return Err(Error::UnsupportedCipher(crypto.cipher.clone()));
+        }
+        let iv: &[u8; 16] = crypto.cipherparams.iv[..]
+            .try_into()
+            .map_err(|_| Error::InvalidParameters)?;
+
+        let derived_key = crypto.kdf.derive_key(password)?;
+        if !constant_time_eq(&mac(&derived_key, &crypto.ciphertext), &crypto.mac) {
+            return Err(Error::WrongPassword);
+        }
+
+        let mut secret_key: Zeroizing<[u8; 32]> = Zeroizing::new(
+            crypto.ciphertext[..]
+                .try_into()
+                .map_err(|_| Error::InvalidSecretKey)?,
+        );
+        apply_cipher(&derived_key, iv, &mut *secret_key);
+
+        SecretKey::from_bytes(&secret_key).map_err(|_| Error::InvalidSecretKey)
+    }
+
+    /// Return the ID of the keystore, usually a UUID.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Return the address of the encrypted key, if the keystore has a valid one. The address is
+    /// not authenticated, and only verified once the key is decrypted.
+    pub fn address(&self) -> Option<Address> {
+        let bytes = hex::decode(self.address.as_ref()?)?;
+        Some(Address::new(bytes[..].try_into().ok()?))
+    }
+}
+
+/// Encrypt or decrypt `data` with AES-128-CTR, keyed with the first half of the derived key.
+fn apply_cipher(derived_key: &[u8], iv: &[u8; 16], data: &mut [u8]) {
+    let key = derived_key[..16]
+        .try_into()
+        .expect("Key must be 16 bytes long");
+    Ctr::new(AES128::new(key), iv).apply_keystream(data);
+}
+
+/// Compute the MAC of the ciphertext, keyed with the second half of the derived key.
+fn mac(derived_key: &[u8], ciphertext: &[u8]) -> H256 {
+    let mut hasher = Keccak256::new();
+    hasher.update(&derived_key[16..32]);
+    hasher.update(ciphertext);
+    hasher.digest()
+}
+
+/// Generate a random version 4 UUID.
+fn random_uuid() -> String {
+    let mut bytes = [0u8; 16];
+    OsRng.fill_bytes(&mut bytes);
+
+    // Set the version to 4 and the variant to RFC 4122.
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    let hex = hex_encode(&bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
@@ -1,6 +1,12 @@
-// intellij-rust won't accept min_const_generics as a substitute for const_generics
-// until this issue is closed: https://github.com/intellij-rust/intellij-rust/issues/6361
-// TODO: replace this with min_const_generics, or remove when Rust 1.51 hits.
-#![feature(const_generics)]
-
+pub mod address;
+pub mod aes;
+pub mod bignum;
+pub mod bn254;
+pub mod ecies;
+pub mod ethash;
 pub mod hash;
+pub mod kdf;
+pub mod keystore;
+pub mod mac;
+pub mod secp256k1;
+pub mod util;
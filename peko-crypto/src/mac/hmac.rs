@@ -0,0 +1,95 @@
+//! HMAC, as specified in [RFC 2104](https://tools.ietf.org/html/rfc2104).
+
+use crate::hash::HashFunction;
+use crate::util::{constant_time_eq, ZeroizeOnDrop, Zeroizing};
+
+/// Byte XOR-ed with the padded key before the inner hash.
+const IPAD: u8 = 0x36;
+
+/// Byte XOR-ed with the padded key before the outer hash.
+const OPAD: u8 = 0x5c;
+
+/// Keyed-hash message authentication code, generic over any [`HashFunction`].
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::hash::SHA256;
+/// use peko_crypto::mac::Hmac;
+///
+/// let mut hmac = Hmac::<SHA256>::new(b"key");
+/// hmac.update(b"The quick brown fox jumps over the lazy dog");
+///
+/// let correct_tag = hex!("f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8");
+///
+/// assert_eq!(hmac.digest(), correct_tag);
+/// assert!(hmac.verify(&correct_tag));
+/// ```
+#[derive(Clone)]
+pub struct Hmac<H>
+where
+    H: HashFunction + Clone,
+{
+    inner: H,
+    outer: H,
+}
+
+impl<H> Hmac<H>
+where
+    H: HashFunction + Clone,
+{
+    /// Create a new instance keyed with `key`. Keys longer than the block size of the hash
+    /// function are hashed first.
+    pub fn new(key: &[u8]) -> Hmac<H> {
+        let mut padded_key = Zeroizing::new(vec![0u8; H::BLOCK_SIZE]);
+        if key.len() > H::BLOCK_SIZE {
+            padded_key[..H::OUTPUT_SIZE].copy_from_slice(Zeroizing::new(H::hash(key)).as_ref());
+        } else {
+            padded_key[..key.len()].copy_from_slice(key);
+        }
+
+        let mut inner = H::new();
+        let inner_key: Zeroizing<Vec<u8>> =
+            Zeroizing::new(padded_key.iter().map(|byte| byte ^ IPAD).collect());
+        inner.update(&inner_key);
+
+        let mut outer = H::new();
+        let outer_key: Zeroizing<Vec<u8>> =
+            Zeroizing::new(padded_key.iter().map(|byte| byte ^ OPAD).collect());
+        outer.update(&outer_key);
+
+        Hmac { inner, outer }
+    }
+
+    /// Update, or add more data to the current MAC.
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    /// Return the authentication tag of all data that have been added so far.
+    pub fn digest(&self) -> H::Output {
+        let mut outer = self.outer.clone();
+        outer.update(self.inner.digest().as_ref());
+        outer.digest()
+    }
+
+    /// Check whether `tag` is the authentication tag of all data that have been added so far.
+    ///
+    /// The comparison takes the same time regardless of where the tags differ, so it does not
+    /// leak how much of a forged tag is correct.
+    pub fn verify(&self, tag: &[u8]) -> bool {
+        constant_time_eq(self.digest().as_ref(), tag)
+    }
+
+    /// Calculate the authentication tag of an array of bytes. This function is a shortcut to the
+    /// new-update-digest process.
+    pub fn mac(key: &[u8], data: &[u8]) -> H::Output {
+        let mut hmac = Self::new(key);
+        hmac.update(data);
+        hmac.digest()
+    }
+}
+
+/// The key only lives in the states of the inner and outer hash functions, which are zeroized on
+/// drop.
+impl<H> ZeroizeOnDrop for Hmac<H> where H: HashFunction + Clone + ZeroizeOnDrop {}
@@ -0,0 +1,4 @@
+//! Message authentication codes.
+mod hmac;
+
+pub use hmac::Hmac;
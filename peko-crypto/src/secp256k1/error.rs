@@ -0,0 +1,39 @@
+//! Errors returned by secp256k1 operations.
+
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Result type returned by secp256k1 operations.
+///
+/// In essence the Error type is fixed to [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors returned by secp256k1 operations.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The secret key is zero, or not less than the order of the curve.
+    InvalidSecretKey,
+
+    /// The public key is not a valid encoding of a point on the curve.
+    InvalidPublicKey,
+
+    /// `r` or `s` is zero or not less than the order of the curve, or the recovery ID is not 0
+    /// or 1.
+    InvalidSignature,
+
+    /// No public key can be recovered from the signature and the message hash.
+    RecoveryFailed,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidSecretKey => f.write_str("invalid secret key"),
+            Error::InvalidPublicKey => f.write_str("invalid public key"),
+            Error::InvalidSignature => f.write_str("invalid signature"),
+            Error::RecoveryFailed => f.write_str("public key recovery failed"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,192 @@
+use crate::hash::H256;
+use crate::secp256k1::rfc6979::NonceGenerator;
+use crate::secp256k1::{Error, Result, Signature};
+use crate::util::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+use k256::elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
+use k256::{AffinePoint, EncodedPoint, FieldBytes, NonZeroScalar, ProjectivePoint, Scalar};
+use rand_core::{OsRng, RngCore};
+
+use std::convert::TryInto;
+
+/// A secp256k1 secret key, a scalar between 1 and the order of the curve.
+///
+/// The scalar is zeroized when the key is dropped.
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::hash::H256;
+/// use peko_crypto::secp256k1::SecretKey;
+///
+/// let secret_key = SecretKey::from_bytes(&hex!(
+///     "289c2857d4598e37fb9647507e47a309d6133539bf21a8b9cb6df88fd5232032"
+/// ))
+/// .unwrap();
+/// let hash = H256::new([0x42u8; 32]);
+///
+/// let signature = secret_key.sign(&hash);
+///
+/// assert!(secret_key.public_key().verify(&hash, &signature));
+/// assert_eq!(signature.recover(&hash).unwrap(), secret_key.public_key());
+/// ```
+#[derive(Clone)]
+pub struct SecretKey {
+    scalar: NonZeroScalar,
+}
+
+impl SecretKey {
+    /// Parse a secret key from its 32-byte big-endian representation.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<SecretKey> {
+        NonZeroScalar::from_repr(FieldBytes::from(*bytes))
+            .map(|scalar| SecretKey { scalar })
+            .ok_or(Error::InvalidSecretKey)
+    }
+
+    /// Generate a random secret key using the operating system's random number generator.
+    pub fn random() -> SecretKey {
+        let mut bytes = Zeroizing::new([0u8; 32]);
+
+        // Rejection sampling, though the odds of a random 256-bit number being zero or not less
+        // than the order of the curve are negligible.
+        loop {
+            OsRng.fill_bytes(&mut *bytes);
+            if let Ok(secret_key) = SecretKey::from_bytes(&bytes) {
+                return secret_key;
+            }
+        }
+    }
+
+    /// Return the 32-byte big-endian representation of the secret key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.scalar.as_ref().to_bytes().into()
+    }
+
+    /// Return the public key corresponding to the secret key.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey {
+            point: (ProjectivePoint::generator() * self.scalar.as_ref()).to_affine(),
+        }
+    }
+
+    /// Compute the ECDH shared secret with `public_key`: the x coordinate of the product of the
+    /// secret key and the public key, as used by devp2p.
+    pub fn shared_secret(&self, public_key: &PublicKey) -> Zeroizing<[u8; 32]> {
+        let point = (ProjectivePoint::from(public_key.point) * self.scalar.as_ref()).to_affine();
+        let mut encoded = point.to_encoded_point(true);
+        let shared_secret = Zeroizing::new((*encoded.x()).into());
+        encoded.zeroize();
+
+        shared_secret
+    }
+
+    /// Sign a 32-byte message hash, with the nonce derived deterministically as in RFC 6979.
+    ///
+    /// The signature is normalized to the lower half of the range of `s`, as required for
+    /// Ethereum transactions since EIP-2.
+    pub fn sign(&self, hash: &H256) -> Signature {
+        let secret = self.scalar.as_ref();
+        let z = Scalar::from_bytes_reduced(&FieldBytes::from(hash.into_bytes()));
+
+        let mut nonces = NonceGenerator::new(&Zeroizing::new(self.to_bytes()), hash.as_bytes());
+        loop {
+            // The nonce reveals the secret key along with the signature, so it is wiped like it.
+            let k = Zeroizing::new(nonces.next());
+
+            let point = (ProjectivePoint::generator() * k.as_ref())
+                .to_affine()
+                .to_encoded_point(true);
+            let r = Scalar::from_bytes_reduced(point.x());
+            // The tag of a compressed point is 0x02 for an even y coordinate, 0x03 for odd.
+            let mut v = point.as_bytes()[0] & 1;
+
+            let k_inverse = Zeroizing::new(k.as_ref().invert().unwrap());
+            let mut s = *k_inverse * (z + r * secret);
+
+            if bool::from(s.is_high()) {
+                s = -s;
+                v ^= 1;
+            }
+
+            if let Ok(signature) = Signature::from_scalars(r, s, v) {
+                return signature;
+            }
+        }
+    }
+}
+
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.scalar.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecretKey {}
+
+/// A secp256k1 public key, a point on the curve other than the point at infinity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PublicKey {
+    point: AffinePoint,
+}
+
+impl PublicKey {
+    /// Wrap a point on the curve. Returns an error for the point at infinity.
+    pub(crate) fn from_point(point: AffinePoint) -> Result<PublicKey> {
+        if bool::from(point.is_identity()) {
+            return Err(Error::InvalidPublicKey);
+        }
+
+        Ok(PublicKey { point })
+    }
+
+    /// Parse a public key from its SEC1 encoding, either compressed (33 bytes) or uncompressed
+    /// (65 bytes), or from the 64-byte concatenation of its coordinates used by Ethereum.
+    pub fn from_bytes(bytes: &[u8]) -> Result<PublicKey> {
+        let encoded_point = if bytes.len() == 64 {
+            EncodedPoint::from_untagged_bytes(bytes.into())
+        } else {
+            EncodedPoint::from_bytes(bytes).map_err(|_| Error::InvalidPublicKey)?
+        };
+
+        AffinePoint::from_encoded_point(&encoded_point)
+            .ok_or(Error::InvalidPublicKey)
+            .and_then(PublicKey::from_point)
+    }
+
+    /// Return the 64-byte concatenation of the big-endian coordinates of the public key, as used
+    /// by Ethereum for addresses and node IDs.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let encoded_point = self.point.to_encoded_point(false);
+        encoded_point.as_bytes()[1..]
+            .try_into()
+            .expect("Uncompressed point must be 65 bytes long")
+    }
+
+    /// Return the 33-byte compressed SEC1 encoding of the public key.
+    pub fn to_compressed_bytes(&self) -> [u8; 33] {
+        let encoded_point = self.point.to_encoded_point(true);
+        encoded_point
+            .as_bytes()
+            .try_into()
+            .expect("Compressed point must be 33 bytes long")
+    }
+
+    /// Check whether `signature` is a valid signature of the 32-byte message hash by this key.
+    ///
+    /// Signatures with `s` in the upper half of its range are accepted, as they are by
+    /// `ecrecover`. The recovery ID is not checked.
+    pub fn verify(&self, hash: &H256, signature: &Signature) -> bool {
+        let z = Scalar::from_bytes_reduced(&FieldBytes::from(hash.into_bytes()));
+        let s_inverse = signature.s_scalar().invert().unwrap();
+
+        let point = (ProjectivePoint::generator() * (z * s_inverse))
+            + (ProjectivePoint::from(self.point) * (signature.r_scalar() * s_inverse));
+        let point = point.to_affine();
+
+        if bool::from(point.is_identity()) {
+            return false;
+        }
+
+        Scalar::from_bytes_reduced(point.to_encoded_point(true).x()) == signature.r_scalar()
+    }
+}
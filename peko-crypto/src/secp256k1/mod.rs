@@ -0,0 +1,13 @@
+//! ECDSA over the secp256k1 elliptic curve, as used by Ethereum for transaction signatures,
+//! `ecrecover` and node identities.
+//!
+//! Curve arithmetic is provided by the `k256` crate, while signing, verification and public
+//! key recovery are implemented here.
+mod error;
+mod key;
+mod rfc6979;
+mod signature;
+
+pub use error::{Error, Result};
+pub use key::{PublicKey, SecretKey};
+pub use signature::Signature;
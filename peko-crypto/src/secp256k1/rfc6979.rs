@@ -0,0 +1,65 @@
+//! Deterministic generation of the ECDSA nonce, as specified in section 3.2 of
+//! [RFC 6979](https://tools.ietf.org/html/rfc6979), with HMAC-SHA256.
+
+use crate::hash::SHA256;
+use crate::mac::Hmac;
+use crate::util::Zeroize;
+
+use k256::{FieldBytes, NonZeroScalar, Scalar};
+
+/// HMAC-SHA256 keyed with `key` over the concatenation of `data`.
+fn hmac(key: &[u8; 32], data: &[&[u8]]) -> [u8; 32] {
+    let mut hmac = Hmac::<SHA256>::new(key);
+    for part in data {
+        hmac.update(part);
+    }
+    hmac.digest().into_bytes()
+}
+
+/// Generator of nonce candidates for a secret key and a message hash.
+pub(crate) struct NonceGenerator {
+    k: [u8; 32],
+    v: [u8; 32],
+}
+
+impl NonceGenerator {
+    /// Create a new generator, seeded with the secret key and the message hash.
+    pub(crate) fn new(secret: &[u8; 32], hash: &[u8; 32]) -> NonceGenerator {
+        // bits2octets: the hash, reduced modulo the order of the curve.
+        let hash = Scalar::from_bytes_reduced(&FieldBytes::from(*hash)).to_bytes();
+
+        let mut k = [0x00u8; 32];
+        let mut v = [0x01u8; 32];
+
+        k = hmac(&k, &[&v, &[0x00], secret, &hash]);
+        v = hmac(&k, &[&v]);
+        k = hmac(&k, &[&v, &[0x01], secret, &hash]);
+        v = hmac(&k, &[&v]);
+
+        NonceGenerator { k, v }
+    }
+
+    /// Return the next nonce candidate. Callers move on to the next candidate if the nonce
+    /// produces a signature with a zero `r` or `s`.
+    pub(crate) fn next(&mut self) -> NonZeroScalar {
+        loop {
+            self.v = hmac(&self.k, &[&self.v]);
+            let candidate = NonZeroScalar::from_repr(FieldBytes::from(self.v));
+
+            // Prepare the state for the next candidate, whether or not this one is accepted.
+            self.k = hmac(&self.k, &[&self.v, &[0x00]]);
+            self.v = hmac(&self.k, &[&self.v]);
+
+            if let Some(k) = candidate {
+                return k;
+            }
+        }
+    }
+}
+
+impl Drop for NonceGenerator {
+    fn drop(&mut self) {
+        self.k.zeroize();
+        self.v.zeroize();
+    }
+}
@@ -0,0 +1,118 @@
+use crate::hash::H256;
+use crate::secp256k1::{Error, PublicKey, Result};
+
+use k256::elliptic_curve::subtle::Choice;
+use k256::elliptic_curve::weierstrass::point::Decompress;
+use k256::{AffinePoint, FieldBytes, NonZeroScalar, ProjectivePoint, Scalar};
+
+/// An ECDSA signature `(r, s)` with its recovery ID `v`, the parity of the y coordinate of the
+/// point whose x coordinate is `r`.
+///
+/// `v` is 0 or 1. Ethereum transactions and `ecrecover` encode it as 27 or 28, and EIP-155
+/// transactions mix the chain ID into it; converting from those is up to the caller.
+///
+/// # Example
+/// ```
+/// use hex_literal::hex;
+/// use peko_crypto::hash::H256;
+/// use peko_crypto::secp256k1::Signature;
+///
+/// let hash = H256::new(hex!(
+///     "456e9aea5e197a1f1af7a3e85a3212fa4049a3ba34c2289b4c860fc0b0c64ef3"
+/// ));
+/// let signature = Signature::new(
+///     &hex!("9242685bf161793cc25603c231bc2f568eb630ea16aa137d2664ac8038825608"),
+///     &hex!("4f8ae3bd7535248d0bd448298cc2e2071e56992d0774dc340c368ae950852ada"),
+///     28 - 27,
+/// )
+/// .unwrap();
+///
+/// let public_key = signature.recover(&hash).unwrap();
+/// assert!(public_key.verify(&hash, &signature));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature {
+    r: Scalar,
+    s: Scalar,
+    v: u8,
+}
+
+impl Signature {
+    /// Create a signature from its components. `r` and `s` are 32-byte big-endian integers which
+    /// must be between 1 and the order of the curve, and `v` must be 0 or 1.
+    pub fn new(r: &[u8; 32], s: &[u8; 32], v: u8) -> Result<Signature> {
+        let r = NonZeroScalar::from_repr(FieldBytes::from(*r)).ok_or(Error::InvalidSignature)?;
+        let s = NonZeroScalar::from_repr(FieldBytes::from(*s)).ok_or(Error::InvalidSignature)?;
+
+        Signature::from_scalars(*r.as_ref(), *s.as_ref(), v)
+    }
+
+    /// Create a signature from already reduced scalars, rejecting zero values.
+    pub(crate) fn from_scalars(r: Scalar, s: Scalar, v: u8) -> Result<Signature> {
+        if bool::from(r.is_zero()) || bool::from(s.is_zero()) || v > 1 {
+            return Err(Error::InvalidSignature);
+        }
+
+        Ok(Signature { r, s, v })
+    }
+
+    /// Parse a signature from the 65-byte concatenation `r || s || v`.
+    pub fn from_bytes(bytes: &[u8; 65]) -> Result<Signature> {
+        let mut r = [0u8; 32];
+        let mut s = [0u8; 32];
+        r.copy_from_slice(&bytes[..32]);
+        s.copy_from_slice(&bytes[32..64]);
+
+        Signature::new(&r, &s, bytes[64])
+    }
+
+    /// Return the 65-byte concatenation `r || s || v`.
+    pub fn to_bytes(&self) -> [u8; 65] {
+        let mut result = [0u8; 65];
+        result[..32].copy_from_slice(&self.r());
+        result[32..64].copy_from_slice(&self.s());
+        result[64] = self.v;
+
+        result
+    }
+
+    /// Return `r` as a 32-byte big-endian integer.
+    pub fn r(&self) -> [u8; 32] {
+        self.r.to_bytes().into()
+    }
+
+    /// Return `s` as a 32-byte big-endian integer.
+    pub fn s(&self) -> [u8; 32] {
+        self.s.to_bytes().into()
+    }
+
+    /// Return the recovery ID, 0 or 1.
+    pub fn v(&self) -> u8 {
+        self.v
+    }
+
+    pub(crate) fn r_scalar(&self) -> Scalar {
+        self.r
+    }
+
+    pub(crate) fn s_scalar(&self) -> Scalar {
+        self.s
+    }
+
+    /// Recover the public key that produced this signature of the 32-byte message hash.
+    pub fn recover(&self, hash: &H256) -> Result<PublicKey> {
+        let z = Scalar::from_bytes_reduced(&FieldBytes::from(hash.into_bytes()));
+
+        // R is the point with x coordinate r and the parity of its y coordinate given by v.
+        let r_point: Option<AffinePoint> =
+            AffinePoint::decompress(&self.r.to_bytes(), Choice::from(self.v)).into();
+        let r_point = r_point.ok_or(Error::RecoveryFailed)?;
+
+        // Q = r^-1 (sR - zG)
+        let r_inverse = self.r.invert().unwrap();
+        let point = (ProjectivePoint::from(r_point) * (self.s * r_inverse))
+            - (ProjectivePoint::generator() * (z * r_inverse));
+
+        PublicKey::from_point(point.to_affine()).map_err(|_| Error::RecoveryFailed)
+    }
+}
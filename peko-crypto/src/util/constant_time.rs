@@ -0,0 +1,58 @@
+//! Operations on secret data whose running time does not depend on the data.
+
+use std::hint::black_box;
+
+/// Compare two byte slices in constant time with respect to their contents.
+///
+/// Only the lengths may be leaked: slices of different lengths are unequal, and are rejected
+/// immediately.
+///
+/// # Example
+/// ```
+/// use peko_crypto::util::constant_time_eq;
+///
+/// assert!(constant_time_eq(b"secret", b"secret"));
+/// assert!(!constant_time_eq(b"secret", b"Secret"));
+/// assert!(!constant_time_eq(b"secret", b"secrets"));
+/// ```
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    // Hide the accumulator from the optimizer, so that it can't stop at the first difference.
+    let difference = a
+        .iter()
+        .zip(b)
+        .fold(0u8, |acc, (x, y)| black_box(acc | (x ^ y)));
+    difference == 0
+}
+
+/// Copy `a` into `output` if `choice` is true, `b` otherwise, in constant time with respect to
+/// `choice` and the contents of the slices.
+///
+/// Panics if the slices are not all the same length.
+///
+/// # Example
+/// ```
+/// use peko_crypto::util::constant_time_select;
+///
+/// let mut output = [0u8; 4];
+/// constant_time_select(true, b"left", b"rght", &mut output);
+/// assert_eq!(&output, b"left");
+///
+/// constant_time_select(false, b"left", b"rght", &mut output);
+/// assert_eq!(&output, b"rght");
+/// ```
+pub fn constant_time_select(choice: bool, a: &[u8], b: &[u8], output: &mut [u8]) {
+    assert!(
+        a.len() == b.len() && a.len() == output.len(),
+        "Inputs and output must be the same length"
+    );
+
+    // All ones if choice is true, all zeros otherwise.
+    let mask = black_box((choice as u8).wrapping_neg());
+    for ((out, x), y) in output.iter_mut().zip(a).zip(b) {
+        *out = y ^ (mask & (x ^ y));
+    }
+}
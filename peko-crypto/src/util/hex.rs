@@ -0,0 +1,51 @@
+//! Hexadecimal encoding and decoding of byte arrays.
+
+/// Lowercase hexadecimal digits.
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Encode bytes as lowercase hexadecimal digits, without the `0x` prefix.
+///
+/// # Example
+/// ```
+/// use peko_crypto::util::hex_encode;
+///
+/// assert_eq!(hex_encode(&[0x01, 0xab, 0xff]), "01abff");
+/// ```
+pub fn hex_encode(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        hex.push(HEX_DIGITS[(byte >> 4) as usize] as char);
+        hex.push(HEX_DIGITS[(byte & 0xf) as usize] as char);
+    }
+
+    hex
+}
+
+/// Decode hexadecimal digits in either case. The `0x` prefix is not accepted here, callers
+/// which allow it strip it first.
+///
+/// Returns `None` if the number of digits is odd, or if any character is not a hexadecimal
+/// digit.
+///
+/// # Example
+/// ```
+/// use peko_crypto::util::hex_decode;
+///
+/// assert_eq!(hex_decode("01abFF"), Some(vec![0x01, 0xab, 0xff]));
+/// assert_eq!(hex_decode("0x01"), None);
+/// assert_eq!(hex_decode("abc"), None);
+/// ```
+pub fn hex_decode(hex: &str) -> Option<Vec<u8>> {
+    let pairs = hex.as_bytes().chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return None;
+    }
+
+    pairs
+        .map(|pair| {
+            let high = (pair[0] as char).to_digit(16)?;
+            let low = (pair[1] as char).to_digit(16)?;
+            Some((high << 4 | low) as u8)
+        })
+        .collect()
+}
@@ -0,0 +1,11 @@
+//! Constant-time operations, zeroization of secrets, and hexadecimal encoding.
+//!
+//! Every type in this crate holding secret material, such as keys, hash function states and
+//! cipher round keys, overwrites it with zeros when dropped. Functions returning secrets wrap
+//! them in [`Zeroizing`] for the same effect.
+mod constant_time;
+mod hex;
+
+pub use constant_time::{constant_time_eq, constant_time_select};
+pub use hex::{hex_decode, hex_encode};
+pub use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
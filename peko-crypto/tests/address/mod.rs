@@ -0,0 +1,3 @@
+mod test_checksum;
+mod test_contract;
+mod test_serde;
@@ -0,0 +1,147 @@
+use hex_literal::hex;
+
+use peko_crypto::address::{Address, Error};
+use peko_crypto::secp256k1::SecretKey;
+
+macro_rules! test_checksum {
+    ($name: ident, $address: expr, $eip55: expr, $chain_30: expr, $chain_31: expr) => {
+        #[test]
+        fn $name() {
+            let address = Address::new($address);
+
+            assert_eq!(
+                address.to_checksum(),
+                $eip55,
+                "EIP-55 checksum for {} does not match",
+                stringify!($name)
+            );
+            assert_eq!(
+                address.to_checksum_with_chain_id(30),
+                $chain_30,
+                "EIP-1191 checksum for chain 30 for {} does not match",
+                stringify!($name)
+            );
+            assert_eq!(
+                address.to_checksum_with_chain_id(31),
+                $chain_31,
+                "EIP-1191 checksum for chain 31 for {} does not match",
+                stringify!($name)
+            );
+
+            assert_eq!($eip55.parse(), Ok(address));
+            assert_eq!(
+                Address::from_checksum_with_chain_id($chain_30, 30),
+                Ok(address)
+            );
+            assert_eq!(
+                Address::from_checksum_with_chain_id($chain_31, 31),
+                Ok(address)
+            );
+        }
+    };
+}
+
+// The following tests are taken from EIP-55 and EIP-1191:
+// https://eips.ethereum.org/EIPS/eip-55
+// https://eips.ethereum.org/EIPS/eip-1191
+test_checksum!(
+    test_checksum_1,
+    hex!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"),
+    "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+    "0x5aaEB6053f3e94c9b9a09f33669435E7ef1bEAeD",
+    "0x5aAeb6053F3e94c9b9A09F33669435E7EF1BEaEd"
+);
+
+test_checksum!(
+    test_checksum_2,
+    hex!("fb6916095ca1df60bb79ce92ce3ea74c37c5d359"),
+    "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+    "0xFb6916095cA1Df60bb79ce92cE3EA74c37c5d359",
+    "0xFb6916095CA1dF60bb79CE92ce3Ea74C37c5D359"
+);
+
+test_checksum!(
+    test_checksum_3,
+    hex!("dbf03b407c01e7cd3cbea99509d93f8dddc8c6fb"),
+    "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+    "0xDBF03B407c01E7CD3cBea99509D93F8Dddc8C6FB",
+    "0xdbF03B407C01E7cd3cbEa99509D93f8dDDc8C6fB"
+);
+
+test_checksum!(
+    test_checksum_4,
+    hex!("d1220a0cf47c7b9be7a2e6ba89f429762e7b9adb"),
+    "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+    "0xD1220A0Cf47c7B9BE7a2e6ba89F429762E7B9adB",
+    "0xd1220a0CF47c7B9Be7A2E6Ba89f429762E7b9adB"
+);
+
+#[test]
+fn test_from_public_key() {
+    // Test key from go-ethereum's crypto package: https://github.com/ethereum/go-ethereum
+    let secret_key = SecretKey::from_bytes(&hex!(
+        "289c2857d4598e37fb9647507e47a309d6133539bf21a8b9cb6df88fd5232032"
+    ))
+    .unwrap();
+
+    assert_eq!(
+        Address::from_public_key(&secret_key.public_key()),
+        Address::new(hex!("970e8128ab834e8eac17ab8e3812f010678cf791"))
+    );
+}
+
+#[test]
+fn test_display() {
+    let address = Address::new(hex!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"));
+
+    assert_eq!(
+        address.to_string(),
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+    );
+}
+
+#[test]
+fn test_parse_without_checksum() {
+    // All-lowercase and all-uppercase addresses, as well as the prefix-less form, are accepted
+    // without verifying the checksum.
+    let address = Address::new(hex!("52908400098527886e0f7030069857d2e4169ee7"));
+
+    assert_eq!(
+        "0x52908400098527886e0f7030069857d2e4169ee7".parse(),
+        Ok(address)
+    );
+    assert_eq!(
+        "0x52908400098527886E0F7030069857D2E4169EE7".parse(),
+        Ok(address)
+    );
+    assert_eq!(
+        "52908400098527886E0F7030069857D2E4169EE7".parse(),
+        Ok(address)
+    );
+}
+
+#[test]
+fn test_parse_invalid() {
+    assert_eq!(
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD".parse::<Address>(),
+        Err(Error::InvalidChecksum)
+    );
+    // Valid EIP-55 checksum, but not for chain 30.
+    assert_eq!(
+        Address::from_checksum_with_chain_id("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed", 30),
+        Err(Error::InvalidChecksum)
+    );
+    assert_eq!(
+        "0x5aaeb6053f3e94c9b9a09f33669435e7ef1bea".parse::<Address>(),
+        Err(Error::InvalidLength)
+    );
+    assert_eq!(
+        "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaedff".parse::<Address>(),
+        Err(Error::InvalidLength)
+    );
+    assert_eq!(
+        "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaeg".parse::<Address>(),
+        Err(Error::InvalidCharacter)
+    );
+    assert_eq!("".parse::<Address>(), Err(Error::InvalidLength));
+}
@@ -0,0 +1,129 @@
+use hex_literal::hex;
+
+use peko_crypto::address::Address;
+
+macro_rules! test_create {
+    ($name: ident, $sender: expr, $nonce: expr, $address: expr) => {
+        #[test]
+        fn $name() {
+            assert_eq!(
+                Address::create(&Address::new($sender), $nonce),
+                Address::new($address),
+                "address for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+macro_rules! test_create2 {
+    ($name: ident, $sender: expr, $salt: expr, $init_code: expr, $address: expr) => {
+        #[test]
+        fn $name() {
+            assert_eq!(
+                Address::create2(&Address::new($sender), &$salt, &$init_code).to_string(),
+                $address,
+                "address for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+// Contracts created by the same sender with increasing nonces. The sender and the first four
+// addresses are from go-ethereum's TestCreateAddress; the last one was generated with a
+// Python RLP and Keccak-256 implementation to cover a multi-byte nonce.
+test_create!(
+    test_create_nonce_0,
+    hex!("6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0"),
+    0,
+    hex!("cd234a471b72ba2f1ccf0a70fcaba648a5eecd8d")
+);
+
+test_create!(
+    test_create_nonce_1,
+    hex!("6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0"),
+    1,
+    hex!("343c43a37d37dff08ae8c4a11544c718abb4fcf8")
+);
+
+test_create!(
+    test_create_nonce_2,
+    hex!("6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0"),
+    2,
+    hex!("f778b86fa74e846c4f0a1fbd1335fe81c00a0c91")
+);
+
+test_create!(
+    test_create_nonce_3,
+    hex!("6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0"),
+    3,
+    hex!("fffd933a0bc612844eaf0c6fe3e5b8e9b6c1d19c")
+);
+
+test_create!(
+    test_create_nonce_1024,
+    hex!("6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0"),
+    1024,
+    hex!("4851395a7875cff1ced5a731d9bf534a57ed0d8c")
+);
+
+// The following tests are taken from EIP-1014: https://eips.ethereum.org/EIPS/eip-1014
+test_create2!(
+    test_create2_example_0,
+    hex!("0000000000000000000000000000000000000000"),
+    hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    hex!("00"),
+    "0x4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38"
+);
+
+test_create2!(
+    test_create2_example_1,
+    hex!("deadbeef00000000000000000000000000000000"),
+    hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    hex!("00"),
+    "0xB928f69Bb1D91Cd65274e3c79d8986362984fDA3"
+);
+
+test_create2!(
+    test_create2_example_2,
+    hex!("deadbeef00000000000000000000000000000000"),
+    hex!("000000000000000000000000feed000000000000000000000000000000000000"),
+    hex!("00"),
+    "0xD04116cDd17beBE565EB2422F2497E06cC1C9833"
+);
+
+test_create2!(
+    test_create2_example_3,
+    hex!("0000000000000000000000000000000000000000"),
+    hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    hex!("deadbeef"),
+    "0x70f2b2914A2a4b783FaEFb75f459A580616Fcb5e"
+);
+
+test_create2!(
+    test_create2_example_4,
+    hex!("00000000000000000000000000000000deadbeef"),
+    hex!("00000000000000000000000000000000000000000000000000000000cafebabe"),
+    hex!("deadbeef"),
+    "0x60f3f640a8508fC6a86d45DF051962668E1e8AC7"
+);
+
+test_create2!(
+    test_create2_example_5,
+    hex!("00000000000000000000000000000000deadbeef"),
+    hex!("00000000000000000000000000000000000000000000000000000000cafebabe"),
+    hex!(
+        "deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef"
+        "deadbeef"
+    ),
+    "0x1d8bfDC5D46DC4f61D6b6115972536eBE6A8854C"
+);
+
+test_create2!(
+    test_create2_example_6,
+    hex!("0000000000000000000000000000000000000000"),
+    hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    hex!(""),
+    "0xE33C0C7F7df4809055C3ebA6c09CFe4BaF1BD9e0"
+);
@@ -0,0 +1,52 @@
+use hex_literal::hex;
+
+use peko_crypto::address::Address;
+use peko_crypto::hash::H256;
+use peko_rlp::RLPDeserializer;
+use serde::Deserialize;
+
+const ADDRESS: [u8; 20] = hex!("970e8128ab834e8eac17ab8e3812f010678cf791");
+
+#[test]
+fn test_debug() {
+    assert_eq!(
+        format!("{:?}", Address::new(ADDRESS)),
+        "Address(0x970e8128ab834e8eac17ab8e3812f010678cf791)"
+    );
+}
+
+#[test]
+fn test_serde_json() {
+    let address = Address::new(ADDRESS);
+    let json = "\"0x970E8128AB834E8EAC17Ab8E3812F010678CF791\"";
+
+    assert_eq!(serde_json::to_string(&address).unwrap(), json);
+    assert_eq!(serde_json::from_str::<Address>(json).unwrap(), address);
+    assert_eq!(
+        serde_json::from_str::<Address>("\"970e8128ab834e8eac17ab8e3812f010678cf791\"").unwrap(),
+        address
+    );
+
+    // The checksum of mixed-case addresses is verified.
+    assert!(
+        serde_json::from_str::<Address>("\"0x970e8128AB834E8EAC17Ab8E3812F010678CF791\"").is_err()
+    );
+    assert!(serde_json::from_str::<Address>("\"0x1234\"").is_err());
+}
+
+#[test]
+fn test_serde_rlp() {
+    // An address is encoded as a byte string of 20 bytes, with the 0x80 + 20 prefix.
+    let address = Address::new(ADDRESS);
+    let encoded = peko_rlp::to_bytes(&address).unwrap();
+
+    assert_eq!(encoded[0], 0x94);
+    assert_eq!(encoded[1..], ADDRESS);
+
+    let mut deserializer = RLPDeserializer::from_bytes(&encoded).unwrap();
+    assert_eq!(Address::deserialize(&mut deserializer).unwrap(), address);
+
+    let encoded = peko_rlp::to_bytes(&H256::default()).unwrap();
+    let mut deserializer = RLPDeserializer::from_bytes(&encoded).unwrap();
+    assert!(Address::deserialize(&mut deserializer).is_err());
+}
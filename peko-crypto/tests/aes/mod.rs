@@ -0,0 +1,2 @@
+mod test_aes;
+mod test_ctr;
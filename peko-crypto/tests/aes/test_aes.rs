@@ -0,0 +1,106 @@
+use hex_literal::hex;
+
+use peko_crypto::aes::{BlockCipher, AES128, AES256};
+
+macro_rules! test_aes {
+    ($name: ident, $cipher: ident, $key: expr, $plaintext: expr, $ciphertext: expr) => {
+        #[test]
+        fn $name() {
+            let cipher = $cipher::new(&$key);
+            let mut block = $plaintext;
+            cipher.encrypt_block(&mut block);
+
+            assert_eq!(
+                block,
+                $ciphertext,
+                "ciphertext for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+// The following tests are the example vectors given in appendix C of FIPS 197:
+// https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.197.pdf
+test_aes!(
+    test_fips197_aes128,
+    AES128,
+    hex!("000102030405060708090a0b0c0d0e0f"),
+    hex!("00112233445566778899aabbccddeeff"),
+    hex!("69c4e0d86a7b0430d8cdb78070b4c55a")
+);
+
+test_aes!(
+    test_fips197_aes256,
+    AES256,
+    hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+    hex!("00112233445566778899aabbccddeeff"),
+    hex!("8ea2b7ca516745bfeafc49904b496089")
+);
+
+// The following tests are the ECB-AES128.Encrypt and ECB-AES256.Encrypt examples given in
+// appendix F.1 of NIST SP 800-38A:
+// https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38a.pdf
+test_aes!(
+    test_ecb_aes128_block_1,
+    AES128,
+    hex!("2b7e151628aed2a6abf7158809cf4f3c"),
+    hex!("6bc1bee22e409f96e93d7e117393172a"),
+    hex!("3ad77bb40d7a3660a89ecaf32466ef97")
+);
+
+test_aes!(
+    test_ecb_aes128_block_2,
+    AES128,
+    hex!("2b7e151628aed2a6abf7158809cf4f3c"),
+    hex!("ae2d8a571e03ac9c9eb76fac45af8e51"),
+    hex!("f5d3d58503b9699de785895a96fdbaaf")
+);
+
+test_aes!(
+    test_ecb_aes128_block_3,
+    AES128,
+    hex!("2b7e151628aed2a6abf7158809cf4f3c"),
+    hex!("30c81c46a35ce411e5fbc1191a0a52ef"),
+    hex!("43b1cd7f598ece23881b00e3ed030688")
+);
+
+test_aes!(
+    test_ecb_aes128_block_4,
+    AES128,
+    hex!("2b7e151628aed2a6abf7158809cf4f3c"),
+    hex!("f69f2445df4f9b17ad2b417be66c3710"),
+    hex!("7b0c785e27e8ad3f8223207104725dd4")
+);
+
+test_aes!(
+    test_ecb_aes256_block_1,
+    AES256,
+    hex!("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4"),
+    hex!("6bc1bee22e409f96e93d7e117393172a"),
+    hex!("f3eed1bdb5d2a03c064b5a7e3db181f8")
+);
+
+test_aes!(
+    test_ecb_aes256_block_2,
+    AES256,
+    hex!("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4"),
+    hex!("ae2d8a571e03ac9c9eb76fac45af8e51"),
+    hex!("591ccb10d410ed26dc5ba74a31362870")
+);
+
+test_aes!(
+    test_ecb_aes256_block_3,
+    AES256,
+    hex!("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4"),
+    hex!("30c81c46a35ce411e5fbc1191a0a52ef"),
+    hex!("b6ed21b99ca6f4f9f153e7b1beafed1d")
+);
+
+test_aes!(
+    test_ecb_aes256_block_4,
+    AES256,
+    hex!("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4"),
+    hex!("f69f2445df4f9b17ad2b417be66c3710"),
+    hex!("23304b7a39f9f3ff067d8d8f9e24ecc7")
+);
@@ -0,0 +1,80 @@
+use hex_literal::hex;
+
+use peko_crypto::aes::{Ctr, AES128, AES256};
+
+macro_rules! test_ctr {
+    ($name: ident, $cipher: ident, $key: expr, $iv: expr, $plaintext: expr, $ciphertext: expr) => {
+        #[test]
+        fn $name() {
+            let mut data = $plaintext;
+            let mut ctr = Ctr::new($cipher::new(&$key), &$iv);
+
+            for chunk in data.chunks_mut(13) {
+                ctr.apply_keystream(chunk);
+            }
+
+            assert_eq!(
+                data[..],
+                $ciphertext[..],
+                "ciphertext for {} does not match",
+                stringify!($name)
+            );
+
+            // Decryption is the same operation.
+            let mut ctr = Ctr::new($cipher::new(&$key), &$iv);
+            ctr.apply_keystream(&mut data);
+
+            assert_eq!(data[..], $plaintext[..]);
+        }
+    };
+}
+
+// The following tests are the CTR-AES128.Encrypt and CTR-AES256.Encrypt examples given in
+// appendix F.5 of NIST SP 800-38A:
+// https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38a.pdf
+test_ctr!(
+    test_aes128,
+    AES128,
+    hex!("2b7e151628aed2a6abf7158809cf4f3c"),
+    hex!("f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff"),
+    hex!(
+        "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51"
+        "30c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710"
+    ),
+    hex!(
+        "874d6191b620e3261bef6864990db6ce9806f66b7970fdff8617187bb9fffdff"
+        "5ae4df3edbd5d35e5b4f09020db03eab1e031dda2fbe03d1792170a0f3009cee"
+    )
+);
+
+test_ctr!(
+    test_aes256,
+    AES256,
+    hex!("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4"),
+    hex!("f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff"),
+    hex!(
+        "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51"
+        "30c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710"
+    ),
+    hex!(
+        "601ec313775789a5b7a7f504bbf3d228f443e3ca4d62b59aca84e990cacaf5c5"
+        "2b0930daa23de94ce87017ba2d84988ddfc9c58db67aada613c2dd08457941a6"
+    )
+);
+
+// The counter wraps around after the all-ones block. Generated with pyca/cryptography:
+// https://cryptography.io
+test_ctr!(
+    test_counter_wrap,
+    AES128,
+    hex!("2b7e151628aed2a6abf7158809cf4f3c"),
+    hex!("ffffffffffffffffffffffffffffffff"),
+    hex!(
+        "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51"
+        "30c81c46a3"
+    ),
+    hex!(
+        "e13338e36cb71962e00d020b4cedbd86d3dae15b04bb352fa0f59febfcb4da3e"
+        "67da610697"
+    )
+);
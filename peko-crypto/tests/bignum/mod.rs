@@ -0,0 +1,4 @@
+mod test_i256;
+mod test_modexp;
+mod test_u256;
+mod test_uint;
@@ -0,0 +1,183 @@
+use peko_crypto::bignum::{I256, U256};
+
+/// Parse a hexadecimal string into the signed integer with the same bits.
+fn signed(value: &str) -> I256 {
+    I256::from(value.parse::<U256>().unwrap())
+}
+
+// The following tests were generated with the integers of Python.
+#[test]
+fn test_div_rem() {
+    for &(a, b, quotient, remainder) in [
+        (
+            "0xf50b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+            "0x1559b577693938c0e58a940250606f95bfbd1d8bc7c2a88b2dd77d8998b31d04",
+            "0x0",
+            "0xf50b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+        ),
+        (
+            "0xf50b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+            "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff9",
+            "0x190a57f6c417111070c8362f248ae2a84e68aeaa70c7dd0ab81dbd25bd61978",
+            "0x0",
+        ),
+        (
+            "0x5566d5dda4e4e303962a50094181be56fef4762f1f0aa22cb75df62662cc7413",
+            "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff9",
+            "0xf3ccbce0562871daea8c3db588ed771824b88166fb90c442c13bb843a8be3890",
+            "0x3",
+        ),
+        (
+            "0x8000000000000000000000000000000000000000000000000000000000000000",
+            "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "0x8000000000000000000000000000000000000000000000000000000000000000",
+            "0x0",
+        ),
+        (
+            "0xf50b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+            "0x0",
+            "0x0",
+            "0x0",
+        ),
+    ]
+    .iter()
+    {
+        assert_eq!(
+            signed(a).div_rem(signed(b)),
+            (signed(quotient), signed(remainder)),
+            "quotient and remainder of {} by {}",
+            a,
+            b
+        );
+    }
+}
+
+#[test]
+fn test_div_rem_small() {
+    for &(a, b) in [
+        (7i64, 2i64),
+        (-7, 2),
+        (7, -2),
+        (-7, -2),
+        (0, -3),
+        (5, 0),
+        (-5, 0),
+    ]
+    .iter()
+    {
+        let (quotient, remainder) = if b == 0 { (0, 0) } else { (a / b, a % b) };
+
+        assert_eq!(
+            I256::from(a) / I256::from(b),
+            I256::from(quotient),
+            "{} / {}",
+            a,
+            b
+        );
+        assert_eq!(
+            I256::from(a) % I256::from(b),
+            I256::from(remainder),
+            "{} % {}",
+            a,
+            b
+        );
+    }
+}
+
+#[test]
+fn test_shr() {
+    let a = signed("0xf50b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8");
+
+    for &(shift, expected) in [
+        (
+            0,
+            "0xf50b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+        ),
+        (
+            1,
+            "0xfa85bcc2051af44467543425b0019e6b2ed919cab75447a5a7b97e9fbe92a6dc",
+        ),
+        (
+            64,
+            "0xfffffffffffffffff50b79840a35e888cea8684b60033cd65db233956ea88f4b",
+        ),
+        (
+            200,
+            "0xfffffffffffffffffffffffffffffffffffffffffffffffffff50b79840a35e8",
+        ),
+        (
+            255,
+            "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        ),
+        (
+            256,
+            "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        ),
+    ]
+    .iter()
+    {
+        assert_eq!(a >> shift, signed(expected), "{} >> {}", a, shift);
+    }
+    assert_eq!(I256::MAX >> 254, I256::ONE);
+    assert_eq!(I256::MAX >> 255, I256::ZERO);
+    assert_eq!(I256::MIN >> 255, I256::MINUS_ONE);
+    assert_eq!(I256::MIN >> u32::MAX, I256::MINUS_ONE);
+}
+
+#[test]
+fn test_ordering() {
+    let mut values = vec![
+        I256::MAX,
+        I256::ONE,
+        I256::MIN,
+        I256::ZERO,
+        I256::MINUS_ONE,
+        I256::from(-2i64),
+    ];
+    values.sort();
+
+    assert_eq!(
+        values,
+        vec![
+            I256::MIN,
+            I256::from(-2i64),
+            I256::MINUS_ONE,
+            I256::ZERO,
+            I256::ONE,
+            I256::MAX
+        ]
+    );
+}
+
+#[test]
+fn test_arithmetic() {
+    assert_eq!(I256::from(-3i64) + I256::from(5i64), I256::from(2i64));
+    assert_eq!(I256::from(-3i64) - I256::from(5i64), I256::from(-8i64));
+    assert_eq!(I256::from(-3i64) * I256::from(-5i64), I256::from(15i64));
+    assert_eq!(I256::MAX + I256::ONE, I256::MIN);
+    assert_eq!(-I256::MIN, I256::MIN);
+    assert_eq!(-I256::MAX, I256::MIN + I256::ONE);
+}
+
+#[test]
+fn test_conversions() {
+    assert_eq!(U256::from(I256::MINUS_ONE), U256::MAX);
+    assert_eq!(U256::from(I256::MIN), U256::ONE << 255);
+    assert_eq!(I256::from(i128::MIN), -I256::from(U256::ONE << 127));
+    assert_eq!(I256::from(i64::MIN), I256::from(i64::MIN as i128));
+    assert_eq!(I256::MIN.unsigned_abs(), U256::ONE << 255);
+    assert_eq!(I256::from(-5i64).unsigned_abs(), U256::from(5u64));
+    assert!(I256::MIN.is_negative() && !I256::MAX.is_negative() && !I256::ZERO.is_negative());
+}
+
+#[test]
+fn test_format() {
+    assert_eq!(I256::from(-1234i64).to_string(), "-1234");
+    assert_eq!(I256::from(1234i64).to_string(), "1234");
+    assert_eq!(
+        I256::MIN.to_string(),
+        "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+    );
+    assert_eq!(format!("{:+}", I256::ONE), "+1");
+    assert_eq!(format!("{:?}", I256::MINUS_ONE), "I256(-1)");
+}
@@ -0,0 +1,228 @@
+use hex_literal::hex;
+
+use peko_crypto::bignum::{self, Error};
+
+macro_rules! test_modexp {
+    ($name: ident, $input: expr, $output: expr, $gas: expr) => {
+        #[test]
+        fn $name() {
+            assert_eq!(
+                bignum::modexp(&$input),
+                $output,
+                "output for {} does not match",
+                stringify!($name)
+            );
+            assert_eq!(
+                bignum::modexp_gas(&$input),
+                $gas,
+                "gas cost for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+// The examples of EIP-198, with the gas costs of EIP-2565.
+test_modexp!(
+    test_eip198_example1,
+    hex!(
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "0000000000000000000000000000000000000000000000000000000000000020"
+        "0000000000000000000000000000000000000000000000000000000000000020"
+        "03fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc"
+        "2efffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc"
+        "2f"
+    ),
+    Ok(hex!("0000000000000000000000000000000000000000000000000000000000000001").to_vec()),
+    1360
+);
+
+test_modexp!(
+    test_eip198_example2,
+    hex!(
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000020"
+        "0000000000000000000000000000000000000000000000000000000000000020"
+        "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e"
+        "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f"
+    ),
+    Ok(hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec()),
+    1360
+);
+
+// The following tests were generated with the integers of Python.
+test_modexp!(
+    test_1024_bits,
+    hex!(
+        "0000000000000000000000000000000000000000000000000000000000000080"
+        "0000000000000000000000000000000000000000000000000000000000000080"
+        "0000000000000000000000000000000000000000000000000000000000000080"
+        "3c1430dd5364b18040c7202c56e7530497f8416ec1384c9194716078e6edea37"
+        "889702f27cf8b9b099cf3b9907f8f793c295a531d357886c6245fe76e8337761"
+        "2ebd16556e8b973f328aefad4a79328787c0d9f60d93bb5174e775817a748114"
+        "fcda9f0bbe7a30bdabb09c6119d2ee9c7b00b455b622fd3f928a1a030d9001eb"
+        "48a9ac17f4aeb19cf1641fce3af89e6d0c379dfeb1c8693952f857c860e70a8c"
+        "5cf1e412d17067d579f3e3a348af8df9ea96b89d1cda9d7e20f33a1e6be5ca90"
+        "6ffbe28bbdab9cf481bb4dc831daff528b7b136c6405e9f0388f6834ae268848"
+        "98619945fd12ea210e69ad85adb34db1ee2aedff19aef1c49ac6ff0f9ec8cf41"
+        "81e8f0300a243a1b9c256bedc4da27f92643e0a5972664513506460bdf2f59f8"
+        "6e299f18de0379a01a9d9bc0fef814236120fdaf560985e93ac607b173e6c0ab"
+        "aaa7b9e955b78a75cebded1c7cb34138276afc3c9b8eec487e501e915fa08b70"
+        "8575448ae20c309de0a58a223b945b2b6a65fbe20e642284110e1b5b3340320b"
+    ),
+    Ok(hex!(
+        "055b157254c3912c6db73d63ff7d794efd6402e99556c3722b94f1975b61b331"
+        "3d9507d99dc6147cb99e862eaf7eb8ea61d6392b3342d670edb38555df6a4383"
+        "43ab3af1fb4c35104791eb12e1acb2887dc75cc4ab5b71f6392e6653df2f9041"
+        "44f32b1ff5d1d4e0ed113fd94a3ec0b00fe7d3388477cd87ce49f3322a051fa9"
+    )
+    .to_vec()),
+    87210
+);
+
+// Only the first 32 bytes of the exponent count towards the gas cost, plus 8 per byte after.
+test_modexp!(
+    test_long_exponent,
+    hex!(
+        "0000000000000000000000000000000000000000000000000000000000000020"
+        "0000000000000000000000000000000000000000000000000000000000000028"
+        "0000000000000000000000000000000000000000000000000000000000000020"
+        "180f52961d05b4c5e832126cb882eb76b112bd3db13a25f8da4f2f80caad3368"
+        "23f503d946136bbf7442c83f2ea793ba884284db63eabd2af496e8ad032d379b"
+        "f2981677a0808181914e58f6a56a074a209a9e031b559fb7bdff8a3739515638"
+        "9468b0b90607d556"
+    ),
+    Ok(hex!("617f788a268a3c66549042db1be989f040ddee1caa94df7428d9e3c588f361be").to_vec()),
+    1690
+);
+
+test_modexp!(
+    test_even_modulus,
+    hex!(
+        "0000000000000000000000000000000000000000000000000000000000000020"
+        "0000000000000000000000000000000000000000000000000000000000000002"
+        "0000000000000000000000000000000000000000000000000000000000000020"
+        "2e93446b451b99a3bb1446cfe766f95396c25c199d085ba8100ac82aa0ca46bd"
+        "100150187489076766a8198d9965119ac393fceeca0b57cb06373e0000000000"
+        "0000"
+    ),
+    Ok(hex!("2b00a96c1f6393435db0e897785f0ea999fa8c3942cd78cb816fc091def886bd").to_vec()),
+    200
+);
+
+// A zero modulus gives zero, with the length of the modulus.
+test_modexp!(
+    test_zero_modulus,
+    hex!(
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "0000000000000000000000000000000000000000000000000000000000000004"
+        "020300000000"
+    ),
+    Ok(hex!("00000000").to_vec()),
+    200
+);
+
+// x^0 = 1, even for x = 0, except modulo 1.
+test_modexp!(
+    test_empty_exponent,
+    hex!(
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "0507"
+    ),
+    Ok(hex!("01").to_vec()),
+    200
+);
+
+test_modexp!(
+    test_empty_exponent_zero_base,
+    hex!(
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "07"
+    ),
+    Ok(hex!("01").to_vec()),
+    200
+);
+
+test_modexp!(
+    test_empty_exponent_modulus_one,
+    hex!(
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "0501"
+    ),
+    Ok(hex!("00").to_vec()),
+    200
+);
+
+test_modexp!(
+    test_empty_modulus,
+    hex!(
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0507"
+    ),
+    Ok(vec![]),
+    200
+);
+
+// The input is right-padded with zeros: the modulus is 0x0100.
+test_modexp!(
+    test_truncated_input,
+    hex!(
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "0000000000000000000000000000000000000000000000000000000000000002"
+        "020301"
+    ),
+    Ok(hex!("0008").to_vec()),
+    200
+);
+
+// Missing lengths are zero too.
+test_modexp!(test_empty_input, [], Ok(vec![]), 200);
+
+// An empty modulus gives an empty output without reading the other operands, however long. The
+// gas cost only depends on the lengths of the base and the modulus then.
+test_modexp!(
+    test_huge_exponent_length,
+    hex!(
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+    ),
+    Ok(vec![]),
+    200
+);
+
+// A long exponent pushes the modulus past the end of the input, so the modulus is zero.
+test_modexp!(
+    test_huge_exponent_length_nonempty_modulus,
+    hex!(
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "07"
+    ),
+    Ok(vec![0]),
+    u64::MAX
+);
+
+// Moduli too long to be paid for are rejected.
+test_modexp!(
+    test_huge_modulus_length,
+    hex!(
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+        "0203"
+    ),
+    Err(Error::ModulusTooLong),
+    u64::MAX
+);
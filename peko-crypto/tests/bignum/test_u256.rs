@@ -0,0 +1,442 @@
+use peko_crypto::bignum::{BigUint, Error, U256};
+use peko_rlp::{MinBigEndian, RLPDeserializer};
+use serde::Deserialize;
+
+use std::convert::TryFrom;
+
+macro_rules! test_binary {
+    ($name: ident, $op: expr, $a: expr, $b: expr, $expected: expr) => {
+        #[test]
+        fn $name() {
+            let a: U256 = $a.parse().unwrap();
+            let b: U256 = $b.parse().unwrap();
+            let expected: U256 = $expected.parse().unwrap();
+
+            assert_eq!(
+                ($op)(a, b),
+                expected,
+                "result for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+// The following tests were generated with the integers of Python.
+test_binary!(
+    test_add,
+    |a, b| a + b,
+    "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+    "0xaacdabbb49c9c6072c54a01283037cadfde8ec5e3e1544596ebbec4cc598e827",
+    "0x1fd9253f53ffae8ffafd085de306b9845b9b1ff3acbdd3a4be2ee98c42be35df"
+);
+test_binary!(
+    test_add_overflow,
+    |a, b| a + b,
+    "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+    "0x1",
+    "0x0"
+);
+test_binary!(
+    test_sub,
+    |a, b| a - b,
+    "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+    "0xaacdabbb49c9c6072c54a01283037cadfde8ec5e3e1544596ebbec4cc598e827",
+    "0xca3dcdc8c06c2281a253c838dcffc0285fc9473730934af1e0b710f2b78c6591"
+);
+test_binary!(
+    test_sub_underflow,
+    |a, b| a - b,
+    "0x0",
+    "0x1",
+    "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+);
+test_binary!(
+    test_mul,
+    |a, b| a * b,
+    "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+    "0xaacdabbb49c9c6072c54a01283037cadfde8ec5e3e1544596ebbec4cc598e827",
+    "0x95ae7d070f28ab2ccbe88383012a43d698ec7a92ddd9ca75e926d3aa9c5d9708"
+);
+test_binary!(
+    test_mul_overflow,
+    |a, b| a * b,
+    "0x100000000000000000000000000000000",
+    "0x100000000000000000000000000000000",
+    "0x0"
+);
+test_binary!(
+    test_div,
+    |a, b| a / b,
+    "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+    "0x28e7981664997082c8b7e20bf",
+    "0x2dc850e857ef84fda80a6139cf793aa53bfc4641"
+);
+test_binary!(
+    test_div_large,
+    |a, b| a / b,
+    "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+    "0x2ab36aeed2727181cb152804a0c0df2b7f7a3b178f8551165baefb1331663a",
+    "0x2bd"
+);
+test_binary!(
+    test_div_by_zero,
+    |a, b| a / b,
+    "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+    "0x0",
+    "0x0"
+);
+test_binary!(
+    test_rem,
+    |a, b| a % b,
+    "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+    "0x28e7981664997082c8b7e20bf",
+    "0x13c78bb59ce0f8fc5426c339"
+);
+test_binary!(
+    test_rem_by_zero,
+    |a, b| a % b,
+    "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+    "0x0",
+    "0x0"
+);
+test_binary!(
+    test_pow,
+    |a: U256, b| a.pow(b),
+    "0x3",
+    "0xaacdabbb49c9c6072c54a01283037cadfde8ec5e3e1544596ebbec4cc598e827",
+    "0xba55813b550ae547a2e1c319c62f1ad952bfc544c0db29254b13acddad40580b"
+);
+test_binary!(
+    test_pow_large_base,
+    |a: U256, b| a.pow(b),
+    "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+    "0xaacdabbb49c9c6072c54a01283037cadfde8ec5e3e1544596ebbec4cc598e827",
+    "0x0"
+);
+test_binary!(
+    test_pow_overflow,
+    |a: U256, b| a.pow(b),
+    "0x2",
+    "0x100",
+    "0x0"
+);
+test_binary!(
+    test_pow_zero_exponent,
+    |a: U256, b| a.pow(b),
+    "0x0",
+    "0x0",
+    "0x1"
+);
+test_binary!(
+    test_add_mod,
+    |a: U256, b| a.add_mod(b, U256::MAX - U256::ONE),
+    "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+    "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+    "0x2"
+);
+test_binary!(
+    test_add_mod_overflow,
+    |a: U256, b| a.add_mod(
+        b,
+        "0xd2aeeaf914c7d3fd9a1ac067541b8ee6f0969fe15284b2bf8e56916a518a4444"
+            .parse()
+            .unwrap()
+    ),
+    "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+    "0xaacdabbb49c9c6072c54a01283037cadfde8ec5e3e1544596ebbec4cc598e827",
+    "0x4d2a3a463f37da9260e247f68eeb2a9d6b0480125a3920e52fd85821f133f19b"
+);
+test_binary!(
+    test_add_mod_zero_modulus,
+    |a: U256, b| a.add_mod(b, U256::ZERO),
+    "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+    "0xaacdabbb49c9c6072c54a01283037cadfde8ec5e3e1544596ebbec4cc598e827",
+    "0x0"
+);
+test_binary!(
+    test_mul_mod,
+    |a: U256, b| a.mul_mod(
+        b,
+        "0xd2aeeaf914c7d3fd9a1ac067541b8ee6f0969fe15284b2bf8e56916a518a4444"
+            .parse()
+            .unwrap()
+    ),
+    "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+    "0xaacdabbb49c9c6072c54a01283037cadfde8ec5e3e1544596ebbec4cc598e827",
+    "0x82d9866989f91aef731492499734e01cf33d9ac41cb665799aeb1a2fbb4248b8"
+);
+test_binary!(
+    test_mul_mod_max,
+    |a: U256, b| a.mul_mod(b, U256::from(12u64)),
+    "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+    "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+    "0x9"
+);
+test_binary!(
+    test_mul_mod_zero_modulus,
+    |a: U256, b| a.mul_mod(b, U256::ZERO),
+    "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+    "0xaacdabbb49c9c6072c54a01283037cadfde8ec5e3e1544596ebbec4cc598e827",
+    "0x0"
+);
+
+#[test]
+fn test_shifts() {
+    let a: U256 = "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8"
+        .parse()
+        .unwrap();
+
+    for &(shift, left, right) in [
+        (
+            0,
+            "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+            "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+        ),
+        (
+            1,
+            "0xea16f308146bd1119d50d096c00679acbb64672add511e969ee5fa7efa4a9b70",
+            "0x3a85bcc2051af44467543425b0019e6b2ed919cab75447a5a7b97e9fbe92a6dc",
+        ),
+        (
+            63,
+            "0x67543425b0019e6b2ed919cab75447a5a7b97e9fbe92a6dc0000000000000000",
+            "0xea16f308146bd1119d50d096c00679acbb64672add511e96",
+        ),
+        (
+            64,
+            "0xcea8684b60033cd65db233956ea88f4b4f72fd3f7d254db80000000000000000",
+            "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b",
+        ),
+        (
+            65,
+            "0x9d50d096c00679acbb64672add511e969ee5fa7efa4a9b700000000000000000",
+            "0x3a85bcc2051af44467543425b0019e6b2ed919cab75447a5",
+        ),
+        (
+            200,
+            "0x72fd3f7d254db800000000000000000000000000000000000000000000000000",
+            "0x750b79840a35e8",
+        ),
+        (255, "0x0", "0x0"),
+        (256, "0x0", "0x0"),
+    ]
+    .iter()
+    {
+        assert_eq!(a << shift, left.parse().unwrap(), "{} << {}", a, shift);
+        assert_eq!(a >> shift, right.parse().unwrap(), "{} >> {}", a, shift);
+    }
+    assert_eq!(U256::MAX << u32::MAX, U256::ZERO);
+    assert_eq!(U256::MAX >> u32::MAX, U256::ZERO);
+}
+
+#[test]
+fn test_byte() {
+    let a: U256 = "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8"
+        .parse()
+        .unwrap();
+
+    assert_eq!(a.byte(0), 0x75);
+    assert_eq!(a.byte(1), 0x0b);
+    assert_eq!(a.byte(31), 0xb8);
+    assert_eq!(a.byte(32), 0);
+    assert_eq!(a.byte(usize::MAX), 0);
+}
+
+#[test]
+fn test_sign_extend() {
+    for &(byte, value, expected) in [
+        (
+            0,
+            "0xff",
+            "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        ),
+        (0, "0x7f", "0x7f"),
+        (
+            1,
+            "0x80ff",
+            "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff80ff",
+        ),
+        (1, "0x7fff", "0x7fff"),
+        (
+            0,
+            "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+            "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffb8",
+        ),
+        (
+            30,
+            "0x758b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+            "0xff8b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+        ),
+        (
+            31,
+            "0xf50b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+            "0xf50b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+        ),
+        (
+            200,
+            "0xf50b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+            "0xf50b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+        ),
+    ]
+    .iter()
+    {
+        let value: U256 = value.parse().unwrap();
+
+        assert_eq!(
+            value.sign_extend(byte),
+            expected.parse().unwrap(),
+            "sign extension of {:#x} from byte {}",
+            value,
+            byte
+        );
+    }
+}
+
+#[test]
+fn test_ordering() {
+    let a = U256::from(u64::MAX);
+    let b = U256::from(1u128 << 64);
+
+    assert!(a < b);
+    assert!(U256::MAX > b);
+    assert_eq!(a.max(b), b);
+    assert_eq!(b.cmp(&b), std::cmp::Ordering::Equal);
+}
+
+#[test]
+fn test_bits() {
+    assert_eq!(U256::ZERO.bits(), 0);
+    assert_eq!(U256::ONE.bits(), 1);
+    assert_eq!(U256::MAX.bits(), 256);
+    assert_eq!((U256::ONE << 200).leading_zeros(), 55);
+    assert!((U256::ONE << 200).bit(200));
+    assert!(!U256::MAX.bit(256));
+}
+
+#[test]
+fn test_overflowing() {
+    assert_eq!(U256::MAX.overflowing_add(U256::ONE), (U256::ZERO, true));
+    assert_eq!(
+        U256::ONE.overflowing_add(U256::ONE),
+        (U256::from(2u64), false)
+    );
+    assert_eq!(U256::ZERO.overflowing_sub(U256::ONE), (U256::MAX, true));
+    assert_eq!(U256::MAX.overflowing_mul(U256::ONE), (U256::MAX, false));
+    assert_eq!(
+        (U256::ONE << 255).overflowing_mul(U256::from(2u64)),
+        (U256::ZERO, true)
+    );
+}
+
+#[test]
+fn test_format() {
+    let a: U256 = "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        a.to_string(),
+        "52940877273050950909856492988689049655643967086755489088925917197648586427832"
+    );
+    assert_eq!(
+        format!("{:#x}", a),
+        "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8"
+    );
+    assert_eq!(format!("{:x}", U256::from(0xabcu64)), "abc");
+    assert_eq!(format!("{:#x}", U256::ZERO), "0x0");
+    assert_eq!(format!("{:>4}", U256::from(42u64)), "  42");
+    assert_eq!(format!("{:?}", U256::ZERO), "U256(0)");
+}
+
+#[test]
+fn test_from_str() {
+    assert_eq!("1000".parse(), Ok(U256::from(1000u64)));
+    assert_eq!("0x1000".parse(), Ok(U256::from(0x1000u64)));
+    assert_eq!("0x00FF".parse(), Ok(U256::from(0xffu64)));
+    assert_eq!(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935".parse(),
+        Ok(U256::MAX)
+    );
+    assert_eq!(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639936"
+            .parse::<U256>(),
+        Err(Error::Overflow)
+    );
+    assert_eq!(
+        format!("0x1{}", "0".repeat(64)).parse::<U256>(),
+        Err(Error::Overflow)
+    );
+    assert_eq!("".parse::<U256>(), Err(Error::InvalidDigit));
+    assert_eq!("0x".parse::<U256>(), Err(Error::InvalidDigit));
+    assert_eq!("12a".parse::<U256>(), Err(Error::InvalidDigit));
+    assert_eq!("-1".parse::<U256>(), Err(Error::InvalidDigit));
+}
+
+#[test]
+fn test_conversions() {
+    let a: U256 = "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8"
+        .parse()
+        .unwrap();
+
+    assert_eq!(U256::from_be_bytes(a.to_be_bytes()), a);
+    assert_eq!(U256::from_limbs(*a.as_limbs()), a);
+    assert_eq!(U256::try_from(BigUint::from(a)), Ok(a));
+    assert_eq!(
+        U256::try_from(&BigUint::from(U256::MAX) * &BigUint::from(2)),
+        Err(Error::Overflow)
+    );
+    assert_eq!(u64::try_from(U256::from(u64::MAX)), Ok(u64::MAX));
+    assert_eq!(u64::try_from(U256::from(1u128 << 64)), Err(Error::Overflow));
+    assert_eq!(U256::from(u128::MAX) + U256::ONE, U256::ONE << 128);
+}
+
+#[test]
+fn test_min_big_endian() {
+    // The encoding matches the one of the primitive integers in RLP.
+    for &value in [0u128, 1, 0x80, 0x0102, u128::MAX].iter() {
+        assert_eq!(U256::from(value).to_min_be(), value.to_min_be());
+    }
+
+    assert_eq!(U256::MAX.to_min_be(), vec![0xff; 32]);
+    assert_eq!(U256::try_from_min_be(&[0xff; 32]), Some(U256::MAX));
+    assert_eq!(U256::try_from_min_be(&[0, 0, 1]), Some(U256::ONE));
+    assert_eq!(U256::try_from_min_be(&[1; 33]), None);
+}
+
+#[test]
+fn test_rlp() {
+    // Integers are encoded like the primitive ones.
+    for &value in [0u128, 0x7f, 0x80, 0x0102, u128::MAX].iter() {
+        assert_eq!(
+            peko_rlp::to_bytes(&U256::from(value)).unwrap(),
+            peko_rlp::to_bytes(&value).unwrap()
+        );
+    }
+
+    let values = vec![U256::ZERO, U256::from(0x80u64), U256::MAX];
+    let encoded = peko_rlp::to_bytes(&values).unwrap();
+    assert_eq!(encoded[..5], [0xe4, 0x80, 0x81, 0x80, 0xa0]);
+
+    for value in values {
+        let encoded = peko_rlp::to_bytes(&value).unwrap();
+        let mut deserializer = RLPDeserializer::from_bytes(&encoded).unwrap();
+        assert_eq!(U256::deserialize(&mut deserializer).unwrap(), value);
+    }
+
+    // Integers of more than 32 bytes do not fit.
+    let encoded = [&[0x80 + 33][..], &[1u8; 33]].concat();
+    let mut deserializer = RLPDeserializer::from_bytes(&encoded).unwrap();
+    assert!(U256::deserialize(&mut deserializer).is_err());
+}
+
+#[test]
+fn test_serde_json() {
+    let a = U256::from(0x1234u64);
+
+    assert_eq!(serde_json::to_string(&a).unwrap(), "\"0x1234\"");
+    assert_eq!(serde_json::to_string(&U256::ZERO).unwrap(), "\"0x0\"");
+    assert_eq!(serde_json::from_str::<U256>("\"0x1234\"").unwrap(), a);
+    assert_eq!(serde_json::from_str::<U256>("\"4660\"").unwrap(), a);
+    assert!(serde_json::from_str::<U256>("\"0xz\"").is_err());
+}
@@ -0,0 +1,202 @@
+use hex_literal::hex;
+
+use peko_crypto::bignum::BigUint;
+use peko_rlp::MinBigEndian;
+
+macro_rules! test_div_rem {
+    ($name: ident, $dividend: expr, $divisor: expr, $quotient: expr, $remainder: expr) => {
+        #[test]
+        fn $name() {
+            let dividend = BigUint::from_be_bytes(&$dividend);
+            let divisor = BigUint::from_be_bytes(&$divisor);
+
+            assert_eq!(
+                dividend.div_rem(&divisor),
+                (
+                    BigUint::from_be_bytes(&$quotient),
+                    BigUint::from_be_bytes(&$remainder)
+                ),
+                "quotient and remainder for {} do not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+const X: [u8; 88] = hex!(
+    "0557f459fc305ebcdd6e51be2bd86ca6712a7756e32a99141e57a6955d2688d6"
+    "451b99a3bb1446cfe766f95396c25c199d085ba8100ac82aa0ca46bd50bbcbe8"
+    "187489076766a8198d9965119ac393fceeca0b57cb06373e"
+);
+
+const Y: [u8; 37] = hex!(
+    "f7ab7324108b54ffe9b25d8ff5950f61775de8da5603e7243a6cbb594cf03cba"
+    "a5e27d3a53"
+);
+
+const PRODUCT: [u8; 125] = hex!(
+    "052b70ea4de9507633732aa9dc5855bb5378730174b49616bc128fe2113b60ce"
+    "5372ddbd07ee1b116e63cc85945770fc77e579b847d5ff9e4c04432fa588cfbc"
+    "696c2f11d9bf4d0c672f65f6711f13cdddff7a30a3afc8a5ae1d708693c410e4"
+    "997ee165cf8c0cbcf7efaa7bdd1d6891f5a6f6b685aed9d739f0cdf51a"
+);
+
+const MODPOW: [u8; 37] = hex!(
+    "0cff70d4a54a96699be289f4bec359cb091088a6083f5b8d338ced7a111eda8a"
+    "1ac5349e6e"
+);
+
+// The following tests were generated with the integers of Python.
+test_div_rem!(
+    test_multiple_limbs,
+    hex!(
+        "0557f459fc305ebcdd6e51be2bd86ca6712a7756e32a99141e57a6955d2688d6"
+        "451b99a3bb1446cfe766f95396c25c199d085ba8100ac82aa0ca46bd50bbcbe8"
+        "187489076766a8198d9965119ac393fceeca0b57cb06373e"
+    ),
+    hex!(
+        "f7ab7324108b54ffe9b25d8ff5950f61775de8da5603e7243a6cbb594cf03cba"
+        "a5e27d3a53"
+    ),
+    hex!(
+        "0585f7119cc064dd100dcb9ab5ec928018f78803b698efcb5861c68ecb23de63"
+        "8b78a6a1875b1dd79a0876096a267b9cc7d96b"
+    ),
+    hex!(
+        "b043d0864628ad9c0621fcb4624dac90c8335dcc1023835bd6638d51c36408b4"
+        "420fb97b8d"
+    )
+);
+
+// The first estimate of a quotient limb is one too large, and the divisor is added back.
+test_div_rem!(
+    test_add_back,
+    hex!(
+        "8000000000000001000000000000000200000000000000020000000000000002"
+        "0000000000000001"
+    ),
+    hex!("80000000000000000000000000000001ffffffffffffffff"),
+    hex!("010000000000000001ffffffffffffffff"),
+    hex!("7fffffffffffffff00000000000000060000000000000000")
+);
+
+test_div_rem!(
+    test_single_limb,
+    hex!(
+        "0557f459fc305ebcdd6e51be2bd86ca6712a7756e32a99141e57a6955d2688d6"
+        "451b99a3bb1446cfe766f95396c25c199d085ba8100ac82aa0ca46bd50bbcbe8"
+        "187489076766a8198d9965119ac393fceeca0b57cb06373e"
+    ),
+    hex!("fedcba9876543210"),
+    hex!(
+        "055e0fba63077172472dceb2d98a0075e12886d2801212df3789b7fb90fd6a62"
+        "b605509cc745b1d0a36f0dd30ca088f3f7c62d5d2aa3ef471b3d0619ca9ea3b9"
+        "c45544a22a885adeaf108f79874623df"
+    ),
+    hex!("4c274d5f14766b4e")
+);
+
+test_div_rem!(
+    test_exact,
+    hex!(
+        "052b70ea4de9507633732aa9dc5855bb5378730174b49616bc128fe2113b60ce"
+        "5372ddbd07ee1b116e63cc85945770fc77e579b847d5ff9e4c04432fa588cfbc"
+        "696c2f11d9bf4d0c672f65f6711f13cdddff7a30a3afc8a5ae1d708693c410e4"
+        "997ee165cf8c0cbcf7efaa7bdd1d6891f5a6f6b685aed9d739f0cdf51a"
+    ),
+    hex!(
+        "f7ab7324108b54ffe9b25d8ff5950f61775de8da5603e7243a6cbb594cf03cba"
+        "a5e27d3a53"
+    ),
+    hex!(
+        "0557f459fc305ebcdd6e51be2bd86ca6712a7756e32a99141e57a6955d2688d6"
+        "451b99a3bb1446cfe766f95396c25c199d085ba8100ac82aa0ca46bd50bbcbe8"
+        "187489076766a8198d9965119ac393fceeca0b57cb06373e"
+    ),
+    hex!("00")
+);
+
+test_div_rem!(
+    test_smaller_dividend,
+    hex!("01"),
+    hex!("0100"),
+    [],
+    hex!("01")
+);
+
+#[test]
+#[should_panic(expected = "Divisor must not be zero")]
+fn test_division_by_zero() {
+    BigUint::one().div_rem(&BigUint::zero());
+}
+
+#[test]
+fn test_mul() {
+    let a = BigUint::from_be_bytes(&X);
+    let b = BigUint::from_be_bytes(&Y);
+
+    assert_eq!(&a * &b, BigUint::from_be_bytes(&PRODUCT));
+    assert_eq!(&a * &BigUint::zero(), BigUint::zero());
+    assert_eq!(&a * &BigUint::one(), a);
+}
+
+#[test]
+fn test_bytes() {
+    let a = BigUint::from_be_bytes(&hex!("0000000000000000000102"));
+
+    assert_eq!(a, BigUint::from(0x0102));
+    assert_eq!(a.to_be_bytes(), hex!("0102"));
+    assert_eq!(a.to_be_bytes_padded(4), Some(hex!("00000102").to_vec()));
+    assert_eq!(a.to_be_bytes_padded(1), None);
+    assert_eq!(a.bits(), 9);
+    assert!(a.bit(8) && !a.bit(7) && a.bit(1) && !a.bit(1000));
+
+    assert!(BigUint::from_be_bytes(&[0, 0, 0]).is_zero());
+    assert_eq!(BigUint::zero().to_be_bytes(), Vec::<u8>::new());
+    assert_eq!(BigUint::zero().bits(), 0);
+}
+
+#[test]
+fn test_min_big_endian() {
+    // The encoding matches the one of the primitive integers in RLP.
+    for value in [0u64, 1, 0x80, 0x0102, u64::MAX] {
+        let a = BigUint::from(value);
+
+        assert_eq!(a.to_min_be(), value.to_min_be());
+        assert_eq!(BigUint::try_from_min_be(&value.to_min_be()), Some(a));
+    }
+
+    assert_eq!(
+        BigUint::try_from_min_be(&PRODUCT),
+        Some(BigUint::from_be_bytes(&PRODUCT))
+    );
+}
+
+#[test]
+fn test_ordering() {
+    let a = BigUint::from_be_bytes(&X);
+    let b = BigUint::from_be_bytes(&Y);
+
+    assert!(a > b);
+    assert!(BigUint::from(u64::MAX) < BigUint::from_be_bytes(&hex!("010000000000000000")));
+    assert!(BigUint::zero() < BigUint::one());
+    assert_eq!(a.cmp(&a.clone()), std::cmp::Ordering::Equal);
+}
+
+#[test]
+fn test_modpow() {
+    let modulus = BigUint::from_be_bytes(&Y);
+
+    assert_eq!(
+        BigUint::zero().modpow(&BigUint::zero(), &modulus),
+        BigUint::one()
+    );
+    assert_eq!(
+        BigUint::from(7).modpow(&BigUint::one(), &BigUint::one()),
+        BigUint::zero()
+    );
+    assert_eq!(
+        BigUint::from_be_bytes(&X).modpow(&BigUint::from(3), &modulus),
+        BigUint::from_be_bytes(&MODPOW)
+    );
+}
@@ -0,0 +1,3 @@
+mod test_add;
+mod test_mul;
+mod test_pairing;
@@ -0,0 +1,95 @@
+use hex_literal::hex;
+
+use peko_crypto::bn254::{self, Error};
+
+macro_rules! test_add {
+    ($name: ident, $input: expr, $output: expr) => {
+        #[test]
+        fn $name() {
+            assert_eq!(
+                bn254::add(&$input).map(|output| output.to_vec()),
+                $output.map(|output: [u8; 64]| output.to_vec()),
+                "sum for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+// Test vectors from the ECADD tests of go-ethereum:
+// https://github.com/ethereum/go-ethereum/blob/master/core/vm/testdata/precompiles/bn256Add.json
+test_add!(
+    test_chfast1,
+    hex!(
+        "18b18acfb4c2c30276db5411368e7185b311dd124691610c5d3b74034e093dc9"
+        "063c909c4720840cb5134cb9f59fa749755796819658d32efc0d288198f37266"
+        "07c2b7f58a84bd6145f00c9c2bc0bb1a187f20ff2c92963a88019e7c6a014eed"
+        "06614e20c147e940f2d70da3f74c9a17df361706a4485c742bd6788478fa17d7"
+    ),
+    Ok(hex!(
+        "2243525c5efd4b9c3d3c45ac0ca3fe4dd85e830a4ce6b65fa1eeaee202839703"
+        "301d1d33be6da8e509df21cc35964723180eed7532537db9ae5e7d48f195c915"
+    ))
+);
+
+// Short inputs are padded with zeros, i.e. the point at infinity.
+test_add!(test_empty, [], Ok([0u8; 64]));
+test_add!(
+    test_point_plus_infinity,
+    hex!(
+        "2243525c5efd4b9c3d3c45ac0ca3fe4dd85e830a4ce6b65fa1eeaee202839703"
+        "301d1d33be6da8e509df21cc35964723180eed7532537db9ae5e7d48f195c915"
+    ),
+    Ok(hex!(
+        "2243525c5efd4b9c3d3c45ac0ca3fe4dd85e830a4ce6b65fa1eeaee202839703"
+        "301d1d33be6da8e509df21cc35964723180eed7532537db9ae5e7d48f195c915"
+    ))
+);
+
+test_add!(
+    test_point_plus_negation,
+    hex!(
+        "2243525c5efd4b9c3d3c45ac0ca3fe4dd85e830a4ce6b65fa1eeaee202839703"
+        "301d1d33be6da8e509df21cc35964723180eed7532537db9ae5e7d48f195c915"
+        "2243525c5efd4b9c3d3c45ac0ca3fe4dd85e830a4ce6b65fa1eeaee202839703"
+        "0047313f22c3f744ae7123ea4beb113a7f727d1c361e4cd38dc20ecde6e73432"
+    ),
+    Ok([0u8; 64])
+);
+
+// Bytes beyond the two points are ignored.
+test_add!(
+    test_doubling_long_input,
+    hex!(
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "0000000000000000000000000000000000000000000000000000000000000002"
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "0000000000000000000000000000000000000000000000000000000000000002"
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+    ),
+    Ok(hex!(
+        "030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3"
+        "15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4"
+    ))
+);
+
+test_add!(
+    test_not_on_curve,
+    hex!(
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "0000000000000000000000000000000000000000000000000000000000000003"
+    ),
+    Err(Error::PointNotOnCurve)
+);
+
+// The field modulus itself is not a valid coordinate, even though it is 0 modulo p.
+test_add!(
+    test_coordinate_not_reduced,
+    hex!(
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+    ),
+    Err(Error::InvalidFieldElement)
+);
@@ -0,0 +1,110 @@
+use hex_literal::hex;
+
+use peko_crypto::bn254::{self, Error};
+
+macro_rules! test_mul {
+    ($name: ident, $input: expr, $output: expr) => {
+        #[test]
+        fn $name() {
+            assert_eq!(
+                bn254::mul(&$input).map(|output| output.to_vec()),
+                $output.map(|output: [u8; 64]| output.to_vec()),
+                "product for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+// Test vector from the ECMUL tests of go-ethereum:
+// https://github.com/ethereum/go-ethereum/blob/master/core/vm/testdata/precompiles/bn256ScalarMul.json
+test_mul!(
+    test_chfast1,
+    hex!(
+        "2bd3e6d0f3b142924f5ca7b49ce5b9d54c4703d7ae5648e61d02268b1a0a9fb7"
+        "21611ce0a6af85915e2f1d70300909ce2e49dfad4a4619c8390cae66cefdb204"
+        "00000000000000000000000000000000000000000000000011138ce750fa15c2"
+    ),
+    Ok(hex!(
+        "070a8d6a982153cae4be29d434e8faef8a47b274a053f5a4ee2a6c9c13c31e5c"
+        "031b8ce914eba3a9ffb989f9cdd5b0f01943074bf4f0f315690ec3cec6981afc"
+    ))
+);
+
+// The following tests were generated with a straightforward Python implementation of the curve.
+test_mul!(
+    test_small_scalar,
+    hex!(
+        "2243525c5efd4b9c3d3c45ac0ca3fe4dd85e830a4ce6b65fa1eeaee202839703"
+        "301d1d33be6da8e509df21cc35964723180eed7532537db9ae5e7d48f195c915"
+        "00000000000000000000000000000000000000000000000011138ce750fa15c2"
+    ),
+    Ok(hex!(
+        "297beb2f6e3a6857d5face84d89aec3e3d68bebad328f18a80d47d24ed1e0e3d"
+        "2c640d3357225fc39899b24d3cf9da9976288619484e299f9e2b84bc88d3fccb"
+    ))
+);
+
+// Scalars are not reduced modulo the group order before multiplying.
+test_mul!(
+    test_max_scalar,
+    hex!(
+        "2243525c5efd4b9c3d3c45ac0ca3fe4dd85e830a4ce6b65fa1eeaee202839703"
+        "301d1d33be6da8e509df21cc35964723180eed7532537db9ae5e7d48f195c915"
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+    ),
+    Ok(hex!(
+        "028d313ab3fc4de0b0f287150f68d790ea91e4c92f5b596de786ef088d7ca695"
+        "2609fc17482b20594df31df34dc95948d3b53d041ca6f706dd8015273c8e377b"
+    ))
+);
+
+test_mul!(
+    test_group_order,
+    hex!(
+        "2243525c5efd4b9c3d3c45ac0ca3fe4dd85e830a4ce6b65fa1eeaee202839703"
+        "301d1d33be6da8e509df21cc35964723180eed7532537db9ae5e7d48f195c915"
+        "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001"
+    ),
+    Ok([0u8; 64])
+);
+
+// A missing scalar is padded with zeros.
+test_mul!(
+    test_zero_scalar,
+    hex!(
+        "2243525c5efd4b9c3d3c45ac0ca3fe4dd85e830a4ce6b65fa1eeaee202839703"
+        "301d1d33be6da8e509df21cc35964723180eed7532537db9ae5e7d48f195c915"
+    ),
+    Ok([0u8; 64])
+);
+
+test_mul!(
+    test_infinity,
+    hex!(
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000002"
+    ),
+    Ok([0u8; 64])
+);
+
+test_mul!(
+    test_not_on_curve,
+    hex!(
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "0000000000000000000000000000000000000000000000000000000000000002"
+    ),
+    Err(Error::PointNotOnCurve)
+);
+
+test_mul!(
+    test_coordinate_not_reduced,
+    hex!(
+        "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd48"
+        "0000000000000000000000000000000000000000000000000000000000000002"
+        "0000000000000000000000000000000000000000000000000000000000000002"
+    ),
+    Err(Error::InvalidFieldElement)
+);
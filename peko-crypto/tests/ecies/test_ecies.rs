@@ -0,0 +1,213 @@
+use hex_literal::hex;
+
+use peko_crypto::ecies;
+use peko_crypto::ecies::Error;
+use peko_crypto::secp256k1::SecretKey;
+
+/// Static key of the initiator in the devp2p handshake test vectors.
+const KEY_A: [u8; 32] = hex!("49a7b37aa6f6645917e7b807e9d1c00d4fa71f18343b0d4122a4d2df64dd6fee");
+
+/// Static key of the recipient in the devp2p handshake test vectors.
+const KEY_B: [u8; 32] = hex!("b71c71a67e1177ad4e901695e1b4b9ee17ae16c6668d313eac2f96dbcda3f291");
+
+macro_rules! test_decrypt {
+    ($name: ident, $key: expr, $shared_mac_data: expr, $ciphertext: expr, $message: expr) => {
+        #[test]
+        fn $name() {
+            let secret_key = SecretKey::from_bytes(&$key).unwrap();
+
+            assert_eq!(
+                ecies::decrypt(&secret_key, &$ciphertext, &$shared_mac_data),
+                Ok($message.to_vec()),
+                "message for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+// The following tests are the handshake test vectors given in the RLPx specification and EIP-8:
+// https://github.com/ethereum/devp2p/blob/master/rlpx.md#test-vectors
+// https://eips.ethereum.org/EIPS/eip-8
+// EIP-8 messages are prefixed with their size, which is used as the shared MAC data.
+test_decrypt!(
+    test_auth_legacy,
+    KEY_B,
+    [],
+    hex!(
+        "048ca79ad18e4b0659fab4853fe5bc58eb83992980f4c9cc147d2aa31532efd2"
+        "9a3d3dc6a3d89eaf913150cfc777ce0ce4af2758bf4810235f6e6ceccfee1acc"
+        "6b22c005e9e3a49d6448610a58e98744ba3ac0399e82692d67c1f58849050b30"
+        "24e21a52c9d3b01d871ff5f210817912773e610443a9ef142e91cdba0bd77b5f"
+        "df0769b05671fc35f83d83e4d3b0b000c6b2a1b1bba89e0fc51bf4e460df3105"
+        "c444f14be226458940d6061c296350937ffd5e3acaceeaaefd3c6f74be8e23e0"
+        "f45163cc7ebd76220f0128410fd05250273156d548a414444ae2f7dea4dfca2d"
+        "43c057adb701a715bf59f6fb66b2d1d20f2c703f851cbf5ac47396d9ca65b626"
+        "0bd141ac4d53e2de585a73d1750780db4c9ee4cd4d225173a4592ee77e2bd94d"
+        "0be3691f3b406f9bba9b591fc63facc016bfa8"
+    ),
+    hex!(
+        "299ca6acfd35e3d72d8ba3d1e2b60b5561d5af5218eb5bc182045769eb422691"
+        "0a301acae3b369fffc4a4899d6b02531e89fd4fe36a2cf0d93607ba470b50f78"
+        "003eb781e508ac1fff27c06cd192e2fe526f85f8f0e266ea55064ba8aefb868f"
+        "d9fda1cff674c90c9a197539fe3dfb53086ace64f83ed7c6eabec741f7f381cc"
+        "803e52ab2cd55d5569bce4347107a310dfd5f88a010cd2ffd1005ca406f18428"
+        "777e968bba13b6c50e2c4cd7f241cc0d64d1ac25c7f5952df231ac6a2bda8ee5"
+        "d600"
+    )
+);
+
+test_decrypt!(
+    test_auth_eip8,
+    KEY_B,
+    hex!("01b3"),
+    hex!(
+        "04ab7578555167be8154d5cc456f567d5ba302662433674222360f08d5f15344"
+        "99d3678b513b0fca474f3a514b18e75683032eb63fccb16c156dc6eb2c0b1593"
+        "f0d84ac74f6e475f1b8d56116b849634a8c458705bf83a626ea0384d4d7341aa"
+        "e591fae42ce6bd5c850bfe0b999a694a49bbbaf3ef6cda61110601d3b4c02ab6"
+        "c30437257a6e0117792631a4b47c1d52fc0f8f89caadeb7d02770bf999cc147d"
+        "2df3b62e1ffb2c9d8c125a3984865356266bca11ce7d3a688663a51d82defaa8"
+        "aad69da39ab6d5470e81ec5f2a7a47fb865ff7cca21516f9299a07b1bc63ba56"
+        "c7a1a892112841ca44b6e0034dee70c9adabc15d76a54f443593fafdc3b27af8"
+        "059703f88928e199cb122362a4b35f62386da7caad09c001edaeb5f8a06d2b26"
+        "fb6cb93c52a9fca51853b68193916982358fe1e5369e249875bb8d0d0ec36f91"
+        "7bc5e1eafd5896d46bd61ff23f1a863a8a8dcd54c7b109b771c8e61ec9c8908c"
+        "733c0263440e2aa067241aaa433f0bb053c7b31a838504b148f570c0ad628371"
+        "29e547678c5190341e4f1693956c3bf7678318e2d5b5340c9e488eefea198576"
+        "344afbdf66db5f51204a6961a63ce072c8926c"
+    ),
+    hex!(
+        "f8a7b841299ca6acfd35e3d72d8ba3d1e2b60b5561d5af5218eb5bc182045769"
+        "eb4226910a301acae3b369fffc4a4899d6b02531e89fd4fe36a2cf0d93607ba4"
+        "70b50f7800b840fda1cff674c90c9a197539fe3dfb53086ace64f83ed7c6eabe"
+        "c741f7f381cc803e52ab2cd55d5569bce4347107a310dfd5f88a010cd2ffd100"
+        "5ca406f1842877a07e968bba13b6c50e2c4cd7f241cc0d64d1ac25c7f5952df2"
+        "31ac6a2bda8ee5d6040000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000"
+    )
+);
+
+test_decrypt!(
+    test_ack_legacy,
+    KEY_A,
+    [],
+    hex!(
+        "049f8abcfa9c0dc65b982e98af921bc0ba6e4243169348a236abe9df5f93aa69"
+        "d99cadddaa387662b0ff2c08e9006d5a11a278b1b3331e5aaabf0a32f01281b6"
+        "f4ede0e09a2d5f585b26513cb794d9635a57563921c04a9090b4f14ee42be1a5"
+        "461049af4ea7a7f49bf4c97a352d39c8d02ee4acc416388c1c66cec761d2bc1c"
+        "72da6ba143477f049c9d2dde846c252c111b904f630ac98e51609b3b1f58168d"
+        "dca6505b7196532e5f85b259a20c45e1979491683fee108e9660edbf38f3add4"
+        "89ae73e3dda2c71bd1497113d5c755e942d1"
+    ),
+    hex!(
+        "b6d82fa3409da933dbf9cb0140c5dde89f4e64aec88d476af648880f4a10e1e4"
+        "9fe35ef3e69e93dd300b4797765a747c6384a6ecf5db9c2690398607a86181e4"
+        "559aead08264d5795d3909718cdd05abd49572e84fe55590eef31a88a08fdffd"
+        "00"
+    )
+);
+
+test_decrypt!(
+    test_ack_eip8,
+    KEY_A,
+    hex!("01ea"),
+    hex!(
+        "0451958701280a56482929d3b0757da8f7fbe5286784beead59d95089c217c9b"
+        "917788989470b0e330cc6e4fb383c0340ed85fab836ec9fb8a49672712aeabbd"
+        "fd1e837c1ff4cace34311cd7f4de05d59279e3524ab26ef753a0095637ac88f2"
+        "b499b9914b5f64e143eae548a1066e14cd2f4bd7f814c4652f11b254f8a2d019"
+        "1e2f5546fae6055694aed14d906df79ad3b407d94692694e259191cde171ad54"
+        "2fc588fa2b7333313d82a9f887332f1dfc36cea03f831cb9a23fea05b33deb99"
+        "9e85489e645f6aab1872475d488d7bd6c7c120caf28dbfc5d6833888155ed69d"
+        "34dbdc39c1f299be1057810f34fbe754d021bfca14dc989753d61c413d261934"
+        "e1a9c67ee060a25eefb54e81a4d14baff922180c395d3f998d70f46f6b58306f"
+        "969627ae364497e73fc27f6d17ae45a413d322cb8814276be6ddd13b885b201b"
+        "943213656cde498fa0e9ddc8e0b8f8a53824fbd82254f3e2c17e8eaea009c38b"
+        "4aa0a3f306e8797db43c25d68e86f262e564086f59a2fc60511c42abfb3057c2"
+        "47a8a8fe4fb3ccbadde17514b7ac8000cdb6a912778426260c47f38919a91f25"
+        "f4b5ffb455d6aaaf150f7e5529c100ce62d6d92826a71778d809bdf60232ae21"
+        "ce8a437eca8223f45ac37f6487452ce626f549b3b5fdee26afd2072e4bc75833"
+        "c2464c805246155289f4"
+    ),
+    hex!(
+        "f864b840b6d82fa3409da933dbf9cb0140c5dde89f4e64aec88d476af648880f"
+        "4a10e1e49fe35ef3e69e93dd300b4797765a747c6384a6ecf5db9c2690398607"
+        "a86181e4a0559aead08264d5795d3909718cdd05abd49572e84fe55590eef31a"
+        "88a08fdffd040000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "00000000000000000000000000000000000000000000000000"
+    )
+);
+
+#[test]
+fn test_round_trip() {
+    let secret_key = SecretKey::random();
+    let message = b"The quick brown fox jumps over the lazy dog";
+
+    let ciphertext = ecies::encrypt(&secret_key.public_key(), message, b"shared");
+    assert_eq!(ciphertext.len(), message.len() + ecies::OVERHEAD);
+
+    assert_eq!(
+        ecies::decrypt(&secret_key, &ciphertext, b"shared"),
+        Ok(message.to_vec())
+    );
+}
+
+#[test]
+fn test_wrong_shared_mac_data() {
+    let secret_key = SecretKey::random();
+    let ciphertext = ecies::encrypt(&secret_key.public_key(), b"peko", b"shared");
+
+    assert_eq!(
+        ecies::decrypt(&secret_key, &ciphertext, b"other"),
+        Err(Error::InvalidTag)
+    );
+}
+
+#[test]
+fn test_tampered_ciphertext() {
+    let secret_key = SecretKey::random();
+    let mut ciphertext = ecies::encrypt(&secret_key.public_key(), b"peko", b"");
+    ciphertext[65 + 16] ^= 1;
+
+    assert_eq!(
+        ecies::decrypt(&secret_key, &ciphertext, b""),
+        Err(Error::InvalidTag)
+    );
+}
+
+#[test]
+fn test_wrong_key() {
+    let ciphertext = ecies::encrypt(&SecretKey::random().public_key(), b"peko", b"");
+
+    assert_eq!(
+        ecies::decrypt(&SecretKey::random(), &ciphertext, b""),
+        Err(Error::InvalidTag)
+    );
+}
+
+#[test]
+fn test_invalid_ciphertext() {
+    let secret_key = SecretKey::random();
+
+    assert_eq!(
+        ecies::decrypt(&secret_key, &[0x04; ecies::OVERHEAD - 1], b""),
+        Err(Error::TooShort)
+    );
+    assert_eq!(
+        ecies::decrypt(&secret_key, &[0x04; ecies::OVERHEAD], b""),
+        Err(Error::InvalidPublicKey)
+    );
+}
@@ -0,0 +1,6 @@
+#[cfg(feature = "full-dag")]
+mod test_dataset;
+mod test_difficulty;
+mod test_hashimoto;
+mod test_sizes;
+mod test_verify;
@@ -0,0 +1,160 @@
+use hex_literal::hex;
+
+use peko_crypto::bignum::U256;
+use peko_crypto::ethash::{self, Cache, Dataset};
+use peko_crypto::hash::{HashFunction, Keccak256, H256};
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+/// Size of the dataset generated from the small cache used by these tests.
+const DATASET_SIZE: u64 = 256 * 1024;
+
+fn small_cache() -> Cache {
+    Cache::with_size(4096, &ethash::seed_hash(0))
+}
+
+/// Return a path in the temporary directory which is unique to the test.
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("peko-ethash-{}-{}", std::process::id(), name))
+}
+
+/// Check that the full and light outputs agree on a few headers and nonces.
+fn check_samples(cache: &Cache, dataset: &Dataset) {
+    for i in 0u64..16 {
+        let header_hash = Keccak256::hash(&i.to_le_bytes());
+        let nonce = i.wrapping_mul(0x9e3779b97f4a7c15);
+
+        assert_eq!(
+            ethash::hashimoto_full(dataset, &header_hash, nonce),
+            ethash::hashimoto_light(cache, dataset.size(), &header_hash, nonce),
+            "full and light outputs for sample {} do not match",
+            i
+        );
+    }
+}
+
+#[test]
+fn test_in_memory() {
+    let cache = small_cache();
+    let dataset = Dataset::with_size(&cache, DATASET_SIZE);
+
+    assert_eq!(dataset.size(), DATASET_SIZE);
+    check_samples(&cache, &dataset);
+}
+
+#[test]
+fn test_create() {
+    let cache = small_cache();
+    let path = temp_path("create");
+    let dataset = Dataset::create_with_size(&cache, DATASET_SIZE, &path).unwrap();
+    check_samples(&cache, &dataset);
+
+    // The file starts with the magic number, followed by the items.
+    let contents = fs::read(&path).unwrap();
+    assert_eq!(contents.len() as u64, 8 + DATASET_SIZE);
+    assert_eq!(contents[..8], hex!("fecaddbaaddee1fe"));
+
+    let loaded = Dataset::load(&path).unwrap();
+    check_samples(&cache, &loaded);
+
+    drop((dataset, loaded));
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_save() {
+    let cache = small_cache();
+    let dataset = Dataset::with_size(&cache, DATASET_SIZE);
+    let saved_path = temp_path("save");
+    let created_path = temp_path("save-created");
+
+    dataset.save(&saved_path).unwrap();
+    let created = Dataset::create_with_size(&cache, DATASET_SIZE, &created_path).unwrap();
+    assert_eq!(
+        fs::read(&saved_path).unwrap(),
+        fs::read(&created_path).unwrap()
+    );
+
+    let loaded = Dataset::load(&saved_path).unwrap();
+    check_samples(&cache, &loaded);
+
+    drop((created, loaded));
+    fs::remove_file(&saved_path).unwrap();
+    fs::remove_file(&created_path).unwrap();
+}
+
+macro_rules! test_load_invalid {
+    ($name: ident, $contents: expr) => {
+        #[test]
+        fn $name() {
+            let path = temp_path(stringify!($name));
+            fs::write(&path, $contents).unwrap();
+
+            let error = Dataset::load(&path).err().unwrap();
+            assert_eq!(
+                error.kind(),
+                io::ErrorKind::InvalidData,
+                "error for {} does not match",
+                stringify!($name)
+            );
+
+            fs::remove_file(&path).unwrap();
+        }
+    };
+}
+
+test_load_invalid!(test_load_empty, []);
+test_load_invalid!(test_load_no_items, hex!("fecaddbaaddee1fe"));
+test_load_invalid!(test_load_invalid_magic, [0u8; 8 + 128]);
+test_load_invalid!(
+    test_load_partial_mix,
+    [&hex!("fecaddbaaddee1fe")[..], &[0u8; 64]].concat()
+);
+
+// The file is sparse, only its magic number is written.
+#[test]
+fn test_load_oversized() {
+    let path = temp_path("test_load_oversized");
+    let file = fs::File::create(&path).unwrap();
+    (&file).write_all(&hex!("fecaddbaaddee1fe")).unwrap();
+    file.set_len(8 + (1 << 38) + 128).unwrap();
+
+    let error = Dataset::load(&path).err().unwrap();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_dataset_file_name() {
+    assert_eq!(ethash::dataset_file_name(0), "full-R23-0000000000000000");
+    assert_eq!(ethash::dataset_file_name(1), "full-R23-290decd9548b62a8");
+}
+
+// This test needs 1 GiB of memory and takes a while, run it with `cargo test --release
+// --features full-dag -- --ignored`.
+#[test]
+#[ignore]
+fn test_epoch_0() {
+    let cache = Cache::new(0);
+    let dataset = Dataset::new(&cache, 0);
+
+    assert_eq!(dataset.size(), ethash::dataset_size(0));
+    check_samples(&cache, &dataset);
+
+    // Block 1 of the Ethereum mainnet.
+    let header_hash = H256::new(hex!(
+        "85913a3057ea8bec78cd916871ca73802e77724e014dda65add3405d02240eb7"
+    ));
+    let output = ethash::hashimoto_full(&dataset, &header_hash, 0x539bd4979fef1ec4);
+    assert_eq!(
+        output.mix_digest,
+        hex!("969b900de27b6ac6a67742365dd65f55a0526c41fd18e1b16f1a1215c2e66f59")
+    );
+    assert!(ethash::check_difficulty(
+        &output.result,
+        &U256::from(17171480576u64)
+    ));
+}
@@ -0,0 +1,51 @@
+use hex_literal::hex;
+
+use peko_crypto::bignum::U256;
+use peko_crypto::ethash;
+use peko_crypto::hash::H256;
+
+macro_rules! test_difficulty {
+    ($name: ident, $result: expr, $difficulty: expr, $valid: expr) => {
+        #[test]
+        fn $name() {
+            assert_eq!(
+                ethash::check_difficulty(&H256::new($result), &$difficulty),
+                $valid,
+                "difficulty check for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+test_difficulty!(test_zero_difficulty, [0u8; 32], U256::ZERO, false);
+test_difficulty!(test_difficulty_1, [0xff; 32], U256::ONE, true);
+test_difficulty!(test_zero_result, [0u8; 32], U256::MAX, true);
+
+// The boundary of difficulty 2 is 2^255, which is itself within the boundary.
+test_difficulty!(
+    test_boundary,
+    hex!("8000000000000000000000000000000000000000000000000000000000000000"),
+    U256::from(2u64),
+    true
+);
+test_difficulty!(
+    test_above_boundary,
+    hex!("8000000000000000000000000000000000000000000000000000000000000001"),
+    U256::from(2u64),
+    false
+);
+
+// The boundary of difficulty 3 is rounded down from 2^256 / 3.
+test_difficulty!(
+    test_rounded_boundary,
+    hex!("5555555555555555555555555555555555555555555555555555555555555555"),
+    U256::from(3u64),
+    true
+);
+test_difficulty!(
+    test_above_rounded_boundary,
+    hex!("5555555555555555555555555555555555555555555555555555555555555556"),
+    U256::from(3u64),
+    false
+);
@@ -0,0 +1,98 @@
+use hex_literal::hex;
+
+use peko_crypto::ethash::{self, Cache};
+use peko_crypto::hash::H256;
+
+/// Size of the dataset of the small cache used by these tests.
+const DATASET_SIZE: u64 = 32 * 1024;
+
+const HEADER_HASH: [u8; 32] =
+    hex!("c9149cc0386e689d789a1c2f3d5d169a61a6218ed30e74414dc736e442ef3d1f");
+
+macro_rules! test_hashimoto {
+    ($name: ident, $nonce: expr, $mix_digest: expr, $result: expr) => {
+        #[test]
+        fn $name() {
+            let cache = Cache::with_size(1024, &ethash::seed_hash(0));
+            let output =
+                ethash::hashimoto_light(&cache, DATASET_SIZE, &H256::new(HEADER_HASH), $nonce);
+
+            assert_eq!(
+                output.mix_digest,
+                $mix_digest,
+                "mix digest for {} does not match",
+                stringify!($name)
+            );
+            assert_eq!(
+                output.result,
+                $result,
+                "result for {} does not match",
+                stringify!($name)
+            );
+
+            #[cfg(feature = "full-dag")]
+            {
+                let dataset = ethash::Dataset::with_size(&cache, DATASET_SIZE);
+                assert_eq!(
+                    ethash::hashimoto_full(&dataset, &H256::new(HEADER_HASH), $nonce),
+                    output,
+                    "full and light outputs for {} do not match",
+                    stringify!($name)
+                );
+            }
+        }
+    };
+}
+
+// TestHashimoto of go-ethereum, with a cache of 1024 bytes generated from the seed of epoch 0
+// and a dataset of 32 KiB.
+test_hashimoto!(
+    test_nonce_0,
+    0,
+    hex!("e4073cffaef931d37117cefd9afd27ea0f1cad6a981dd2605c4a1ac97c519800"),
+    hex!("d3539235ee2e6f8db665c0a72169f55b7f6c605712330b778ec3944f0eb5a557")
+);
+
+// The "first" test of ethash_tests.json from ethereum/tests, over the cache and the dataset of
+// epoch 0. Generating the cache of about 16 MB takes a while, run this test with
+// `cargo test -- --ignored`.
+#[test]
+#[ignore]
+fn test_ethash_tests_first() {
+    let cache = Cache::new(0);
+    let output = ethash::hashimoto_light(
+        &cache,
+        ethash::dataset_size(0),
+        &H256::new(hex!(
+            "2a8de2adf89af77358250bf908bf04ba94a6e8c3ba87775564a41d269a05e4ce"
+        )),
+        0x4242424242424242,
+    );
+
+    assert_eq!(
+        output.mix_digest,
+        hex!("58f759ede17a706c93f13030328bcea40c1d1341fb26f2facd21ceb0dae57017")
+    );
+    assert_eq!(
+        output.result,
+        hex!("dd47fd2d98db51078356852d7c4014e6a5d6c387c35f40e2875b74a256ed7906")
+    );
+}
+
+macro_rules! test_invalid_dataset_size {
+    ($name: ident, $dataset_size: expr) => {
+        #[test]
+        #[should_panic(
+            expected = "Dataset size must be a non-zero multiple of 128 bytes of at most 256 GiB"
+        )]
+        fn $name() {
+            let cache = Cache::with_size(1024, &ethash::seed_hash(0));
+            ethash::hashimoto_light(&cache, $dataset_size, &H256::new(HEADER_HASH), 0);
+        }
+    };
+}
+
+test_invalid_dataset_size!(test_zero_dataset_size, 0);
+test_invalid_dataset_size!(test_small_dataset_size, 64);
+test_invalid_dataset_size!(test_unaligned_dataset_size, DATASET_SIZE + 64);
+test_invalid_dataset_size!(test_oversized_dataset_size, (1 << 38) + 128);
@@ -0,0 +1,47 @@
+use hex_literal::hex;
+
+use peko_crypto::ethash;
+use peko_crypto::hash::{HashFunction, Keccak256};
+
+macro_rules! test_sizes {
+    ($name: ident, $epoch: expr, $cache_size: expr, $dataset_size: expr) => {
+        #[test]
+        fn $name() {
+            assert_eq!(
+                ethash::cache_size($epoch),
+                $cache_size,
+                "cache size for {} does not match",
+                stringify!($name)
+            );
+            assert_eq!(
+                ethash::dataset_size($epoch),
+                $dataset_size,
+                "dataset size for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+test_sizes!(test_epoch_0, 0, 16776896, 1073739904);
+test_sizes!(test_epoch_1, 1, 16907456, 1082130304);
+test_sizes!(test_epoch_100, 100, 29882816, 1912601216);
+test_sizes!(test_epoch_1000, 1000, 147848768, 9462346624);
+
+#[test]
+fn test_epoch() {
+    assert_eq!(ethash::epoch(0), 0);
+    assert_eq!(ethash::epoch(29999), 0);
+    assert_eq!(ethash::epoch(30000), 1);
+    assert_eq!(ethash::epoch(3311058), 110);
+}
+
+#[test]
+fn test_seed_hash() {
+    assert_eq!(ethash::seed_hash(0), [0u8; 32]);
+    assert_eq!(
+        ethash::seed_hash(1),
+        hex!("290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563")
+    );
+    assert_eq!(ethash::seed_hash(2), Keccak256::hash(&ethash::seed_hash(1)));
+}
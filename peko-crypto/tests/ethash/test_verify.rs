@@ -0,0 +1,105 @@
+use hex_literal::hex;
+
+use peko_crypto::bignum::U256;
+use peko_crypto::ethash::{self, Cache, Error};
+use peko_crypto::hash::H256;
+
+const HEADER_HASH: [u8; 32] =
+    hex!("c9149cc0386e689d789a1c2f3d5d169a61a6218ed30e74414dc736e442ef3d1f");
+
+// Mix digest of the nonce 0, whose result is d3539235..., above the boundary of difficulty 2.
+const MIX_DIGEST: [u8; 32] =
+    hex!("e4073cffaef931d37117cefd9afd27ea0f1cad6a981dd2605c4a1ac97c519800");
+
+macro_rules! test_verify {
+    ($name: ident, $mix_digest: expr, $difficulty: expr, $result: expr) => {
+        test_verify!($name, 32 * 1024, $mix_digest, $difficulty, $result);
+    };
+    ($name: ident, $dataset_size: expr, $mix_digest: expr, $difficulty: expr, $result: expr) => {
+        #[test]
+        fn $name() {
+            let cache = Cache::with_size(1024, &ethash::seed_hash(0));
+
+            assert_eq!(
+                ethash::verify_light(
+                    &cache,
+                    $dataset_size,
+                    &H256::new(HEADER_HASH),
+                    0,
+                    &H256::new($mix_digest),
+                    &$difficulty
+                ),
+                $result,
+                "verification for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+test_verify!(test_valid, MIX_DIGEST, U256::ONE, Ok(()));
+test_verify!(
+    test_insufficient_difficulty,
+    MIX_DIGEST,
+    U256::from(2u64),
+    Err(Error::InsufficientDifficulty)
+);
+test_verify!(
+    test_invalid_mix_digest,
+    [0u8; 32],
+    U256::ONE,
+    Err(Error::InvalidMixDigest)
+);
+test_verify!(
+    test_zero_difficulty,
+    MIX_DIGEST,
+    U256::ZERO,
+    Err(Error::ZeroDifficulty)
+);
+test_verify!(
+    test_zero_dataset_size,
+    0,
+    MIX_DIGEST,
+    U256::ONE,
+    Err(Error::InvalidDatasetSize)
+);
+test_verify!(
+    test_small_dataset_size,
+    64,
+    MIX_DIGEST,
+    U256::ONE,
+    Err(Error::InvalidDatasetSize)
+);
+test_verify!(
+    test_oversized_dataset_size,
+    (1 << 38) + 128,
+    MIX_DIGEST,
+    U256::ONE,
+    Err(Error::InvalidDatasetSize)
+);
+
+// Block 3311058 of the Ethereum mainnet, in epoch 110. Generating its cache of about 30 MB takes
+// a while, run this test with `cargo test -- --ignored`.
+#[test]
+#[ignore]
+fn test_block_3311058() {
+    let block_number = 3311058;
+    let epoch = ethash::epoch(block_number);
+    let cache = Cache::new(epoch);
+
+    assert_eq!(
+        ethash::verify_light(
+            &cache,
+            ethash::dataset_size(epoch),
+            &H256::new(hex!(
+                "543e8c0c744afd5ca511d4080dc71bc8206c9e34d991bc06462b938e37cda38c"
+            )),
+            0xf400cd0006070c49,
+            &H256::new(hex!(
+                "3e140b0784516af5e5ec6730f2fb20cca22f32be399b9e4ad77d32541f798cd0"
+            )),
+            &U256::from(167925187834220u64)
+        ),
+        Ok(())
+    );
+}
@@ -1 +1,17 @@
+mod test_batch;
+mod test_blake2b;
+mod test_digest;
+mod test_dyn;
+mod test_io;
+mod test_keccak256;
+mod test_keccak512;
+mod test_resume;
+mod test_ripemd160;
+mod test_sha224;
 mod test_sha256;
+mod test_sha3;
+mod test_sha384;
+mod test_sha512;
+mod test_sha512_224;
+mod test_sha512_256;
+mod test_shake;
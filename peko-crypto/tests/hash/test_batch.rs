@@ -0,0 +1,74 @@
+use peko_crypto::hash::HashFunction;
+use peko_crypto::hash::{
+    BLAKE2b512, Keccak256, SHA256Backend, SHA224, SHA256, SHA3_224, SHA3_256, SHA3_384, SHA3_512,
+    SHA512,
+};
+
+/// Messages of every length up to a few blocks of the largest rate, followed by a mix of
+/// lengths in no particular order so that lanes finish at different times.
+fn messages() -> Vec<Vec<u8>> {
+    let mut lengths: Vec<usize> = (0..=300).collect();
+    lengths.extend(&[1000, 0, 55, 56, 64, 135, 136, 137, 2, 500, 63, 1]);
+
+    lengths
+        .iter()
+        .enumerate()
+        .map(|(i, &length)| (0..length).map(|j| (i * 31 + j * 7) as u8).collect())
+        .collect()
+}
+
+macro_rules! test_batch {
+    ($name: ident, $hasher: ty) => {
+        #[test]
+        fn $name() {
+            let messages = messages();
+            let messages: Vec<&[u8]> = messages.iter().map(|message| &message[..]).collect();
+
+            let digests = <$hasher>::hash_batch(&messages);
+            assert_eq!(digests.len(), messages.len());
+
+            for (message, digest) in messages.iter().zip(digests.iter()) {
+                assert_eq!(
+                    digest,
+                    &<$hasher>::hash(message),
+                    "batch hash for {} of {} bytes does not match",
+                    stringify!($name),
+                    message.len()
+                );
+            }
+
+            assert!(<$hasher>::hash_batch(&[]).is_empty());
+        }
+    };
+}
+
+test_batch!(test_sha224, SHA224);
+test_batch!(test_sha256, SHA256);
+test_batch!(test_keccak256, Keccak256);
+test_batch!(test_sha3_224, SHA3_224);
+test_batch!(test_sha3_256, SHA3_256);
+test_batch!(test_sha3_384, SHA3_384);
+test_batch!(test_sha3_512, SHA3_512);
+// Hash functions without a multi-buffer implementation fall back to hashing one at a time.
+test_batch!(test_sha512, SHA512);
+test_batch!(test_blake2b512, BLAKE2b512);
+
+#[test]
+fn test_sha256_backends() {
+    let messages = messages();
+    let messages: Vec<&[u8]> = messages.iter().map(|message| &message[..]).collect();
+
+    for &backend in SHA256Backend::ALL.iter().filter(|b| b.is_available()) {
+        let digests = SHA256::hash_batch_with_backend(backend, &messages);
+
+        for (message, digest) in messages.iter().zip(digests.iter()) {
+            assert_eq!(
+                digest,
+                &SHA256::hash(message),
+                "batch hash of {} bytes with the {:?} backend does not match",
+                message.len(),
+                backend
+            );
+        }
+    }
+}
@@ -0,0 +1,342 @@
+use hex_literal::hex;
+
+use peko_crypto::hash::{blake2b_f, BLAKE2b, BLAKE2b256, BLAKE2b512, HashFunction};
+
+use std::convert::TryInto;
+
+/// Run the F compression function on an input in the format of the BLAKE2 precompiled contract:
+/// rounds (4 bytes, big-endian), h (64 bytes), m (128 bytes), t (16 bytes), all little-endian
+/// words, and the final block flag (1 byte).
+fn precompile_f(input: &[u8; 213]) -> Vec<u8> {
+    let rounds = u32::from_be_bytes(input[0..4].try_into().unwrap());
+
+    let words: Vec<u64> = input[4..212]
+        .chunks_exact(8)
+        .map(|word| u64::from_le_bytes(word.try_into().unwrap()))
+        .collect();
+
+    let mut h: [u64; 8] = words[0..8].try_into().unwrap();
+    let m: [u64; 16] = words[8..24].try_into().unwrap();
+    let t = [words[24], words[25]];
+
+    blake2b_f(rounds, &mut h, &m, t, input[212] == 1);
+
+    h.iter()
+        .flat_map(|word| word.to_le_bytes().to_vec())
+        .collect()
+}
+
+macro_rules! test_blake2b_f {
+    ($name: ident, $input: expr, $output: expr) => {
+        #[test]
+        fn $name() {
+            assert_eq!(precompile_f(&$input), $output.to_vec());
+        }
+    };
+}
+
+// The following tests are extracted from the test vectors of EIP-152:
+// https://eips.ethereum.org/EIPS/eip-152
+test_blake2b_f!(
+    test_f_0_rounds,
+    hex!(
+        "0000000048c9bdf267e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f"
+        "3af54fa5d182e6ad7f520e511f6c3e2b8c68059b6bbd41fbabd9831f79217e13"
+        "19cde05b61626300000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "000000000300000000000000000000000000000001"
+    ),
+    hex!(
+        "08c9bcf367e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f3af54fa5"
+        "d282e6ad7f520e511f6c3e2b8c68059b9442be0454267ce079217e1319cde05b"
+    )
+);
+
+test_blake2b_f!(
+    test_f_12_rounds,
+    hex!(
+        "0000000c48c9bdf267e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f"
+        "3af54fa5d182e6ad7f520e511f6c3e2b8c68059b6bbd41fbabd9831f79217e13"
+        "19cde05b61626300000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "000000000300000000000000000000000000000001"
+    ),
+    hex!(
+        "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1"
+        "7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+    )
+);
+
+test_blake2b_f!(
+    test_f_not_final,
+    hex!(
+        "0000000c48c9bdf267e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f"
+        "3af54fa5d182e6ad7f520e511f6c3e2b8c68059b6bbd41fbabd9831f79217e13"
+        "19cde05b61626300000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "000000000300000000000000000000000000000000"
+    ),
+    hex!(
+        "75ab69d3190a562c51aef8d88f1c2775876944407270c42c9844252c26d28752"
+        "98743e7f6d5ea2f2d3e8d226039cd31b4e426ac4f2d3d666a610c2116fde4735"
+    )
+);
+
+test_blake2b_f!(
+    test_f_1_round,
+    hex!(
+        "0000000148c9bdf267e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f"
+        "3af54fa5d182e6ad7f520e511f6c3e2b8c68059b6bbd41fbabd9831f79217e13"
+        "19cde05b61626300000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "0000000000000000000000000000000000000000000000000000000000000000"
+        "000000000300000000000000000000000000000001"
+    ),
+    hex!(
+        "b63a380cb2897d521994a85234ee2c181b5f844d2c624c002677e9703449d2fb"
+        "a551b3a8333bcdf5f2f7e08993d53923de3d64fcc68c034e717b9293fed7a421"
+    )
+);
+
+// 2^32 - 1 rounds takes a long time to compute, so this test is ignored by default.
+#[test]
+#[ignore]
+fn test_f_max_rounds() {
+    assert_eq!(
+        precompile_f(&hex!(
+            "ffffffff48c9bdf267e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f"
+            "3af54fa5d182e6ad7f520e511f6c3e2b8c68059b6bbd41fbabd9831f79217e13"
+            "19cde05b61626300000000000000000000000000000000000000000000000000"
+            "0000000000000000000000000000000000000000000000000000000000000000"
+            "0000000000000000000000000000000000000000000000000000000000000000"
+            "0000000000000000000000000000000000000000000000000000000000000000"
+            "000000000300000000000000000000000000000001"
+        )),
+        hex!(
+            "fc59093aafa9ab43daae0e914c57635c5402d8e3d2130eb9b3cc181de7f0ecf9"
+            "b22bf99a7815ce16419e200e01846e6b5df8cc7703041bbceb571de6631d2615"
+        )
+        .to_vec()
+    );
+}
+
+macro_rules! test_blake2b {
+    ($name: ident, $output_size: expr, $key: expr, $input: expr, $hash: expr) => {
+        #[test]
+        fn $name() {
+            let mut hasher = BLAKE2b::new($output_size, &$key);
+
+            for chunk in $input.chunks(13) {
+                hasher.update(chunk);
+            }
+
+            assert_eq!(
+                hasher.digest(),
+                $hash.to_vec(),
+                "hash for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+// The following tests are extracted from the blake2b-kat.txt file in the BLAKE2 reference
+// implementation, where the key is 0x00, 0x01, ..., 0x3f and the input is 0x00, 0x01, ...:
+// https://github.com/BLAKE2/BLAKE2/tree/master/testvectors
+test_blake2b!(
+    test_keyed_0,
+    64,
+    hex!(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        "202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"
+    ),
+    hex!(""),
+    hex!(
+        "10ebb67700b1868efb4417987acf4690ae9d972fb7a590c2f02871799aaa4786"
+        "b5e996e8f0f4eb981fc214b005f42d2ff4233499391653df7aefcbc13fc51568"
+    )
+);
+
+test_blake2b!(
+    test_keyed_1,
+    64,
+    hex!(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        "202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"
+    ),
+    hex!("00"),
+    hex!(
+        "961f6dd1e4dd30f63901690c512e78e4b45e4742ed197c3c5e45c549fd25f2e4"
+        "187b0bc9fe30492b16b0d0bc4ef9b0f34c7003fac09a5ef1532e69430234cebd"
+    )
+);
+
+test_blake2b!(
+    test_keyed_2,
+    64,
+    hex!(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        "202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"
+    ),
+    hex!("0001"),
+    hex!(
+        "da2cfbe2d8409a0f38026113884f84b50156371ae304c4430173d08a99d9fb1b"
+        "983164a3770706d537f49e0c916d9f32b95cc37a95b99d857436f0232c88a965"
+    )
+);
+
+test_blake2b!(
+    test_keyed_63,
+    64,
+    hex!(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        "202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"
+    ),
+    hex!(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        "202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e"
+    ),
+    hex!(
+        "bd965bf31e87d70327536f2a341cebc4768eca275fa05ef98f7f1b71a0351298"
+        "de006fba73fe6733ed01d75801b4a928e54231b38e38c562b2e33ea1284992fa"
+    )
+);
+
+test_blake2b!(
+    test_keyed_127,
+    64,
+    hex!(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        "202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"
+    ),
+    hex!(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        "202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"
+        "404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f"
+        "606162636465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e"
+    ),
+    hex!(
+        "76d2d819c92bce55fa8e092ab1bf9b9eab237a25267986cacf2b8ee14d214d73"
+        "0dc9a5aa2d7b596e86a1fd8fa0804c77402d2fcd45083688b218b1cdfa0dcbcb"
+    )
+);
+
+test_blake2b!(
+    test_keyed_128,
+    64,
+    hex!(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        "202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"
+    ),
+    hex!(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        "202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"
+        "404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f"
+        "606162636465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e7f"
+    ),
+    hex!(
+        "72065ee4dd91c2d8509fa1fc28a37c7fc9fa7d5b3f8ad3d0d7a25626b57b1b44"
+        "788d4caf806290425f9890a3a2a35a905ab4b37acfd0da6e4517b2525c9651e4"
+    )
+);
+
+test_blake2b!(
+    test_keyed_129,
+    64,
+    hex!(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        "202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"
+    ),
+    hex!(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        "202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"
+        "404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f"
+        "606162636465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e7f"
+        "80"
+    ),
+    hex!(
+        "64475dfe7600d7171bea0b394e27c9b00d8e74dd1e416a79473682ad3dfdbb70"
+        "6631558055cfc8a40e07bd015a4540dcdea15883cbbf31412df1de1cd4152b91"
+    )
+);
+
+test_blake2b!(
+    test_keyed_255,
+    64,
+    hex!(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        "202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"
+    ),
+    hex!(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        "202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"
+        "404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f"
+        "606162636465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e7f"
+        "808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f"
+        "a0a1a2a3a4a5a6a7a8a9aaabacadaeafb0b1b2b3b4b5b6b7b8b9babbbcbdbebf"
+        "c0c1c2c3c4c5c6c7c8c9cacbcccdcecfd0d1d2d3d4d5d6d7d8d9dadbdcdddedf"
+        "e0e1e2e3e4e5e6e7e8e9eaebecedeeeff0f1f2f3f4f5f6f7f8f9fafbfcfdfe"
+    ),
+    hex!(
+        "142709d62e28fcccd0af97fad0f8465b971e82201dc51070faa0372aa43e9248"
+        "4be1c1e73ba10906d5d1853db6a4106e0a7bf9800d373d6dee2d46d62ef2a461"
+    )
+);
+
+// Unkeyed hashes with a variable output size.
+test_blake2b!(test_unkeyed_1, 1, [], hex!(""), hex!("2e"));
+
+test_blake2b!(
+    test_unkeyed_20,
+    20,
+    [],
+    hex!("616263"),
+    hex!("384264f676f39536840523f284921cdc68b6846b")
+);
+
+test_blake2b!(
+    test_unkeyed_32,
+    32,
+    [],
+    hex!("616263"),
+    hex!("bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319")
+);
+
+test_blake2b!(
+    test_unkeyed_64,
+    64,
+    [],
+    hex!("616263"),
+    hex!(
+        "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1"
+        "7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+    )
+);
+
+// The following test is extracted from RFC 7693, Appendix A:
+// https://tools.ietf.org/html/rfc7693#appendix-A
+#[test]
+fn test_blake2b512_rfc7693() {
+    assert_eq!(
+        BLAKE2b512::hash(b"abc"),
+        hex!(
+            "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1"
+            "7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+        )
+    );
+}
+
+#[test]
+fn test_blake2b256() {
+    assert_eq!(
+        BLAKE2b256::hash(b""),
+        hex!("0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8")
+    );
+}
@@ -0,0 +1,115 @@
+use hex_literal::hex;
+
+use std::collections::HashSet;
+
+use peko_crypto::hash::{Digest, Error, HashFunction, Keccak256, H256, RIPEMD160};
+use peko_rlp::RLPDeserializer;
+use serde::Deserialize;
+
+/// Keccak-256 of the empty string.
+const EMPTY_KECCAK: [u8; 32] =
+    hex!("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470");
+
+#[test]
+fn test_display() {
+    let digest = Keccak256::hash(b"");
+
+    assert_eq!(
+        digest.to_string(),
+        "0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+    );
+    assert_eq!(
+        format!("{:x}", digest),
+        "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+    );
+    assert_eq!(
+        format!("{:?}", RIPEMD160::hash(b"")),
+        "Digest(0x9c1185a5c5e9fc54612808977ee8f548b2258d31)"
+    );
+}
+
+#[test]
+fn test_from_str() {
+    let lowercase = "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470";
+    let uppercase = "0xC5D2460186F7233C927E7DB2DCC703C0E500B653CA82273B7BFAD8045D85A470";
+
+    assert_eq!(lowercase.parse::<H256>(), Ok(H256::new(EMPTY_KECCAK)));
+    assert_eq!(uppercase.parse::<H256>(), Ok(H256::new(EMPTY_KECCAK)));
+
+    assert_eq!("0x".parse::<Digest<0>>(), Ok(Digest::new([])));
+    assert_eq!("0x1234".parse::<H256>(), Err(Error::InvalidLength));
+    assert_eq!(
+        format!("{}00", lowercase).parse::<H256>(),
+        Err(Error::InvalidLength)
+    );
+    assert_eq!(
+        format!("0x{}", &lowercase.replace('c', "g")).parse::<H256>(),
+        Err(Error::InvalidCharacter)
+    );
+    // Signs accepted by integer parsing are not hexadecimal digits.
+    assert_eq!(
+        format!("+{}", &lowercase[1..]).parse::<H256>(),
+        Err(Error::InvalidCharacter)
+    );
+}
+
+#[test]
+fn test_equality() {
+    let digest = Keccak256::hash(b"");
+    let mut other = digest;
+    other[31] ^= 0x01;
+
+    assert_eq!(digest, EMPTY_KECCAK);
+    assert_eq!(EMPTY_KECCAK, digest);
+    assert_ne!(digest, other);
+    assert_ne!(other, EMPTY_KECCAK);
+
+    let set: HashSet<H256> = vec![digest, other, digest].into_iter().collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_conversions() {
+    let digest = H256::from(EMPTY_KECCAK);
+
+    assert_eq!(digest.as_bytes(), &EMPTY_KECCAK);
+    assert_eq!(digest.as_ref(), &EMPTY_KECCAK[..]);
+    assert_eq!(&digest[12..], &EMPTY_KECCAK[12..]);
+    assert_eq!(<[u8; 32]>::from(digest), EMPTY_KECCAK);
+    assert_eq!(digest.into_bytes(), EMPTY_KECCAK);
+    assert_eq!(H256::default(), [0u8; 32]);
+}
+
+#[test]
+fn test_serde_json() {
+    let digest = Keccak256::hash(b"");
+    let json = "\"0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470\"";
+
+    assert_eq!(serde_json::to_string(&digest).unwrap(), json);
+    assert_eq!(serde_json::from_str::<H256>(json).unwrap(), digest);
+    assert!(serde_json::from_str::<H256>("\"0x1234\"").is_err());
+}
+
+#[test]
+fn test_serde_rlp() {
+    // A digest is encoded as a byte string of fixed length, so a 32-byte digest gets the
+    // 0x80 + 32 prefix.
+    let digest = Keccak256::hash(b"");
+    let encoded = peko_rlp::to_bytes(&digest).unwrap();
+
+    assert_eq!(encoded[0], 0xa0);
+    assert_eq!(encoded[1..], EMPTY_KECCAK);
+
+    let mut deserializer = RLPDeserializer::from_bytes(&encoded).unwrap();
+    assert_eq!(H256::deserialize(&mut deserializer).unwrap(), digest);
+
+    // Byte strings of the wrong length and lists are rejected.
+    let encoded = peko_rlp::to_bytes(&RIPEMD160::hash(b"")).unwrap();
+    let mut deserializer = RLPDeserializer::from_bytes(&encoded).unwrap();
+    assert!(H256::deserialize(&mut deserializer).is_err());
+    let mut deserializer = RLPDeserializer::from_bytes(&hex!("c0")).unwrap();
+    assert_eq!(
+        H256::deserialize(&mut deserializer),
+        Err(peko_rlp::Error::NotByteArray)
+    );
+}
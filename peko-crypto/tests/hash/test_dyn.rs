@@ -0,0 +1,83 @@
+use peko_crypto::hash::{
+    self, BLAKE2b256, BLAKE2b512, DynHash, HashFunction, Keccak256, Keccak512, RIPEMD160, SHA224,
+    SHA256, SHA384, SHA3_224, SHA3_256, SHA3_384, SHA3_512, SHA512, SHA512_224, SHA512_256,
+};
+
+macro_rules! test_dyn {
+    ($name: ident, $hash_name: expr, $hasher: ty) => {
+        #[test]
+        fn $name() {
+            let message: Vec<u8> = (0..300).map(|i| (i * 5 % 256) as u8).collect();
+            let mut hasher = hash::from_name($hash_name).unwrap();
+
+            assert_eq!(hasher.output_size(), <$hasher>::OUTPUT_SIZE);
+            assert_eq!(hasher.block_size(), <$hasher>::BLOCK_SIZE);
+
+            for chunk in message.chunks(13) {
+                hasher.update(chunk);
+            }
+
+            assert_eq!(
+                hasher.digest(),
+                <$hasher>::hash(&message).to_vec(),
+                "hash for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+test_dyn!(test_sha224, "sha224", SHA224);
+test_dyn!(test_sha256, "sha256", SHA256);
+test_dyn!(test_sha384, "sha384", SHA384);
+test_dyn!(test_sha512, "sha512", SHA512);
+test_dyn!(test_sha512_224, "sha512_224", SHA512_224);
+test_dyn!(test_sha512_256, "sha512_256", SHA512_256);
+test_dyn!(test_sha3_224, "sha3_224", SHA3_224);
+test_dyn!(test_sha3_256, "sha3_256", SHA3_256);
+test_dyn!(test_sha3_384, "sha3_384", SHA3_384);
+test_dyn!(test_sha3_512, "sha3_512", SHA3_512);
+test_dyn!(test_ripemd160, "ripemd160", RIPEMD160);
+test_dyn!(test_blake2b, "blake2b", BLAKE2b512);
+test_dyn!(test_blake2b256, "blake2b256", BLAKE2b256);
+test_dyn!(test_keccak256, "keccak256", Keccak256);
+test_dyn!(test_keccak512, "keccak512", Keccak512);
+
+#[test]
+fn test_unknown_name() {
+    assert!(hash::from_name("md5").is_none());
+    assert!(hash::from_name("SHA256").is_none());
+    assert!(hash::from_name("").is_none());
+}
+
+#[test]
+fn test_clone_and_reset() {
+    let mut hasher = hash::from_name("sha256").unwrap();
+    hasher.update(b"The quick brown fox ");
+
+    let mut copy = hasher.clone();
+    copy.update(b"jumps over the lazy dog");
+    assert_eq!(
+        copy.digest(),
+        SHA256::hash(b"The quick brown fox jumps over the lazy dog").to_vec()
+    );
+
+    // The original is unaffected by updates to the copy.
+    assert_eq!(
+        hasher.digest(),
+        SHA256::hash(b"The quick brown fox ").to_vec()
+    );
+
+    hasher.reset();
+    assert_eq!(hasher.digest(), SHA256::hash(b"").to_vec());
+}
+
+#[test]
+fn test_boxed_static_hasher() {
+    // Any hash function can be boxed directly, not only those returned by from_name.
+    let hashers: Vec<Box<dyn DynHash>> = vec![Box::new(SHA256::new()), Box::new(Keccak256::new())];
+    let digests: Vec<Vec<u8>> = hashers.iter().map(|hasher| hasher.digest()).collect();
+
+    assert_eq!(digests[0], SHA256::hash(b"").to_vec());
+    assert_eq!(digests[1], Keccak256::hash(b"").to_vec());
+}
@@ -0,0 +1,120 @@
+use hex_literal::hex;
+
+use std::io::{self, Read, Write};
+
+use peko_crypto::hash::{HashFunction, HashReader, HashWriter, Keccak256, SHA256};
+
+/// A reader returning at most `limit` bytes per call, as sockets and pipes may do.
+struct ShortReader<'a> {
+    data: &'a [u8],
+    limit: usize,
+}
+
+impl Read for ShortReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let length = buf.len().min(self.limit).min(self.data.len());
+        buf[..length].copy_from_slice(&self.data[..length]);
+        self.data = &self.data[length..];
+
+        Ok(length)
+    }
+}
+
+fn message() -> Vec<u8> {
+    (0..100_000).map(|i| (i * 13 % 256) as u8).collect()
+}
+
+#[test]
+fn test_writer_copy() {
+    let message = message();
+    let mut writer = HashWriter::<SHA256>::new();
+
+    let copied = io::copy(&mut &message[..], &mut writer).unwrap();
+
+    assert_eq!(copied, message.len() as u64);
+    assert_eq!(writer.digest(), SHA256::hash(&message));
+}
+
+#[test]
+fn test_writer_write_all() {
+    // Many small writes must give the same result as a single update.
+    let mut writer = HashWriter::<Keccak256>::default();
+    for _ in 0..1000 {
+        writer.write_all(&[b'a'; 1000]).unwrap();
+    }
+    writer.flush().unwrap();
+
+    assert_eq!(writer.digest(), Keccak256::hash(&vec![b'a'; 1_000_000]));
+}
+
+#[test]
+fn test_writer_with_hasher() {
+    let mut hasher = SHA256::new();
+    hasher.update(b"The quick brown fox ");
+
+    let adjective = "lazy";
+    let mut writer = HashWriter::with_hasher(hasher);
+    write!(writer, "jumps over the {} dog", adjective).unwrap();
+
+    assert_eq!(
+        writer.into_inner().digest(),
+        hex!("d7a8fbb307d7809469ca9abcb0082e4f8d5651e46d3cdb762d02d0bf37c9e592")
+    );
+}
+
+#[test]
+fn test_reader_short_reads() {
+    let message = message();
+
+    for &limit in [1, 7, 64, 1000].iter() {
+        let mut reader = HashReader::<_, SHA256>::new(ShortReader {
+            data: &message,
+            limit,
+        });
+
+        let mut contents = Vec::new();
+        reader.read_to_end(&mut contents).unwrap();
+
+        assert_eq!(
+            contents, message,
+            "data read with limit {} does not match",
+            limit
+        );
+        assert_eq!(
+            reader.digest(),
+            SHA256::hash(&message),
+            "hash with limit {} does not match",
+            limit
+        );
+    }
+}
+
+#[test]
+fn test_reader_partial() {
+    // Only the bytes actually read are hashed.
+    let message = message();
+    let mut reader = HashReader::<_, Keccak256>::new(&message[..]);
+
+    let mut buffer = [0u8; 1000];
+    reader.read_exact(&mut buffer).unwrap();
+
+    assert_eq!(reader.digest(), Keccak256::hash(&message[..1000]));
+    assert_eq!(reader.get_ref().len(), message.len() - 1000);
+
+    let (rest, hasher) = reader.into_inner();
+    assert_eq!(rest, &message[1000..]);
+    assert_eq!(hasher.digest(), Keccak256::hash(&message[..1000]));
+}
+
+#[test]
+fn test_reader_into_writer() {
+    // Verify a download while saving it, without holding it in memory twice.
+    let message = message();
+    let mut reader = HashReader::<_, SHA256>::new(&message[..]);
+    let mut writer = HashWriter::<Keccak256>::new();
+
+    io::copy(&mut reader, &mut writer).unwrap();
+
+    assert_eq!(reader.digest(), SHA256::hash(&message));
+    assert_eq!(writer.digest(), Keccak256::hash(&message));
+}
@@ -0,0 +1,167 @@
+use hex_literal::hex;
+
+use peko_crypto::hash::HashFunction;
+use peko_crypto::hash::Keccak256;
+
+macro_rules! test_keccak256 {
+    ($name: ident, $input: expr, $hash: expr) => {
+        #[test]
+        fn $name() {
+            let mut hasher = Keccak256::new();
+
+            for chunk in $input.chunks(13) {
+                hasher.update(chunk);
+            }
+
+            assert_eq!(
+                hasher.digest(),
+                $hash,
+                "hash for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+// The following tests are extracted from the ShortMsgKAT_256.txt file in the
+// Keccak Known Answer Tests:
+// https://keccak.team/archives.html
+test_keccak256!(
+    test_0,
+    hex!(""),
+    hex!("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+);
+
+test_keccak256!(
+    test_1,
+    hex!("cc"),
+    hex!("eead6dbfc7340a56caedc044696a168870549a6a7f6f56961e84a54bd9970b8a")
+);
+
+test_keccak256!(
+    test_2,
+    hex!("41fb"),
+    hex!("a8eaceda4d47b3281a795ad9e1ea2122b407baf9aabcb9e18b5717b7873537d2")
+);
+
+test_keccak256!(
+    test_3,
+    hex!("1f877c"),
+    hex!("627d7bc1491b2ab127282827b8de2d276b13d7d70fb4c5957fdf20655bc7ac30")
+);
+
+test_keccak256!(
+    test_4,
+    hex!("c1ecfdfc"),
+    hex!("b149e766d7612eaf7d55f74e1a4fdd63709a8115b14f61fcd22aa4abc8b8e122")
+);
+
+test_keccak256!(
+    test_5,
+    hex!("21f134ac57"),
+    hex!("67f05544dbe97d5d6417c1b1ea9bc0e3a99a541381d1cd9b08a9765687eb5bb4")
+);
+
+test_keccak256!(
+    test_6,
+    hex!("c6f50bb74e29"),
+    hex!("923062c4e6f057597220d182dbb10e81cd25f60b54005b2a75dd33d6dac518d0")
+);
+
+test_keccak256!(
+    test_7,
+    hex!("119713cc83eeef"),
+    hex!("feb8405dcd315d48c6cbf7a3504996de8e25cc22566efec67433712eda99894f")
+);
+
+test_keccak256!(
+    test_8,
+    hex!("4a4f202484512526"),
+    hex!("e620d8f2982b24fedaaa3baa9b46c3f9ce204ee356666553ecb35e15c3ff9bf9")
+);
+
+test_keccak256!(
+    test_9,
+    hex!("1f66ab4185ed9b6375"),
+    hex!("9e03f7c9a3d055eca1d786ed6fb624d93f1cf0ac27f9c2b6c05e509fac9e7fca")
+);
+
+test_keccak256!(
+    test_10,
+    hex!("eed7422227613b6f53c9"),
+    hex!("caad8e1ed546630748a12f5351b518a9a431cda6ba56cbfc3ccbdd8aae5092f7")
+);
+
+test_keccak256!(
+    test_11,
+    hex!("eaeed5cdffd89dece455f1"),
+    hex!("d61708bdb3211a9aab28d4df01dfa4b29ed40285844d841042257e97488617b0")
+);
+
+test_keccak256!(
+    test_12,
+    hex!("5be43c90f22902e4fe8ed2d3"),
+    hex!("0f53be55990780b3fad9870f04f7d8153c3ae605c057c85abb5d71765043aaa8")
+);
+
+test_keccak256!(
+    test_13,
+    hex!("a746273228122f381c3b46e4f1"),
+    hex!("32215ae88204a782b62d1810d945de49948de458600f5e1e3896ceca2ed3292b")
+);
+
+test_keccak256!(
+    test_14,
+    hex!("3c5871cd619c69a63b540eb5a625"),
+    hex!("9510da68e58ebb8d2ab9de8485bb408e358299a9c011ae8544b0d0faf9d4a4ea")
+);
+
+test_keccak256!(
+    test_15,
+    hex!("fa22874bcc068879e8ef11a69f0722"),
+    hex!("f20b3bcf743aa6fa084038520791c364cb6d3d1dd75841f8d7021cd98322bd8f")
+);
+
+test_keccak256!(
+    test_16,
+    hex!("52a608ab21ccdd8a4457a57ede782176"),
+    hex!("0e32defa2071f0b5ac0e6a108b842ed0f1d3249712f58ee0ddf956fe332a5f95")
+);
+
+test_keccak256!(
+    test_64,
+    hex!("e926ae8b0af6e53176dbffcc2a6b88c6bd765f939d3d178a9bde9ef3aa131c61e31c1e42cdfaf4b4dcde579a37e150efbef5555b4c1cb40439d835a724e2fae7"),
+    hex!("574271cd13959e8ddeae5bfbdb02a3fdf54f2babfd0cbeb893082a974957d0c1")
+);
+
+// The following tests cover messages around and beyond the 136-byte rate of Keccak-256, so
+// that the permutation is applied while absorbing as well as during padding.
+test_keccak256!(
+    test_135,
+    hex!("b771d5cef5d1a41a93d15643d7181d2a2ef0a8e84d91812f20ed21f147bef732bf3a60ef4067c3734b85bc8cd471780f10dc9e8291b58339a677b960218f71e793f2797aea349406512829065d37bb55ea796fa4f56fd8896b49b2cd19b43215ad967c712b24e5032d065232e02c127409d2ed4146b9d75d763d52db98d949d3b0fed6a8052fbb"),
+    hex!("bd6f5492582a7c1b116304de28314df9fffe95b0da11af52fe9440a717a34859")
+);
+
+test_keccak256!(
+    test_136,
+    hex!("b32d95b0b9aad2a8816de6d06d1f86008505bd8c14124f6e9a163b5a2ade55f835d0ec3880ef50700d3b25e42cc0af050ccd1be5e555b23087e04d7bf9813622780c7313a1954f8740b6ee2d3f71f768dd417f520482bd3a08d4f222b4ee9dbd015447b33507dd50f3ab4247c5de9a8abd62a8decea01e3b87c8b927f5b08beb37674c6f8e380c04"),
+    hex!("e717a7769448abbe5fef8187954a88ac56ded1d22e63940ab80d029585a21921")
+);
+
+test_keccak256!(
+    test_137,
+    hex!("04410e31082a47584b406f051398a6abe74e4da59bb6f85e6b49e8a1f7f2ca00dfba5462c2cd2bfde8b64fb21d70c083f11318b56a52d03b81cac5eec29eb31bd0078b6156786da3d6d8c33098c5c47bb67ac64db14165af65b44544d806dde5f487d5373c7f9792c299e9686b7e5821e7c8e2458315b996b5677d926dac57b3f22da873c601016a0d"),
+    hex!("a95d50b50b4545f0947441df74a1e9d74622eb3baa49c1bbfc3a0cce6619c1aa")
+);
+
+test_keccak256!(
+    test_200,
+    hex!("8c3798e51bc68482d7337d3abb75dc9ffe860714a9ad73551e120059860dde24ab87327222b64cf774415a70f724cdf270de3fe47dda07b61c9ef2a3551f45a5584860248fabde676e1cd75f6355aa3eaeabe3b51dc813d9fb2eaa4f0f1d9f834d7cad9c7c695ae84b329385bc0bef895b9f1edf44a03d4b410cc23a79a6b62e4f346a5e8dd851c2857995ddbf5b2d717aeb847310e1f6a46ac3d26a7f9b44985af656d2b7c9406e8a9e8f47dcb4ef6b83caacf9aefb6118bfcff7e44bef6937ebddc89186839b77"),
+    hex!("e83ea21f5bc0976953af86069a10eb6024a1ac59d609688e4a9759bb8b6c9441")
+);
+
+test_keccak256!(
+    test_255,
+    hex!("3a3a819c48efde2ad914fbf00e18ab6bc4f14513ab27d0c178a188b61431e7f5623cb66b23346775d386b50e982c493adbbfc54b9a3cd383382336a1a0b2150a15358f336d03ae18f666c7573d55c4fd181c29e6ccfde63ea35f0adf5885cfc0a3d84a2b2e4dd24496db789e663170cef74798aa1bbcd4574ea0bba40489d764b2f83aadc66b148b4a0cd95246c127d5871c4f11418690a5ddf01246a0c80a43c70088b6183639dcfda4125bd113a8f49ee23ed306faac576c3fb0c1e256671d817fc2534a52f5b439f72e424de376f4c565cca82307dd9ef76da5b7c4eb7e085172e328807c02d011ffbf33785378d79dc266f6a5be6bb0e4a92eceebaeb1"),
+    hex!("348fb774adc970a16b1105669442625e6adaa8257a89effdb5a802f161b862ea")
+);
@@ -0,0 +1,90 @@
+use hex_literal::hex;
+
+use peko_crypto::hash::HashFunction;
+use peko_crypto::hash::Keccak512;
+
+macro_rules! test_keccak512 {
+    ($name: ident, $input: expr, $hash: expr) => {
+        #[test]
+        fn $name() {
+            let mut hasher = Keccak512::new();
+
+            for chunk in $input.chunks(13) {
+                hasher.update(chunk);
+            }
+
+            assert_eq!(
+                hasher.digest(),
+                $hash,
+                "hash for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+// The following tests are extracted from the ShortMsgKAT_512.txt file in the
+// Keccak Known Answer Tests:
+// https://keccak.team/archives.html
+test_keccak512!(
+    test_0,
+    hex!(""),
+    hex!(
+        "0eab42de4c3ceb9235fc91acffe746b29c29a8c366b7c60e4e67c466f36a4304"
+        "c00fa9caf9d87976ba469bcbe06713b435f091ef2769fb160cdab33d3670680e"
+    )
+);
+
+test_keccak512!(
+    test_1,
+    hex!("cc"),
+    hex!(
+        "8630c13cbd066ea74bbe7fe468fec1dee10edc1254fb4c1b7c5fd69b646e4416"
+        "0b8ce01d05a0908ca790dfb080f4b513bc3b6225ece7a810371441a5ac666eb9"
+    )
+);
+
+test_keccak512!(
+    test_2,
+    hex!("41fb"),
+    hex!(
+        "551da6236f8b96fce9f97f1190e901324f0b45e06dbbb5cdb8355d6ed1dc34b3"
+        "f0eae7dcb68622ff232fa3cece0d4616cdeb3931f93803662a28df1cd535b731"
+    )
+);
+
+test_keccak512!(
+    test_8,
+    hex!("1f877c"),
+    hex!(
+        "eb7f2a98e00af37d964f7d8c44c1fb6e114d8ee21a7b976ae736539efdc1e3fe"
+        "43becef5015171e6da30168cae99a82c53fa99042774ef982c01626a540f08c0"
+    )
+);
+
+test_keccak512!(
+    test_32,
+    hex!("c1ecfdfc"),
+    hex!(
+        "952d4c0a6f0ef5ce438c52e3edd345ea00f91cf5da8097c1168a16069e958fc0"
+        "5bad90a0c5fb4dd9ec28e84b226b94a847d6bb89235692ef4c9712f0c7030fae"
+    )
+);
+
+test_keccak512!(
+    test_136,
+    hex!(
+        "3a3a819c48efde2ad914fbf00e18ab6bc4f14513ab27d0c178a188b61431e7f5"
+        "623cb66b23346775d386b50e982c493adbbfc54b9a3cd383382336a1a0b2150a"
+        "15358f336d03ae18f666c7573d55c4fd181c29e6ccfde63ea35f0adf5885cfc0"
+        "a3d84a2b2e4dd24496db789e663170cef74798aa1bbcd4574ea0bba40489d764"
+        "b2f83aadc66b148b4a0cd95246c127d5871c4f11418690a5ddf01246a0c80a43"
+        "c70088b6183639dcfda4125bd113a8f49ee23ed306faac576c3fb0c1e256671d"
+        "817fc2534a52f5b439f72e424de376f4c565cca82307dd9ef76da5b7c4eb7e08"
+        "5172e328807c02d011ffbf33785378d79dc266f6a5be6bb0e4a92eceebaeb1"
+    ),
+    hex!(
+        "81950e7096d31d4f22e3db71cac725bf59e81af54c7ca9e6aeee71c010fc5467"
+        "466312a01aa5c137cfb140646941556796f612c9351268737c7e9a2b9631d1fa"
+    )
+);
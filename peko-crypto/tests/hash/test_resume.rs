@@ -0,0 +1,132 @@
+use hex_literal::hex;
+
+use peko_crypto::hash::{
+    Error, HashFunction, Keccak256, ResumableHash, RIPEMD160, SHA224, SHA256, SHA384, SHA3_256,
+    SHA3_512, SHA512, SHA512_256,
+};
+
+macro_rules! test_resume {
+    ($name: ident, $hasher: ty) => {
+        #[test]
+        fn $name() {
+            // Export the state at every position of a message spanning a few blocks, and check
+            // that resuming from it gives the same digest.
+            let message: Vec<u8> = (0..400).map(|i| (i * 11 % 256) as u8).collect();
+            let digest = <$hasher>::hash(&message);
+
+            for split in 0..=message.len() {
+                let mut hasher = <$hasher>::new();
+                hasher.update(&message[..split]);
+
+                let mut resumed = <$hasher>::import_state(&hasher.export_state()).unwrap();
+                resumed.update(&message[split..]);
+
+                assert_eq!(
+                    resumed.digest(),
+                    digest,
+                    "resumed hash for {} split at {} does not match",
+                    stringify!($name),
+                    split
+                );
+            }
+        }
+    };
+}
+
+test_resume!(test_sha224, SHA224);
+test_resume!(test_sha256, SHA256);
+test_resume!(test_sha384, SHA384);
+test_resume!(test_sha512, SHA512);
+test_resume!(test_sha512_256, SHA512_256);
+test_resume!(test_ripemd160, RIPEMD160);
+test_resume!(test_keccak256, Keccak256);
+test_resume!(test_sha3_256, SHA3_256);
+test_resume!(test_sha3_512, SHA3_512);
+
+#[test]
+fn test_sha256_format() {
+    // The initial state is the initial hash value from FIPS 180-4, section 5.3.3, and a zero
+    // length.
+    assert_eq!(
+        SHA256::new().export_state(),
+        hex!(
+            "6a09e667bb67ae853c6ef372a54ff53a510e527f9b05688c1f83d9ab5be0cd19"
+            "0000000000000000"
+        )
+        .to_vec()
+    );
+
+    let mut hasher = SHA256::new();
+    hasher.update(&[0xaa; 70]);
+    let state = hasher.export_state();
+
+    // A full chunk was compressed, and the remaining 6 bytes are buffered.
+    assert_eq!(state.len(), 32 + 8 + 6);
+    assert_eq!(state[32..40], 70u64.to_be_bytes());
+    assert_eq!(state[40..], [0xaa; 6]);
+}
+
+#[test]
+fn test_sha256_length_extension() {
+    // Continue hashing from a finished digest, treating it as the chaining value after the
+    // padded secret.
+    let secret = b"secret key";
+    let extension = b"extension";
+
+    let mut padded = secret.to_vec();
+    padded.push(0x80);
+    padded.resize(56, 0x00);
+    padded.extend_from_slice(&(secret.len() as u64 * 8).to_be_bytes());
+
+    let mut state = SHA256::hash(secret).to_vec();
+    state.extend_from_slice(&(padded.len() as u64).to_be_bytes());
+
+    let mut forged = SHA256::import_state(&state).unwrap();
+    forged.update(extension);
+
+    padded.extend_from_slice(extension);
+    assert_eq!(forged.digest(), SHA256::hash(&padded));
+}
+
+#[test]
+fn test_keccak256_format() {
+    assert_eq!(Keccak256::new().export_state(), vec![0u8; 201]);
+
+    let mut hasher = Keccak256::new();
+    hasher.update(&[0xff; 3]);
+    let state = hasher.export_state();
+
+    assert_eq!(state[..8], hex!("ffffff0000000000"));
+    assert_eq!(state[200], 3);
+}
+
+#[test]
+fn test_invalid_state() {
+    // Too short to hold the chaining value and the length.
+    assert_eq!(
+        SHA256::import_state(&[0u8; 39]).err(),
+        Some(Error::InvalidStateLength)
+    );
+
+    // The length says 3 bytes are buffered, but only 2 are present.
+    let mut state = SHA256::new().export_state();
+    state[39] = 3;
+    state.extend_from_slice(&[0x00; 2]);
+    assert_eq!(
+        SHA256::import_state(&state).err(),
+        Some(Error::InvalidStateLength)
+    );
+
+    assert_eq!(
+        Keccak256::import_state(&[0u8; 200]).err(),
+        Some(Error::InvalidStateLength)
+    );
+
+    // The position must be inside the 136-byte block.
+    let mut state = vec![0u8; 201];
+    state[200] = 136;
+    assert_eq!(
+        Keccak256::import_state(&state).err(),
+        Some(Error::InvalidState)
+    );
+}
@@ -0,0 +1,80 @@
+use hex_literal::hex;
+
+use peko_crypto::hash::HashFunction;
+use peko_crypto::hash::RIPEMD160;
+
+macro_rules! test_ripemd160 {
+    ($name: ident, $input: expr, $hash: expr) => {
+        #[test]
+        fn $name() {
+            let mut hasher = RIPEMD160::new();
+
+            for chunk in $input.chunks(13) {
+                hasher.update(chunk);
+            }
+
+            assert_eq!(
+                hasher.digest(),
+                $hash,
+                "hash for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+// The following tests are the test vectors given by the designers of RIPEMD-160:
+// https://homes.esat.kuleuven.be/~bosselae/ripemd160.html
+test_ripemd160!(
+    test_empty,
+    b"",
+    hex!("9c1185a5c5e9fc54612808977ee8f548b2258d31")
+);
+
+test_ripemd160!(
+    test_a,
+    b"a",
+    hex!("0bdc9d2d256b3ee9daae347be6f4dc835a467ffe")
+);
+
+test_ripemd160!(
+    test_abc,
+    b"abc",
+    hex!("8eb208f7e05d987a9b044a8e98c6b087f15a0bfc")
+);
+
+test_ripemd160!(
+    test_message_digest,
+    b"message digest",
+    hex!("5d0689ef49d2fae572b881b123a85ffa21595f36")
+);
+
+test_ripemd160!(
+    test_alphabet,
+    b"abcdefghijklmnopqrstuvwxyz",
+    hex!("f71c27109c692c1b56bbdceb5b9d2865b3708dbc")
+);
+
+test_ripemd160!(
+    test_overlapping_alphabet,
+    b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+    hex!("12a053384a9c0c88e405a06c27dcf49ada62eb2b")
+);
+
+test_ripemd160!(
+    test_alphanumeric,
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+    hex!("b0e20b6e3116640286ed3a87a5713079b21f5189")
+);
+
+test_ripemd160!(
+    test_8_times_digits,
+    b"1234567890".repeat(8),
+    hex!("9b752e45573d4b39f4dbd3323cab82bf63326bfb")
+);
+
+test_ripemd160!(
+    test_million_a,
+    b"a".repeat(1_000_000),
+    hex!("52783243c1697bdbe16d37f97f68f08325dc1528")
+);
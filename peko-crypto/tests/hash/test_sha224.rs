@@ -0,0 +1,202 @@
+use hex_literal::hex;
+
+use peko_crypto::hash::HashFunction;
+use peko_crypto::hash::{SHA256Backend, SHA224};
+
+// The following test is extracted from the SHA224Monte.rsp file in the
+// SHA Test Vectors for Hashing Byte-Oriented Messages:
+// https://csrc.nist.gov/projects/cryptographic-algorithm-validation-program/secure-hashing
+#[test]
+fn test_monte() {
+    let checkpoint_hashes = [
+        hex!("cd94d7da13c030208b2d0d78fcfe9ea22fa8906df66aa9a1f42afa70"),
+        hex!("555846e884633639565d5e0c01dd93ba58edb01ee18e68ccca28f7b8"),
+        hex!("44d5f4a179b33231f24cc209ed2542ddb931391f2a2d604f80ed460b"),
+        hex!("18678e3c151f05f92a89fc5b2ec56bfc6fafa66d73ffc1937fcab4d0"),
+        hex!("b285f829b0499ff45f8454eda2d4e0997b3f438c2728f1a25cfbb05a"),
+        hex!("206d442c6605be0e675b0efc76243c2f18f2260a93375fb36e469631"),
+        hex!("1cd8ea34d8483b6a513c52a74e416bac2f322bbaeee02c6b0b05a781"),
+        hex!("00cee48001fe8442ef39c3433ed05473179f34205d337940d4bfd3cd"),
+        hex!("ead3ad27819401912bc9abfdb50037672a3aed0e94fbaa1cc0560621"),
+        hex!("8f4dd5aef9cea829d8802ffcced2e8ed6b48ac23bbfbb0fae2fad0fd"),
+        hex!("03aeb918feab459e39af29ff3aaf406088bf06d793338bbd563641a2"),
+        hex!("de80c312b153fbd7241c8bc432d1ed253d26dcc6f458b953ac2d9259"),
+        hex!("3eb8d347cc2565ddd71f7fc21cff7eb3a2cf8e85c5e1d4c751f69f26"),
+        hex!("dbfd7033a4f884ecf7053f07b4c51f3efb1c09084cc7bbe667196a3e"),
+        hex!("5323fc60310fe29900eb7a500f29897001c37945c5f8849674725553"),
+        hex!("c3d9416549bebebf679c0122a9c5bb86c0b514c6a4e9eda1e9782040"),
+        hex!("749fc9c8c21957ddcaf5eff69c297284d722c79be1fc6c910495a586"),
+        hex!("aa307d91c4037372ff0ca60eb17ec8f1faba862601b95754783ea808"),
+        hex!("071e361909c38791e941d995b0b25a3294bdf39456cc012806ada3c5"),
+        hex!("18751a765f3b06fc2c9a1888d4bb78b2d2226799a54dba72b5429f25"),
+        hex!("54b39c96f6377e3fc2ae0ba4ec89049a6c04808da3fa0415c9053ce4"),
+        hex!("58c1eda7eab2fc4046ae153ee95de5df036dbba25b9bb5c5428ea882"),
+        hex!("3a02eaf55d04b6052b7d79b96d1e316f90f5dbbb3217dbfaea55faef"),
+        hex!("c8b5eac17f450458c60c075a8f75a24a1dbc58247fcd0ccfaf03e446"),
+        hex!("53084cacfebbc4d1ff2db614b42714c18ddde36c6b7c2fbc3b1a8706"),
+        hex!("528b867aa481d42fc4931a47d24c3c905aaafa8f6dd5820c67d3579c"),
+        hex!("f0a3cd3f53eb72df80ab67d264a973b6bb2f58bde8f636d9100e8864"),
+        hex!("7912f20299d803ba917f408a5a59822d147bcd1008ad5c7b678e2390"),
+        hex!("6f0e49505c15669302133d66e45d192e0c6ad02fc5b9aa128aa5517a"),
+        hex!("d06aed0f18e6c377174fd00cc74a92020b3df218c376eac0501a094a"),
+        hex!("cb1bbf7cc5dad591d32534c570e5bca93b8952832779dd6e0ccdc91c"),
+        hex!("4775bc11834930118654a3e66e5b7f51871d6f5068f4305dc2845574"),
+        hex!("ce3b5703ed9f946ec4af62fade6e69c2751474ab8da570064ecd2ef6"),
+        hex!("49a9e1aa84700874ac27eee43f193df69ed6718b131c4854f729a32e"),
+        hex!("d2c6592251a27cae7d819ac7b476c8a2ff608e57b018f79e0cf19b87"),
+        hex!("a861be4fe188858b913aad179ba575cec91bed54c1ca27608daa27dc"),
+        hex!("bf7975e63aa7f1bef84e7446f1c4a00a75c13285fd7c4a7a8318b1cf"),
+        hex!("5d125b14e966c9e431bdc5592d3e6305fae678dc5d6dd064fa1345f9"),
+        hex!("8c0fbb471404371145dbb66e8b1c6fc428e6dcfa263e3f5ddb47b30d"),
+        hex!("7148b69b04457296fca18e6f7b7f2145d9af9e6bc8f48b8571af0e53"),
+        hex!("0bd47a04fc42fb3d9643496e5b0719c22262f741e862adfcef91d61c"),
+        hex!("3dbb14133351e5d4bc885d9594c07f7f0f99d4a7c745eff3e7aadc2c"),
+        hex!("94f6ba7f94ba9903f7e9bde9b131353fce560c7de7159458f1a39cfa"),
+        hex!("65fc03fabbf57904f572358c20f004aa16e0b5ae6530fa961ea10b9d"),
+        hex!("e46532e3e4bd0a0cb389abfba5d679e916a7c193324f1bac498ee1ee"),
+        hex!("131d096c684345311cff958706eded139676a74d97a1beb861998485"),
+        hex!("fe3e03637088ac8ee2e035bfc1e7f4e944304663c832c26089e85f9f"),
+        hex!("d570c2b7040fc721b41f2d213f6ee87ac1e37f2b86526cf46c699aa7"),
+        hex!("82ede72ad163b914be7c22c085cd99438b6d5557ddd3b752f0a9fb7b"),
+        hex!("343c21a0cbde3cccdbbd66eee32c50f5a54b0ac267ec3f41ec07a67f"),
+        hex!("94ad254f3b4a76f6140d0dd3775bd75eb3c081085fcb76c91b4cca92"),
+        hex!("65fa84f358bc32caaff799129bc2cad883636826415703a2dd1a3cbe"),
+        hex!("1c2f47c532856198d03dd85275357dce085c8f6c5a871aac4ff4ea28"),
+        hex!("1d51c1019131b41a076cc603f4a8e56b2f4ee70dba326af30d926827"),
+        hex!("9789daba3a8e9702d2d0b319878f88b08ebc5876dd5dff6414bf1922"),
+        hex!("1968789785f1ef61f849bcb29fbc1491c006021f729718e72f29b80d"),
+        hex!("62dca9550461f8a85e1abca4192a8a55a6e6663ebcda9ba6fb95f10c"),
+        hex!("ee190aa251c1a2ae0a376b4c6b6ab3bb09f743fa01eafaab68d170e3"),
+        hex!("02273be94aaaf4a1d22496821e8abda8c418d3a4c278947c27d6c912"),
+        hex!("3998a213e392978a38016545a59bd435180da66d2b3da373088f406a"),
+        hex!("7308f2145d345bdb01c38a9993a0ec81ed5164ed0c6caabfa3b23fea"),
+        hex!("3ccde61f4734978995b7489bad8c1e6bafe03f7c3886e9b4ef0f1aa0"),
+        hex!("cca9745f59f3ae2bbb8d65d31c171aa33960c8c6fa4689bb7e6d2152"),
+        hex!("c976de72db46c1a254293af6093c563ce43232077c249584c016ff6f"),
+        hex!("f1448af3cfe317aff1470f0a3de7bf533d77dc7f55e8dd790fd57727"),
+        hex!("d4be0ccfe4913851c9636ed036c625524e72891c5c0627aae50288ce"),
+        hex!("6bd99c53693d4e2467ba6094710a6d2f48cc2ae907c4ae28604586a1"),
+        hex!("2eb4fa0872ede2a378386e40002cb00b4d1c2fca3413b944ed210915"),
+        hex!("48b624151c9d3a1cc8e9d6665d42d4e640ac91abcd3556a31ff0250c"),
+        hex!("31159840b7cb040d819ebee1ed0e52d09f5805be523cccc22eeacba9"),
+        hex!("f9a67791dcae0aea00f77f8536ddba439e9fcf7e5b1ed827f83818a8"),
+        hex!("a5913105fba645ba0df942da96d271a1d5efb923a4f61eb463450ea9"),
+        hex!("6ece291f81eceaa9eb5a5e228c9924f165b8b10e2cf0e143dd5fe601"),
+        hex!("e07ab143f09eb8ad0d381b888adb22229c2e2a8b067e0fd012ef67a7"),
+        hex!("88a33980be5bc911c1713d5c2bd2e5ecca7fc87879501aefa9722c89"),
+        hex!("a709d188da8ee187d91bd17069f785ebb379df013d78844a45b2bfe5"),
+        hex!("306fa7bd696b3e9841f84d1c861712acba0febddd7a952499b96579e"),
+        hex!("61341dee2e2869112bba2e1077fb409375f755dcafc1457bf49e0e8e"),
+        hex!("0959a6e3b727c6213119b9e8411132b5819eb848bec6ebda0b75578a"),
+        hex!("11cef0312aaedb9d0b26de64656406c8f4c358e6d3db459d364481de"),
+        hex!("5de71b191eec70e591c22ebe3a5d2973aa3172f1c272e926cc0d4873"),
+        hex!("ab218bf4268aec9b41dd2db80622e4c0319cc0de12a60e06d80414ea"),
+        hex!("5c83874afe6da0443abfbbbf8ceef38f9400b63593ee7a29d467b4f5"),
+        hex!("ba0fd01f699a0d00a0dff4c63f6ad19e9530a7ad11fec504e6481816"),
+        hex!("835ec2c57424baaeed09a7c0c0b6e8bf9d1cec83de4c719846c990fb"),
+        hex!("64d95f2c92343d8fca6f6914fba8814478850b5d4c2eb227f4ae6fa1"),
+        hex!("f8dd5355827ac4bd040fc05ed6cb2914d013f126487a6d5f2c22f767"),
+        hex!("be5bbf68d6b99749edefa6b113638ca5cf5fddfd8fcd4d719aeb54b5"),
+        hex!("3434d03e98d0af69281e7a7ff8301369c5bc6166cd29b83397ad3fd7"),
+        hex!("747b2a7cfb8c4fef7de0a08499f8b19f37e9161b855a84bd50ef84c5"),
+        hex!("c1c1fa2bbb10c5672b040ed0c33d4d93e0fd210d1373fc7fd2312c0c"),
+        hex!("671f67380b7676ee7c9fbfe71f3807e3575745ec3ae3128420a141fd"),
+        hex!("e88394adf710b9764a448abc6d62928b0268c6b119306f3c93d7b6d2"),
+        hex!("711cc90bfdeed121bd5a8629a9cba6df7bf8df89184ec64ee918cc67"),
+        hex!("3f63432484eaa1f389d27947a84e256618f9bc81015993cac386887a"),
+        hex!("e00e0bf2a32227461230a065bbd2eeb5364277e83a850c53ef5c92e9"),
+        hex!("1977311cea23a681c83dc58a6863e362bf6c02e30f4c9c4c8439ab4e"),
+        hex!("2853a64f56c6282de53e30eba9418dd62eccb8c9a662c313b4768265"),
+        hex!("ca9d0a2eeb484b9809c3850f166362d893f951f5e93cc7a3c72522e0"),
+        hex!("27033d2d89329ba9d2a39c0292552a5f1f945c115d5abf2064e93754"),
+    ];
+
+    let mut seed: <SHA224 as HashFunction>::Output =
+        hex!("ed2b70d575d9d0b4196ae84a03eed940057ea89cdd729b95b7d4e6a5").into();
+
+    for &checkpoint_hash in checkpoint_hashes.iter() {
+        let mut h0 = seed;
+        let mut h1 = seed;
+        let mut h2 = seed;
+
+        for _ in 0..1000 {
+            let mut hasher = SHA224::new();
+            hasher.update(&h0);
+            hasher.update(&h1);
+            hasher.update(&h2);
+
+            h0 = h1;
+            h1 = h2;
+            h2 = hasher.digest();
+        }
+
+        assert_eq!(h2, checkpoint_hash);
+        seed = h2;
+    }
+}
+
+macro_rules! test_sha224 {
+    ($name: ident, $input: expr, $hash: expr) => {
+        #[test]
+        fn $name() {
+            // Every backend available on this CPU must produce the same output.
+            for &backend in SHA256Backend::ALL.iter().filter(|b| b.is_available()) {
+                let mut hasher = SHA224::with_backend(backend);
+
+                for chunk in $input.chunks(13) {
+                    hasher.update(chunk);
+                }
+
+                assert_eq!(
+                    hasher.digest(),
+                    $hash,
+                    "hash for {} with the {:?} backend does not match",
+                    stringify!($name),
+                    backend
+                );
+            }
+        }
+    };
+}
+
+// The following test is extracted from the SHA224ShortMsg.rsp file in the
+// SHA Test Vectors for Hashing Byte-Oriented Messages:
+// https://csrc.nist.gov/projects/cryptographic-algorithm-validation-program/secure-hashing
+test_sha224!(
+    test_0,
+    hex!(""),
+    hex!("d14a028c2a3a2bc9476102bb288234c415a2b01f828ea62ac5b3e42f")
+);
+
+test_sha224!(
+    test_1,
+    hex!("84"),
+    hex!("3cd36921df5d6963e73739cf4d20211e2d8877c19cff087ade9d0e3a")
+);
+
+// The following tests hash the messages of the SHA256LongMsg.rsp file in the
+// SHA Test Vectors for Hashing Byte-Oriented Messages, so that messages spanning several
+// chunks are covered.
+test_sha224!(
+    test_163,
+    hex!("451101250ec6f26652249d59dc974b7361d571a8101cdfd36aba3b5854d3ae086b5fdd4597721b66e3c0dc5d8c606d9657d0e323283a5217d1f53f2f284f57b85c8a61ac8924711f895c5ed90ef17745ed2d728abd22a5f7a13479a462d71b56c19a74a40b655c58edfe0a188ad2cf46cbf30524f65d423c837dd1ff2bf462ac4198007345bb44dbb7b1c861298cdf61982a833afc728fae1eda2f87aa2c9480858bec"),
+    hex!("7302c919d6d23376368431f0482f5e0dd7fbbaad78f1fd448daaf7c1")
+);
+
+test_sha224!(
+    test_757,
+    hex!("42172d5fdae447c4cfdd13836bb8b833c0cb85f7327f92983501a4d7583a5201830266c37c908640b0351461314b526cfb68cad97bd7ed615248fa5756c6213bd9eae98d2f4ecfdf6a452f2e68c9687210b53c74d83575e08a7ace9b49b21056cf377c64f80669c884742e93181c426d871ca2715081733e68ffe94a39e6677aea51e8f0e1a09d258629d7374a2b2884e903c577eba32fa2713f130d2e496eceb4a0f4daf105b31bf9cef4c306de62dfbcd46e2fb283f1352fa3138c31c56d7bb48d6aca301bf3d464ca4bde521d37a78bf66340ac09011e2991b36e4941aba8727e1067a7cba4784f85a53138d0f104dbd16d54e21ea686e772b95c7fa6717e77dcb05a5dfe102e4267c963bfdfd61d36cd53105aa82a95f2afeefddada07254a10104a5a9a7d1fc6d8811def322f1b2352df1e1e90d372d1ae1afa62c6b5c47380f9e0a788347362409307d1b243252bc8d72636bfea460cd905fa1f52c3847b9632c44bb17d519f07c8c86c455c64d49704cfa81cb6382c9776a61a67788ce9b9859d4efc9fe10495e809c9d4c000a9272ec27e8e8171b84f37a65aeb1d054550b814b950e44d1952bb71ee48b8202fe11ca7c0ff9119386b0ea1e7c8fa1618c594d0939792ba66a708a9e5878cecf02b9825745630573452c43fcae457e8e87fe17ae4b8f25274fa9958b67b848d736e68e4a47ba453356c21290a297ca240e667b9b59b4c3dcab43427670ae82b4013558d57553536c221ec07af7db06da562ed360d28e8a3f03ea2be021effede08027c896ce2d2864d9ef80c2ca3d71a15b3d98f4470dab6ffeabc48e9e12fcda1fa63c68cdd250a2fcf03d49f769d5bb391d8872e0057dce5e16e214726980b6579a92d53b6ed704f2b8e64fec7dc27c6456ae90db164295c5adbf9b824ca0fd8fca71e5fe47e412230f22d991c05f6a45b0b1552089224d9b36042bb603843631ff82a1ffa5a055f8bc99f1ce7cd50f42f23aca97a6447d477a58ccf6d555e9a4016d1026d23354d789f49e8bf74bf3c4e6f0f529b4d1ad334164872a0c3b9e5098d93a"),
+    hex!("822e9f4e201b10077dd456fb75657c75b5c1d991a39597c37cd5b6bc")
+);
+
+test_sha224!(
+    test_1648,
+    hex!("3cfbc77b8897b6a5613f62f6b1c89b0d68f272c6c19b9e0ec6331ef616702006e64322d3460a57d3a5074c719811cb5dd78900268890da0ac177b40d487735489da374843a1a6007198160ae77b1363cd8ac29f24bd66360ef62987ab60ae8ee690307b5ec309be8c496e5d6d610a453714336538fd501b758da1166e88f02a0524b218fe5b2ce1fae2c25103d96dd4aac376f70def57bb705c868f967704c0561630b3aac0ac254df2c668535aba8c8916e1c72bf9e9b09fd15e65aba138bc69d330dddc99e3f2e607ff15c45b7ce7527d18580bb38275548a7d0b269e28a8a0fa46ea0c5d80d55380b0ebef62218fa7648f2c3592be842ef687128fd4310fabd9c78ac271ff3726fba04d3cf544bff86bcaa6221dfa679f93f10e5fda0e4beb104712977daba2d0e731dc258b5322b6013f6869bbd29a26e13f410f160cf7df3c5a23f3e732a2d1b1d9fb419ae94270b371e57502b386457ce66d261eb99df89c5531402510b1ba1a2d3d09ba5389a8f0e6afcc7929d67bb57ae53d6a90a8e7deeccd34edc259ea5e9013f6503e72df582219e885b1e54a29614bad802e92fd72754a2a77c405f31da7128ff316988447a8d641cec84d31473e030ed5e006d9d5734a9979923054c5d6ab4f295865284ddf4770aaf968b1ad659dc9f2515edd968b512a59b9739ff5a360bc5990634bd959ffeda0a1e25bba7c8e775bff15a92411d025aea64a351b91b5400a4b0d5f889d6221567e24800ce7578f7945c5ad1cab4a33cee52ea4a6262b82c1d4dde3da1e10b422dac9def33a8b8ae0c1e959debc41dd51028b7f23e525ed06ea5f692c507e0e9c442cc93bfa9ce2190feb5fe8c9398adb6b0b15233356e74fe80c601dd91ca92946c7cf158bfe3d986c55ffc956a3b4a4ab081712a5112a035a6f591d1c7f0c605f45513e7341f5c583b3da0ee912e3632f1ce570cf070dd7bdf2c4a89f17b0c7fa10100554ea9346c28f7f180509af9d83b410dbf58b0b238f21335ee5792ed6a3a25c08856aae82c5435f731efa719a256829a2dd1fba8d4a85159e6415ef02e886c5c6a934901ff879ebbeab57c20fa0938063ca57946b7c98fbf5af6069d33537bdbe24faf064c88da4494ec4296471d0b5ad61a51144a1d746d33bfb37ce162fd45d7d0037f7d20929b15da2001f05ba59de27aac9af36ba96d57e48d16a17f98a233606ef5f9176ec657c73feb5f88abd7480bb1611b7dd5c7b0adbbf2d970f4fbdd4518ef283515cf40fdc6dbfb6fc810f01689f02b19a18125616698f3feba57bdc728f5724130bdbac3de5a2cd7a251c2e25bcf8908b5a59d4ab59155f192bfbb30c78f3f056699ac60afef5a87e1a1a6b950879f11b83a02aed1646911233abfc61c46f747606afd1d6022ed482e0984e1909fd4fe53493bd6e199952616d0b1d350a102d0c53897421a7b4de2319044a3429f8f962dd9300a0eb943f71289799d8a29c8683e0c61170483e31d913ece478e0f3aa0eff2c992804bda4dc06eff6df364b2d673ed43e347c115c1f61b0a159d783ce5409ceeb55ed7c2f0c8862c8ab3dc26e40be325f00abd4c950a30e0559762fd2dc9e087bcd0e562a8d1dae63ef1198d58aff6ef847600f1627284394ea453e53f31c57cef00fe243d837016cfd5a150fb062f89e8b3116af0d91ffb49ead55ce52154b59613a96f768852a58688fed595b995dc64e8782107e50a108e946fcae541941af9346fac1c858879ab32b886ee6f30ce3f3dbe7c6f3e4c5e65c8aa9708388d946089d741f725187c86fa55cacdd10948c3db6ae5ef8dedcf16ae0c2b16af4709123a6997cf0d0c945e095f08fc86273336ea04c3517af64ae8701feed74dcb635870ad166ce86bab7d875eaa41379375e191157e5bac6eb625336b4913bb20887a532c26965c3ea8299bd817658c53f80cf9247ed6cc5dfd132277a291a04e62c00a6d3014118c73ab6d57b7302c71a2ff4ab92a8981cc06fa62cb621aa55ed771bef89aafb15df44b2662f269c0f45d01ab5cec3e74c1b9241eeff2767257d1d79656ce2fe1ba1350ee8230daefe5105b44962bbb4d47fed87c6cb86dae413738bb0e325c4ba9b804ec3dd949624ba3563eee50c9b363bd5da4c81ec60124f1dd54a830fd9735cbad491e557fc12efd38c1edc1304e1fdefcbf8e8b4b1509e60e8aad2fdc6d6334942caa0c44b3970849f419e7b8c78249fdf54367c28ff7001385a497bf733ddb01be6b675fe08cabd300fd2a29c694e7a86d7119f41af726336c15cf6f54caa83b27e8cc9af118ce1c0d1ef34af25e9e44509f0ba95c"),
+    hex!("2b45bbf8a1c529def187065fdff8fafffd5bed4297f3dea18a31053e")
+);
+
+test_sha224!(
+    test_6400,
+    hex!("37ebe98ef52bfb240b9ad369153afe081bbcf9d7ae43e8ba336b8ac57e8a6da0a3365e3008072473bf9d6eac13e509c1619956e12a06fc696512da091a7d40232c675e737713fcf51aea6c0316c3bdbe196132b0943df2b013860105ce676fce7b88d0a167d7ec72c588b7b6465a83c9ea1d748d15713455e5d0e901c3cf646a38a09b0002dc5ab1687f350dca35c1a87cd404c0d529292082f77844203d86be0bb8a9d970a9af7baad8d050cbd9e024788eca91fbed39db930398180e393d949ad7e173d9c65498339a6ec670d049058653ad48af45cc4cbffd30c3b54cf1b290052b1864bcafd0accdf9b8e2a163134d2c982c1bba4a3dafec288e3cfd0ae1934a6f0e39122aebbd7a586e48d495167620708664d31c740bd868c1ccd5f0e94baf959e81502cb00da87330cbf149d5a8381e9eb519a8b97acad7a48c5b0c92623b861064ff1ce8455f32469381e6198c7b8abc341357d6a4c85f7fa517c4a47df728ac09a6645b0ca77df7c70cd4aacaf19c280949919132dde7993e9181e647e964ba99cd6bd10b893c8d90187a5009a23d295d43bfb4cc0e583b8052ac21651b23813bfc9912ea0c574e152f42d3f1975309588a4705196598ad93e1ab1d82954b4a18bc56e55039b6837fd893fa2bd7c70e21a5934dc2e990379ec6e8a2445dc55d57940a14e5164273f59cd58e5f6a8281e11c09536ea22821c98ac978537d7a02220d1d6552aee168a001715834596baabf7813e1c69949b23eb4b86658fd51819eadf8a13f067ca8a791cd1d53ab69d0e43f18bd72d5d93322cc1c36fbe33121f5ff01905328fc7c33d452a86468663c77fc80b0195ec1eca05a5daee339042b4f88a1f9371b472c6c5168c00e984937a134b282633dea25dde7e397b907b1e7d3d240a593e747007990782cf944fa078a7118fbfa793b2604fa15b82453209daa64475d0e95e2408319e8b5ce7460f4593a19e3831a9b363b1c5ddbcd273995fbc61ce7502b0233b1752223352e654837181d01a929f49faad422c65b8ae416ef81290b02b48e222c2b8c3ed57cf0494b928c1e11ad2da77baacd427785096aae1cd593cc356e551bc390cd5765ea41be30cf0266ae2e97d326c417c91e90d75f1f874555b88a14a7c5959a62f23976b77a4c754e35dfb7ddd1700df85f61a62b12a9eb4644caa7f8ba036b9f29c6315ff96c3f7148284ebe3239ecad50641f397ea24b46e21655352a4109b61479b9dd34972779f2f1a6a1d2887b8ff88289b2ebda2efe995668879bb93c4ebb3a585ab336f70b382205ac37c383475fa12ebddfb95b157172261597d2cb0f24f254feffaf75d224a3b407eb54cc7c8daa5483e4a79c347252d808a5f480a35987f6f09f6c6a73bd5cfbdb76a11ed78b86442b810cb703a5dec5874e8721af62e386591bd39d990b3521505e144100601b46de3f50752911ff37bb18f377de45ec4c60fc4ed8ea1717708d2d13fc9e1453a1c4a4db9e4fbe9b74cb8da14ad50c8c8f2ec944e10ee8e82ebb6a081959b0159f043a15fa1cb59bc5e035f7623fbfaa99ea0a1d81ae8692a4019e5a5edb3a4886c789675039fde87222975e86c2642eb0bd48408072fafb1a88507194c9bdd69f3418376a4d9e68c3b83b3f800605ff1dcf0917a6014b0dd77708b583ce3ea632746fee0e01a10500cba90016b4a9072847d809bb0481ae25f74f8ef290c7a087ae16f505fd0da670826a0b1174592d184e3a7e8622a5c84a30ab64aab75face50b96b217e8ea335c0605c638ed1c59370bb9ded004be428f49a79f74ec0fb296b3758f0b6b41930c7e029b55c8fa73cba7dc926151d4043c6bc8a716d7de9ae0cd3ef3ab2d19b0c813eaf12eacfb641d492b0001b2f0f699bd98e4581fd44c0c817646bdd77a71d8ed432f8d422812751a2f9178cf1800ee689ebf046cf9b161f9a7ef0a106cbe833398bf383288661b426fad8d4f570a8293629ee06856af295a58585a81f87f130e6e08f723234856e874bd0adbb2fc9e676deab6b9f22faacf12e875d1259ccea54f7294be02a16f34c427b51a33be8a0c460c4c07d51a2e7d5c0722a9fcfefd21c265d5aa2c57ae4fe95556b5e1388ea9756a6afb0856fb8fbe1d2bb1838be7a95049848fa9545b616badb753c453f266836eda3c92cd592bc0925690c42cd6667f866717827ebe91d0999f9de5f5fd6cf77f63737b65927aebcf6cefc7ca107fda8447e8bebf1f08a280d53a4b07f8e35904cc48cc08eda3c63a3475924bde1de6acebaa65fec5ee68ca22d3fe722bf33267de628c9db1ceda3c78cb2f9988682d641d068023f96aabde4e10071cdec2080f616ac30c2725ad3efe98a69a56873615a3a3161503a4f22621986def597b66641d07793d97cdc9a68f85fd3890a38928462b2fbe2bc5c509631438d2e344d1ced9e2b71748f1b6ddf33a3e597de3af03ce43d305b9f5acefdb2b71acc645d3b55fa3848484b7fa4cf25e71e766702f1003950bd2f45b304052861f6748a8f38175f1e96c91471f5a54999cc9937191b6adc9de0d2520d86590cd4aeab292ba9ae474edb5b8caad6ee095c9e74c0f5e5c9387559f946b2dc45da7fa1d4c2dae6973d5984841682af25ff7ff29d9721d6c7e76776e8965b6c681bc38e85da15954ecbcf20d7448204d9a6a477781c1564d363e4c634c36fbd3c3b50b332f1643c415d004ec999316e75694a8b98e2591678388dc6624058454ec3a7ce608b3f222b8bad5cef77095285e1d2ad746c557222dfc30605bfadaafc4f292e931a0f0d49b226d99d708247879aed5b9f2ca2fe6fb414f37373f844e13865524f206c54487aed53781834b3f6eefb248d95ba21bb60041d501f90a97a19dcd80920df7d84309148e3d0892e50687c86a45a1372926e00f200053f5f436e003e35bdc10fa99d9328853bf82d2091f1f087cc37678138ac0027e73cbcc99f7fe37939c98114fc7380c0ad1a26e3f5ec00bc7eae77045a55c62c18117879389c662837415852e7a2d01ac667a226fedb2596e3e137a83daec2712a65e8cec3e644e738d11bdfe9b19517fa593546373fddcb9e681fc97d1763bb9092a456cc0dfe1aa0e132387d105e3ccb7746ee199aa7af00bb96047310585fed40219dab43f057220a41e90c5f89fdac4a5d6b207c01d5ad4440c5ca29eed292c6f7000c58da111eb4b16e31efa6df3f3aff69e6447ac406aa96a9ece4b5b813bf8b3a499d09cd0969073468513355d6c19346c58480feaf470e0d45a13b74f2925488fd810e0f74afb9e82a24cdf61586bfae68dc92ea09b22d8c8f1ffe9db1e7e98892b5554ce2e15fd5f1cac5347df2eafd2a8d5f1aa8746b9403915da6d418c0b5a3aa8e09d6b65f9a49c3b7a5728e9baf95471404fdf64eb05da5f704dbad60ac9ac106cab2873fb1bc9023ad95c24852337a703d9cc04d6df7de594c3b2e4fb9f2996e0418ec8698a4c087c14a2687717f97e228e75afe295caae2f16513f47a45b4124a7c5ebacbacc562951233bf89f43ff85b703ec77f168c2278fbe6e57a0e7192125f4642d73f2f227d806287081bd30149b9d44fdb90029667622f9925b7826bd0343bc537c66e660f174b447860e1bb8846c3edcb639ebd213a4695f9cb471e188db7a859fcf3abae49569e676dec857b897627cb0bc1155ad6d45282d430176fde4262da2d5f41ff890ceb319d73dda804738456f30a3d68da41554d4cede62aa8549b24e211e76768e6b17379f842a24a449a0ba3ea73cfc72624b5afd118fd7e76a7c6b5bbfa7a6b6c97b97dea52decd51cf35a8e277140ffb2748777a1e3cc3211f3c12be099d0316f45023da6cd200339a718c72a5ca172903922e59648d08dc67f173788363c26e5df406391f107552925ba91b9e569f38101f5eef9a52d201288372abf6532beb4af19fa6d81eaf473d40896dbf4deac0f35c63bd1e129147c76e7aa8d0ef921631f55a7436411079f1bcc7b98714ac2c13b5e7326e60d918db1f05ffb19da767a95bb141a84c4b73664ccebf844f3601f7c853f009b21becba11af3106f1de5827b14e9fac84b2cbf16d18c045622acb260024768e8acc4c0ae2c0bd5f60a98023828cdec18ed8dc298a306c38d1ece01509f3265b5f8cbf441f0525097e8b48234bf69f65cf402c7540a023ed231ef95b222a900ea4bfaeec02c6d8b3b01648ad7a165237ca6b557b1ce287b0ea137f4ef54534070ee793695a9078ec89bcea389956878614ccbf917b61f8427b7cda870fdd92d2d297154262fc65f28ff1a54b2651afff12d6f36ee8c906107bbda399ce5e2cf0a430ad0dd86520841757126bad725bf1593c7959f16221894f5852ddad3172fef866b3321755491fd44fba009b42ec0b6c4fb9e901d7eb3b8acf70e94911f54c538bd0559c5740042b6df4a07c3e00bba0934d92a684b39592a576331e5a44672a227ccef3e595ffa1146ac1dcee0a70baa9acfd5c132b361b5ceb519984b0ee00cd2124aa8acb50c9e574fb19bd99c8fef5407faeedb28b796848bb372beb3f5bde55ed2cb140b60a53bba2df471f330208b09ffb8eda04315a06d693aa53d9bff8939ef6f3a68de6e1975f79f50b3d484665e4ee71124ed794be3a2baa7b5b918e62a095bc5d46e401a0979641fe465640e8d4d43eeba9d0cac76c7b86d22375123b988585e58f86566fd190d868eca08aa1e66932d6d3b14ecad3efd9f8cfcf2696ed42eadfa642324d941602cbaebb8639a00a17542afda32117051e4fbf243dfd255a559c49ac37c265827ba70b0bc618882336f43e1a6a729c57be478008cae6c74840bbe828c976ac628d7b6015bcb705612c277bac0727da645480a0e14fdc497956aef05c89d30f22c2c96c6dfc9dae30617e6206fbd957975b8ba0524f563289e1f5f09bdb6fd46fa6117e78e854f91d71699fcfcadfaa7d4db8fcb04bed08d68d11677b5085b295c1d414cb12456c84c669737af6c33992a5a9149fc7f9330bb291d38f6bed10318081dde8fd178f02eb0e8b7d022c8b63fdcc867546035775fcf7b32c8fee83df7cbb28372b23c71459b9566a7f64165da0a3d0e538a3dcc1b6a384f75f0263dc10e0924a0ef2ab459d0a52b7c112710c58cf72442253396b8a25d7644be166c3e7828aa62b1ca1f32f620ed969b021ec609fe926958a03cff21f08f7c8d3d3235b219fb0020a51b97b60f963ebb58f7a62a5b41104c0b28b58cfc81668825f87064e401c263421152b8790dbc99b3032c9615187f29fcc1a58e86364ad45524b5358fa2f0a3296729a3663a585e9aa922f534fefd16fb6f96cd9895709c5520cdcd24c8d107e387e520de055a3296544ef1c1ddd43b919a4ff139861f06ae5280d5aa5aaeb8f7d74ed6ea56093c2e697a30c29c4ac145aa99a372f1a03ae72495f52a40cfddedc12b6e9115aea5ea516c5a4223a8d0a0073c8b4abe3c6188fdd6d4ab627c9f4eab468fdc2a91945274ed18465a368f291a0050c9d638a31944091b35a8fd26a1ff65e2d17dfa32ef3ac412d8293b276849ad9af71fdf272363f771d0fa99996e24510e7bf731a7480cbbefff7801c0e5fd0a13dd8278162ec1687f85409a203e82d2bcdf7e7d1ae5509857c42fce80299fe06182e74a97c0c624ed5b6246e59781af9407fb28b34f7024f42d36eb92bb95f72cee379ed363daf2625b48e60d0489b23dfa57789c0dd2276b4575a01c2349171d2a58bcf29e659b868cdac1c30a02a160c078b6faa7e0696711d43447ea2108db3d34ec1bf9cfe802f601212d335445a4624829f8a600b18e9b3cf13a9787910f2fb27676fd809e7ea1a34c7306e766b2e7ae1bbb919cc888ea931d1eb2e27c6109b9a12c31e188a196a98bbe0b24cc315791d26ef01b77fe06c3011ac39a8f78d233b7651e586d14dcfc2636cb713ecabadb97374ce58498f8b2e557531793fd9207fe484a4e147f7b826502cd3785251973b23e2b62b7fdc74a10fce9c04f97511dbffe3f2c46887c25904b99df69e97b416bac18fadad67b71cc320eff8def185d41ae8558cbdae6ccee38b8cfb2bfe92d0aa99815b3ca1d115f21493b13adeeafce81a23c6b1bc15fc8f2b171284e6a1fd65c351b0c82b31112f022ddaa78dcfbac9f203eeef415c566a00c2c933f06ff18ee7674aba548592dc8214b1af8e929242f87c81b0cebe8106b5267ba39c5b51987e38858dce1d1f8d0cfee2bd61d217e5a5d41bb0c4aaf0e7b0a8c66e5b0291e4d05bfddcf8861bb31b32ea5ba80cb02472c11969b3b02a7f7bc025feace44726b6382012544f1bd1256744f4b1b0ff81f7b9f7462c5c92507f1316df228ec5c0786378b871e69479c3e26f232f5d6a709d3551d08f0ecced52f8158a2c40a234af448449c1cb1a1f6f5ae56171606582ebb9a5836c454eb86015ae7a4ac87105b371bf40d49b1134a037243a0878953b5bbd6ef944ae7c345ec24e4a0e8496b62d71a6381aa52e5bdeedc81784f45e0c75b72a8c9898ea0387a47153d7e3a7c895aab58a1497a5e794052d7457624478c24d44c7e8932c887322b422478418af64a389c152d12c7a6803e0fb0050dcf2b9d65a35a53b9845b9c3835fddd45dfd12e28f8845e03686b3707ef6003e7c1cd4f8d7406ee0d1cdc41d7b56fb630c1438fe33196e53389f1ec1540fe789c6599c0b589296214d831a86e89220ae97974f4d112f4c98c726027d0c9316d1303b87a43a86cb8b800835a677abfe1584e8be55a624612f56bdf71a054a2e834e35105a19a77f7dfdbf9dd2850ee44658ab0eae6e833c855bb9650eda7f8f4e74d8de73526f12773b2bcbb1bd35639f8730d8cdd6d64f496abae4e1f8cdc96148894aa691683515bcdf37ba6caa0cbf953c752a7b9819e9f834ff39ec8f6d8a3dd8dd5a431d47c7f74c7a633ff73ff507009c5ac9431cc588ba0c6d226edc17c94a0f14d3e8db0c7ef60c3293878dfe513f96b54c61c88a90aca4f246d6a5988f5f785ce0655f51b85e55af03e5772a083bfcf0816ebd97a4af416fa6414a9ad47b7198e51d55463807ef4f0d9b7c06a0a84762e4e46c8b39147a4bdd594b8d4d40b36f5e6b4d48726551890d040d229ee70ea3034d45b3c28eb80d686918fe6e219636b8f9b7e6fc08f4e3bed9bafc778aab274913e9cfd570732ab3fb434c9ba0928581232580495571e56f6705f2af05b56642c2b93df65c443a6caa5b167a4040d2438206d2cefd3114ab466eb3c9eaa5e66cf4447c89c493a2eee0b0ea6e7329b37c90ec2d0142bae7fef265ae3c9c053e44031c0a142bf9faa728e5170cdba59fa8da361d94d887d5d6f58b409bbc4bd4548990653a04dfb841fd784ac9cc4cfd34c88512de212074dfba30295badf22f1af2522c5fe1cd423bd8eae429d7a862bcd649ab61bf0d3b55daf4b6f0f390c503d7c1bdea453b5ef145bd8191802056bd9e0455a404b6afe5b25977f02f902caba46f988d91b2350ebe4091b5584d4f938a45803984a5291beadeeadda488dc7ed2dc4aae69ca8ae0bd4492f9b297c3fb257de986c1615d44dee59e1e14d34af9fd7852b13fdcb713dd1a03d341884a30ea1dc0104d63a31d291df035d317fea98ec44f5a86715014783172e667a748f162c5c26a8b34a0f133d89fb971bf6e0a01507efed010cc7f194b5e87a77d56a909d65efa0d5ccd6da9b5eb1d73422f97ffad8012af43a2905a98354b8362e9c459f0044336348eded53660d65a38a9efc42be13a6672790496d875a67e0078dfdd8340dab8547be140ca9f88891b635e195c20daa8359658785cbe3d09ce8a580f009324e6550b0196e305889262f28f49dead77e6f5a0e859c57d53c935a4c9590879b6528eb2bc3230217b0897cddfeff405a6a54b2f50c58311af1ede4ea0660b73037f9a097d9d0271b45e325bec666cc7cb65ae780e361639838d10fe79907a0da0efef85d2420a84e905bb33116789526a9a88319d460f539586762ab172e4a7f305f7ae36cb88c96d91aada0b4dda3418c670e27a5fdede39bd8659e477cbe08e645af927843dbdd67489b72693efeb3a7be0e121fdf5580474ca028f39a035e78d81dd212679d0a830c050ffd43af6642d60d410aaf34f7a5ea9cb2e12f21672e3f4e0c00ccdb05758e74df3893bd40a5d7921e2e149330fddbe0a2dae4210d50a3caa60b1b9db685f7704ae2d7302b18e8261052b779139747f462a6610a37252b170afbfce905fb6f7fb8c2b6100ee231507f403fee88ba5561580d4de4cdf600bf9e9816c9da1e1d2b91a1d966d04cdb98d3be55fb77af2daeeed750b8b60b494accaa12441d372afb3d47e7395b9e0e867595a1a6c8bff8638bcb138ddcac2f3efbf89762b68ebd77247c89929620f1a3cb8dcaf9632fde0996b33e6b2621da25924b4e2c8d6bff28ae0867786919ad763e6d79fc304a06277955795a7cb17186fb6bdfa98a16189544b228f3bcd3698737ff55b6185799459b796a63c6a61cea9d20f1e296d62f474c43750b77944e5f1c09072f019dbeeb64e9bc8dec4605d8e0322cdd97f56cc43084f5c983a584855654366fd5659ea23c6c15e1d7da51d82c683aa477b9f896563a5134c64e32814ea88b7f7af760f18bc91e656da92b72e98bc03f1c6bfb442830305529d681dc6bccae66da9b2e61b9c97e2397fdb92f7f6369b470529c570c2d3b329487981d148a462cdb992d792e34dd233e1c239657b8da0d59b804566cf81ad5f0a7a0ccb3a8fbda673887c153d2e56c484f9230d752be52c1e35bc9af5a7446237fc072afef777665c264c18e6a3c059fde2e8368f9bb898f1cc8393d1bf18b1757219670275f0bbc7deb0248c68af929111e19737479bcabab732d7e033aaeb277eac05e185e9e56b2450beaac784dd0308b7a5e8ca1f2fcd8852ddad9f7b7de264478e1891a391aa89964dae5ad0b7a829c2c9209db346ceb26c1b967cfac82ad574761443be3f0a910968239d23b11507ab978b3ce89e22b7d7283736b9786544ab4460f"),
+    hex!("43a20b10be7652f7d0668e3e81633d08792ac867a8924bd172dd6b0a")
+);
@@ -0,0 +1,282 @@
+use hex_literal::hex;
+
+use peko_crypto::hash::HashFunction;
+use peko_crypto::hash::{SHA3_224, SHA3_256, SHA3_384, SHA3_512};
+
+macro_rules! test_sha3 {
+    ($name: ident, $hasher: ident, $input: expr, $hash: expr) => {
+        #[test]
+        fn $name() {
+            let mut hasher = $hasher::new();
+
+            for chunk in $input.chunks(13) {
+                hasher.update(chunk);
+            }
+
+            assert_eq!(
+                hasher.digest(),
+                $hash,
+                "hash for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+// The following tests are extracted from the SHA3_224ShortMsg.rsp file in the
+// SHA-3 Test Vectors for Hashing Byte-Oriented Messages:
+// https://csrc.nist.gov/projects/cryptographic-algorithm-validation-program/secure-hashing
+test_sha3!(
+    test_sha3_224_0,
+    SHA3_224,
+    hex!(""),
+    hex!("6b4e03423667dbb73b6e15454f0eb1abd4597f9a1b078e3f5b5a6bc7")
+);
+
+test_sha3!(
+    test_sha3_224_1,
+    SHA3_224,
+    hex!("01"),
+    hex!("488286d9d32716e5881ea1ee51f36d3660d70f0db03b3f612ce9eda4")
+);
+
+test_sha3!(
+    test_sha3_224_2,
+    SHA3_224,
+    hex!("69cb"),
+    hex!("94bd25c4cf6ca889126df37ddd9c36e6a9b28a4fe15cc3da6debcdd7")
+);
+
+// Messages around and beyond the rate of SHA3-224, so that the permutation is applied while
+// absorbing as well as during padding.
+test_sha3!(
+    test_sha3_224_143,
+    SHA3_224,
+    hex!("ea40e83cb18b3a242c1ecc6ccd0b7853a439dab2c569cfc6dc38a19f5c90acbf76aef9ea3742ff3b54ef7d36eb7ce4ff1c9ab3bc119cff6be93c03e208783335c0ab8137be5b10cdc66ff3f89a1bddc6a1eed74f504cbe7290690bb295a872b9e3fe2cee9e6c67c41db8efd7d863cf10f840fe618e7936da3dca5ca6df933f24f6954ba0801a1294cd8d7e66dfafec"),
+    hex!("ab0fd308590574d6f6130232d9fafa9ffcfea78579a6a8f67c590420")
+);
+
+test_sha3!(
+    test_sha3_224_144,
+    SHA3_224,
+    hex!("157d5b7e4507f66d9a267476d33831e7bb768d4d04cc3438da12f9010263ea5fcafbde2579db2f6b58f911d593d5f79fb05fe3596e3fa80ff2f761d1b0e57080055c118c53e53cdb63055261d7c9b2b39bd90acc32520cbbdbda2c4fd8856dbcee173132a2679198daf83007a9b5c51511ae49766c792a29520388444ebefe28256fb33d4260439cba73a9479ee00c63"),
+    hex!("d5134200dc98f4ca480cd24d24497737252b55977ae5a869ba27089d")
+);
+
+test_sha3!(
+    test_sha3_224_145,
+    SHA3_224,
+    hex!("836b34b515476f613fe447a4e0c3f3b8f20910ac89a3977055c960d2d5d2b72bd8acc715a9035321b86703a411dde0466d58a59769672aa60ad587b8481de4bba552a1645779789501ec53d540b904821f32b0bd1855b04e4848f9f8cfe9ebd8911be95781a759d7ad9724a7102dbe576776b7c632bc39b9b5e19057e226552a5994c1dbb3b5c7871a11f5537011044c53"),
+    hex!("494cbc9b649e48ec5ad7364aeb9c8edf4a4f400789ef203f7b818a44")
+);
+
+test_sha3!(
+    test_sha3_224_255,
+    SHA3_224,
+    hex!("3a3a819c48efde2ad914fbf00e18ab6bc4f14513ab27d0c178a188b61431e7f5623cb66b23346775d386b50e982c493adbbfc54b9a3cd383382336a1a0b2150a15358f336d03ae18f666c7573d55c4fd181c29e6ccfde63ea35f0adf5885cfc0a3d84a2b2e4dd24496db789e663170cef74798aa1bbcd4574ea0bba40489d764b2f83aadc66b148b4a0cd95246c127d5871c4f11418690a5ddf01246a0c80a43c70088b6183639dcfda4125bd113a8f49ee23ed306faac576c3fb0c1e256671d817fc2534a52f5b439f72e424de376f4c565cca82307dd9ef76da5b7c4eb7e085172e328807c02d011ffbf33785378d79dc266f6a5be6bb0e4a92eceebaeb1"),
+    hex!("94689ea9f347dda8dd798a858605868743c6bd03a6a65c6085d52bed")
+);
+
+// The following tests are extracted from the SHA3_256ShortMsg.rsp file in the
+// SHA-3 Test Vectors for Hashing Byte-Oriented Messages:
+// https://csrc.nist.gov/projects/cryptographic-algorithm-validation-program/secure-hashing
+test_sha3!(
+    test_sha3_256_0,
+    SHA3_256,
+    hex!(""),
+    hex!("a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a")
+);
+
+test_sha3!(
+    test_sha3_256_1,
+    SHA3_256,
+    hex!("e9"),
+    hex!("f0d04dd1e6cfc29a4460d521796852f25d9ef8d28b44ee91ff5b759d72c1e6d6")
+);
+
+test_sha3!(
+    test_sha3_256_2,
+    SHA3_256,
+    hex!("d477"),
+    hex!("94279e8f5ccdf6e17f292b59698ab4e614dfe696a46c46da78305fc6a3146ab7")
+);
+
+test_sha3!(
+    test_sha3_256_3,
+    SHA3_256,
+    hex!("b053fa"),
+    hex!("9d0ff086cd0ec06a682c51c094dc73abdc492004292344bd41b82a60498ccfdb")
+);
+
+test_sha3!(
+    test_sha3_256_4,
+    SHA3_256,
+    hex!("e7372105"),
+    hex!("3a42b68ab079f28c4ca3c752296f279006c4fe78b1eb79d989777f051e4046ae")
+);
+
+test_sha3!(
+    test_sha3_256_5,
+    SHA3_256,
+    hex!("0296f2c40a"),
+    hex!("53a018937221081d09ed0497377e32a1fa724025dfdc1871fa503d545df4b40d")
+);
+
+test_sha3!(
+    test_sha3_256_6,
+    SHA3_256,
+    hex!("e6fd42037f80"),
+    hex!("2294f8d3834f24aa9037c431f8c233a66a57b23fa3de10530bbb6911f6e1850f")
+);
+
+test_sha3!(
+    test_sha3_256_7,
+    SHA3_256,
+    hex!("37b442385e0538"),
+    hex!("cfa55031e716bbd7a83f2157513099e229a88891bb899d9ccd317191819998f8")
+);
+
+test_sha3!(
+    test_sha3_256_8,
+    SHA3_256,
+    hex!("8bca931c8a132d2f"),
+    hex!("dbb8be5dec1d715bd117b24566dc3f24f2cc0c799795d0638d9537481ef1e03e")
+);
+
+// Messages around and beyond the rate of SHA3-256, so that the permutation is applied while
+// absorbing as well as during padding.
+test_sha3!(
+    test_sha3_256_135,
+    SHA3_256,
+    hex!("b771d5cef5d1a41a93d15643d7181d2a2ef0a8e84d91812f20ed21f147bef732bf3a60ef4067c3734b85bc8cd471780f10dc9e8291b58339a677b960218f71e793f2797aea349406512829065d37bb55ea796fa4f56fd8896b49b2cd19b43215ad967c712b24e5032d065232e02c127409d2ed4146b9d75d763d52db98d949d3b0fed6a8052fbb"),
+    hex!("a19eee92bb2097b64e823d597798aa18be9b7c736b8059abfd6779ac35ac81b5")
+);
+
+test_sha3!(
+    test_sha3_256_136,
+    SHA3_256,
+    hex!("b32d95b0b9aad2a8816de6d06d1f86008505bd8c14124f6e9a163b5a2ade55f835d0ec3880ef50700d3b25e42cc0af050ccd1be5e555b23087e04d7bf9813622780c7313a1954f8740b6ee2d3f71f768dd417f520482bd3a08d4f222b4ee9dbd015447b33507dd50f3ab4247c5de9a8abd62a8decea01e3b87c8b927f5b08beb37674c6f8e380c04"),
+    hex!("df673f4105379ff6b755eeab20ceb0dc77b5286364fe16c59cc8a907aff07732")
+);
+
+test_sha3!(
+    test_sha3_256_137,
+    SHA3_256,
+    hex!("04410e31082a47584b406f051398a6abe74e4da59bb6f85e6b49e8a1f7f2ca00dfba5462c2cd2bfde8b64fb21d70c083f11318b56a52d03b81cac5eec29eb31bd0078b6156786da3d6d8c33098c5c47bb67ac64db14165af65b44544d806dde5f487d5373c7f9792c299e9686b7e5821e7c8e2458315b996b5677d926dac57b3f22da873c601016a0d"),
+    hex!("d52432cf3b6b4b949aa848e058dcd62d735e0177279222e7ac0af8504762faa0")
+);
+
+test_sha3!(
+    test_sha3_256_255,
+    SHA3_256,
+    hex!("3a3a819c48efde2ad914fbf00e18ab6bc4f14513ab27d0c178a188b61431e7f5623cb66b23346775d386b50e982c493adbbfc54b9a3cd383382336a1a0b2150a15358f336d03ae18f666c7573d55c4fd181c29e6ccfde63ea35f0adf5885cfc0a3d84a2b2e4dd24496db789e663170cef74798aa1bbcd4574ea0bba40489d764b2f83aadc66b148b4a0cd95246c127d5871c4f11418690a5ddf01246a0c80a43c70088b6183639dcfda4125bd113a8f49ee23ed306faac576c3fb0c1e256671d817fc2534a52f5b439f72e424de376f4c565cca82307dd9ef76da5b7c4eb7e085172e328807c02d011ffbf33785378d79dc266f6a5be6bb0e4a92eceebaeb1"),
+    hex!("c11f3522a8fb7b3532d80b6d40023a92b489addad93bf5d64b23f35e9663521c")
+);
+
+// The following tests are extracted from the SHA3_384ShortMsg.rsp file in the
+// SHA-3 Test Vectors for Hashing Byte-Oriented Messages:
+// https://csrc.nist.gov/projects/cryptographic-algorithm-validation-program/secure-hashing
+test_sha3!(
+    test_sha3_384_0,
+    SHA3_384,
+    hex!(""),
+    hex!("0c63a75b845e4f7d01107d852e4c2485c51a50aaaa94fc61995e71bbee983a2ac3713831264adb47fb6bd1e058d5f004")
+);
+
+test_sha3!(
+    test_sha3_384_1,
+    SHA3_384,
+    hex!("80"),
+    hex!("7541384852e10ff10d5fb6a7213a4a6c15ccc86d8bc1068ac04f69277142944f4ee50d91fdc56553db06b2f5039c8ab7")
+);
+
+test_sha3!(
+    test_sha3_384_2,
+    SHA3_384,
+    hex!("fb52"),
+    hex!("d73a9d0e7f1802352ea54f3e062d3910577bf87edda48101de92a3de957e698b836085f5f10cab1de19fd0c906e48385")
+);
+
+// Messages around and beyond the rate of SHA3-384, so that the permutation is applied while
+// absorbing as well as during padding.
+test_sha3!(
+    test_sha3_384_103,
+    SHA3_384,
+    hex!("f13c972c52cb3cc4a4df28c97f2df11ce089b815466be88863243eb318c2adb1a417cb1041308598541720197b9b1cb5ba2318bd5574d1df2174af14884149ba9b2f446d609df240ce335599957b8ec80876d9a085ae084907bc5961b20bf5f6ca58d5dab38adb"),
+    hex!("0a834e111b4e840e787c19748465a47d88b3f0f3daaf15db25536bdc6078fa9c05e6c953830274223968847da8bfd20d")
+);
+
+test_sha3!(
+    test_sha3_384_104,
+    SHA3_384,
+    hex!("e35780eb9799ad4c77535d4ddb683cf33ef367715327cf4c4a58ed9cbdcdd486f669f80189d549a9364fa82a51a52654ec721bb3aab95dceb4a86a6afa93826db923517e928f33e3fba850d45660ef83b9876accafa2a9987a254b137c6e140a21691e1069413848"),
+    hex!("d1c0fa85c8d183beff99ad9d752b263e286b477f79f0710b010317017397813344b99daf3bb7b1bc5e8d722bac85943a")
+);
+
+test_sha3!(
+    test_sha3_384_105,
+    SHA3_384,
+    hex!("64ec021c9585e01ffe6d31bb50d44c79b6993d72678163db474947a053674619d158016adb243f5c8d50aa92f50ab36e579ff2dabb780a2b529370daa299207cfbcdd3a9a25006d19c4f1fe33e4b1eaec315d8c6ee1e730623fd1941875b924eb57d6d0c2edc4e78d6"),
+    hex!("6aedcf4426b2483c0d0d04695bcc052bedd04fa4d17a1bbb2797f6272fa476bfc138e4091409feb1ac0e8bff350a6663")
+);
+
+test_sha3!(
+    test_sha3_384_255,
+    SHA3_384,
+    hex!("3a3a819c48efde2ad914fbf00e18ab6bc4f14513ab27d0c178a188b61431e7f5623cb66b23346775d386b50e982c493adbbfc54b9a3cd383382336a1a0b2150a15358f336d03ae18f666c7573d55c4fd181c29e6ccfde63ea35f0adf5885cfc0a3d84a2b2e4dd24496db789e663170cef74798aa1bbcd4574ea0bba40489d764b2f83aadc66b148b4a0cd95246c127d5871c4f11418690a5ddf01246a0c80a43c70088b6183639dcfda4125bd113a8f49ee23ed306faac576c3fb0c1e256671d817fc2534a52f5b439f72e424de376f4c565cca82307dd9ef76da5b7c4eb7e085172e328807c02d011ffbf33785378d79dc266f6a5be6bb0e4a92eceebaeb1"),
+    hex!("128dc611762be9b135b3739484cfaadca7481d68514f3dfd6f5d78bb1863ae68130835cdc7061a7ed964b32f1db75ee1")
+);
+
+// The following tests are extracted from the SHA3_512ShortMsg.rsp file in the
+// SHA-3 Test Vectors for Hashing Byte-Oriented Messages:
+// https://csrc.nist.gov/projects/cryptographic-algorithm-validation-program/secure-hashing
+test_sha3!(
+    test_sha3_512_0,
+    SHA3_512,
+    hex!(""),
+    hex!("a69f73cca23a9ac5c8b567dc185a756e97c982164fe25859e0d1dcc1475c80a615b2123af1f5f94c11e3e9402c3ac558f500199d95b6d3e301758586281dcd26")
+);
+
+test_sha3!(
+    test_sha3_512_1,
+    SHA3_512,
+    hex!("e5"),
+    hex!("150240baf95fb36f8ccb87a19a41767e7aed95125075a2b2dbba6e565e1ce8575f2b042b62e29a04e9440314a821c6224182964d8b557b16a492b3806f4c39c1")
+);
+
+test_sha3!(
+    test_sha3_512_2,
+    SHA3_512,
+    hex!("ef26"),
+    hex!("809b4124d2b174731db14585c253194c8619a68294c8c48947879316fef249b1575da81ab72aad8fae08d24ece75ca1be46d0634143705d79d2f5177856a0437")
+);
+
+// Messages around and beyond the rate of SHA3-512, so that the permutation is applied while
+// absorbing as well as during padding.
+test_sha3!(
+    test_sha3_512_143,
+    SHA3_512,
+    hex!("ea40e83cb18b3a242c1ecc6ccd0b7853a439dab2c569cfc6dc38a19f5c90acbf76aef9ea3742ff3b54ef7d36eb7ce4ff1c9ab3bc119cff6be93c03e208783335c0ab8137be5b10cdc66ff3f89a1bddc6a1eed74f504cbe7290690bb295a872b9e3fe2cee9e6c67c41db8efd7d863cf10f840fe618e7936da3dca5ca6df933f24f6954ba0801a1294cd8d7e66dfafec"),
+    hex!("3a8e938c45f3f177991296b24565d9a6605516615d96a062c8be53a0d6c5a6487be35d2a8f3cf6620d0c2dba2c560d68295f284be7f82f3b92919033c9ce5d80")
+);
+
+test_sha3!(
+    test_sha3_512_144,
+    SHA3_512,
+    hex!("157d5b7e4507f66d9a267476d33831e7bb768d4d04cc3438da12f9010263ea5fcafbde2579db2f6b58f911d593d5f79fb05fe3596e3fa80ff2f761d1b0e57080055c118c53e53cdb63055261d7c9b2b39bd90acc32520cbbdbda2c4fd8856dbcee173132a2679198daf83007a9b5c51511ae49766c792a29520388444ebefe28256fb33d4260439cba73a9479ee00c63"),
+    hex!("fe45289874879720ce2a844ae34bb73522775dcb6019dcd22b8885994672a0889c69e8115c641dc8b83e39f7311815a164dc46e0ba2fca344d86d4bc2ef2532c")
+);
+
+test_sha3!(
+    test_sha3_512_145,
+    SHA3_512,
+    hex!("836b34b515476f613fe447a4e0c3f3b8f20910ac89a3977055c960d2d5d2b72bd8acc715a9035321b86703a411dde0466d58a59769672aa60ad587b8481de4bba552a1645779789501ec53d540b904821f32b0bd1855b04e4848f9f8cfe9ebd8911be95781a759d7ad9724a7102dbe576776b7c632bc39b9b5e19057e226552a5994c1dbb3b5c7871a11f5537011044c53"),
+    hex!("aff61c6e11b98e55ac213b1a0bc7de0405221ac5efb1229842e4614f4a029c9bd14a0ed7fd99af3681429f3f309fdb53166aa9a3cd9f1f1223d04b4a9015e94a")
+);
+
+test_sha3!(
+    test_sha3_512_255,
+    SHA3_512,
+    hex!("3a3a819c48efde2ad914fbf00e18ab6bc4f14513ab27d0c178a188b61431e7f5623cb66b23346775d386b50e982c493adbbfc54b9a3cd383382336a1a0b2150a15358f336d03ae18f666c7573d55c4fd181c29e6ccfde63ea35f0adf5885cfc0a3d84a2b2e4dd24496db789e663170cef74798aa1bbcd4574ea0bba40489d764b2f83aadc66b148b4a0cd95246c127d5871c4f11418690a5ddf01246a0c80a43c70088b6183639dcfda4125bd113a8f49ee23ed306faac576c3fb0c1e256671d817fc2534a52f5b439f72e424de376f4c565cca82307dd9ef76da5b7c4eb7e085172e328807c02d011ffbf33785378d79dc266f6a5be6bb0e4a92eceebaeb1"),
+    hex!("6e8b8bd195bdd560689af2348bdc74ab7cd05ed8b9a57711e9be71e9726fda4591fee12205edacaf82ffbbaf16dff9e702a708862080166c2ff6ba379bc7ffc2")
+);
@@ -0,0 +1,519 @@
+use hex_literal::hex;
+
+use peko_crypto::hash::HashFunction;
+use peko_crypto::hash::SHA384;
+
+// The following test is extracted from the SHA384Monte.rsp file in the
+// SHA Test Vectors for Hashing Byte-Oriented Messages:
+// https://csrc.nist.gov/projects/cryptographic-algorithm-validation-program/secure-hashing
+#[test]
+fn test_monte() {
+    let checkpoint_hashes = [
+        hex!(
+            "e81b86c49a38feddfd185f71ca7da6732a053ed4a2640d52d27f53f9f7642265"
+            "0b0e93645301ac99f8295d6f820f1035"
+        ),
+        hex!(
+            "1d6bd21713bffd50946a10c39a7742d740e8f271f0c8f643d4c95375094fd9bf"
+            "29d89ee61a76053f22e44a4b058a64ed"
+        ),
+        hex!(
+            "425167b66ae965bd7d68515b54ebfa16f33d2bdb2147a4eac515a75224cd19ce"
+            "a564d692017d2a1c41c1a3f68bb5a209"
+        ),
+        hex!(
+            "9e7477ffd4baad1fcca035f4687b35ed47a57832fb27d131eb8018fcb41edf4d"
+            "5e25874466d2e2d61ae3accdfc7aa364"
+        ),
+        hex!(
+            "d7b4d4e779ca70c8d065630db1f9128ee43b4bde08a81bce13d48659b6ef47b6"
+            "cfc802af6d8756f6cd43c709bb445bab"
+        ),
+        hex!(
+            "a038eaa91772d458a7339612f6a9214e6550f78d5870d91fa7a3dc1c2321e511"
+            "759c159a371475dda7ad1fd7f51ac82b"
+        ),
+        hex!(
+            "8552b4ea2da9f855fcc7230ac88ecb1c3cb4841cc28e0495bf1ab97d100d4e4c"
+            "60a1c51949d26723415f560da2951bb0"
+        ),
+        hex!(
+            "668338b2e661d9e25b799d7329e55629b62416ee4d42d2e55245ffe7092e7ae4"
+            "a3da6903944d532c2a88e4632f9a4b36"
+        ),
+        hex!(
+            "c40cd19cb770b1fe81135fcb9223ffb9ef50340d13660658ba60656a88f4ea08"
+            "ee3b572680e2b4abbaf4392486ea589a"
+        ),
+        hex!(
+            "540c154e82b57914abc0452990abf988e46a2f65b40f5e9ef80a38c7431293d5"
+            "efa31c7c83df5345b079ad4e31b2c8b2"
+        ),
+        hex!(
+            "e112f9359983e7df9b266f53ec950ed2b951b6d832c8fc1ffed22b4be3eae3d0"
+            "15e0771e96d344672000d4959d2829ab"
+        ),
+        hex!(
+            "186e69c28033e11b152542c86a94f59a25ec4edb7ab5b735faff16bdbacc682b"
+            "2ae79b5f5a68ece9fee5ea4530b8a8f4"
+        ),
+        hex!(
+            "a37c790e641f911ef2929bab0ccbb890627c7e38e1a2fbbd71cd4215de919763"
+            "c2819c35a0e93984b7d85d0e8858573f"
+        ),
+        hex!(
+            "f1b4bdcfe0cce284ca26e0b7e01f26de21bf4a7a409ee99fee1019cc8d23e775"
+            "bc134ec7987a6575fa64160b5c7f849e"
+        ),
+        hex!(
+            "45aabfb784a29d6be30104ae9f3c5daa58e48a9c94f61fed94dccd2ec06b527e"
+            "d564643573d588ce10d3847361a57961"
+        ),
+        hex!(
+            "be3a49139a48dafba418bb9e0e8e2bd195e0d239eec77c43d1f0cee844b65f65"
+            "c30cddfc05b644f9a792459695883a2e"
+        ),
+        hex!(
+            "3b263277a0d11de4cb4616591d4623e0821da4f111dae33938329cb8cc47f467"
+            "42c70c09463edb9ff4a0171c4604b243"
+        ),
+        hex!(
+            "2be323079381bd13a02cca824c2f3cd18cbc89b0c3319afbf33c6f06f2abf2fa"
+            "3af35ea9752ffe153d1775a95e7accbd"
+        ),
+        hex!(
+            "14c8c3ea3f5aea2e74a2f138863accf1b5f563d96194c0dcf08ca816e1ac9156"
+            "c3f5d15aa5701d93c188c2c1f7237518"
+        ),
+        hex!(
+            "b9158943803c47678fefafa91c98966aa3dc1fd96f4e86cfdde7ca879dbf9fa9"
+            "f54b1988a53376f7005df7fd87b1396b"
+        ),
+        hex!(
+            "f4395f88c4dc24b6edca5868fcebd24991ced2e3826c04ec00e4630d7e1f70ee"
+            "56124a90657a795f446f7db94d8b439e"
+        ),
+        hex!(
+            "bb56b4ed4683fce0d6d412ef84b7985ccc5fe30306679a994c8221c1212ff7f1"
+            "492512095b7ddd250411008ce9d54020"
+        ),
+        hex!(
+            "cacaeb081a62a4e384e0082f8b994dcd721f38b3c3e4b5836ddcaaab74bcf270"
+            "0fdc8b6faf3f4df1ec175a0628728a4c"
+        ),
+        hex!(
+            "231796f44eba3c8c6ad064b0e350093fcae026c3c03d669c28e5f36befa5f354"
+            "89bf595a0312c38b9b73b7bb4dad5b96"
+        ),
+        hex!(
+            "d54559e7eb20534025f8f265bdbdab0e7dd9e2459305288d3ca84190b6d2c6e8"
+            "ae2cd6f3e51ccb54551d94e9e40b31ac"
+        ),
+        hex!(
+            "d6231b809b70ed19478cdf7e05534e92ea01e74e970fe17e92ec9a67b1b7977c"
+            "4c485cfa74787224fe326b1b8d1ede87"
+        ),
+        hex!(
+            "8ca1d462cc1b16b734c0e4f1a6b7f7d9d0ba9ce8074addc7755243b05fe269af"
+            "ab6618f07fe73089d9379bcbdce6c728"
+        ),
+        hex!(
+            "60fa725bbf21ee56545ab24e6f26ec5ec749c4971bf761923c23415cbcb0c02e"
+            "6a474deb50144abad2b0f16ddbe850a9"
+        ),
+        hex!(
+            "8b60fe287f5bc133ff842a246bf161a58b4b8ead5be073c702552d664653e384"
+            "e28e70d28624aa1da951ed33dcdfc7fd"
+        ),
+        hex!(
+            "d18a080af3ae7e6318dc69298bd7b13644b7ab00adac18b446c1e9a9366e68da"
+            "de87687b9129aefae9a98b531309f1c7"
+        ),
+        hex!(
+            "faad832a7ce865cf3183e51e07f08aad6570795f8d0274fae52fc5cb637ce4b7"
+            "cdb8f8092e2ac4a7c96beb70cb288b69"
+        ),
+        hex!(
+            "b7bea61748495351409fcf1bd45a94af34e7dd899933ed06d5759fe728f69337"
+            "81c21f1986b99906c6910d976a0d9c4b"
+        ),
+        hex!(
+            "b35027199cf6467302a88e5d0106d01953b41dfa172234b3e11d3ccad33bda9a"
+            "836e44f43ae94451e2bea28f0dca7989"
+        ),
+        hex!(
+            "8571acd0d5fdd20909e9cd74e125cb9cd65a2e74056eab3f85f6f13e31a5fc15"
+            "80aa588997d31a0a3ba1a16dea5528d3"
+        ),
+        hex!(
+            "3dcf49cb8d79d4ca3f6e06e8a58bb92a7a42d7915b9e710a29d37ece6c1c32eb"
+            "89a897e9935354db3cbe384a1149964a"
+        ),
+        hex!(
+            "7b2258c5fbf26780f55054b4aa462a607eecbf7382af941efd75b9b4cadd5b97"
+            "936a762b9c03e133d7cfb65de501e6df"
+        ),
+        hex!(
+            "c54cfebd2381621fed5cf6b82b3dea25965ec99365ce415b184ded71b949eb80"
+            "09d91c85c02b30b7465fdcf18be885ca"
+        ),
+        hex!(
+            "39bed6de0e885dfd51569f3c5dc967f7f551065e87b6e7108d15ef5ea407419f"
+            "936f5ae2288aeecf98777249f384fdfa"
+        ),
+        hex!(
+            "b1c341b1428e5df6f4bcec533f1b5ca57e02102f5647b82be0986e523fad24b7"
+            "f27aa29336a3f333817e8a5336a4a3b4"
+        ),
+        hex!(
+            "2e1e67546b9424a2f0bd8931082f9fb8951b9fe57a2b61683a5e197017ebcd96"
+            "592dc47a75d2ae4ab8f436edd5e5bb4e"
+        ),
+        hex!(
+            "6c84a39bc94af5960715818bf9b4694548dd1f0af8f6344d56b0dc7f86b181d5"
+            "249172c82572ec8748ff35b6c0a2abd5"
+        ),
+        hex!(
+            "576705bec035d07e31ebb091f180cd68c3873ea306708c5259f50491463c68d9"
+            "12080ba9f11bcc983a4b849ca19df008"
+        ),
+        hex!(
+            "d45c7e9080b6223a2185c490363764f9fb0634f3865d57a15bea438fb243e98f"
+            "ccad4176bf24c4cb7247dd2c5728b761"
+        ),
+        hex!(
+            "b3021cedd2ba38b69348867729cfe2ab172e4f1643eb4971cde2db002413458a"
+            "566ea884d651a9c010b1a6b869168497"
+        ),
+        hex!(
+            "1cb9c05e35029fe6b114c85a457091b7d9aaf7c95f32447f3f20cd034bc54f87"
+            "ae85c4013d18fe2a94de8ecf9c6b9f05"
+        ),
+        hex!(
+            "1e8f037fb920b836b8f36a1ed4875cf7d61390f68d4843e420b2c1ca702a1045"
+            "24c1187c8eec7bb4b174a252e1ae1462"
+        ),
+        hex!(
+            "07c790c3d4948347ad5fad9992d8a0f6603a2133d138f1ff5cbbdc04c39277fb"
+            "67d45b2e2c8e6c51fdaa6c5883e3a69c"
+        ),
+        hex!(
+            "98ef381d9b6b4e26ebf2bc293743e1e07943a3663b17f1be52d12ef8d1962126"
+            "3efb8525506ef6b95f746567a43577c6"
+        ),
+        hex!(
+            "8beefea2f858f8902928dae6060b10cf6d4a3cd1f91cf1ee5ddef0ee5fc25a82"
+            "69367c114c1c5c5ab5287c48edc59274"
+        ),
+        hex!(
+            "03a6509ad6eb7f009931e596f3dab586de3bd6549afab4f218eba4fe47daf37c"
+            "6faa360afdd931c5a95544f1a028195b"
+        ),
+        hex!(
+            "bdf7e8d538e7ef418c808bf2dec1242b716326f83bf0a53db81f4d63aba37a24"
+            "12f6b2bf00957ad6faf8404e4e2067a8"
+        ),
+        hex!(
+            "71ad43312ed6e403a5e174480fb14b3c2a3a60bba36611e1c99adf8013d243fe"
+            "945b947b362b6dc51a3dd96235472f5e"
+        ),
+        hex!(
+            "bec45a229217e5fe28d6d1675dc7440ccb5616c0f02eb5d4c814921fc82fdddb"
+            "04592ce7af192fee6c61b1a08f6ab6aa"
+        ),
+        hex!(
+            "5d6bc2d2cd0d8f5e41656f73a473bef1eff212fe98b184386cdecade24c5c5e7"
+            "b92cf76ea16f582b9951634881f85585"
+        ),
+        hex!(
+            "96e3568d30a1f7810404f3be8d2d26e5606da6c3fc064d0ef62298ca327476d5"
+            "87a1e3ef0d6554f4ad529053b7a651fd"
+        ),
+        hex!(
+            "e4df3b7028f5e68753c1f21a556d8468a5d80fb048f6b92d405e519ea9ce44c6"
+            "c95fb362119c553f5921dc9616dd3937"
+        ),
+        hex!(
+            "7881e36eef708df4d9c06e37bfa7af5ce7d41d31a2e4332d23922a518de3e2e6"
+            "fd4b9f27ee64379afae7ca2570d24748"
+        ),
+        hex!(
+            "aa12548b63d3a58f6914744111292cfc1f1358f717f1b19fab1a4ecac6292f1f"
+            "f4b4c67b8a260b048f00ddc83b42453a"
+        ),
+        hex!(
+            "b421b6941b5d7748765a4090c224dbbd98e85dcff9a65a77db0c2a83b92f4cad"
+            "961b5b8ff76b5513d4a7af45ec4d4550"
+        ),
+        hex!(
+            "14e1b1733b16899c4046a604f8e1e777d55649c5357d7d9e3d7a1c395b6275ae"
+            "cf733a598de1d0bfd7eeaa9ecbd7d1e7"
+        ),
+        hex!(
+            "bd05a1f9fa5b77371005a8073f0f3bcc4cb4e08fde3335dd3688921cf9cb5e97"
+            "cf1b3052ff74bed8a359d170d2bea48f"
+        ),
+        hex!(
+            "a319d9b3eeee6da494940ffb08903bcd588b18733a64ed435eadc5d9add6ab4f"
+            "0c35fc050958bed81303409f388a065e"
+        ),
+        hex!(
+            "6f19824ec874b55e88fe4b1387433dab85415148870bf4a0612aa9c1cbcd9627"
+            "925616fcdb66d68760c50fb308f628b0"
+        ),
+        hex!(
+            "bacb435a1ff538d0ba3e3d0ab04b5e8868bc1f84e964409229d7eada4b846b81"
+            "3c0e30d8e962786aa83aac2dacf02d19"
+        ),
+        hex!(
+            "e8f013470eafd5af84f63d51d51af2ca884789d03d79f8c3089810254b95a6f5"
+            "4fb86c08202cae94681ad702ea29451a"
+        ),
+        hex!(
+            "374b07621c018cc3935374c2f2f098e661ca0656181f67f55fb80ac36e23da37"
+            "9c4f6c8a3683c2621f874afa1241b918"
+        ),
+        hex!(
+            "5878f1ebcbe60aa62a7b149bd181167e5898d08a3627a08c589436f007bfb82c"
+            "040b26ea9944c6f0c9c4079b9b0e1ecf"
+        ),
+        hex!(
+            "05961b57507c99d0cb7dc24ae34eddde94ac484129de621edac5b001ac5c0b97"
+            "4d09d24f75504f3be1a3cd635c44bf71"
+        ),
+        hex!(
+            "d961eb883eecbc083533fa5128695c8d28281fbac23308dd2f504eb079d2d311"
+            "b973f1a52b45aa6275550e14477a8876"
+        ),
+        hex!(
+            "a4557f990f4ccce585ba33453090f66af576f0a501d26667031f48f19538b820"
+            "b84f870579efb554e7550f9f53fea5ea"
+        ),
+        hex!(
+            "82194c49f24084249567f0e8963c5f72a23bc20a8f522a6108f12abf95b7437a"
+            "d93673860a953264838a09bb3968d0a9"
+        ),
+        hex!(
+            "371dc5573b145f2136eb854591ece253efebf8732d3898bea063fdc3889d0795"
+            "3ee646e533b214f8c2dd66f1355b03cf"
+        ),
+        hex!(
+            "cfc4dcecc6103027232029dd9a19850a6f79b9004be7d70054d0af11c692affa"
+            "44c537f7cd749f2b6317cafe1fa52fe4"
+        ),
+        hex!(
+            "9920b835400795bd3ed8ae0bc12417d58be8c5ffd6eed151ed738c3031e624c7"
+            "4fb85488953ac81c75f395cab74f1679"
+        ),
+        hex!(
+            "4a1b040fa38b5cee63f5d308b55502d2a017b349ead5172c288289f42ba9874d"
+            "0d11c9ac43255580c428a99067495782"
+        ),
+        hex!(
+            "93c1cb94d0689301728165299057edd78ef48a6dc7654931ae2bf7ea5bc733f3"
+            "b724f4c3081bc93ed61e7d739c38e137"
+        ),
+        hex!(
+            "8f2e6c868b224b3cd5ac80669da0ba1d7e799e85a124c9e81c6865ebab1c0481"
+            "e4ed4957a8989902ec565169ac53b7b4"
+        ),
+        hex!(
+            "df045c9302fba73f9f27ceb0fb70e6ca3897f410e81a2b8392489e40aa17f15a"
+            "c59cf8d6893ab10bacd8b59704eab22b"
+        ),
+        hex!(
+            "8ab095f49aa7ebfcb8b1410f42c38fb1755a0560e3638b8b82c7a852e8bce8f4"
+            "b780015e051dda0d2cbd6d6cb08e30c0"
+        ),
+        hex!(
+            "d77fdff2f768188efa63a7e29d73b8ade14c1aeb12e77866a57ea12c81bf0b3e"
+            "1421d1af57fccf91b2098ba02ffb4118"
+        ),
+        hex!(
+            "fac5ee7450b3fd1ae2152f5d020680137f553a2c210c57290d058f330d114075"
+            "93d74c9d3d9ac88bf4af44e023345168"
+        ),
+        hex!(
+            "39d0ee95db114925ed7ff1577a22eb3dedb8658ce31504bd0f9f8a8f11f90825"
+            "587203f26c432d216918156ca931fc82"
+        ),
+        hex!(
+            "17d22080e8a9f589a80a5ca8291b0479c41351008dffff79ff522779c35ba0b0"
+            "9acc2dedde936b07e260451d35ce86a9"
+        ),
+        hex!(
+            "9d75befac42e6d4d544e70477f7581264b5f8dda988da0dc40ef32f85c31b709"
+            "284aef5f4f0246d20a855eee9175948e"
+        ),
+        hex!(
+            "3985f0cbca4c25f624850580516184e3d75996d77f138839c7570b4539b90fae"
+            "8e751d1cea642816abd7f9ebf9d86c8f"
+        ),
+        hex!(
+            "93cfd14ade34e50deeee23aa75a63a017b6974e23051117e7e6b56b4ddb88f91"
+            "7a5d88d3af2af27da8e63fe130502f8e"
+        ),
+        hex!(
+            "7ae513480491a9500fa9afb8f64b8914ffdbeece3b3103048e91f6510b64cdcc"
+            "c8273257e275e5b34ec14c4c4aff4405"
+        ),
+        hex!(
+            "2585bab1cd98d4b51a1475fbfbe3bca43da2a7be842c5667c98d3b62a9f05918"
+            "108be94198d96c67388f83c2abebe498"
+        ),
+        hex!(
+            "32f67419616e11fea79e3baeee4524c58d09f0cfb42049cea70f9a4a74e0096d"
+            "f841a0cf5177e402dd5803f4b51c602c"
+        ),
+        hex!(
+            "9f4486d93c599e68e7463d07d5cc9d589ab3a7c3d4d3c2b2d1f81f65b5c85068"
+            "331f4142215f337c3621d096eb36aa91"
+        ),
+        hex!(
+            "e3399ed2ac93c6a4a6c88c11bd89655aac3e573493483c81631fd67dba3bb237"
+            "d46f9e8ddab3a9fd78236296d00dfd79"
+        ),
+        hex!(
+            "bd9d1de114afa5ffacfbeb488d4846d012aa6ef66ce09725ae7b15e680d719fc"
+            "2447f308eeb8247ae8e91e34b5a21ea2"
+        ),
+        hex!(
+            "e1c3511ed2ed26f770bf5212c7ec245ab2ba49e1c09edae2abad6a3ee41c9e25"
+            "445f5e5317cf7c9c3c3f702ecd6778a5"
+        ),
+        hex!(
+            "c363234d1a6272d081f351cd68ac90abea09d3eae3a4d64fae7fab251a252591"
+            "cb34dc63fb10abcbc5460129464c868b"
+        ),
+        hex!(
+            "6e5f1531eb282a2911a64b72b043cfe43b527d4d557abb9a31a9a632cdf5b5e0"
+            "55317ecb72a517a025eb4286d6f00433"
+        ),
+        hex!(
+            "19c85253b3c703fee80a70bb2ac2ef836bf8e14464d2a17f35bd5e4f2b0b3a05"
+            "9a27891410950a9ce07197f5b306ae3f"
+        ),
+        hex!(
+            "eec713a44cb778811795609610f2f9bfc9bba479e415746efe0dc530b6de66d7"
+            "3cb85b8698a8c0c2ef9344a2043b7a31"
+        ),
+        hex!(
+            "b799577aab5e8898326ed88eb96de38a27e76280ce44c3f16e70a1f96543ee70"
+            "20bc29913ea0b9a9b9d92ae201143e0b"
+        ),
+        hex!(
+            "e4dcabf1e22134076a21ea7cf9be6e03b099be40efc2b3080b9ec358cb021623"
+            "ad27d33129bc68fce3eaec6b25aa2329"
+        ),
+        hex!(
+            "ccde4359f23e64579c5c0380df837ee950928aa82937a2d2ed33d216e707c46d"
+            "847efa5ca52dcbda551145e164fbd594"
+        ),
+    ];
+
+    let mut seed: <SHA384 as HashFunction>::Output = hex!(
+            "edff07255c71b54a9beae52cdfa083569a08be89949cbba73ddc8acf429359ca"
+            "5e5be7a673633ca0d9709848f522a9df"
+    )
+    .into();
+
+    for &checkpoint_hash in checkpoint_hashes.iter() {
+        let mut h0 = seed;
+        let mut h1 = seed;
+        let mut h2 = seed;
+
+        for _ in 0..1000 {
+            let mut hasher = SHA384::new();
+            hasher.update(&h0);
+            hasher.update(&h1);
+            hasher.update(&h2);
+
+            h0 = h1;
+            h1 = h2;
+            h2 = hasher.digest();
+        }
+
+        assert_eq!(h2, checkpoint_hash);
+        seed = h2;
+    }
+}
+
+macro_rules! test_sha384 {
+    ($name: ident, $input: expr, $hash: expr) => {
+        #[test]
+        fn $name() {
+            let mut hasher = SHA384::new();
+
+            for chunk in $input.chunks(13) {
+                hasher.update(chunk);
+            }
+
+            assert_eq!(
+                hasher.digest(),
+                $hash,
+                "hash for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+// The following test is extracted from the SHA384ShortMsg.rsp file in the
+// SHA Test Vectors for Hashing Byte-Oriented Messages:
+// https://csrc.nist.gov/projects/cryptographic-algorithm-validation-program/secure-hashing
+test_sha384!(
+    test_0,
+    hex!(""),
+    hex!(
+        "38b060a751ac96384cd9327eb1b1e36a21fdb71114be07434c0cc7bf63f6e1da"
+        "274edebfe76f65fbd51ad2f14898b95b"
+    )
+);
+
+test_sha384!(
+    test_1,
+    hex!("c5"),
+    hex!(
+        "b52b72da75d0666379e20f9b4a79c33a329a01f06a2fb7865c9062a28c1de860"
+        "ba432edfd86b4cb1cb8a75b46076e3b1"
+    )
+);
+
+// The following tests hash the messages of the SHA256LongMsg.rsp file in the
+// SHA Test Vectors for Hashing Byte-Oriented Messages, so that messages spanning several
+// chunks are covered.
+test_sha384!(
+    test_163,
+    hex!("451101250ec6f26652249d59dc974b7361d571a8101cdfd36aba3b5854d3ae086b5fdd4597721b66e3c0dc5d8c606d9657d0e323283a5217d1f53f2f284f57b85c8a61ac8924711f895c5ed90ef17745ed2d728abd22a5f7a13479a462d71b56c19a74a40b655c58edfe0a188ad2cf46cbf30524f65d423c837dd1ff2bf462ac4198007345bb44dbb7b1c861298cdf61982a833afc728fae1eda2f87aa2c9480858bec"),
+    hex!(
+        "2f8316a798e517891bbaa0934c46373c7503bee1bb682044215ba272893735e5"
+        "b11110dd2ba9dfde198c25c56dc7cd7a"
+    )
+);
+
+test_sha384!(
+    test_757,
+    hex!("42172d5fdae447c4cfdd13836bb8b833c0cb85f7327f92983501a4d7583a5201830266c37c908640b0351461314b526cfb68cad97bd7ed615248fa5756c6213bd9eae98d2f4ecfdf6a452f2e68c9687210b53c74d83575e08a7ace9b49b21056cf377c64f80669c884742e93181c426d871ca2715081733e68ffe94a39e6677aea51e8f0e1a09d258629d7374a2b2884e903c577eba32fa2713f130d2e496eceb4a0f4daf105b31bf9cef4c306de62dfbcd46e2fb283f1352fa3138c31c56d7bb48d6aca301bf3d464ca4bde521d37a78bf66340ac09011e2991b36e4941aba8727e1067a7cba4784f85a53138d0f104dbd16d54e21ea686e772b95c7fa6717e77dcb05a5dfe102e4267c963bfdfd61d36cd53105aa82a95f2afeefddada07254a10104a5a9a7d1fc6d8811def322f1b2352df1e1e90d372d1ae1afa62c6b5c47380f9e0a788347362409307d1b243252bc8d72636bfea460cd905fa1f52c3847b9632c44bb17d519f07c8c86c455c64d49704cfa81cb6382c9776a61a67788ce9b9859d4efc9fe10495e809c9d4c000a9272ec27e8e8171b84f37a65aeb1d054550b814b950e44d1952bb71ee48b8202fe11ca7c0ff9119386b0ea1e7c8fa1618c594d0939792ba66a708a9e5878cecf02b9825745630573452c43fcae457e8e87fe17ae4b8f25274fa9958b67b848d736e68e4a47ba453356c21290a297ca240e667b9b59b4c3dcab43427670ae82b4013558d57553536c221ec07af7db06da562ed360d28e8a3f03ea2be021effede08027c896ce2d2864d9ef80c2ca3d71a15b3d98f4470dab6ffeabc48e9e12fcda1fa63c68cdd250a2fcf03d49f769d5bb391d8872e0057dce5e16e214726980b6579a92d53b6ed704f2b8e64fec7dc27c6456ae90db164295c5adbf9b824ca0fd8fca71e5fe47e412230f22d991c05f6a45b0b1552089224d9b36042bb603843631ff82a1ffa5a055f8bc99f1ce7cd50f42f23aca97a6447d477a58ccf6d555e9a4016d1026d23354d789f49e8bf74bf3c4e6f0f529b4d1ad334164872a0c3b9e5098d93a"),
+    hex!(
+        "864911d80f8de3f0974a43d73359c8699568f15e8e4a03ceaddba9b0b2d23426"
+        "a8ffd3bfce9dc45aabee86bfa99336df"
+    )
+);
+
+test_sha384!(
+    test_1648,
+    hex!("3cfbc77b8897b6a5613f62f6b1c89b0d68f272c6c19b9e0ec6331ef616702006e64322d3460a57d3a5074c719811cb5dd78900268890da0ac177b40d487735489da374843a1a6007198160ae77b1363cd8ac29f24bd66360ef62987ab60ae8ee690307b5ec309be8c496e5d6d610a453714336538fd501b758da1166e88f02a0524b218fe5b2ce1fae2c25103d96dd4aac376f70def57bb705c868f967704c0561630b3aac0ac254df2c668535aba8c8916e1c72bf9e9b09fd15e65aba138bc69d330dddc99e3f2e607ff15c45b7ce7527d18580bb38275548a7d0b269e28a8a0fa46ea0c5d80d55380b0ebef62218fa7648f2c3592be842ef687128fd4310fabd9c78ac271ff3726fba04d3cf544bff86bcaa6221dfa679f93f10e5fda0e4beb104712977daba2d0e731dc258b5322b6013f6869bbd29a26e13f410f160cf7df3c5a23f3e732a2d1b1d9fb419ae94270b371e57502b386457ce66d261eb99df89c5531402510b1ba1a2d3d09ba5389a8f0e6afcc7929d67bb57ae53d6a90a8e7deeccd34edc259ea5e9013f6503e72df582219e885b1e54a29614bad802e92fd72754a2a77c405f31da7128ff316988447a8d641cec84d31473e030ed5e006d9d5734a9979923054c5d6ab4f295865284ddf4770aaf968b1ad659dc9f2515edd968b512a59b9739ff5a360bc5990634bd959ffeda0a1e25bba7c8e775bff15a92411d025aea64a351b91b5400a4b0d5f889d6221567e24800ce7578f7945c5ad1cab4a33cee52ea4a6262b82c1d4dde3da1e10b422dac9def33a8b8ae0c1e959debc41dd51028b7f23e525ed06ea5f692c507e0e9c442cc93bfa9ce2190feb5fe8c9398adb6b0b15233356e74fe80c601dd91ca92946c7cf158bfe3d986c55ffc956a3b4a4ab081712a5112a035a6f591d1c7f0c605f45513e7341f5c583b3da0ee912e3632f1ce570cf070dd7bdf2c4a89f17b0c7fa10100554ea9346c28f7f180509af9d83b410dbf58b0b238f21335ee5792ed6a3a25c08856aae82c5435f731efa719a256829a2dd1fba8d4a85159e6415ef02e886c5c6a934901ff879ebbeab57c20fa0938063ca57946b7c98fbf5af6069d33537bdbe24faf064c88da4494ec4296471d0b5ad61a51144a1d746d33bfb37ce162fd45d7d0037f7d20929b15da2001f05ba59de27aac9af36ba96d57e48d16a17f98a233606ef5f9176ec657c73feb5f88abd7480bb1611b7dd5c7b0adbbf2d970f4fbdd4518ef283515cf40fdc6dbfb6fc810f01689f02b19a18125616698f3feba57bdc728f5724130bdbac3de5a2cd7a251c2e25bcf8908b5a59d4ab59155f192bfbb30c78f3f056699ac60afef5a87e1a1a6b950879f11b83a02aed1646911233abfc61c46f747606afd1d6022ed482e0984e1909fd4fe53493bd6e199952616d0b1d350a102d0c53897421a7b4de2319044a3429f8f962dd9300a0eb943f71289799d8a29c8683e0c61170483e31d913ece478e0f3aa0eff2c992804bda4dc06eff6df364b2d673ed43e347c115c1f61b0a159d783ce5409ceeb55ed7c2f0c8862c8ab3dc26e40be325f00abd4c950a30e0559762fd2dc9e087bcd0e562a8d1dae63ef1198d58aff6ef847600f1627284394ea453e53f31c57cef00fe243d837016cfd5a150fb062f89e8b3116af0d91ffb49ead55ce52154b59613a96f768852a58688fed595b995dc64e8782107e50a108e946fcae541941af9346fac1c858879ab32b886ee6f30ce3f3dbe7c6f3e4c5e65c8aa9708388d946089d741f725187c86fa55cacdd10948c3db6ae5ef8dedcf16ae0c2b16af4709123a6997cf0d0c945e095f08fc86273336ea04c3517af64ae8701feed74dcb635870ad166ce86bab7d875eaa41379375e191157e5bac6eb625336b4913bb20887a532c26965c3ea8299bd817658c53f80cf9247ed6cc5dfd132277a291a04e62c00a6d3014118c73ab6d57b7302c71a2ff4ab92a8981cc06fa62cb621aa55ed771bef89aafb15df44b2662f269c0f45d01ab5cec3e74c1b9241eeff2767257d1d79656ce2fe1ba1350ee8230daefe5105b44962bbb4d47fed87c6cb86dae413738bb0e325c4ba9b804ec3dd949624ba3563eee50c9b363bd5da4c81ec60124f1dd54a830fd9735cbad491e557fc12efd38c1edc1304e1fdefcbf8e8b4b1509e60e8aad2fdc6d6334942caa0c44b3970849f419e7b8c78249fdf54367c28ff7001385a497bf733ddb01be6b675fe08cabd300fd2a29c694e7a86d7119f41af726336c15cf6f54caa83b27e8cc9af118ce1c0d1ef34af25e9e44509f0ba95c"),
+    hex!(
+        "497af4be991621644496de0fabeef2dfc2fb8feaa0cc8099f7bbd0170f5a525b"
+        "cffd208254d9e82011c682c65b49a313"
+    )
+);
+
+test_sha384!(
+    test_6400,
+    hex!("37ebe98ef52bfb240b9ad369153afe081bbcf9d7ae43e8ba336b8ac57e8a6da0a3365e3008072473bf9d6eac13e509c1619956e12a06fc696512da091a7d40232c675e737713fcf51aea6c0316c3bdbe196132b0943df2b013860105ce676fce7b88d0a167d7ec72c588b7b6465a83c9ea1d748d15713455e5d0e901c3cf646a38a09b0002dc5ab1687f350dca35c1a87cd404c0d529292082f77844203d86be0bb8a9d970a9af7baad8d050cbd9e024788eca91fbed39db930398180e393d949ad7e173d9c65498339a6ec670d049058653ad48af45cc4cbffd30c3b54cf1b290052b1864bcafd0accdf9b8e2a163134d2c982c1bba4a3dafec288e3cfd0ae1934a6f0e39122aebbd7a586e48d495167620708664d31c740bd868c1ccd5f0e94baf959e81502cb00da87330cbf149d5a8381e9eb519a8b97acad7a48c5b0c92623b861064ff1ce8455f32469381e6198c7b8abc341357d6a4c85f7fa517c4a47df728ac09a6645b0ca77df7c70cd4aacaf19c280949919132dde7993e9181e647e964ba99cd6bd10b893c8d90187a5009a23d295d43bfb4cc0e583b8052ac21651b23813bfc9912ea0c574e152f42d3f1975309588a4705196598ad93e1ab1d82954b4a18bc56e55039b6837fd893fa2bd7c70e21a5934dc2e990379ec6e8a2445dc55d57940a14e5164273f59cd58e5f6a8281e11c09536ea22821c98ac978537d7a02220d1d6552aee168a001715834596baabf7813e1c69949b23eb4b86658fd51819eadf8a13f067ca8a791cd1d53ab69d0e43f18bd72d5d93322cc1c36fbe33121f5ff01905328fc7c33d452a86468663c77fc80b0195ec1eca05a5daee339042b4f88a1f9371b472c6c5168c00e984937a134b282633dea25dde7e397b907b1e7d3d240a593e747007990782cf944fa078a7118fbfa793b2604fa15b82453209daa64475d0e95e2408319e8b5ce7460f4593a19e3831a9b363b1c5ddbcd273995fbc61ce7502b0233b1752223352e654837181d01a929f49faad422c65b8ae416ef81290b02b48e222c2b8c3ed57cf0494b928c1e11ad2da77baacd427785096aae1cd593cc356e551bc390cd5765ea41be30cf0266ae2e97d326c417c91e90d75f1f874555b88a14a7c5959a62f23976b77a4c754e35dfb7ddd1700df85f61a62b12a9eb4644caa7f8ba036b9f29c6315ff96c3f7148284ebe3239ecad50641f397ea24b46e21655352a4109b61479b9dd34972779f2f1a6a1d2887b8ff88289b2ebda2efe995668879bb93c4ebb3a585ab336f70b382205ac37c383475fa12ebddfb95b157172261597d2cb0f24f254feffaf75d224a3b407eb54cc7c8daa5483e4a79c347252d808a5f480a35987f6f09f6c6a73bd5cfbdb76a11ed78b86442b810cb703a5dec5874e8721af62e386591bd39d990b3521505e144100601b46de3f50752911ff37bb18f377de45ec4c60fc4ed8ea1717708d2d13fc9e1453a1c4a4db9e4fbe9b74cb8da14ad50c8c8f2ec944e10ee8e82ebb6a081959b0159f043a15fa1cb59bc5e035f7623fbfaa99ea0a1d81ae8692a4019e5a5edb3a4886c789675039fde87222975e86c2642eb0bd48408072fafb1a88507194c9bdd69f3418376a4d9e68c3b83b3f800605ff1dcf0917a6014b0dd77708b583ce3ea632746fee0e01a10500cba90016b4a9072847d809bb0481ae25f74f8ef290c7a087ae16f505fd0da670826a0b1174592d184e3a7e8622a5c84a30ab64aab75face50b96b217e8ea335c0605c638ed1c59370bb9ded004be428f49a79f74ec0fb296b3758f0b6b41930c7e029b55c8fa73cba7dc926151d4043c6bc8a716d7de9ae0cd3ef3ab2d19b0c813eaf12eacfb641d492b0001b2f0f699bd98e4581fd44c0c817646bdd77a71d8ed432f8d422812751a2f9178cf1800ee689ebf046cf9b161f9a7ef0a106cbe833398bf383288661b426fad8d4f570a8293629ee06856af295a58585a81f87f130e6e08f723234856e874bd0adbb2fc9e676deab6b9f22faacf12e875d1259ccea54f7294be02a16f34c427b51a33be8a0c460c4c07d51a2e7d5c0722a9fcfefd21c265d5aa2c57ae4fe95556b5e1388ea9756a6afb0856fb8fbe1d2bb1838be7a95049848fa9545b616badb753c453f266836eda3c92cd592bc0925690c42cd6667f866717827ebe91d0999f9de5f5fd6cf77f63737b65927aebcf6cefc7ca107fda8447e8bebf1f08a280d53a4b07f8e35904cc48cc08eda3c63a3475924bde1de6acebaa65fec5ee68ca22d3fe722bf33267de628c9db1ceda3c78cb2f9988682d641d068023f96aabde4e10071cdec2080f616ac30c2725ad3efe98a69a56873615a3a3161503a4f22621986def597b66641d07793d97cdc9a68f85fd3890a38928462b2fbe2bc5c509631438d2e344d1ced9e2b71748f1b6ddf33a3e597de3af03ce43d305b9f5acefdb2b71acc645d3b55fa3848484b7fa4cf25e71e766702f1003950bd2f45b304052861f6748a8f38175f1e96c91471f5a54999cc9937191b6adc9de0d2520d86590cd4aeab292ba9ae474edb5b8caad6ee095c9e74c0f5e5c9387559f946b2dc45da7fa1d4c2dae6973d5984841682af25ff7ff29d9721d6c7e76776e8965b6c681bc38e85da15954ecbcf20d7448204d9a6a477781c1564d363e4c634c36fbd3c3b50b332f1643c415d004ec999316e75694a8b98e2591678388dc6624058454ec3a7ce608b3f222b8bad5cef77095285e1d2ad746c557222dfc30605bfadaafc4f292e931a0f0d49b226d99d708247879aed5b9f2ca2fe6fb414f37373f844e13865524f206c54487aed53781834b3f6eefb248d95ba21bb60041d501f90a97a19dcd80920df7d84309148e3d0892e50687c86a45a1372926e00f200053f5f436e003e35bdc10fa99d9328853bf82d2091f1f087cc37678138ac0027e73cbcc99f7fe37939c98114fc7380c0ad1a26e3f5ec00bc7eae77045a55c62c18117879389c662837415852e7a2d01ac667a226fedb2596e3e137a83daec2712a65e8cec3e644e738d11bdfe9b19517fa593546373fddcb9e681fc97d1763bb9092a456cc0dfe1aa0e132387d105e3ccb7746ee199aa7af00bb96047310585fed40219dab43f057220a41e90c5f89fdac4a5d6b207c01d5ad4440c5ca29eed292c6f7000c58da111eb4b16e31efa6df3f3aff69e6447ac406aa96a9ece4b5b813bf8b3a499d09cd0969073468513355d6c19346c58480feaf470e0d45a13b74f2925488fd810e0f74afb9e82a24cdf61586bfae68dc92ea09b22d8c8f1ffe9db1e7e98892b5554ce2e15fd5f1cac5347df2eafd2a8d5f1aa8746b9403915da6d418c0b5a3aa8e09d6b65f9a49c3b7a5728e9baf95471404fdf64eb05da5f704dbad60ac9ac106cab2873fb1bc9023ad95c24852337a703d9cc04d6df7de594c3b2e4fb9f2996e0418ec8698a4c087c14a2687717f97e228e75afe295caae2f16513f47a45b4124a7c5ebacbacc562951233bf89f43ff85b703ec77f168c2278fbe6e57a0e7192125f4642d73f2f227d806287081bd30149b9d44fdb90029667622f9925b7826bd0343bc537c66e660f174b447860e1bb8846c3edcb639ebd213a4695f9cb471e188db7a859fcf3abae49569e676dec857b897627cb0bc1155ad6d45282d430176fde4262da2d5f41ff890ceb319d73dda804738456f30a3d68da41554d4cede62aa8549b24e211e76768e6b17379f842a24a449a0ba3ea73cfc72624b5afd118fd7e76a7c6b5bbfa7a6b6c97b97dea52decd51cf35a8e277140ffb2748777a1e3cc3211f3c12be099d0316f45023da6cd200339a718c72a5ca172903922e59648d08dc67f173788363c26e5df406391f107552925ba91b9e569f38101f5eef9a52d201288372abf6532beb4af19fa6d81eaf473d40896dbf4deac0f35c63bd1e129147c76e7aa8d0ef921631f55a7436411079f1bcc7b98714ac2c13b5e7326e60d918db1f05ffb19da767a95bb141a84c4b73664ccebf844f3601f7c853f009b21becba11af3106f1de5827b14e9fac84b2cbf16d18c045622acb260024768e8acc4c0ae2c0bd5f60a98023828cdec18ed8dc298a306c38d1ece01509f3265b5f8cbf441f0525097e8b48234bf69f65cf402c7540a023ed231ef95b222a900ea4bfaeec02c6d8b3b01648ad7a165237ca6b557b1ce287b0ea137f4ef54534070ee793695a9078ec89bcea389956878614ccbf917b61f8427b7cda870fdd92d2d297154262fc65f28ff1a54b2651afff12d6f36ee8c906107bbda399ce5e2cf0a430ad0dd86520841757126bad725bf1593c7959f16221894f5852ddad3172fef866b3321755491fd44fba009b42ec0b6c4fb9e901d7eb3b8acf70e94911f54c538bd0559c5740042b6df4a07c3e00bba0934d92a684b39592a576331e5a44672a227ccef3e595ffa1146ac1dcee0a70baa9acfd5c132b361b5ceb519984b0ee00cd2124aa8acb50c9e574fb19bd99c8fef5407faeedb28b796848bb372beb3f5bde55ed2cb140b60a53bba2df471f330208b09ffb8eda04315a06d693aa53d9bff8939ef6f3a68de6e1975f79f50b3d484665e4ee71124ed794be3a2baa7b5b918e62a095bc5d46e401a0979641fe465640e8d4d43eeba9d0cac76c7b86d22375123b988585e58f86566fd190d868eca08aa1e66932d6d3b14ecad3efd9f8cfcf2696ed42eadfa642324d941602cbaebb8639a00a17542afda32117051e4fbf243dfd255a559c49ac37c265827ba70b0bc618882336f43e1a6a729c57be478008cae6c74840bbe828c976ac628d7b6015bcb705612c277bac0727da645480a0e14fdc497956aef05c89d30f22c2c96c6dfc9dae30617e6206fbd957975b8ba0524f563289e1f5f09bdb6fd46fa6117e78e854f91d71699fcfcadfaa7d4db8fcb04bed08d68d11677b5085b295c1d414cb12456c84c669737af6c33992a5a9149fc7f9330bb291d38f6bed10318081dde8fd178f02eb0e8b7d022c8b63fdcc867546035775fcf7b32c8fee83df7cbb28372b23c71459b9566a7f64165da0a3d0e538a3dcc1b6a384f75f0263dc10e0924a0ef2ab459d0a52b7c112710c58cf72442253396b8a25d7644be166c3e7828aa62b1ca1f32f620ed969b021ec609fe926958a03cff21f08f7c8d3d3235b219fb0020a51b97b60f963ebb58f7a62a5b41104c0b28b58cfc81668825f87064e401c263421152b8790dbc99b3032c9615187f29fcc1a58e86364ad45524b5358fa2f0a3296729a3663a585e9aa922f534fefd16fb6f96cd9895709c5520cdcd24c8d107e387e520de055a3296544ef1c1ddd43b919a4ff139861f06ae5280d5aa5aaeb8f7d74ed6ea56093c2e697a30c29c4ac145aa99a372f1a03ae72495f52a40cfddedc12b6e9115aea5ea516c5a4223a8d0a0073c8b4abe3c6188fdd6d4ab627c9f4eab468fdc2a91945274ed18465a368f291a0050c9d638a31944091b35a8fd26a1ff65e2d17dfa32ef3ac412d8293b276849ad9af71fdf272363f771d0fa99996e24510e7bf731a7480cbbefff7801c0e5fd0a13dd8278162ec1687f85409a203e82d2bcdf7e7d1ae5509857c42fce80299fe06182e74a97c0c624ed5b6246e59781af9407fb28b34f7024f42d36eb92bb95f72cee379ed363daf2625b48e60d0489b23dfa57789c0dd2276b4575a01c2349171d2a58bcf29e659b868cdac1c30a02a160c078b6faa7e0696711d43447ea2108db3d34ec1bf9cfe802f601212d335445a4624829f8a600b18e9b3cf13a9787910f2fb27676fd809e7ea1a34c7306e766b2e7ae1bbb919cc888ea931d1eb2e27c6109b9a12c31e188a196a98bbe0b24cc315791d26ef01b77fe06c3011ac39a8f78d233b7651e586d14dcfc2636cb713ecabadb97374ce58498f8b2e557531793fd9207fe484a4e147f7b826502cd3785251973b23e2b62b7fdc74a10fce9c04f97511dbffe3f2c46887c25904b99df69e97b416bac18fadad67b71cc320eff8def185d41ae8558cbdae6ccee38b8cfb2bfe92d0aa99815b3ca1d115f21493b13adeeafce81a23c6b1bc15fc8f2b171284e6a1fd65c351b0c82b31112f022ddaa78dcfbac9f203eeef415c566a00c2c933f06ff18ee7674aba548592dc8214b1af8e929242f87c81b0cebe8106b5267ba39c5b51987e38858dce1d1f8d0cfee2bd61d217e5a5d41bb0c4aaf0e7b0a8c66e5b0291e4d05bfddcf8861bb31b32ea5ba80cb02472c11969b3b02a7f7bc025feace44726b6382012544f1bd1256744f4b1b0ff81f7b9f7462c5c92507f1316df228ec5c0786378b871e69479c3e26f232f5d6a709d3551d08f0ecced52f8158a2c40a234af448449c1cb1a1f6f5ae56171606582ebb9a5836c454eb86015ae7a4ac87105b371bf40d49b1134a037243a0878953b5bbd6ef944ae7c345ec24e4a0e8496b62d71a6381aa52e5bdeedc81784f45e0c75b72a8c9898ea0387a47153d7e3a7c895aab58a1497a5e794052d7457624478c24d44c7e8932c887322b422478418af64a389c152d12c7a6803e0fb0050dcf2b9d65a35a53b9845b9c3835fddd45dfd12e28f8845e03686b3707ef6003e7c1cd4f8d7406ee0d1cdc41d7b56fb630c1438fe33196e53389f1ec1540fe789c6599c0b589296214d831a86e89220ae97974f4d112f4c98c726027d0c9316d1303b87a43a86cb8b800835a677abfe1584e8be55a624612f56bdf71a054a2e834e35105a19a77f7dfdbf9dd2850ee44658ab0eae6e833c855bb9650eda7f8f4e74d8de73526f12773b2bcbb1bd35639f8730d8cdd6d64f496abae4e1f8cdc96148894aa691683515bcdf37ba6caa0cbf953c752a7b9819e9f834ff39ec8f6d8a3dd8dd5a431d47c7f74c7a633ff73ff507009c5ac9431cc588ba0c6d226edc17c94a0f14d3e8db0c7ef60c3293878dfe513f96b54c61c88a90aca4f246d6a5988f5f785ce0655f51b85e55af03e5772a083bfcf0816ebd97a4af416fa6414a9ad47b7198e51d55463807ef4f0d9b7c06a0a84762e4e46c8b39147a4bdd594b8d4d40b36f5e6b4d48726551890d040d229ee70ea3034d45b3c28eb80d686918fe6e219636b8f9b7e6fc08f4e3bed9bafc778aab274913e9cfd570732ab3fb434c9ba0928581232580495571e56f6705f2af05b56642c2b93df65c443a6caa5b167a4040d2438206d2cefd3114ab466eb3c9eaa5e66cf4447c89c493a2eee0b0ea6e7329b37c90ec2d0142bae7fef265ae3c9c053e44031c0a142bf9faa728e5170cdba59fa8da361d94d887d5d6f58b409bbc4bd4548990653a04dfb841fd784ac9cc4cfd34c88512de212074dfba30295badf22f1af2522c5fe1cd423bd8eae429d7a862bcd649ab61bf0d3b55daf4b6f0f390c503d7c1bdea453b5ef145bd8191802056bd9e0455a404b6afe5b25977f02f902caba46f988d91b2350ebe4091b5584d4f938a45803984a5291beadeeadda488dc7ed2dc4aae69ca8ae0bd4492f9b297c3fb257de986c1615d44dee59e1e14d34af9fd7852b13fdcb713dd1a03d341884a30ea1dc0104d63a31d291df035d317fea98ec44f5a86715014783172e667a748f162c5c26a8b34a0f133d89fb971bf6e0a01507efed010cc7f194b5e87a77d56a909d65efa0d5ccd6da9b5eb1d73422f97ffad8012af43a2905a98354b8362e9c459f0044336348eded53660d65a38a9efc42be13a6672790496d875a67e0078dfdd8340dab8547be140ca9f88891b635e195c20daa8359658785cbe3d09ce8a580f009324e6550b0196e305889262f28f49dead77e6f5a0e859c57d53c935a4c9590879b6528eb2bc3230217b0897cddfeff405a6a54b2f50c58311af1ede4ea0660b73037f9a097d9d0271b45e325bec666cc7cb65ae780e361639838d10fe79907a0da0efef85d2420a84e905bb33116789526a9a88319d460f539586762ab172e4a7f305f7ae36cb88c96d91aada0b4dda3418c670e27a5fdede39bd8659e477cbe08e645af927843dbdd67489b72693efeb3a7be0e121fdf5580474ca028f39a035e78d81dd212679d0a830c050ffd43af6642d60d410aaf34f7a5ea9cb2e12f21672e3f4e0c00ccdb05758e74df3893bd40a5d7921e2e149330fddbe0a2dae4210d50a3caa60b1b9db685f7704ae2d7302b18e8261052b779139747f462a6610a37252b170afbfce905fb6f7fb8c2b6100ee231507f403fee88ba5561580d4de4cdf600bf9e9816c9da1e1d2b91a1d966d04cdb98d3be55fb77af2daeeed750b8b60b494accaa12441d372afb3d47e7395b9e0e867595a1a6c8bff8638bcb138ddcac2f3efbf89762b68ebd77247c89929620f1a3cb8dcaf9632fde0996b33e6b2621da25924b4e2c8d6bff28ae0867786919ad763e6d79fc304a06277955795a7cb17186fb6bdfa98a16189544b228f3bcd3698737ff55b6185799459b796a63c6a61cea9d20f1e296d62f474c43750b77944e5f1c09072f019dbeeb64e9bc8dec4605d8e0322cdd97f56cc43084f5c983a584855654366fd5659ea23c6c15e1d7da51d82c683aa477b9f896563a5134c64e32814ea88b7f7af760f18bc91e656da92b72e98bc03f1c6bfb442830305529d681dc6bccae66da9b2e61b9c97e2397fdb92f7f6369b470529c570c2d3b329487981d148a462cdb992d792e34dd233e1c239657b8da0d59b804566cf81ad5f0a7a0ccb3a8fbda673887c153d2e56c484f9230d752be52c1e35bc9af5a7446237fc072afef777665c264c18e6a3c059fde2e8368f9bb898f1cc8393d1bf18b1757219670275f0bbc7deb0248c68af929111e19737479bcabab732d7e033aaeb277eac05e185e9e56b2450beaac784dd0308b7a5e8ca1f2fcd8852ddad9f7b7de264478e1891a391aa89964dae5ad0b7a829c2c9209db346ceb26c1b967cfac82ad574761443be3f0a910968239d23b11507ab978b3ce89e22b7d7283736b9786544ab4460f"),
+    hex!(
+        "69b148d627ce6fc09efb50368937c635f5528958bfb5aff0a10760f1943f9356"
+        "3a8d6831fe310013023c5f3a3435c395"
+    )
+);
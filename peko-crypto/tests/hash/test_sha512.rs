@@ -0,0 +1,519 @@
+use hex_literal::hex;
+
+use peko_crypto::hash::HashFunction;
+use peko_crypto::hash::SHA512;
+
+// The following test is extracted from the SHA512Monte.rsp file in the
+// SHA Test Vectors for Hashing Byte-Oriented Messages:
+// https://csrc.nist.gov/projects/cryptographic-algorithm-validation-program/secure-hashing
+#[test]
+fn test_monte() {
+    let checkpoint_hashes = [
+        hex!(
+            "ada69add0071b794463c8806a177326735fa624b68ab7bcab2388b9276c036e4"
+            "eaaff87333e83c81c0bca0359d4aeebcbcfd314c0630e0c2af68c1fb19cc470e"
+        ),
+        hex!(
+            "ef219b37c24ae507a2b2b26d1add51b31fb5327eb8c3b19b882fe38049433dbe"
+            "ccd63b3d5b99ba2398920bcefb8aca98cd28a1ee5d2aaf139ce58a15d71b06b4"
+        ),
+        hex!(
+            "c3d5087a62db0e5c6f5755c417f69037308cbce0e54519ea5be8171496cc6d18"
+            "023ba15768153cfd74c7e7dc103227e9eed4b0f82233362b2a7b1a2cbcda9daf"
+        ),
+        hex!(
+            "bb3a58f71148116e377505461d65d6c89906481fedfbcfe481b7aa8ceb977d25"
+            "2b3fe21bfff6e7fbf7575ceecf5936bd635e1cf52698c36ef6908ddbd5b6ae05"
+        ),
+        hex!(
+            "b68f0cd2d63566b3934a50666dec6d62ca1db98e49d7733084c1f86d91a8a08c"
+            "756fa7ece815e20930dd7cb66351bad8c087c2f94e8757cb98e7f4b86b21a8a8"
+        ),
+        hex!(
+            "937d7856a82a84c163c79417d0540c47daaf9ffe662c843737dbbcbe5f865bf6"
+            "f47a9d2bd10129a4f498073094653c324a2519a1c71ac1279b1623ff7d24647a"
+        ),
+        hex!(
+            "f8fbc058c2b9f84131c9decfa543a35ade41581f670398efd61b3abfced9c1cf"
+            "cb5324f2370487f9c59a65bc668ea596c8d22ce8a33014dfad28357fa7d05f04"
+        ),
+        hex!(
+            "4ab0c9484ff5c30fa64ae6e81510c5fea566eafb88f175f8bc19109f40fe8001"
+            "4c8b77fff10b8750778429bf3c5497e4cb92d9b30014f4cb975dff2a45244c28"
+        ),
+        hex!(
+            "685179397554d276513d630234a03419808c698abf2600d7490aabb8e455c6ab"
+            "6ea412c7729dc140a79dff66533c6946cbe90f9da9ed16e2e629db1651bea870"
+        ),
+        hex!(
+            "335e6e941ab7dadfecdb74ea6cb4e8584b6e3408841a33a6cf7fd6a63294b193"
+            "0a60983240311672acac3840a90e64cc366ce75081b2252627e9c31197ebad03"
+        ),
+        hex!(
+            "e3217f6af6e279e9445dc3738cbf9ba0e9edba0455844a73648139777afdea2c"
+            "4d8032e214f541bf92675fb23f24df8e4fe98e0003aadfb6d8f9cc2cd799bbf7"
+        ),
+        hex!(
+            "ee2fdfb3ae630613b7d890977cf2515deac272a37f27e4a01961ecf103d4ff5b"
+            "45cc8aef53b635dd75aa51aabf71c0642555ccd3281e0388f8ca09d83258cf30"
+        ),
+        hex!(
+            "6a30d97cc98af6a25b673dce7aeab8d762bf2e55ea0c6dc899179281f84dd02a"
+            "2896f77e9c106b472f55f7adbef7b1157be567ee1236ebdac2a3c5d8cb133eb5"
+        ),
+        hex!(
+            "ac1176abdc5f71170183d92ae55856221b0d95590af11d9d72ba605ec026bbec"
+            "52d6974bc43a1efb125ff2b161fbdc616fda00f04193a0bc26aacdfa052a5741"
+        ),
+        hex!(
+            "59fa909480620ecc08d34531a6da1b55158b74fc93ddf68e1d242615b6f3843a"
+            "7952e63e798c6445cde1b07e0be09d0d711cb7b42a0e7760a593b08acfceb63d"
+        ),
+        hex!(
+            "9eb253319efa61b864f27bd334d7dd78b38d3265fb544e0c8edee950a547e1d8"
+            "db921a285774ab94d66beae933298d20f2a5aa87c62fe1e383cc3b18e7af18ac"
+        ),
+        hex!(
+            "81735324005671f7bdad9e685ee8257f5e0622b9fcb5d38dbdfb2df27258c3e1"
+            "d46d76e24c0c92c744e1b50a2b4b0d31525b3af83cc80a75722d921bdeef59c4"
+        ),
+        hex!(
+            "17498cdff4323bb8021e44eca6559e05d8ff9a0ef2ee9d4ba0ac6e73f83972a0"
+            "dfbb6d47728fa70311d7c82e154966e1b7678263b0f65133e9116969193d429b"
+        ),
+        hex!(
+            "228c4574d7c45eb9ba9240722133fce74abe00c7328ab30b4bde373dc79afdd6"
+            "e0569d36268cd5eaa2f27205fc00512577bcbb6699e1d66ed85eafaba7548afb"
+        ),
+        hex!(
+            "3d40ccd9cc445bbecca9227c67fe455d89e0b7c1c858d32f30e2b544ca9a5a60"
+            "6535aea2e59fec6ec4d1ba898cc4338c6eadef9c0884bcf56aca2f481a2d7d3e"
+        ),
+        hex!(
+            "e1e577aeac92e3a2b7f8a262bf2ac9c037d2274ca6618fbe4cc21db7c699e994"
+            "6b6671ae45ea433a1e392a5bc9eec96fd641ba8f4a047f022a04a337227004df"
+        ),
+        hex!(
+            "5e4424c0bcb2f0f7a2428821a9d5840a82401f4440ae6bed25c53cd9e71cf9d3"
+            "9904d6a375bd721f4332ab0202529c91feb9c094c3e6d34ca4f66649ee6fa212"
+        ),
+        hex!(
+            "56b199d63ca37189d5ca0d40006ac7bcb9f39cbdc00ef7b8a5697caa7d81d05b"
+            "645a146995b1151d01958f1589337e14afc6e7dd10a815170e527a398e6ce8c3"
+        ),
+        hex!(
+            "d2d498ff93fb03013a64f295b5bc68e57d2fb5600da578aa011d43ff432eae3e"
+            "0c800f9e2a53155e56fdbf5e068fe2b4beb3e42b2585531b8b16c4d8ca3356c6"
+        ),
+        hex!(
+            "3d3875489903710f17cf4247b5842ace6f017b1a3b99e9ee5fbc04fc7898e78b"
+            "12693879878028ca40c63cd0f6925fb7d0ca0412e4f06619e3ace223690f03b8"
+        ),
+        hex!(
+            "a013e21cd1234483c95c2ea2757be949bc79401ba39b09c316a1612d594642be"
+            "65ca106e12695ac3808c57c6f2980e895fd1fe188946562afc238414e1e43649"
+        ),
+        hex!(
+            "c5f6367d7195489e16242f912fbe0d8002e947de3a7e9c53f77b1e5e90e05bd7"
+            "ca395e787e34cb5f500c02da59c9d83de35601de7ae80dae74a0d6b4a292d43b"
+        ),
+        hex!(
+            "7c28c44c6aaba83c122f24d68273e28a5afd65b4071d02b7ea3300478d511897"
+            "1e1356ae57cbc70d2a177ea464a1c2c50d4297b933e789c63b1481797ae8f08c"
+        ),
+        hex!(
+            "af7cb42b1c70a85ac1ae1c2991b25b657c19f4fcf83af7f7dc0ae1028c1452a6"
+            "a17dc98929634fe6ed3855b70b96bc2caa93d82037b94ebeddc77e4c1a7cc563"
+        ),
+        hex!(
+            "bd56ad4c0cbd162706053da929d667253aadcf417affb483fff4f2699bf406d1"
+            "28cfdf5196dfbb05bb89ccbf04c5147bd2ebb3156b0bc1768ca6faa171c91c01"
+        ),
+        hex!(
+            "004d7b0fff9bcddf4b3913ae190a76728705a3d23874d92a8b7ff246c8fcad46"
+            "623cb04723c8aded0cba4968d1a8cc1375b99005786c1bcb7ae4bf13325c3ae0"
+        ),
+        hex!(
+            "8299a5bf5ed64f525c4eebbeca969fc1b91a81adb58c584bdd2d7676386a31fa"
+            "546643a3cf505007584f02fb712d708cab645bf078a1b9339f5a76aee985d017"
+        ),
+        hex!(
+            "ce7100f3455db1a9776a9f40d562ea998afca1f9fee7e0d81c8db34cf68ad23a"
+            "8bfa6fc04774703e1e56d5196b66966158fcf2a8335a58c6ba7ba1af756ba1dc"
+        ),
+        hex!(
+            "90aaabcb655ee921b8350229efe6064a60051cf0cac858fa3d43afd5b97cc823"
+            "01bd1b8cc1f874022e5af948185638783a13ca1bbd5049ace7fbf4f6d90c201f"
+        ),
+        hex!(
+            "3cf0a25b33ded3e0806dfe603b9987f1d6f2b3fdcb1ec7f8566828c00e17e8f5"
+            "9e38b3bca302396c7525ca194e6cc8501369059e2e34ae21e3141215876847c4"
+        ),
+        hex!(
+            "bdc5266aee339a1ff13fcf5229773cd3d14b47101e83076927c160bb71bf7445"
+            "590525a2012d52af008e118e16df1b6bfcaf8f22b4e45f9e749f3c20625a2bc8"
+        ),
+        hex!(
+            "ef8d2ba885381ab97756d59dbbbf53a1ea35d152b2d8f82c3518430aa34e7083"
+            "59194ea43950d032e151f576d343a5c3cfe6b71d4ed0ead9d3a107402589bad0"
+        ),
+        hex!(
+            "194ea5324c4179998dd7057755f255fdea04dadf533f7851e3e9718b610948e3"
+            "2fd28323077d9421142ac808978adfa325b668c8599a2e01c757a5a14ed2dd37"
+        ),
+        hex!(
+            "106984d2f0087e621dae760552bc6279072267883c204079481af6034354f1a2"
+            "b77c17e6c039a1063e479342aa3ccd90330dd3fb5a7d5e976619497e2d3326cd"
+        ),
+        hex!(
+            "a1347216f1a6db47b90c4ded3c5c75440f54c22c87d538314d1340f86f88acba"
+            "01378acb933ddad0adc6b75d55bfb7e8efc9c4a531b2a410610b7515b6dac66a"
+        ),
+        hex!(
+            "b76e4db147e0eaa4f04880654088b9d0fce518c8c377d92c846345604dc6b2b1"
+            "8d377fdb8e30f06d9bcfe6d7dacc07d6adff73d98d49f8f132b80f3084390830"
+        ),
+        hex!(
+            "acd4e527763dfd4513f0def0b1edf8ea12dc78d336b7b796f3dcc32e10687254"
+            "43a2f55ab4f666b27d6bf2ab39669c98293f0a9108051fd3144d31a1ed171ddd"
+        ),
+        hex!(
+            "10128c15494bc87a87374f676ef9fe2df20b36ffcca41a80bd40b216637b3de7"
+            "10efd070e277827820a7bba3cceb7b21f8fe7f9775d6c4df4d3da5349434ec49"
+        ),
+        hex!(
+            "2632dd5c188c6ed3a4610405fdda704add752f5424d9de65a51400fe478e26cd"
+            "0412e5f91ca4b744c34f4954f40a3a4254431d21954623208b527b7b4daa687e"
+        ),
+        hex!(
+            "45707f5b6fc5ccd1f78d77f177d10fb8b462c74cc821518cd5cfa4b5d6b40b41"
+            "8044900693c37abbb82367d340fec67f800d74072935da1706b4d90ae26099c7"
+        ),
+        hex!(
+            "56c37f31220b5b3040373d91b2c5e42fe9e601a12f7f8dc4534459bf28e484b8"
+            "713db243c5782c031e674003a3c14c42fd152e7188789065e82795e10f87d54b"
+        ),
+        hex!(
+            "5da94c899d48bd8299fee3d81662f8d6c5f8f8bc54d18cb0368b13cebaee7ad7"
+            "1e74ea80f34974ad166f04f9a0602809166fe4085a475a8ca86cade12b6754c4"
+        ),
+        hex!(
+            "0664363f97ba910760b0922e31ca880ca97469506cb007e3108c36c3ce3ce180"
+            "1fb4197609479339e8820632b6a38bffffee05a9adc11cc544b9aa6f5b95cc6f"
+        ),
+        hex!(
+            "732c41a1edaa727c04f627ff158aaff67c18efd667216132b99ab84d108996a1"
+            "0bb008b5d803b22ed1aa78bb0d10f8a762fd34777d7dccce8e84827ba88d4193"
+        ),
+        hex!(
+            "fc9c21d67e393a2b05a23a17d8db630cbaebaa3def211181749f1bcad1815606"
+            "27fb60ee20fae2e5980cbf50fce0a19dce807e7fb75c4da0ef008bc75d413a65"
+        ),
+        hex!(
+            "0453b765afc1edffa595efe345177f5805ed3abc1297ceab757ae7161723a614"
+            "4cb543299f418049276d16b7896662631634fab9549127c10f27505b7dee8665"
+        ),
+        hex!(
+            "3853f3bf024e0668e8d1ea53733a97537f97d9307c5f3a19864ab4eeb1654710"
+            "693bb961a344dec8a758f5e64b26fcb6dd423419c4a114fa749211a9de06c281"
+        ),
+        hex!(
+            "240137f0dd57beb3f7fc283bb3ead423c67883fd46f4e27471d7be57ad469a49"
+            "bad03a3658418bd55614678f3a463bceff85291314b90ef43ccbcb028f0a7a07"
+        ),
+        hex!(
+            "f9050a5271edbe4cfdb9520ec05bbdc3cbcb9bce36fd212338d3e7028a39b9ab"
+            "30793e561d75a2e424193264c7f0775e65599ef0c94e0ad24dbfe18252364267"
+        ),
+        hex!(
+            "47caa7a5862fad837aaa409a4a9df2575e645528c35159115911b7c4e2f08ae4"
+            "9d68de97249b31b83ce2c163f649cad4559dc6e6a7191f2922d79a5fd6af167b"
+        ),
+        hex!(
+            "13f5825c41fa49edf6104e3e35c9c224eba93e37374f730004c39c54e7391e4a"
+            "847fd61865235a3fe32224c96fbe86f7e14c3d5df496e83ec989a71b4f293a44"
+        ),
+        hex!(
+            "e5b55e05efe1ca6b9a96a57e3a1523d610d70f837e93b31fa98c2736d3e114d2"
+            "38d46ec6b6e3d19e774b253f6b0c7a2ebe69b7e60fc0874444806b2a2278df45"
+        ),
+        hex!(
+            "f14a586ac30f0af255f597a9aef9abba5e99c04d17b01f24427c4ee2c196b52a"
+            "cb1ceefc9b15cb822b3ecffdc2f7c49e11d3fc0769acee33361537d379c62e0c"
+        ),
+        hex!(
+            "7e2d3398807195c48e6ec52d20710bbf8b21ea8de4d1abc197897ccc58aeff40"
+            "259edc67270cdae0edcc686c0d0dccc5760c1495ab1cf48482dc2000ae2d42ad"
+        ),
+        hex!(
+            "2f3d5c5f990bf615d5e8b396ccbd0337da39fad09b059f955a431db76a9dc720"
+            "dffc4e02c0be397c7e0463799cd75fd6ab7c52bec66c8df5ef0d47e14a4c5927"
+        ),
+        hex!(
+            "483a1764d308cc494a2b543d29ba616483aefdf91c7769fd084eedaac1add189"
+            "1df95d317a47430b2bf73e4081f86597020e28afe2d34a22b77ea62b6112d09a"
+        ),
+        hex!(
+            "bfa88691ec951511651c6f14af100eeb26d87729e18ac3ef49a80d73ffeaeea5"
+            "3e97c4a7277a7ee9f2fba070b1c9720d6cdba407dd82267019e3f0f5662b2f2b"
+        ),
+        hex!(
+            "4c17c8e2e7132dbf82afebc40efc77926d16f4d2c082d846dac28733aa767e28"
+            "40ebf04f2563df75933466a36e11968d342e4157827605d04d9627ce9b5216c8"
+        ),
+        hex!(
+            "70bbfc29a2a765220af84e7bb10d759a3152ad4b5643ef6b89966950ec7ef950"
+            "3d57bc0a28c4ee789a60bf9dcac59139e15241d73b990410cf92eff213da9eca"
+        ),
+        hex!(
+            "8d1d56f37fc19b84984a6fa33aa9c2dbdbf79a29c04ad0b4cf20333e6bec9434"
+            "47be2416242f8cd2f9732e79bb925cc5a61a80c5fc9c079961243fd1c1f5900e"
+        ),
+        hex!(
+            "492fd0171f4dcd5d20ea6c0d34b5576c8894664ae5955e6737f5e3b711c2804d"
+            "99ccca065b7ec18c82da98b18a3029b765c51ebc7c433b36492e0ed6b8511bb6"
+        ),
+        hex!(
+            "7f49e8e54db7e5b4323cae2db71f3e8b8eba172dcad3602e9b7b058007a55893"
+            "58732d5afffa56072a46e89b1ea27ef8d556deb86b569c635d394f15d99d8a15"
+        ),
+        hex!(
+            "56884a6a9210d5f371e25823efb2511a9c410c26a441e07c1bdffe8605084267"
+            "d49c315baf6a692d7d97844b2714b4930877a5d7f52cf6fa151700fcb6980546"
+        ),
+        hex!(
+            "6aaef8284eef221ecb17ea3c9596f075b5155fe7b925d737ed3c6543c761c28c"
+            "7cd9d9d4b5e2a37b2f183a2a367bbd34b633497bc7a1737d61c8c1f3ef295062"
+        ),
+        hex!(
+            "38ef178f5688e59d47c375252db7b39f40c0c84169878ee7ba5086e4b25fea81"
+            "076b9c37847e9e6bf24ae0b343689c265ec5ca7469e619acd61b0276721efb1b"
+        ),
+        hex!(
+            "e3fe1aabad120777cf24eaae289b486632ca46ceb89afae73dbae5fa87c76787"
+            "9369355a9cc5c21ca604ed91d0f2f58c466573f3e6d88e52c62c0d3cb188e141"
+        ),
+        hex!(
+            "82f5bd920457bb2763a0da031a7fed47b236951b1ea420c20fd2b6de1dbfbb9c"
+            "4600ea7092788493e2d4be6ee24b6dba04e57af3e8f2f14d9837295420ac7631"
+        ),
+        hex!(
+            "6d0b26208ba9b1615067bb3ff97b292fe67e4c02d240d649c32370e0a4cd22d0"
+            "3bdf864be4d24a3f5f51aeccfd1afd5191e590edeb5f7bec323b0506c3104b89"
+        ),
+        hex!(
+            "d081083158054d08371ec84f4d3aa5aa761734ac6091a30330a861fda056f835"
+            "c750bf4f7981af1693ff28545366bd05cec47bccd77a7d237befb0135c534138"
+        ),
+        hex!(
+            "6ba8b52780b8a07a2a2015dd8f0c5e7437b8e024c4ee428f7ba91dfea118cb72"
+            "a939872550983317132b841b7cbc29a22b8f1cfea0c55203cafc69b55ed6244a"
+        ),
+        hex!(
+            "312692b0a51f002b7f06d05b39d15a5637dbddd2f4f1a73e6c88a4c841cdba5c"
+            "d8e69c0939ab39bb1a9c54fa35402143c97edb9704a0e9e1a98701710f6a5dad"
+        ),
+        hex!(
+            "aaee960de201a8dcccff95b834fccf0dafc03fe6cffc0429162bf4aff01165ab"
+            "07a0c9435e9cb412121b7ba010657ccc3152118602b665072136317d92fd4262"
+        ),
+        hex!(
+            "21fdff552e08c86c07f080cefacaaaf31846eb893bfe2e4f88c3c3cd8cbf592a"
+            "84500942695a5e5ae971ab343ce2695dd1baeb1f94dd4b53d678e14265e421ae"
+        ),
+        hex!(
+            "ca8f1a5b2172f6adb474da53b35e3f73ffd88263d3eecde72e48b16e1a065801"
+            "5b555ee319005a1d82802e91431ee777610f9b1028d819921e1044ad426b0270"
+        ),
+        hex!(
+            "ce5ab25eff9c1ddc569a1eaaa66b689109ee269db7066e0b02d39b3564fd14ca"
+            "6249987b7791e203d3d7c2ebf18558d2f23f94c03dd1d03aa63849e4d2889a76"
+        ),
+        hex!(
+            "a6f8b0561000dd4ae8b828c5f676e8c1a6474c4a042a645f1815bd52e9ff53c9"
+            "7dc36d5d8997f8ce332185feead76267f5b2e63f597fb3345ca0046e58fc0f24"
+        ),
+        hex!(
+            "fec86794bad4106c5ad1c1a2d9a1b7aae480396ec231eb5cac21c4077d17a0b6"
+            "52da0037363399a5a1dababa4a40e4c54b9124167580dee9108c4dbb24c57512"
+        ),
+        hex!(
+            "594f5dd3f4c87bdc0d81309386e9163a9718e34c7b0dcb4613f8487aa786f9d2"
+            "11cfb61bb247fa9f5ecef042e710f192850f5571807294bfd8a54397850e5773"
+        ),
+        hex!(
+            "d81ad866f25ef6a0a6431d267114da564513e5ebdcf48db7e95db8cf32a89f0a"
+            "b107874d796035db97420ffcf1db5f04dc1a52ddbbb960fc63b7f3f835cc8be6"
+        ),
+        hex!(
+            "431d537e098e9949f6a68108d55d20952e3bfcdeb7273bac3917e37790a84fa5"
+            "db04c33a79c113a06cf333e831d7702a00853a93fd0aa5146d934f4f71242a6a"
+        ),
+        hex!(
+            "4ed95636c6885ae4e63d042e82f4da830c702dbf3b9746d64770a64dd666b332"
+            "08315f3a947c4dff790771ef283788a9c74da83e22b97f750286a820ee46698c"
+        ),
+        hex!(
+            "a9bcb60b4d7724cdddddbc232b4ac70b94d0d7e9f0724b1222d918930cbb9bdb"
+            "b04b3ad43e3c8caf3bf8b004ee4aec6bd527ff8eb6189b44827f7ba7057f6a90"
+        ),
+        hex!(
+            "d6d5e44d5bb07fc4144ab6ab309f048968f73f7992beb326047e9e2cd7af6240"
+            "bc8abf46703c32fdb58fb2a8672594a660ef855be74f24cec09d4fb00219de82"
+        ),
+        hex!(
+            "dfda9ac0c7147530da97715ccf47814182255f2f2cf40287db97a4c63b43fcd3"
+            "9e6d41e560921492badb253a7dea0aba863c7c33b912bb59d1ff4de03a4f03bb"
+        ),
+        hex!(
+            "0395faaaf2e907f27779d6f1cc9c9db68ec390a38fbb0702c6475b46f7a39949"
+            "8d46fd8014f834b131e1e83abba0359b1f16d8fc0a393580615def2ad0caba73"
+        ),
+        hex!(
+            "41cb98f09029abe85d24a0f131f116c7f69f54f7e91c250642606512bf3da4ca"
+            "89ba70a4714a5f66d9ae81ff09317dadaff12a02057074c970f0f02a52bfafd2"
+        ),
+        hex!(
+            "8e8f161d48e306c5533ed614b8ef3a1979df6db7e13d0780a73c4a3980ddf0a9"
+            "5f93941d412c93683e39915a660c3fbec0dbb1bb6beea2e2099cd968011535c0"
+        ),
+        hex!(
+            "789593f0b8fb83ef9b3ec50ab8f6e1e47344f763d4f7ceab5600989e7b6fd5fe"
+            "f6ee5e487975f64474af6cd71ae4d9ecce8f009edea0227c7ebe73080b8f961b"
+        ),
+        hex!(
+            "f37e1449e0b313d9537a6177f7a31158d353e5b79c781facf02526ec94e0c6cf"
+            "da37105bac67098b194ea82efb307c2929a9ab8aca0e76c53e829e3f901cd245"
+        ),
+        hex!(
+            "2e74e745caaf2d449ab3b031dd214b48616853a512cf2e95c40cb8e7594fe5e4"
+            "879ac8a26d02eb35b3b96a5c9e7dcae3e15fd050a0bcc1fb3b9cb9c4df0fad3e"
+        ),
+        hex!(
+            "6eac7069c26082e52574ca6a58abb9b1b9faf452e8cca9f1c7023679ce192ca5"
+            "54892f30e38104d39088a24df35612444a0fc90084af7535fd9344fa51dded84"
+        ),
+        hex!(
+            "ada6caf30c4f6e3644d952366e01519af6771b406e2c447552f0c597b8dd10e9"
+            "e9b4e699c9a835de03f422be8980538d9786172dfd2fe511db272a1543d5aa35"
+        ),
+        hex!(
+            "4d4b0086b2cb05d713f2805caa7e6605c8f7dbbb2e0f92aa159aebdcd6306030"
+            "5f47b748f1bca6e0b6e11cf8f9697fcccb6584b878c4b54a699290728a40aa1b"
+        ),
+        hex!(
+            "97420b8a0ad102aeb92139da2c052d2748dd7d2dbb93a9ea79dc15b520d0ca7c"
+            "ab8cb7a00f5b5aebcb49d7e7f52a27180935ce617aeecdecba04064c668edd37"
+        ),
+        hex!(
+            "4aa7dad74eb51d09a6ae7735c4b795b078f51c314f14f42a0d63071e13bdc5fd"
+            "9f51612e77b36d44567502a3b5eb66c609ec017e51d8df93e58d1a44f3c1e375"
+        ),
+    ];
+
+    let mut seed: <SHA512 as HashFunction>::Output = hex!(
+            "5c337de5caf35d18ed90b5cddfce001ca1b8ee8602f367e7c24ccca6f893802f"
+            "b1aca7a3dae32dcd60800a59959bc540d63237876b799229ae71a2526fbc52cd"
+    )
+    .into();
+
+    for &checkpoint_hash in checkpoint_hashes.iter() {
+        let mut h0 = seed;
+        let mut h1 = seed;
+        let mut h2 = seed;
+
+        for _ in 0..1000 {
+            let mut hasher = SHA512::new();
+            hasher.update(&h0);
+            hasher.update(&h1);
+            hasher.update(&h2);
+
+            h0 = h1;
+            h1 = h2;
+            h2 = hasher.digest();
+        }
+
+        assert_eq!(h2, checkpoint_hash);
+        seed = h2;
+    }
+}
+
+macro_rules! test_sha512 {
+    ($name: ident, $input: expr, $hash: expr) => {
+        #[test]
+        fn $name() {
+            let mut hasher = SHA512::new();
+
+            for chunk in $input.chunks(13) {
+                hasher.update(chunk);
+            }
+
+            assert_eq!(
+                hasher.digest(),
+                $hash,
+                "hash for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+// The following test is extracted from the SHA512ShortMsg.rsp file in the
+// SHA Test Vectors for Hashing Byte-Oriented Messages:
+// https://csrc.nist.gov/projects/cryptographic-algorithm-validation-program/secure-hashing
+test_sha512!(
+    test_0,
+    hex!(""),
+    hex!(
+        "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce"
+        "47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e"
+    )
+);
+
+test_sha512!(
+    test_1,
+    hex!("21"),
+    hex!(
+        "3831a6a6155e509dee59a7f451eb35324d8f8f2df6e3708894740f98fdee2388"
+        "9f4de5adb0c5010dfb555cda77c8ab5dc902094c52de3278f35a75ebc25f093a"
+    )
+);
+
+// The following tests hash the messages of the SHA256LongMsg.rsp file in the
+// SHA Test Vectors for Hashing Byte-Oriented Messages, so that messages spanning several
+// chunks are covered.
+test_sha512!(
+    test_163,
+    hex!("451101250ec6f26652249d59dc974b7361d571a8101cdfd36aba3b5854d3ae086b5fdd4597721b66e3c0dc5d8c606d9657d0e323283a5217d1f53f2f284f57b85c8a61ac8924711f895c5ed90ef17745ed2d728abd22a5f7a13479a462d71b56c19a74a40b655c58edfe0a188ad2cf46cbf30524f65d423c837dd1ff2bf462ac4198007345bb44dbb7b1c861298cdf61982a833afc728fae1eda2f87aa2c9480858bec"),
+    hex!(
+        "ae4b5efe9d7e301699dc03ec1bc1f60f83565be9a40d8e38c660a7f9463b22b1"
+        "ed907f9f4024c9d0ba30c9c38ac12d8cc354d8c4539e63dd6f26a0ce9ce7b71a"
+    )
+);
+
+test_sha512!(
+    test_757,
+    hex!("42172d5fdae447c4cfdd13836bb8b833c0cb85f7327f92983501a4d7583a5201830266c37c908640b0351461314b526cfb68cad97bd7ed615248fa5756c6213bd9eae98d2f4ecfdf6a452f2e68c9687210b53c74d83575e08a7ace9b49b21056cf377c64f80669c884742e93181c426d871ca2715081733e68ffe94a39e6677aea51e8f0e1a09d258629d7374a2b2884e903c577eba32fa2713f130d2e496eceb4a0f4daf105b31bf9cef4c306de62dfbcd46e2fb283f1352fa3138c31c56d7bb48d6aca301bf3d464ca4bde521d37a78bf66340ac09011e2991b36e4941aba8727e1067a7cba4784f85a53138d0f104dbd16d54e21ea686e772b95c7fa6717e77dcb05a5dfe102e4267c963bfdfd61d36cd53105aa82a95f2afeefddada07254a10104a5a9a7d1fc6d8811def322f1b2352df1e1e90d372d1ae1afa62c6b5c47380f9e0a788347362409307d1b243252bc8d72636bfea460cd905fa1f52c3847b9632c44bb17d519f07c8c86c455c64d49704cfa81cb6382c9776a61a67788ce9b9859d4efc9fe10495e809c9d4c000a9272ec27e8e8171b84f37a65aeb1d054550b814b950e44d1952bb71ee48b8202fe11ca7c0ff9119386b0ea1e7c8fa1618c594d0939792ba66a708a9e5878cecf02b9825745630573452c43fcae457e8e87fe17ae4b8f25274fa9958b67b848d736e68e4a47ba453356c21290a297ca240e667b9b59b4c3dcab43427670ae82b4013558d57553536c221ec07af7db06da562ed360d28e8a3f03ea2be021effede08027c896ce2d2864d9ef80c2ca3d71a15b3d98f4470dab6ffeabc48e9e12fcda1fa63c68cdd250a2fcf03d49f769d5bb391d8872e0057dce5e16e214726980b6579a92d53b6ed704f2b8e64fec7dc27c6456ae90db164295c5adbf9b824ca0fd8fca71e5fe47e412230f22d991c05f6a45b0b1552089224d9b36042bb603843631ff82a1ffa5a055f8bc99f1ce7cd50f42f23aca97a6447d477a58ccf6d555e9a4016d1026d23354d789f49e8bf74bf3c4e6f0f529b4d1ad334164872a0c3b9e5098d93a"),
+    hex!(
+        "b466e45b28f36e3ce728678df14933aa4e25d01844fafd232e359ffa970adc0a"
+        "4b852fe38deb44e5044bea22e537ea17e915f61e0f8df7b8b2a493a6bd44fba1"
+    )
+);
+
+test_sha512!(
+    test_1648,
+    hex!("3cfbc77b8897b6a5613f62f6b1c89b0d68f272c6c19b9e0ec6331ef616702006e64322d3460a57d3a5074c719811cb5dd78900268890da0ac177b40d487735489da374843a1a6007198160ae77b1363cd8ac29f24bd66360ef62987ab60ae8ee690307b5ec309be8c496e5d6d610a453714336538fd501b758da1166e88f02a0524b218fe5b2ce1fae2c25103d96dd4aac376f70def57bb705c868f967704c0561630b3aac0ac254df2c668535aba8c8916e1c72bf9e9b09fd15e65aba138bc69d330dddc99e3f2e607ff15c45b7ce7527d18580bb38275548a7d0b269e28a8a0fa46ea0c5d80d55380b0ebef62218fa7648f2c3592be842ef687128fd4310fabd9c78ac271ff3726fba04d3cf544bff86bcaa6221dfa679f93f10e5fda0e4beb104712977daba2d0e731dc258b5322b6013f6869bbd29a26e13f410f160cf7df3c5a23f3e732a2d1b1d9fb419ae94270b371e57502b386457ce66d261eb99df89c5531402510b1ba1a2d3d09ba5389a8f0e6afcc7929d67bb57ae53d6a90a8e7deeccd34edc259ea5e9013f6503e72df582219e885b1e54a29614bad802e92fd72754a2a77c405f31da7128ff316988447a8d641cec84d31473e030ed5e006d9d5734a9979923054c5d6ab4f295865284ddf4770aaf968b1ad659dc9f2515edd968b512a59b9739ff5a360bc5990634bd959ffeda0a1e25bba7c8e775bff15a92411d025aea64a351b91b5400a4b0d5f889d6221567e24800ce7578f7945c5ad1cab4a33cee52ea4a6262b82c1d4dde3da1e10b422dac9def33a8b8ae0c1e959debc41dd51028b7f23e525ed06ea5f692c507e0e9c442cc93bfa9ce2190feb5fe8c9398adb6b0b15233356e74fe80c601dd91ca92946c7cf158bfe3d986c55ffc956a3b4a4ab081712a5112a035a6f591d1c7f0c605f45513e7341f5c583b3da0ee912e3632f1ce570cf070dd7bdf2c4a89f17b0c7fa10100554ea9346c28f7f180509af9d83b410dbf58b0b238f21335ee5792ed6a3a25c08856aae82c5435f731efa719a256829a2dd1fba8d4a85159e6415ef02e886c5c6a934901ff879ebbeab57c20fa0938063ca57946b7c98fbf5af6069d33537bdbe24faf064c88da4494ec4296471d0b5ad61a51144a1d746d33bfb37ce162fd45d7d0037f7d20929b15da2001f05ba59de27aac9af36ba96d57e48d16a17f98a233606ef5f9176ec657c73feb5f88abd7480bb1611b7dd5c7b0adbbf2d970f4fbdd4518ef283515cf40fdc6dbfb6fc810f01689f02b19a18125616698f3feba57bdc728f5724130bdbac3de5a2cd7a251c2e25bcf8908b5a59d4ab59155f192bfbb30c78f3f056699ac60afef5a87e1a1a6b950879f11b83a02aed1646911233abfc61c46f747606afd1d6022ed482e0984e1909fd4fe53493bd6e199952616d0b1d350a102d0c53897421a7b4de2319044a3429f8f962dd9300a0eb943f71289799d8a29c8683e0c61170483e31d913ece478e0f3aa0eff2c992804bda4dc06eff6df364b2d673ed43e347c115c1f61b0a159d783ce5409ceeb55ed7c2f0c8862c8ab3dc26e40be325f00abd4c950a30e0559762fd2dc9e087bcd0e562a8d1dae63ef1198d58aff6ef847600f1627284394ea453e53f31c57cef00fe243d837016cfd5a150fb062f89e8b3116af0d91ffb49ead55ce52154b59613a96f768852a58688fed595b995dc64e8782107e50a108e946fcae541941af9346fac1c858879ab32b886ee6f30ce3f3dbe7c6f3e4c5e65c8aa9708388d946089d741f725187c86fa55cacdd10948c3db6ae5ef8dedcf16ae0c2b16af4709123a6997cf0d0c945e095f08fc86273336ea04c3517af64ae8701feed74dcb635870ad166ce86bab7d875eaa41379375e191157e5bac6eb625336b4913bb20887a532c26965c3ea8299bd817658c53f80cf9247ed6cc5dfd132277a291a04e62c00a6d3014118c73ab6d57b7302c71a2ff4ab92a8981cc06fa62cb621aa55ed771bef89aafb15df44b2662f269c0f45d01ab5cec3e74c1b9241eeff2767257d1d79656ce2fe1ba1350ee8230daefe5105b44962bbb4d47fed87c6cb86dae413738bb0e325c4ba9b804ec3dd949624ba3563eee50c9b363bd5da4c81ec60124f1dd54a830fd9735cbad491e557fc12efd38c1edc1304e1fdefcbf8e8b4b1509e60e8aad2fdc6d6334942caa0c44b3970849f419e7b8c78249fdf54367c28ff7001385a497bf733ddb01be6b675fe08cabd300fd2a29c694e7a86d7119f41af726336c15cf6f54caa83b27e8cc9af118ce1c0d1ef34af25e9e44509f0ba95c"),
+    hex!(
+        "6ba1acc3409b94e55e82481ce69683c2a41b4b0e01d1913032a32102037c9638"
+        "ce12a21c59d2aef55549bf4adb8de2f1a87547ce378bfeef3070993a0ac044ed"
+    )
+);
+
+test_sha512!(
+    test_6400,
+    hex!("37ebe98ef52bfb240b9ad369153afe081bbcf9d7ae43e8ba336b8ac57e8a6da0a3365e3008072473bf9d6eac13e509c1619956e12a06fc696512da091a7d40232c675e737713fcf51aea6c0316c3bdbe196132b0943df2b013860105ce676fce7b88d0a167d7ec72c588b7b6465a83c9ea1d748d15713455e5d0e901c3cf646a38a09b0002dc5ab1687f350dca35c1a87cd404c0d529292082f77844203d86be0bb8a9d970a9af7baad8d050cbd9e024788eca91fbed39db930398180e393d949ad7e173d9c65498339a6ec670d049058653ad48af45cc4cbffd30c3b54cf1b290052b1864bcafd0accdf9b8e2a163134d2c982c1bba4a3dafec288e3cfd0ae1934a6f0e39122aebbd7a586e48d495167620708664d31c740bd868c1ccd5f0e94baf959e81502cb00da87330cbf149d5a8381e9eb519a8b97acad7a48c5b0c92623b861064ff1ce8455f32469381e6198c7b8abc341357d6a4c85f7fa517c4a47df728ac09a6645b0ca77df7c70cd4aacaf19c280949919132dde7993e9181e647e964ba99cd6bd10b893c8d90187a5009a23d295d43bfb4cc0e583b8052ac21651b23813bfc9912ea0c574e152f42d3f1975309588a4705196598ad93e1ab1d82954b4a18bc56e55039b6837fd893fa2bd7c70e21a5934dc2e990379ec6e8a2445dc55d57940a14e5164273f59cd58e5f6a8281e11c09536ea22821c98ac978537d7a02220d1d6552aee168a001715834596baabf7813e1c69949b23eb4b86658fd51819eadf8a13f067ca8a791cd1d53ab69d0e43f18bd72d5d93322cc1c36fbe33121f5ff01905328fc7c33d452a86468663c77fc80b0195ec1eca05a5daee339042b4f88a1f9371b472c6c5168c00e984937a134b282633dea25dde7e397b907b1e7d3d240a593e747007990782cf944fa078a7118fbfa793b2604fa15b82453209daa64475d0e95e2408319e8b5ce7460f4593a19e3831a9b363b1c5ddbcd273995fbc61ce7502b0233b1752223352e654837181d01a929f49faad422c65b8ae416ef81290b02b48e222c2b8c3ed57cf0494b928c1e11ad2da77baacd427785096aae1cd593cc356e551bc390cd5765ea41be30cf0266ae2e97d326c417c91e90d75f1f874555b88a14a7c5959a62f23976b77a4c754e35dfb7ddd1700df85f61a62b12a9eb4644caa7f8ba036b9f29c6315ff96c3f7148284ebe3239ecad50641f397ea24b46e21655352a4109b61479b9dd34972779f2f1a6a1d2887b8ff88289b2ebda2efe995668879bb93c4ebb3a585ab336f70b382205ac37c383475fa12ebddfb95b157172261597d2cb0f24f254feffaf75d224a3b407eb54cc7c8daa5483e4a79c347252d808a5f480a35987f6f09f6c6a73bd5cfbdb76a11ed78b86442b810cb703a5dec5874e8721af62e386591bd39d990b3521505e144100601b46de3f50752911ff37bb18f377de45ec4c60fc4ed8ea1717708d2d13fc9e1453a1c4a4db9e4fbe9b74cb8da14ad50c8c8f2ec944e10ee8e82ebb6a081959b0159f043a15fa1cb59bc5e035f7623fbfaa99ea0a1d81ae8692a4019e5a5edb3a4886c789675039fde87222975e86c2642eb0bd48408072fafb1a88507194c9bdd69f3418376a4d9e68c3b83b3f800605ff1dcf0917a6014b0dd77708b583ce3ea632746fee0e01a10500cba90016b4a9072847d809bb0481ae25f74f8ef290c7a087ae16f505fd0da670826a0b1174592d184e3a7e8622a5c84a30ab64aab75face50b96b217e8ea335c0605c638ed1c59370bb9ded004be428f49a79f74ec0fb296b3758f0b6b41930c7e029b55c8fa73cba7dc926151d4043c6bc8a716d7de9ae0cd3ef3ab2d19b0c813eaf12eacfb641d492b0001b2f0f699bd98e4581fd44c0c817646bdd77a71d8ed432f8d422812751a2f9178cf1800ee689ebf046cf9b161f9a7ef0a106cbe833398bf383288661b426fad8d4f570a8293629ee06856af295a58585a81f87f130e6e08f723234856e874bd0adbb2fc9e676deab6b9f22faacf12e875d1259ccea54f7294be02a16f34c427b51a33be8a0c460c4c07d51a2e7d5c0722a9fcfefd21c265d5aa2c57ae4fe95556b5e1388ea9756a6afb0856fb8fbe1d2bb1838be7a95049848fa9545b616badb753c453f266836eda3c92cd592bc0925690c42cd6667f866717827ebe91d0999f9de5f5fd6cf77f63737b65927aebcf6cefc7ca107fda8447e8bebf1f08a280d53a4b07f8e35904cc48cc08eda3c63a3475924bde1de6acebaa65fec5ee68ca22d3fe722bf33267de628c9db1ceda3c78cb2f9988682d641d068023f96aabde4e10071cdec2080f616ac30c2725ad3efe98a69a56873615a3a3161503a4f22621986def597b66641d07793d97cdc9a68f85fd3890a38928462b2fbe2bc5c509631438d2e344d1ced9e2b71748f1b6ddf33a3e597de3af03ce43d305b9f5acefdb2b71acc645d3b55fa3848484b7fa4cf25e71e766702f1003950bd2f45b304052861f6748a8f38175f1e96c91471f5a54999cc9937191b6adc9de0d2520d86590cd4aeab292ba9ae474edb5b8caad6ee095c9e74c0f5e5c9387559f946b2dc45da7fa1d4c2dae6973d5984841682af25ff7ff29d9721d6c7e76776e8965b6c681bc38e85da15954ecbcf20d7448204d9a6a477781c1564d363e4c634c36fbd3c3b50b332f1643c415d004ec999316e75694a8b98e2591678388dc6624058454ec3a7ce608b3f222b8bad5cef77095285e1d2ad746c557222dfc30605bfadaafc4f292e931a0f0d49b226d99d708247879aed5b9f2ca2fe6fb414f37373f844e13865524f206c54487aed53781834b3f6eefb248d95ba21bb60041d501f90a97a19dcd80920df7d84309148e3d0892e50687c86a45a1372926e00f200053f5f436e003e35bdc10fa99d9328853bf82d2091f1f087cc37678138ac0027e73cbcc99f7fe37939c98114fc7380c0ad1a26e3f5ec00bc7eae77045a55c62c18117879389c662837415852e7a2d01ac667a226fedb2596e3e137a83daec2712a65e8cec3e644e738d11bdfe9b19517fa593546373fddcb9e681fc97d1763bb9092a456cc0dfe1aa0e132387d105e3ccb7746ee199aa7af00bb96047310585fed40219dab43f057220a41e90c5f89fdac4a5d6b207c01d5ad4440c5ca29eed292c6f7000c58da111eb4b16e31efa6df3f3aff69e6447ac406aa96a9ece4b5b813bf8b3a499d09cd0969073468513355d6c19346c58480feaf470e0d45a13b74f2925488fd810e0f74afb9e82a24cdf61586bfae68dc92ea09b22d8c8f1ffe9db1e7e98892b5554ce2e15fd5f1cac5347df2eafd2a8d5f1aa8746b9403915da6d418c0b5a3aa8e09d6b65f9a49c3b7a5728e9baf95471404fdf64eb05da5f704dbad60ac9ac106cab2873fb1bc9023ad95c24852337a703d9cc04d6df7de594c3b2e4fb9f2996e0418ec8698a4c087c14a2687717f97e228e75afe295caae2f16513f47a45b4124a7c5ebacbacc562951233bf89f43ff85b703ec77f168c2278fbe6e57a0e7192125f4642d73f2f227d806287081bd30149b9d44fdb90029667622f9925b7826bd0343bc537c66e660f174b447860e1bb8846c3edcb639ebd213a4695f9cb471e188db7a859fcf3abae49569e676dec857b897627cb0bc1155ad6d45282d430176fde4262da2d5f41ff890ceb319d73dda804738456f30a3d68da41554d4cede62aa8549b24e211e76768e6b17379f842a24a449a0ba3ea73cfc72624b5afd118fd7e76a7c6b5bbfa7a6b6c97b97dea52decd51cf35a8e277140ffb2748777a1e3cc3211f3c12be099d0316f45023da6cd200339a718c72a5ca172903922e59648d08dc67f173788363c26e5df406391f107552925ba91b9e569f38101f5eef9a52d201288372abf6532beb4af19fa6d81eaf473d40896dbf4deac0f35c63bd1e129147c76e7aa8d0ef921631f55a7436411079f1bcc7b98714ac2c13b5e7326e60d918db1f05ffb19da767a95bb141a84c4b73664ccebf844f3601f7c853f009b21becba11af3106f1de5827b14e9fac84b2cbf16d18c045622acb260024768e8acc4c0ae2c0bd5f60a98023828cdec18ed8dc298a306c38d1ece01509f3265b5f8cbf441f0525097e8b48234bf69f65cf402c7540a023ed231ef95b222a900ea4bfaeec02c6d8b3b01648ad7a165237ca6b557b1ce287b0ea137f4ef54534070ee793695a9078ec89bcea389956878614ccbf917b61f8427b7cda870fdd92d2d297154262fc65f28ff1a54b2651afff12d6f36ee8c906107bbda399ce5e2cf0a430ad0dd86520841757126bad725bf1593c7959f16221894f5852ddad3172fef866b3321755491fd44fba009b42ec0b6c4fb9e901d7eb3b8acf70e94911f54c538bd0559c5740042b6df4a07c3e00bba0934d92a684b39592a576331e5a44672a227ccef3e595ffa1146ac1dcee0a70baa9acfd5c132b361b5ceb519984b0ee00cd2124aa8acb50c9e574fb19bd99c8fef5407faeedb28b796848bb372beb3f5bde55ed2cb140b60a53bba2df471f330208b09ffb8eda04315a06d693aa53d9bff8939ef6f3a68de6e1975f79f50b3d484665e4ee71124ed794be3a2baa7b5b918e62a095bc5d46e401a0979641fe465640e8d4d43eeba9d0cac76c7b86d22375123b988585e58f86566fd190d868eca08aa1e66932d6d3b14ecad3efd9f8cfcf2696ed42eadfa642324d941602cbaebb8639a00a17542afda32117051e4fbf243dfd255a559c49ac37c265827ba70b0bc618882336f43e1a6a729c57be478008cae6c74840bbe828c976ac628d7b6015bcb705612c277bac0727da645480a0e14fdc497956aef05c89d30f22c2c96c6dfc9dae30617e6206fbd957975b8ba0524f563289e1f5f09bdb6fd46fa6117e78e854f91d71699fcfcadfaa7d4db8fcb04bed08d68d11677b5085b295c1d414cb12456c84c669737af6c33992a5a9149fc7f9330bb291d38f6bed10318081dde8fd178f02eb0e8b7d022c8b63fdcc867546035775fcf7b32c8fee83df7cbb28372b23c71459b9566a7f64165da0a3d0e538a3dcc1b6a384f75f0263dc10e0924a0ef2ab459d0a52b7c112710c58cf72442253396b8a25d7644be166c3e7828aa62b1ca1f32f620ed969b021ec609fe926958a03cff21f08f7c8d3d3235b219fb0020a51b97b60f963ebb58f7a62a5b41104c0b28b58cfc81668825f87064e401c263421152b8790dbc99b3032c9615187f29fcc1a58e86364ad45524b5358fa2f0a3296729a3663a585e9aa922f534fefd16fb6f96cd9895709c5520cdcd24c8d107e387e520de055a3296544ef1c1ddd43b919a4ff139861f06ae5280d5aa5aaeb8f7d74ed6ea56093c2e697a30c29c4ac145aa99a372f1a03ae72495f52a40cfddedc12b6e9115aea5ea516c5a4223a8d0a0073c8b4abe3c6188fdd6d4ab627c9f4eab468fdc2a91945274ed18465a368f291a0050c9d638a31944091b35a8fd26a1ff65e2d17dfa32ef3ac412d8293b276849ad9af71fdf272363f771d0fa99996e24510e7bf731a7480cbbefff7801c0e5fd0a13dd8278162ec1687f85409a203e82d2bcdf7e7d1ae5509857c42fce80299fe06182e74a97c0c624ed5b6246e59781af9407fb28b34f7024f42d36eb92bb95f72cee379ed363daf2625b48e60d0489b23dfa57789c0dd2276b4575a01c2349171d2a58bcf29e659b868cdac1c30a02a160c078b6faa7e0696711d43447ea2108db3d34ec1bf9cfe802f601212d335445a4624829f8a600b18e9b3cf13a9787910f2fb27676fd809e7ea1a34c7306e766b2e7ae1bbb919cc888ea931d1eb2e27c6109b9a12c31e188a196a98bbe0b24cc315791d26ef01b77fe06c3011ac39a8f78d233b7651e586d14dcfc2636cb713ecabadb97374ce58498f8b2e557531793fd9207fe484a4e147f7b826502cd3785251973b23e2b62b7fdc74a10fce9c04f97511dbffe3f2c46887c25904b99df69e97b416bac18fadad67b71cc320eff8def185d41ae8558cbdae6ccee38b8cfb2bfe92d0aa99815b3ca1d115f21493b13adeeafce81a23c6b1bc15fc8f2b171284e6a1fd65c351b0c82b31112f022ddaa78dcfbac9f203eeef415c566a00c2c933f06ff18ee7674aba548592dc8214b1af8e929242f87c81b0cebe8106b5267ba39c5b51987e38858dce1d1f8d0cfee2bd61d217e5a5d41bb0c4aaf0e7b0a8c66e5b0291e4d05bfddcf8861bb31b32ea5ba80cb02472c11969b3b02a7f7bc025feace44726b6382012544f1bd1256744f4b1b0ff81f7b9f7462c5c92507f1316df228ec5c0786378b871e69479c3e26f232f5d6a709d3551d08f0ecced52f8158a2c40a234af448449c1cb1a1f6f5ae56171606582ebb9a5836c454eb86015ae7a4ac87105b371bf40d49b1134a037243a0878953b5bbd6ef944ae7c345ec24e4a0e8496b62d71a6381aa52e5bdeedc81784f45e0c75b72a8c9898ea0387a47153d7e3a7c895aab58a1497a5e794052d7457624478c24d44c7e8932c887322b422478418af64a389c152d12c7a6803e0fb0050dcf2b9d65a35a53b9845b9c3835fddd45dfd12e28f8845e03686b3707ef6003e7c1cd4f8d7406ee0d1cdc41d7b56fb630c1438fe33196e53389f1ec1540fe789c6599c0b589296214d831a86e89220ae97974f4d112f4c98c726027d0c9316d1303b87a43a86cb8b800835a677abfe1584e8be55a624612f56bdf71a054a2e834e35105a19a77f7dfdbf9dd2850ee44658ab0eae6e833c855bb9650eda7f8f4e74d8de73526f12773b2bcbb1bd35639f8730d8cdd6d64f496abae4e1f8cdc96148894aa691683515bcdf37ba6caa0cbf953c752a7b9819e9f834ff39ec8f6d8a3dd8dd5a431d47c7f74c7a633ff73ff507009c5ac9431cc588ba0c6d226edc17c94a0f14d3e8db0c7ef60c3293878dfe513f96b54c61c88a90aca4f246d6a5988f5f785ce0655f51b85e55af03e5772a083bfcf0816ebd97a4af416fa6414a9ad47b7198e51d55463807ef4f0d9b7c06a0a84762e4e46c8b39147a4bdd594b8d4d40b36f5e6b4d48726551890d040d229ee70ea3034d45b3c28eb80d686918fe6e219636b8f9b7e6fc08f4e3bed9bafc778aab274913e9cfd570732ab3fb434c9ba0928581232580495571e56f6705f2af05b56642c2b93df65c443a6caa5b167a4040d2438206d2cefd3114ab466eb3c9eaa5e66cf4447c89c493a2eee0b0ea6e7329b37c90ec2d0142bae7fef265ae3c9c053e44031c0a142bf9faa728e5170cdba59fa8da361d94d887d5d6f58b409bbc4bd4548990653a04dfb841fd784ac9cc4cfd34c88512de212074dfba30295badf22f1af2522c5fe1cd423bd8eae429d7a862bcd649ab61bf0d3b55daf4b6f0f390c503d7c1bdea453b5ef145bd8191802056bd9e0455a404b6afe5b25977f02f902caba46f988d91b2350ebe4091b5584d4f938a45803984a5291beadeeadda488dc7ed2dc4aae69ca8ae0bd4492f9b297c3fb257de986c1615d44dee59e1e14d34af9fd7852b13fdcb713dd1a03d341884a30ea1dc0104d63a31d291df035d317fea98ec44f5a86715014783172e667a748f162c5c26a8b34a0f133d89fb971bf6e0a01507efed010cc7f194b5e87a77d56a909d65efa0d5ccd6da9b5eb1d73422f97ffad8012af43a2905a98354b8362e9c459f0044336348eded53660d65a38a9efc42be13a6672790496d875a67e0078dfdd8340dab8547be140ca9f88891b635e195c20daa8359658785cbe3d09ce8a580f009324e6550b0196e305889262f28f49dead77e6f5a0e859c57d53c935a4c9590879b6528eb2bc3230217b0897cddfeff405a6a54b2f50c58311af1ede4ea0660b73037f9a097d9d0271b45e325bec666cc7cb65ae780e361639838d10fe79907a0da0efef85d2420a84e905bb33116789526a9a88319d460f539586762ab172e4a7f305f7ae36cb88c96d91aada0b4dda3418c670e27a5fdede39bd8659e477cbe08e645af927843dbdd67489b72693efeb3a7be0e121fdf5580474ca028f39a035e78d81dd212679d0a830c050ffd43af6642d60d410aaf34f7a5ea9cb2e12f21672e3f4e0c00ccdb05758e74df3893bd40a5d7921e2e149330fddbe0a2dae4210d50a3caa60b1b9db685f7704ae2d7302b18e8261052b779139747f462a6610a37252b170afbfce905fb6f7fb8c2b6100ee231507f403fee88ba5561580d4de4cdf600bf9e9816c9da1e1d2b91a1d966d04cdb98d3be55fb77af2daeeed750b8b60b494accaa12441d372afb3d47e7395b9e0e867595a1a6c8bff8638bcb138ddcac2f3efbf89762b68ebd77247c89929620f1a3cb8dcaf9632fde0996b33e6b2621da25924b4e2c8d6bff28ae0867786919ad763e6d79fc304a06277955795a7cb17186fb6bdfa98a16189544b228f3bcd3698737ff55b6185799459b796a63c6a61cea9d20f1e296d62f474c43750b77944e5f1c09072f019dbeeb64e9bc8dec4605d8e0322cdd97f56cc43084f5c983a584855654366fd5659ea23c6c15e1d7da51d82c683aa477b9f896563a5134c64e32814ea88b7f7af760f18bc91e656da92b72e98bc03f1c6bfb442830305529d681dc6bccae66da9b2e61b9c97e2397fdb92f7f6369b470529c570c2d3b329487981d148a462cdb992d792e34dd233e1c239657b8da0d59b804566cf81ad5f0a7a0ccb3a8fbda673887c153d2e56c484f9230d752be52c1e35bc9af5a7446237fc072afef777665c264c18e6a3c059fde2e8368f9bb898f1cc8393d1bf18b1757219670275f0bbc7deb0248c68af929111e19737479bcabab732d7e033aaeb277eac05e185e9e56b2450beaac784dd0308b7a5e8ca1f2fcd8852ddad9f7b7de264478e1891a391aa89964dae5ad0b7a829c2c9209db346ceb26c1b967cfac82ad574761443be3f0a910968239d23b11507ab978b3ce89e22b7d7283736b9786544ab4460f"),
+    hex!(
+        "832a5587bf9c2e685ff8b142581495a72064e8daacaf9cfc84fade9b38c4d0f5"
+        "545699a952b6609398d445a0a9d2c01a15eef97c372658a85739af9a3356e4d2"
+    )
+);
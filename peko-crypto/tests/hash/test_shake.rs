@@ -0,0 +1,103 @@
+use hex_literal::hex;
+
+use peko_crypto::hash::{ExtendableOutputFunction, XofReader};
+use peko_crypto::hash::{SHAKE128, SHAKE256};
+
+macro_rules! test_shake {
+    ($name: ident, $xof: ident, $input: expr, $output: expr) => {
+        #[test]
+        fn $name() {
+            let expected = $output;
+
+            let mut xof = $xof::new();
+            for chunk in $input.chunks(13) {
+                xof.update(chunk);
+            }
+
+            assert_eq!(
+                xof.digest(expected.len()),
+                expected,
+                "output for {} does not match",
+                stringify!($name)
+            );
+
+            // Squeezing the output in small steps must give the same result.
+            let mut reader = xof.finalize_xof();
+            let mut output = vec![0u8; expected.len()];
+            for chunk in output.chunks_mut(7) {
+                reader.read(chunk);
+            }
+
+            assert_eq!(
+                output,
+                expected,
+                "squeezed output for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+// The following tests are extracted from the SHAKE128ShortMsg.rsp file in the
+// SHA-3 XOF Test Vectors for Byte-Oriented Output:
+// https://csrc.nist.gov/projects/cryptographic-algorithm-validation-program/secure-hashing
+test_shake!(
+    test_shake128_0,
+    SHAKE128,
+    hex!(""),
+    hex!("7f9c2ba4e88f827d616045507605853e")
+);
+
+test_shake!(
+    test_shake128_16,
+    SHAKE128,
+    hex!("84e950051876050dc851fbd99e6247b8"),
+    hex!("8599bd89f63a848c49ca593ec37a12c6")
+);
+
+// Long outputs, so that the permutation is applied several times while squeezing.
+test_shake!(
+    test_shake128_0_long_output,
+    SHAKE128,
+    hex!(""),
+    hex!("7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef263cb1eea988004b93103cfb0aeefd2a686e01fa4a58e8a3639ca8a1e3f9ae57e235b8cc873c23dc62b8d260169afa2f75ab916a58d974918835d25e6a435085b2badfd6dfaac359a5efbb7bcc4b59d538df9a04302e10c8bc1cbf1a0b3a5120ea17cda7cfad765f5623474d368ccca8af0007cd9f5e4c849f167a580b14aabdefaee7eef47cb0fca9767be1fda69419dfb927e9df07348b196691abaeb580b32def58538b8d23f87732ea63b02b4fa0f4873360e2841928cd60dd4cee8cc0d4c922a96188d032675c8ac850933c7aff1533b94c834adbb69c6115bad4692d8619f90b0cdf8a7b9c264029ac185b70b83f2801f2f4b3f70c593ea3aeeb613a7f1b1de33fd75081f592305f2e4526edc09631b10958f464d889f31ba010250fda7f1368ec2967fc84ef2ae9aff268e0b1700affc6820b523a3d917135f2dff2ee06bfe72b3124721d4a26c04e53a75e30e73a7a9c4a95d91c55d495e9f51dd0b5e9d83c6d5e8ce803aa62b8d654db53d09b8dcff273cdfeb573fad8bcd45578bec2e770d01efde86e721a3f7c6cce275dabe6e2143f1af18da7efddc4c7b70b5e345db93cc936bea323491ccb38a388f546a9ff00dd4e1300b9b2153d2041d205b443e41b45a653f2a5c4492c1add544512dda2529833462b71a41a45be97290b6f")
+);
+
+test_shake!(
+    test_shake128_9_long_output,
+    SHAKE128,
+    hex!("1f66ab4185ed9b6375"),
+    hex!("1d96e0454be037d890fbeb776ce5c922bdfb827af1d1abee1bc8b3ae95453532d40c0b3f9c93e400ff70f6fbd4dd0470df1f06e04ab4a596b6f92bbcaad3650d60b940096260226913435c9bbf5f29de8bcc9721748e3f1deb7fae88741084507e1ba72a267c2c2276d8bd8ed284578b46ae81230f383214137cd7c190524818576f77b0a53c3421e0476d91e11bae79acc4995a24fd1398f6f67e22033bc381abe6de4e0563238fdc39a1b305fa9403ee35800432999eb3421c8cc17f77f2a544957afc2ce9496d2b739511b3cc52db118ee874e43e95fa69d7ef024931377af86a9448f3899dd0a87edcfecfc1bcf7c17d470c71cc488744f2b627626112aee465adde9c6719ac0f6e928c77b0ba0ae8a5ce20005c46768021438b611826675e0b94fdc7484a3854936216e2239650c794ef001ef8f9f4cb440664472445875d06c519e14c957d6fcfb73fb72b46cfac4548ba94b95979fe5feac07035e0fdd21af7e3b69228afb2d97f2a333a5d40a84399c6d4f5a7ebef9acd08d66c2781eb9085a42e38c434230816b90dd838a2f10e727ee5f58d7469b47fd5324ef53a88208c44943009ae7a2803d55760a6ad4bc6e961c8bcc7f7f5bd07da63709d85832e26772e01886b2d5b73ca488b8402c6a15d2aec605d154f9a41efa93601d26374d3fe8a5337e98bb7a1b5f0071d3c8bcb69cbd0b3683e7a494808c9bc4490")
+);
+
+// The following tests are extracted from the SHAKE256ShortMsg.rsp file in the
+// SHA-3 XOF Test Vectors for Byte-Oriented Output:
+// https://csrc.nist.gov/projects/cryptographic-algorithm-validation-program/secure-hashing
+test_shake!(
+    test_shake256_0,
+    SHAKE256,
+    hex!(""),
+    hex!("46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762f")
+);
+
+test_shake!(
+    test_shake256_1,
+    SHAKE256,
+    hex!("0f"),
+    hex!("aabb07488ff9edd05d6a603b7791b60a16d45093608f1badc0c9cc9a9154f215")
+);
+
+// Long outputs, so that the permutation is applied several times while squeezing.
+test_shake!(
+    test_shake256_0_long_output,
+    SHAKE256,
+    hex!(""),
+    hex!("46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762fd75dc4ddd8c0f200cb05019d67b592f6fc821c49479ab48640292eacb3b7c4be141e96616fb13957692cc7edd0b45ae3dc07223c8e92937bef84bc0eab862853349ec75546f58fb7c2775c38462c5010d846c185c15111e595522a6bcd16cf86f3d122109e3b1fdd943b6aec468a2d621a7c06c6a957c62b54dafc3be87567d677231395f6147293b68ceab7a9e0c58d864e8efde4e1b9a46cbe854713672f5caaae314ed9083dab4b099f8e300f01b8650f1f4b1d8fcf3f3cb53fb8e9eb2ea203bdc970f50ae55428a91f7f53ac266b28419c3778a15fd248d339ede785fb7f5a1aaa96d313eacc890936c173cdcd0fab882c45755feb3aed96d477ff96390bf9a66d1368b208e21f7c10d04a3dbd4e360633e5db4b602601c14cea737db3dcf722632cc77851cbdde2aaf0a33a07b373445df490cc8fc1e4160ff118378f11f0477de055a81a9eda57a4a2cfb0c83929d310912f729ec6cfa36c6ac6a75837143045d791cc85eff5b21932f23861bcf23a52b5da67eaf7baae0f5fb1369db78f3ac45f8c4ac5671d85735cdddb09d2b1e34a1fc066ff4a162cb263d6541274ae2fcc865f618abe27c124cd8b074ccd516301b91875824d09958f341ef274bdab0bae316339894304e35877b0c28a9b1fd166c796b9cc258a064a8f57e27f2a")
+);
+
+test_shake!(
+    test_shake256_9_long_output,
+    SHAKE256,
+    hex!("1f66ab4185ed9b6375"),
+    hex!("7b5562aba86e6329693ce1caab2506af93ad28be4de045e66a7b277c4dc3c2c9e698ad5f6d8826230a38fce87142dd83b28f055fa7a9212f5017374a7eac5bb7824c1540597de34be9ec894eabcf751bfd5de029a1cada7e599d433bac0499aad9782579d28223a5d16264efbf55eb1370748e7dac4b56255468fa9694a93c4b3759f691d9a4a08b53a15f61faa1268e38aaeb43b3fcdbf0ba003e2037eb52c8e9931db9c60ffc1d1fcee7663d1017ab6dcc1b92472a88a3d568af5aff930aeadbae751753cbf2f987338deb1aa822fe28788866073319662438b6caf6bd8d79de14fdb6d30f7983f5711177765d7d5e1efb610fafc9de733c9db3fd2f1c35421d29cb96f87a0dd1e83ad19acdc7c2bf7a6f047994a96e6d91bec34aaaf18aab7b32a0b379a60f12afc697770cc6039031c13b5dd66e0d368609d3018accfb02fa4973bda707cda096d712faf59b43be3ef5eb7c227530c987dc4991955796b2f588359112b9e7566ca785cc5706ac333d12e2da37a97a13b69540084d1a5908ed3baf73a0e1c55a6bd036f1342f8ec671593b9b555defbabb3364914e4396d6e2b2adfa951a6a263aada6d879b4ff330f848d2598cb418e530bc63bb11e7a247c5a2c21d5c2a34d23721955567e4452df9f5e2108c1fea8fbf672422bb3b85c7c5664b918d3f3644339367cefbe609a9b00c250111801c3f365ba752709b76e")
+);
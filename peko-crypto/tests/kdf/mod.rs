@@ -0,0 +1,4 @@
+mod test_concat_kdf;
+mod test_hkdf;
+mod test_pbkdf2;
+mod test_scrypt;
@@ -0,0 +1,48 @@
+use hex_literal::hex;
+
+use peko_crypto::hash::SHA256;
+use peko_crypto::kdf::concat_kdf;
+
+macro_rules! test_concat_kdf_sha256 {
+    ($name: ident, $z: expr, $other_info: expr, $key: expr) => {
+        #[test]
+        fn $name() {
+            assert_eq!(
+                *concat_kdf::<SHA256>($z, $other_info, $key.len()),
+                $key.to_vec(),
+                "key for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+// There are no RFC test vectors for the concatenation KDF. The following tests were generated
+// with ConcatKDFHash from pyca/cryptography: https://cryptography.io
+test_concat_kdf_sha256!(
+    test_zero_secret,
+    &hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    b"",
+    hex!("c2de8abf5c6306f427fc4de808c7177456c1c0a9f0254d4bae120a88d45b4003")
+);
+
+test_concat_kdf_sha256!(
+    test_multiple_blocks,
+    &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+    b"",
+    hex!(
+        "22b288a146b89e364069f6f367618a0ebeb5b83e5462685ab127b8edf8d2690a"
+        "94d779b1e04efb3427ef0667e6154e8e666cedc34cd4aa0369cfcb87718cd172"
+        "c4"
+    )
+);
+
+test_concat_kdf_sha256!(
+    test_other_info,
+    &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+    b"peko",
+    hex!(
+        "4242d641fd5f6e7ea4fc548dd5f6ca80391ea4c1d17314f7491eadf77bb1586e"
+        "8364b9cc889c057b8f5bfd867d0487d5"
+    )
+);
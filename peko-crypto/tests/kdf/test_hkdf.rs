@@ -0,0 +1,81 @@
+use hex_literal::hex;
+
+use peko_crypto::hash::SHA256;
+use peko_crypto::kdf::{hkdf, hkdf_expand, hkdf_extract};
+
+macro_rules! test_hkdf {
+    ($name: ident, $ikm: expr, $salt: expr, $info: expr, $prk: expr, $okm: expr) => {
+        #[test]
+        fn $name() {
+            let prk = hkdf_extract::<SHA256>($salt, $ikm);
+            assert_eq!(*prk, $prk, "PRK for {} does not match", stringify!($name));
+
+            let okm = hkdf_expand::<SHA256>(&prk, $info, $okm.len());
+            assert_eq!(
+                *okm,
+                $okm.to_vec(),
+                "OKM for {} does not match",
+                stringify!($name)
+            );
+
+            assert_eq!(*hkdf::<SHA256>($salt, $ikm, $info, $okm.len()), *okm);
+        }
+    };
+}
+
+// The following tests are the SHA-256 test cases given in appendix A of RFC 5869:
+// https://tools.ietf.org/html/rfc5869
+test_hkdf!(
+    test_case_1,
+    &hex!("0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b"),
+    &hex!("000102030405060708090a0b0c"),
+    &hex!("f0f1f2f3f4f5f6f7f8f9"),
+    hex!("077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5"),
+    hex!(
+        "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf"
+        "34007208d5b887185865"
+    )
+);
+
+test_hkdf!(
+    test_case_2,
+    &hex!(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        "202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"
+        "404142434445464748494a4b4c4d4e4f"
+    ),
+    &hex!(
+        "606162636465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e7f"
+        "808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f"
+        "a0a1a2a3a4a5a6a7a8a9aaabacadaeaf"
+    ),
+    &hex!(
+        "b0b1b2b3b4b5b6b7b8b9babbbcbdbebfc0c1c2c3c4c5c6c7c8c9cacbcccdcecf"
+        "d0d1d2d3d4d5d6d7d8d9dadbdcdddedfe0e1e2e3e4e5e6e7e8e9eaebecedeeef"
+        "f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff"
+    ),
+    hex!("06a6b88c5853361a06104c9ceb35b45cef760014904671014a193f40c15fc244"),
+    hex!(
+        "b11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c"
+        "59045a99cac7827271cb41c65e590e09da3275600c2f09b8367793a9aca3db71"
+        "cc30c58179ec3e87c14c01d5c1f3434f1d87"
+    )
+);
+
+test_hkdf!(
+    test_case_3,
+    &hex!("0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b"),
+    b"",
+    b"",
+    hex!("19ef24a32c717b167f33a91d6f648bdf96596776afdb6377ac434c1c293ccb04"),
+    hex!(
+        "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d"
+        "9d201395faa4b61a96c8"
+    )
+);
+
+#[test]
+#[should_panic]
+fn test_expand_too_long() {
+    hkdf_expand::<SHA256>(&[0u8; 32], b"", 255 * 32 + 1);
+}
@@ -0,0 +1,52 @@
+use hex_literal::hex;
+
+use peko_crypto::hash::SHA256;
+use peko_crypto::kdf::pbkdf2;
+
+macro_rules! test_pbkdf2_sha256 {
+    ($name: ident, $password: expr, $salt: expr, $iterations: expr, $key: expr) => {
+        #[test]
+        fn $name() {
+            assert_eq!(
+                *pbkdf2::<SHA256>($password, $salt, $iterations, $key.len()),
+                $key.to_vec(),
+                "key for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+// The following tests are the PBKDF2-HMAC-SHA256 test vectors given in section 11 of RFC 7914:
+// https://tools.ietf.org/html/rfc7914
+test_pbkdf2_sha256!(
+    test_1_iteration,
+    b"passwd",
+    b"salt",
+    1,
+    hex!(
+        "55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc"
+        "49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783"
+    )
+);
+
+test_pbkdf2_sha256!(
+    test_80000_iterations,
+    b"Password",
+    b"NaCl",
+    80000,
+    hex!(
+        "4ddcd8f60b98be21830cee5ef22701f9641a4418d04c0414aeff08876b34ab56"
+        "a1d425a1225833549adb841b51c9b3176a272bdebba1d078478f62b397f33c8d"
+    )
+);
+
+// Output lengths that are not a multiple of the hash output size, with the blocks checked
+// against the 64-byte output above.
+test_pbkdf2_sha256!(
+    test_truncated_block,
+    b"passwd",
+    b"salt",
+    1,
+    hex!("55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc49ca9c")
+);
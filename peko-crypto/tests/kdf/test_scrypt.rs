@@ -0,0 +1,72 @@
+use hex_literal::hex;
+
+use peko_crypto::kdf::scrypt;
+
+macro_rules! test_scrypt {
+    ($name: ident, $password: expr, $salt: expr, $n: expr, $r: expr, $p: expr, $key: expr) => {
+        #[test]
+        fn $name() {
+            assert_eq!(
+                *scrypt($password, $salt, $n, $r, $p, $key.len()),
+                $key.to_vec(),
+                "key for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+// The following tests are the test vectors given in section 12 of RFC 7914:
+// https://tools.ietf.org/html/rfc7914
+test_scrypt!(
+    test_empty,
+    b"",
+    b"",
+    16,
+    1,
+    1,
+    hex!(
+        "77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442"
+        "fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906"
+    )
+);
+
+test_scrypt!(
+    test_password,
+    b"password",
+    b"NaCl",
+    1024,
+    8,
+    16,
+    hex!(
+        "fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b373162"
+        "2eaf30d92e22a3886ff109279d9830dac727afb94a83ee6d8360cbdfa2cc0640"
+    )
+);
+
+test_scrypt!(
+    test_pleaseletmein,
+    b"pleaseletmein",
+    b"SodiumChloride",
+    16384,
+    8,
+    1,
+    hex!(
+        "7023bdcb3afd7348461c06cd81fd38ebfda8fbba904f8e3ea9b543f6545da1f2"
+        "d5432955613f0fcf62d49705242a9af9e61e85dc0d651e40dfcf017b45575887"
+    )
+);
+
+// This test needs 1 GiB of memory, run it with `cargo test -- --ignored`.
+#[test]
+#[ignore]
+fn test_pleaseletmein_large() {
+    assert_eq!(
+        *scrypt(b"pleaseletmein", b"SodiumChloride", 1048576, 8, 1, 64),
+        hex!(
+            "2101cb9b6a511aaeaddbbe09cf70f881ec568d574a2ffd4dabe5ee9820adaa47"
+            "8e56fd8f4ba5d09ffa1c6d927c40f4c337304049e8a952fbcbf45c6fa77a41a4"
+        )
+        .to_vec()
+    );
+}
@@ -0,0 +1,241 @@
+use hex_literal::hex;
+
+use peko_crypto::address::Address;
+use peko_crypto::keystore::{Error, Kdf, Keystore};
+use peko_crypto::secp256k1::SecretKey;
+
+// The following tests are taken from the Web3 Secret Storage Definition:
+// https://github.com/ethereum/wiki/wiki/Web3-Secret-Storage-Definition
+const PBKDF2_KEYSTORE: &str = r#"{
+    "crypto" : {
+        "cipher" : "aes-128-ctr",
+        "cipherparams" : {
+            "iv" : "6087dab2f9fdbbfaddc31a909735c1e6"
+        },
+        "ciphertext" : "5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46",
+        "kdf" : "pbkdf2",
+        "kdfparams" : {
+            "c" : 262144,
+            "dklen" : 32,
+            "prf" : "hmac-sha256",
+            "salt" : "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"
+        },
+        "mac" : "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"
+    },
+    "id" : "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+    "version" : 3
+}"#;
+
+const SCRYPT_KEYSTORE: &str = r#"{
+    "crypto" : {
+        "cipher" : "aes-128-ctr",
+        "cipherparams" : {
+            "iv" : "83dbcc02d8ccb40e466191a123791e0e"
+        },
+        "ciphertext" : "d172bf743a674da9cdad04534d56926ef8358534d458fffccd4e6ad2fbde479c",
+        "kdf" : "scrypt",
+        "kdfparams" : {
+            "dklen" : 32,
+            "n" : 262144,
+            "p" : 8,
+            "r" : 1,
+            "salt" : "ab0c7876052600dd703518d6fc3fe8984592145b591fc8fb5c6d43190334ba19"
+        },
+        "mac" : "2103ac29920d71da29f15d75b4a16dbe95cfd7ff8faea1056c33131d846e3097"
+    },
+    "id" : "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+    "version" : 3
+}"#;
+
+const PASSWORD: &[u8] = b"testpassword";
+const SECRET_KEY: [u8; 32] =
+    hex!("7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d");
+
+#[test]
+fn test_pbkdf2() {
+    let keystore = Keystore::from_json(PBKDF2_KEYSTORE).unwrap();
+
+    assert_eq!(keystore.id(), "3198bc9c-6672-5ab3-d995-4942343ae5b6");
+    assert_eq!(keystore.address(), None);
+    assert_eq!(keystore.decrypt(PASSWORD).unwrap().to_bytes(), SECRET_KEY);
+}
+
+#[test]
+fn test_scrypt() {
+    let keystore = Keystore::from_json(SCRYPT_KEYSTORE).unwrap();
+
+    assert_eq!(keystore.decrypt(PASSWORD).unwrap().to_bytes(), SECRET_KEY);
+}
+
+#[test]
+fn test_wrong_password() {
+    let keystore = Keystore::from_json(PBKDF2_KEYSTORE).unwrap();
+
+    assert_eq!(
+        keystore.decrypt(b"wrongpassword").err(),
+        Some(Error::WrongPassword)
+    );
+}
+
+#[test]
+fn test_capitalized_crypto() {
+    let json = PBKDF2_KEYSTORE.replace("\"crypto\"", "\"Crypto\"");
+    let keystore = Keystore::from_json(&json).unwrap();
+
+    assert_eq!(keystore.decrypt(PASSWORD).unwrap().to_bytes(), SECRET_KEY);
+}
+
+#[test]
+fn test_round_trip() {
+    let secret_key = SecretKey::from_bytes(&SECRET_KEY).unwrap();
+
+    for kdf in [Kdf::LIGHT_SCRYPT, Kdf::Pbkdf2 { iterations: 1024 }].iter() {
+        let keystore = Keystore::encrypt(&secret_key, PASSWORD, *kdf).unwrap();
+        assert_eq!(
+            keystore.address(),
+            Some(Address::from_public_key(&secret_key.public_key()))
+        );
+
+        let keystore = Keystore::from_json(&keystore.to_json()).unwrap();
+        assert_eq!(keystore.decrypt(PASSWORD).unwrap().to_bytes(), SECRET_KEY);
+        assert_eq!(
+            keystore.decrypt(b"wrongpassword").err(),
+            Some(Error::WrongPassword)
+        );
+    }
+}
+
+#[test]
+fn test_random_id() {
+    let secret_key = SecretKey::from_bytes(&SECRET_KEY).unwrap();
+    let kdf = Kdf::Pbkdf2 { iterations: 1 };
+
+    let id = Keystore::encrypt(&secret_key, PASSWORD, kdf)
+        .unwrap()
+        .id()
+        .to_string();
+    assert_eq!(id.len(), 36);
+    assert_eq!(&id[14..15], "4");
+    assert_ne!(
+        Keystore::encrypt(&secret_key, PASSWORD, kdf).unwrap().id(),
+        id
+    );
+}
+
+#[test]
+fn test_invalid_keystore() {
+    assert!(matches!(
+        Keystore::from_json("{}"),
+        Err(Error::InvalidFormat(_))
+    ));
+    assert!(matches!(
+        Keystore::from_json(&PBKDF2_KEYSTORE.replace("\"pbkdf2\"", "\"argon2\"")),
+        Err(Error::InvalidFormat(_))
+    ));
+    assert!(matches!(
+        Keystore::from_json(&PBKDF2_KEYSTORE.replace("6087dab2", "6087dabz")),
+        Err(Error::InvalidFormat(_))
+    ));
+    assert_eq!(
+        Keystore::from_json(&PBKDF2_KEYSTORE.replace("\"version\" : 3", "\"version\" : 1")).err(),
+        Some(Error::UnsupportedVersion(1))
+    );
+}
+
+#[test]
+fn test_unsupported_parameters() {
+    let decrypt = |json: String| Keystore::from_json(&json).unwrap().decrypt(PASSWORD).err();
+
+    assert_eq!(
+        decrypt(PBKDF2_KEYSTORE.replace("aes-128-ctr", "aes-128-cbc")),
+        Some(Error::UnsupportedCipher("aes-128-cbc".to_string()))
+    );
+    assert_eq!(
+        decrypt(PBKDF2_KEYSTORE.replace("hmac-sha256", "hmac-sha512")),
+        Some(Error::UnsupportedPrf("hmac-sha512".to_string()))
+    );
+    assert_eq!(
+        decrypt(PBKDF2_KEYSTORE.replace("\"c\" : 262144", "\"c\" : 0")),
+        Some(Error::InvalidParameters)
+    );
+    assert_eq!(
+        decrypt(SCRYPT_KEYSTORE.replace("\"n\" : 262144", "\"n\" : 1000")),
+        Some(Error::InvalidParameters)
+    );
+    assert_eq!(
+        decrypt(PBKDF2_KEYSTORE.replace("\"dklen\" : 32", "\"dklen\" : 16")),
+        Some(Error::InvalidParameters)
+    );
+    assert_eq!(
+        decrypt(PBKDF2_KEYSTORE.replace("6087dab2f9fdbbfaddc31a909735c1e6", "6087dab2")),
+        Some(Error::InvalidParameters)
+    );
+}
+
+#[test]
+fn test_oversized_parameters() {
+    let decrypt = |json: String| Keystore::from_json(&json).unwrap().decrypt(PASSWORD).err();
+
+    // 2^40 would overflow the size of the lookup table, 2^25 is above the limit by itself.
+    assert_eq!(
+        decrypt(SCRYPT_KEYSTORE.replace("\"n\" : 262144", "\"n\" : 1099511627776")),
+        Some(Error::InvalidParameters)
+    );
+    assert_eq!(
+        decrypt(SCRYPT_KEYSTORE.replace("\"n\" : 262144", "\"n\" : 33554432")),
+        Some(Error::InvalidParameters)
+    );
+    // The cost is within its own limit, but would take 2 GiB of memory with r = 1.
+    assert_eq!(
+        decrypt(SCRYPT_KEYSTORE.replace("\"n\" : 262144", "\"n\" : 16777216")),
+        Some(Error::InvalidParameters)
+    );
+    assert_eq!(
+        decrypt(SCRYPT_KEYSTORE.replace("\"r\" : 1", "\"r\" : 4294967296")),
+        Some(Error::InvalidParameters)
+    );
+    assert_eq!(
+        decrypt(SCRYPT_KEYSTORE.replace("\"dklen\" : 32", "\"dklen\" : 4294967296")),
+        Some(Error::InvalidParameters)
+    );
+    assert_eq!(
+        decrypt(PBKDF2_KEYSTORE.replace("\"dklen\" : 32", "\"dklen\" : 4294967296")),
+        Some(Error::InvalidParameters)
+    );
+    assert_eq!(
+        decrypt(PBKDF2_KEYSTORE.replace("\"c\" : 262144", "\"c\" : 4294967295")),
+        Some(Error::InvalidParameters)
+    );
+}
+
+#[test]
+fn test_encrypt_invalid_parameters() {
+    let secret_key = SecretKey::from_bytes(&SECRET_KEY).unwrap();
+
+    for kdf in [
+        Kdf::Scrypt { n: 3, r: 8, p: 1 },
+        Kdf::Scrypt {
+            n: 1 << 40,
+            r: 8,
+            p: 1,
+        },
+        Kdf::Scrypt {
+            n: 1 << 12,
+            r: 0,
+            p: 1,
+        },
+        Kdf::Pbkdf2 { iterations: 0 },
+        Kdf::Pbkdf2 {
+            iterations: u32::MAX,
+        },
+    ]
+    .iter()
+    {
+        assert_eq!(
+            Keystore::encrypt(&secret_key, PASSWORD, *kdf).err(),
+            Some(Error::InvalidParameters),
+            "encryption with {:?} does not fail",
+            kdf
+        );
+    }
+}
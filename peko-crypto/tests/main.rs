@@ -1,3 +1,14 @@
 extern crate peko_crypto;
 
+mod address;
+mod aes;
+mod bignum;
+mod bn254;
+mod ecies;
+mod ethash;
 mod hash;
+mod kdf;
+mod keystore;
+mod mac;
+mod secp256k1;
+mod util;
@@ -0,0 +1,264 @@
+use hex_literal::hex;
+
+use peko_crypto::hash::{HashFunction, Keccak256, H256, SHA256};
+use peko_crypto::secp256k1::{Error, PublicKey, SecretKey, Signature};
+
+macro_rules! test_sign {
+    ($name: ident, $secret_key: expr, $message: expr, $public_key: expr, $signature: expr) => {
+        #[test]
+        fn $name() {
+            let secret_key = SecretKey::from_bytes(&$secret_key).unwrap();
+            let public_key = secret_key.public_key();
+            let hash = SHA256::hash($message);
+
+            assert_eq!(
+                public_key.to_bytes()[..],
+                $public_key[..],
+                "public key for {} does not match",
+                stringify!($name)
+            );
+
+            let signature = secret_key.sign(&hash);
+            assert_eq!(
+                signature.to_bytes()[..],
+                $signature[..],
+                "signature for {} does not match",
+                stringify!($name)
+            );
+
+            assert!(public_key.verify(&hash, &signature));
+            assert_eq!(signature.recover(&hash), Ok(public_key));
+        }
+    };
+}
+
+// The following tests are the RFC 6979 secp256k1 test vectors commonly used by Bitcoin libraries,
+// such as bitcoinjs-lib: https://github.com/bitcoinjs/bitcoinjs-lib
+// Messages are hashed with SHA-256, signatures are normalized to the lower half of s and are
+// followed by the recovery ID.
+test_sign!(
+    test_satoshi_nakamoto,
+    hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+    b"Satoshi Nakamoto",
+    hex!(
+        "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+        "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"
+    ),
+    hex!(
+        "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8"
+        "2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5"
+        "01"
+    )
+);
+
+test_sign!(
+    test_tears_in_rain,
+    hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+    b"All those moments will be lost in time, like tears in rain. Time to die...",
+    hex!(
+        "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+        "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"
+    ),
+    hex!(
+        "8600dbd41e348fe5c9465ab92d23e3db8b98b873beecd930736488696438cb6b"
+        "547fe64427496db33bf66019dacbf0039c04199abb0122918601db38a72cfc21"
+        "00"
+    )
+);
+
+test_sign!(
+    test_largest_key,
+    hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140"),
+    b"Satoshi Nakamoto",
+    hex!(
+        "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+        "b7c52588d95c3b9aa25b0403f1eef75702e84bb7597aabe663b82f6f04ef2777"
+    ),
+    hex!(
+        "fd567d121db66e382991534ada77a6bd3106f0a1098c231e47993447cd6af2d0"
+        "6b39cd0eb1bc8603e159ef5c20a5c8ad685a45b06ce9bebed3f153d10d93bed5"
+        "00"
+    )
+);
+
+test_sign!(
+    test_alan_turing,
+    hex!("f8b8af8ce3c7cca5e300d33939540c10d45ce001b8f252bfbc57ba0342904181"),
+    b"Alan Turing",
+    hex!(
+        "92df7b245b81aa637ab4e867c8d511008f79161a97d64f2ac709600352f7acbc"
+        "e9bfdf1b13fa0cb1de4521e5386cde3a1cd26c5ab584989d07bbed58a5419f62"
+    ),
+    hex!(
+        "7063ae83e7f62bbb171798131b4a0564b956930092b33b07b395615d9ec7e15c"
+        "58dfcc1e00a35e1572f366ffe34ba0fc47db1e7189759b9fb233c5b05ab388ea"
+        "00"
+    )
+);
+
+test_sign!(
+    test_computer_disease,
+    hex!("e91671c46231f833a6406ccbea0e3e392c76c167bac1cb013f6f1013980455c2"),
+    b"There is a computer disease that anybody who works with computers knows about. \
+      It's a very serious disease and it interferes completely with the work. The \
+      trouble with computers is that you 'play' with them!",
+    hex!(
+        "567b7512001f3cc4dcb8b8096c046fff571ab07adb2126cd42908f2ff1ca424a"
+        "3ab781fe65ba2bbc50b71532312b3836793be62bc4f313e8874fa8a2b1cacea1"
+    ),
+    hex!(
+        "b552edd27580141f3b2a5463048cb7cd3e047b97c9f98076c32dbdf85a68718b"
+        "279fa72dd19bfae05577e06c7c0c1900c371fcd5893f7e1d56a37d30174671f6"
+        "01"
+    )
+);
+
+/// Derive the Ethereum address of a public key: the last 20 bytes of its Keccak-256 hash.
+fn address(public_key: &PublicKey) -> [u8; 20] {
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&Keccak256::hash(&public_key.to_bytes())[12..]);
+    address
+}
+
+// The following test is the test key in go-ethereum's crypto package:
+// https://github.com/ethereum/go-ethereum/blob/master/crypto/crypto_test.go
+#[test]
+fn test_public_key_address() {
+    let secret_key = SecretKey::from_bytes(&hex!(
+        "289c2857d4598e37fb9647507e47a309d6133539bf21a8b9cb6df88fd5232032"
+    ))
+    .unwrap();
+
+    assert_eq!(
+        address(&secret_key.public_key()),
+        hex!("970e8128ab834e8eac17ab8e3812f010678cf791")
+    );
+}
+
+// The following test is the ecrecover test in go-ethereum's precompiled contract tests:
+// https://github.com/ethereum/go-ethereum/blob/master/core/vm/contracts_test.go
+#[test]
+fn test_recover() {
+    let hash = H256::new(hex!(
+        "456e9aea5e197a1f1af7a3e85a3212fa4049a3ba34c2289b4c860fc0b0c64ef3"
+    ));
+    let signature = Signature::new(
+        &hex!("9242685bf161793cc25603c231bc2f568eb630ea16aa137d2664ac8038825608"),
+        &hex!("4f8ae3bd7535248d0bd448298cc2e2071e56992d0774dc340c368ae950852ada"),
+        28 - 27,
+    )
+    .unwrap();
+
+    let public_key = signature.recover(&hash).unwrap();
+
+    assert_eq!(
+        address(&public_key),
+        hex!("7156526fbd7a3c72969b54f64e42c10fbb768c8a")
+    );
+    assert!(public_key.verify(&hash, &signature));
+}
+
+#[test]
+fn test_random_key() {
+    let secret_key = SecretKey::random();
+    let hash = SHA256::hash(b"peko");
+
+    let signature = secret_key.sign(&hash);
+
+    assert_eq!(signature.recover(&hash), Ok(secret_key.public_key()));
+    assert_eq!(
+        SecretKey::from_bytes(&secret_key.to_bytes())
+            .unwrap()
+            .public_key(),
+        secret_key.public_key()
+    );
+}
+
+#[test]
+fn test_verify_wrong_hash() {
+    let secret_key = SecretKey::from_bytes(&[0x01; 32]).unwrap();
+    let signature = secret_key.sign(&H256::new([0x02; 32]));
+
+    let wrong_hash = H256::new([0x03; 32]);
+    assert!(!secret_key.public_key().verify(&wrong_hash, &signature));
+    assert_ne!(signature.recover(&wrong_hash), Ok(secret_key.public_key()));
+}
+
+#[test]
+fn test_verify_high_s() {
+    let secret_key = SecretKey::from_bytes(&[0x01; 32]).unwrap();
+    let hash = H256::new([0x02; 32]);
+    let signature = secret_key.sign(&hash);
+
+    // n - s, with the recovery ID flipped, is also a valid signature.
+    let n = hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+    let s = signature.s();
+    let mut high_s = [0u8; 32];
+    let mut borrow = 0i16;
+    for i in (0..32).rev() {
+        let difference = n[i] as i16 - s[i] as i16 - borrow;
+        borrow = (difference < 0) as i16;
+        high_s[i] = difference.rem_euclid(256) as u8;
+    }
+
+    let high_s_signature = Signature::new(&signature.r(), &high_s, signature.v() ^ 1).unwrap();
+    assert!(secret_key.public_key().verify(&hash, &high_s_signature));
+    assert_eq!(high_s_signature.recover(&hash), Ok(secret_key.public_key()));
+}
+
+#[test]
+fn test_public_key_encodings() {
+    let public_key = SecretKey::from_bytes(&[0x01; 32]).unwrap().public_key();
+
+    let mut uncompressed = [0x04u8; 65];
+    uncompressed[1..].copy_from_slice(&public_key.to_bytes());
+
+    assert_eq!(
+        PublicKey::from_bytes(&public_key.to_bytes()),
+        Ok(public_key)
+    );
+    assert_eq!(PublicKey::from_bytes(&uncompressed), Ok(public_key));
+    assert_eq!(
+        PublicKey::from_bytes(&public_key.to_compressed_bytes()),
+        Ok(public_key)
+    );
+}
+
+#[test]
+fn test_invalid_secret_key() {
+    let n = hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+
+    assert!(SecretKey::from_bytes(&[0x00; 32]).is_err());
+    assert!(SecretKey::from_bytes(&n).is_err());
+    assert!(SecretKey::from_bytes(&[0xff; 32]).is_err());
+}
+
+#[test]
+fn test_invalid_public_key() {
+    // (1, 1) is not on the curve.
+    let mut point = [0u8; 64];
+    point[31] = 1;
+    point[63] = 1;
+
+    assert_eq!(PublicKey::from_bytes(&point), Err(Error::InvalidPublicKey));
+    assert_eq!(
+        PublicKey::from_bytes(&[0x04; 10]),
+        Err(Error::InvalidPublicKey)
+    );
+}
+
+#[test]
+fn test_invalid_signature() {
+    assert_eq!(
+        Signature::new(&[0x00; 32], &[0x01; 32], 0),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        Signature::new(&[0x01; 32], &[0xff; 32], 0),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        Signature::new(&[0x01; 32], &[0x01; 32], 2),
+        Err(Error::InvalidSignature)
+    );
+}
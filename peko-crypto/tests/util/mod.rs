@@ -0,0 +1,3 @@
+mod test_constant_time;
+mod test_hex;
+mod test_zeroize;
@@ -0,0 +1,50 @@
+use peko_crypto::util::{constant_time_eq, constant_time_select};
+
+#[test]
+fn test_eq() {
+    assert!(constant_time_eq(b"", b""));
+    assert!(constant_time_eq(b"peko", b"peko"));
+
+    // A difference in any position, and in any bit, makes the slices unequal.
+    let secret = [0x5au8; 32];
+    for i in 0..secret.len() {
+        for bit in 0..8 {
+            let mut other = secret;
+            other[i] ^= 1 << bit;
+            assert!(
+                !constant_time_eq(&secret, &other),
+                "bit {} of byte {} is ignored",
+                bit,
+                i
+            );
+        }
+    }
+}
+
+#[test]
+fn test_eq_different_lengths() {
+    assert!(!constant_time_eq(b"peko", b"pekora"));
+    assert!(!constant_time_eq(b"pekora", b"peko"));
+    assert!(!constant_time_eq(b"", b"\x00"));
+}
+
+#[test]
+fn test_select() {
+    let a = [0x00, 0xff, 0x5a, 0xa5];
+    let b = [0xff, 0x00, 0x12, 0x34];
+    let mut output = [0u8; 4];
+
+    constant_time_select(true, &a, &b, &mut output);
+    assert_eq!(output, a);
+
+    constant_time_select(false, &a, &b, &mut output);
+    assert_eq!(output, b);
+
+    constant_time_select(true, &[], &[], &mut []);
+}
+
+#[test]
+#[should_panic(expected = "Inputs and output must be the same length")]
+fn test_select_different_lengths() {
+    constant_time_select(true, b"peko", b"pekora", &mut [0u8; 4]);
+}
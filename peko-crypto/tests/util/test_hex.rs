@@ -0,0 +1,51 @@
+use hex_literal::hex;
+
+use peko_crypto::util::{hex_decode, hex_encode};
+
+macro_rules! test_hex {
+    ($name: ident, $bytes: expr, $hex: expr) => {
+        #[test]
+        fn $name() {
+            assert_eq!(
+                hex_encode(&$bytes),
+                $hex,
+                "encoding for {} does not match",
+                stringify!($name)
+            );
+            assert_eq!(
+                hex_decode($hex),
+                Some($bytes.to_vec()),
+                "decoding for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+test_hex!(test_empty, [0u8; 0], "");
+test_hex!(test_one_byte, [0x0f], "0f");
+test_hex!(
+    test_all_digits,
+    hex!("0123456789abcdef"),
+    "0123456789abcdef"
+);
+
+#[test]
+fn test_decode_uppercase() {
+    assert_eq!(
+        hex_decode("0123456789ABCDEF"),
+        Some(hex!("0123456789abcdef").to_vec())
+    );
+    assert_eq!(hex_decode("aBcD"), Some(vec![0xab, 0xcd]));
+}
+
+#[test]
+fn test_decode_invalid() {
+    // Odd number of digits.
+    assert_eq!(hex_decode("abc"), None);
+    // The prefix is not accepted.
+    assert_eq!(hex_decode("0x12"), None);
+    assert_eq!(hex_decode("zz"), None);
+    assert_eq!(hex_decode("é"), None);
+    assert_eq!(hex_decode("+1"), None);
+}
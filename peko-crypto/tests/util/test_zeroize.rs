@@ -0,0 +1,81 @@
+use hex_literal::hex;
+
+use peko_crypto::aes::{Ctr, AES128, AES256};
+use peko_crypto::hash::{
+    BLAKE2b, BLAKE2b512, Keccak256, Sponge, SpongeReader, H256, RIPEMD160, SHA224, SHA256,
+    SHA3_256, SHA512, SHAKE128,
+};
+use peko_crypto::kdf::{hkdf_extract, pbkdf2};
+use peko_crypto::mac::Hmac;
+use peko_crypto::secp256k1::SecretKey;
+use peko_crypto::util::{Zeroize, ZeroizeOnDrop};
+
+use std::mem::{self, ManuallyDrop};
+use std::{ptr, slice};
+
+/// Drop `value` in place, and return the bytes left behind in its memory.
+///
+/// Only meaningful for types without padding, whose fields are all wiped on drop.
+fn bytes_after_drop<T>(value: T) -> Vec<u8> {
+    let mut value = ManuallyDrop::new(value);
+    let pointer: *mut T = &mut *value;
+
+    unsafe {
+        ptr::drop_in_place(pointer);
+        slice::from_raw_parts(pointer as *const u8, mem::size_of::<T>()).to_vec()
+    }
+}
+
+#[test]
+fn test_aes_round_keys() {
+    let bytes = bytes_after_drop(AES128::new(&hex!("000102030405060708090a0b0c0d0e0f")));
+    assert!(bytes.iter().all(|&byte| byte == 0));
+
+    let bytes = bytes_after_drop(AES256::new(&[0xffu8; 32]));
+    assert!(bytes.iter().all(|&byte| byte == 0));
+}
+
+#[test]
+fn test_digest() {
+    let mut digest: H256 = *hkdf_extract::<SHA256>(b"salt", b"input keying material");
+    assert_ne!(digest, H256::default());
+
+    digest.zeroize();
+    assert_eq!(digest, H256::default());
+}
+
+#[test]
+fn test_kdf_output() {
+    let mut key = pbkdf2::<SHA256>(b"password", b"salt", 1, 32);
+    assert_eq!(key.len(), 32);
+
+    key.zeroize();
+    assert!(key.is_empty());
+}
+
+/// Check that `T` is zeroized on drop.
+fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+
+#[test]
+fn test_zeroize_on_drop() {
+    assert_zeroize_on_drop::<SecretKey>();
+
+    assert_zeroize_on_drop::<AES128>();
+    assert_zeroize_on_drop::<AES256>();
+    assert_zeroize_on_drop::<Ctr<AES128>>();
+
+    assert_zeroize_on_drop::<SHA224>();
+    assert_zeroize_on_drop::<SHA256>();
+    assert_zeroize_on_drop::<SHA512>();
+    assert_zeroize_on_drop::<RIPEMD160>();
+    assert_zeroize_on_drop::<Keccak256>();
+    assert_zeroize_on_drop::<SHA3_256>();
+    assert_zeroize_on_drop::<SHAKE128>();
+    assert_zeroize_on_drop::<BLAKE2b>();
+    assert_zeroize_on_drop::<BLAKE2b512>();
+    assert_zeroize_on_drop::<Sponge>();
+    assert_zeroize_on_drop::<SpongeReader>();
+
+    assert_zeroize_on_drop::<Hmac<SHA256>>();
+    assert_zeroize_on_drop::<Hmac<Keccak256>>();
+}
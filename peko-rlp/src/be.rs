@@ -1,9 +1,14 @@
+/// Conversion between unsigned integers and their minimal big-endian encoding, as used by RLP:
+/// no leading zero bytes, with zero encoded as an empty byte array.
 pub trait MinBigEndian
 where
     Self: Sized,
 {
+    /// Encode to big-endian bytes, without leading zeros.
     fn to_min_be(&self) -> Vec<u8>;
 
+    /// Decode big-endian bytes, returning `None` if the value doesn't fit in `Self`. Leading
+    /// zeros are accepted.
     fn try_from_min_be(input: &[u8]) -> Option<Self>;
 }
 
@@ -13,29 +18,24 @@ macro_rules! define_minimal_big_endian {
             fn to_min_be(&self) -> Vec<u8> {
                 let be = self.to_be_bytes();
 
-                for i in 0..be.len() {
-                    if be[i] != 0 {
-                        return Vec::from(&be[i..]);
-                    }
+                // Zero is encoded as an empty byte array.
+                match be.iter().position(|&byte| byte != 0) {
+                    Some(start) => Vec::from(&be[start..]),
+                    None => vec![],
                 }
-
-                vec![0x00];
             }
 
             fn try_from_min_be(input: &[u8]) -> Option<Self> {
-                let mut result: Self = 0x00;
-
-                for byte in input {
-                    match result
-                        .checked_shl(8)
-                        .and_then(|value| value.checked_add(byte as Self))
-                    {
-                        Some(value) => result = value,
-                        None => return None,
-                    }
+                const SIZE: usize = std::mem::size_of::<$type>();
+
+                if input.len() > SIZE {
+                    return None;
                 }
 
-                Some(result)
+                let mut be = [0u8; SIZE];
+                be[SIZE - input.len()..].copy_from_slice(input);
+
+                Some(Self::from_be_bytes(be))
             }
         }
     };
@@ -4,26 +4,21 @@ use crate::error::UnsupportedType;
 use crate::parser::{parse, Item};
 
 use serde::de::Visitor;
-use serde::{Deserialize, Deserializer};
+use serde::Deserializer;
 
-fn from_bytes<'de, T: Deserialize<'de>>(input: &'de [u8]) -> Result<T, Error> {
-    let mut deserializer = RLPDeserializer::from_bytes(value)?;
-    T::deserialize(&mut deserializer)
-}
-
-struct RLPDeserializer<'de> {
+pub struct RLPDeserializer<'de> {
     item: Item<'de>,
 }
 
 impl<'de> RLPDeserializer<'de> {
-    fn from_bytes(input: &[u8]) -> Result<RLPDeserializer, Error> {
+    pub fn from_bytes(input: &'de [u8]) -> Result<RLPDeserializer<'de>, Error> {
         let parsed_item = parse(input)?;
 
         Ok(RLPDeserializer { item: parsed_item })
     }
 }
 
-impl<'de> Deserializer<'de> for &'de mut RLPDeserializer {
+impl<'de> Deserializer<'de> for &mut RLPDeserializer<'de> {
     type Error = Error;
 
     fn deserialize_any<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
@@ -40,30 +35,37 @@ impl<'de> Deserializer<'de> for &'de mut RLPDeserializer {
     where
         V: Visitor<'de>,
     {
+        // Booleans are serialized as the integers 0 and 1.
+        match self.item {
+            Item::ByteArray([]) => visitor.visit_bool(false),
+            Item::ByteArray([1]) => visitor.visit_bool(true),
+            Item::ByteArray(_) => Err(Error::InvalidInput),
+            _ => Err(Error::NotByteArray),
+        }
     }
 
-    fn deserialize_i8<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_i8<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
         Err(Error::UnsupportedType(UnsupportedType::I8))
     }
 
-    fn deserialize_i16<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_i16<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
         Err(Error::UnsupportedType(UnsupportedType::I16))
     }
 
-    fn deserialize_i32<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_i32<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
         Err(Error::UnsupportedType(UnsupportedType::I32))
     }
 
-    fn deserialize_i64<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_i64<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
@@ -75,61 +77,62 @@ impl<'de> Deserializer<'de> for &'de mut RLPDeserializer {
         V: Visitor<'de>,
     {
         match self.item {
-            Item::ByteArray(byte_array) => visitor.visit_u8(u8::try_from_min_be(byte_array)?),
+            Item::ByteArray(byte_array) => visitor
+                .visit_u8(u8::try_from_min_be(byte_array).ok_or(Error::OverflowedIntegerForType)?),
             _ => Err(Error::NotByteArray),
         }
     }
 
-    fn deserialize_u16<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_u16<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
         unimplemented!()
     }
 
-    fn deserialize_u32<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_u32<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
         unimplemented!()
     }
 
-    fn deserialize_u64<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_u64<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
         unimplemented!()
     }
 
-    fn deserialize_f32<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_f32<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
         unimplemented!()
     }
 
-    fn deserialize_f64<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_f64<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
         unimplemented!()
     }
 
-    fn deserialize_char<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_char<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
         unimplemented!()
     }
 
-    fn deserialize_str<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_str<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
         unimplemented!()
     }
 
-    fn deserialize_string<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_string<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
@@ -140,24 +143,27 @@ impl<'de> Deserializer<'de> for &'de mut RLPDeserializer {
     where
         V: Visitor<'de>,
     {
-        unimplemented!()
+        match self.item {
+            Item::ByteArray(byte_array) => visitor.visit_borrowed_bytes(byte_array),
+            _ => Err(Error::NotByteArray),
+        }
     }
 
     fn deserialize_byte_buf<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        unimplemented!()
+        self.deserialize_bytes(visitor)
     }
 
-    fn deserialize_option<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_option<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
         unimplemented!()
     }
 
-    fn deserialize_unit<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_unit<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
@@ -166,8 +172,8 @@ impl<'de> Deserializer<'de> for &'de mut RLPDeserializer {
 
     fn deserialize_unit_struct<V>(
         self,
-        name: &'static str,
-        visitor: V,
+        _name: &'static str,
+        _visitor: V,
     ) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
@@ -177,8 +183,8 @@ impl<'de> Deserializer<'de> for &'de mut RLPDeserializer {
 
     fn deserialize_newtype_struct<V>(
         self,
-        name: &'static str,
-        visitor: V,
+        _name: &'static str,
+        _visitor: V,
     ) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
@@ -186,7 +192,7 @@ impl<'de> Deserializer<'de> for &'de mut RLPDeserializer {
         unimplemented!()
     }
 
-    fn deserialize_seq<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_seq<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
@@ -195,8 +201,8 @@ impl<'de> Deserializer<'de> for &'de mut RLPDeserializer {
 
     fn deserialize_tuple<V>(
         self,
-        len: usize,
-        visitor: V,
+        _len: usize,
+        _visitor: V,
     ) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
@@ -206,9 +212,9 @@ impl<'de> Deserializer<'de> for &'de mut RLPDeserializer {
 
     fn deserialize_tuple_struct<V>(
         self,
-        name: &'static str,
-        len: usize,
-        visitor: V,
+        _name: &'static str,
+        _len: usize,
+        _visitor: V,
     ) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
@@ -216,7 +222,7 @@ impl<'de> Deserializer<'de> for &'de mut RLPDeserializer {
         unimplemented!()
     }
 
-    fn deserialize_map<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_map<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
@@ -225,9 +231,9 @@ impl<'de> Deserializer<'de> for &'de mut RLPDeserializer {
 
     fn deserialize_struct<V>(
         self,
-        name: &'static str,
-        fields: &'static [&'static str],
-        visitor: V,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        _visitor: V,
     ) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
@@ -237,9 +243,9 @@ impl<'de> Deserializer<'de> for &'de mut RLPDeserializer {
 
     fn deserialize_enum<V>(
         self,
-        name: &'static str,
-        variants: &'static [&'static str],
-        visitor: V,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        _visitor: V,
     ) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
@@ -249,7 +255,7 @@ impl<'de> Deserializer<'de> for &'de mut RLPDeserializer {
 
     fn deserialize_identifier<V>(
         self,
-        visitor: V,
+        _visitor: V,
     ) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
@@ -259,11 +265,15 @@ impl<'de> Deserializer<'de> for &'de mut RLPDeserializer {
 
     fn deserialize_ignored_any<V>(
         self,
-        visitor: V,
+        _visitor: V,
     ) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
         unimplemented!()
     }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
 }
@@ -12,8 +12,8 @@ pub type Result<T> = std::result::Result<T, Error>;
 
 /// Data types which can't be serialized to RLP.
 ///
-/// These are used by [`Error::UnsupportedDataType`] to indicate which type is encountered.
-#[derive(Debug)]
+/// These are used by [`Error::UnsupportedType`] to indicate which type is encountered.
+#[derive(Debug, PartialEq, Eq)]
 pub enum UnsupportedType {
     I8,
     I16,
@@ -55,7 +55,7 @@ impl fmt::Display for UnsupportedType {
 }
 
 /// Errors returned during the serialization/deserialization process.
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub enum Error {
     /// Error created through [`serde::ser::Error`] and [`serde::de::Error`]
     Message(String),
@@ -97,10 +97,7 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             Error::Message(msg) => f.write_str(msg),
-            Error::UnsupportedType(data_type) => {
-                f.write_str("unsupported data type: ");
-                data_type.fmt(f);
-            }
+            Error::UnsupportedType(data_type) => write!(f, "unsupported data type: {}", data_type),
             Error::CallingSerializeKeyTwice => f.write_str("calling serialize_key twice"),
             Error::CallingSerializeValueWithoutKey => {
                 f.write_str("calling serialize_value without serialize_key first")
@@ -114,9 +111,7 @@ impl fmt::Display for Error {
             Error::OverflowedIntegerForType => {
                 f.write_str("integer type is too small for big-endian-encoded number")
             }
-        };
-
-        Ok(())
+        }
     }
 }
 
@@ -4,6 +4,7 @@ mod error;
 mod parser;
 mod ser;
 
+pub use be::MinBigEndian;
 pub use de::RLPDeserializer;
 pub use error::{Error, Result};
 pub use ser::{to_bytes, RLPSerializer};
@@ -11,6 +11,8 @@ use crate::error::{Error, Result};
 
 pub enum Item<'a> {
     ByteArray(&'a [u8]),
+    // Not read until the deserializer supports sequences.
+    #[allow(dead_code)]
     Sequence(Vec<Item<'a>>),
 }
 
@@ -18,13 +20,13 @@ pub enum Item<'a> {
 ///
 /// On success, returns a tuple containing two items: the decoded byte array as an [`Item`], and
 /// a slice of any trailing data.
-fn parse_byte_array(input: &[u8]) -> Result<(Item, &[u8])> {
-    let length_marker = input.get(0).ok_or(Err(Error::EOF))?;
+fn parse_byte_array(input: &[u8]) -> Result<(Item<'_>, &[u8])> {
+    let length_marker = *input.first().ok_or(Error::EOF)?;
 
     // Determine the length of the byte array, and where does it start.
     let (length, data_start) = match length_marker {
         // Byte array of one element smaller than 128.
-        0..=127 => (1, &input[1..]),
+        0..=127 => (1, input),
 
         // Byte array shorter than 56 elements.
         // Follows the first byte is the byte array itself.
@@ -48,7 +50,7 @@ fn parse_byte_array(input: &[u8]) -> Result<(Item, &[u8])> {
             }
         }
 
-        _ => Err(Error::NotAByteArray),
+        _ => return Err(Error::NotByteArray),
     };
 
     if data_start.len() < length {
@@ -66,9 +68,9 @@ fn parse_byte_array(input: &[u8]) -> Result<(Item, &[u8])> {
 /// On success, returns a tuple containing two items: the decoded sequence an [`Item`], and
 /// a slice of any trailing data. The parser recursively decodes all RLP-encoded items in the
 /// sequence.
-fn parse_sequence(input: &[u8]) -> Result<(Item, &[u8])> {
+fn parse_sequence(input: &[u8]) -> Result<(Item<'_>, &[u8])> {
     // First byte in the input encodes the length of the sequence.
-    let length_marker = input.get(0).ok_or(Err(Error::EOF))?;
+    let length_marker = *input.first().ok_or(Error::EOF)?;
 
     // Determine the length of the sequence, and where does it start.
     let (length, data_start) = match length_marker {
@@ -89,7 +91,7 @@ fn parse_sequence(input: &[u8]) -> Result<(Item, &[u8])> {
             }
         }
 
-        _ => Err(Error::NotASequence),
+        _ => return Err(Error::NotSequence),
     };
 
     if data_start.len() < length {
@@ -97,22 +99,22 @@ fn parse_sequence(input: &[u8]) -> Result<(Item, &[u8])> {
     } else {
         let mut sequence = Vec::new();
 
-        let mut current_trailing = &input[..length];
+        let mut current_trailing = &data_start[..length];
         while !current_trailing.is_empty() {
             let (item, trailing) = try_parse(current_trailing)?;
             sequence.push(item);
             current_trailing = trailing;
         }
 
-        Ok((Item::Sequence(sequence), &input[length..]))
+        Ok((Item::Sequence(sequence), &data_start[length..]))
     }
 }
 
-fn try_parse(input: &[u8]) -> Result<(Item, &[u8])> {
+fn try_parse(input: &[u8]) -> Result<(Item<'_>, &[u8])> {
     parse_byte_array(input).or_else(|_| parse_sequence(input))
 }
 
-pub fn parse(input: &[u8]) -> Result<Item> {
+pub fn parse(input: &[u8]) -> Result<Item<'_>> {
     try_parse(input).and_then(|result| {
         if result.1.is_empty() {
             Ok(result.0)
@@ -8,13 +8,36 @@ use serde::ser::{
 use serde::{Serialize, Serializer};
 use std::fmt::Display;
 
-pub fn to_bytes<T: Serialize>(value: &T) -> Result<Vec<u8>, Error> {
+/// Offset of the first byte of an encoded byte array.
+const BYTE_ARRAY_OFFSET: u8 = 128;
+
+/// Offset of the first byte of an encoded sequence.
+const SEQUENCE_OFFSET: u8 = 192;
+
+/// Serialize a value to RLP.
+///
+/// Integers are encoded as big-endian byte arrays without leading zeros, and sequences, tuples
+/// and structs as RLP sequences of their elements. Note that serde serializes byte slices and
+/// arrays as sequences of integers; types holding raw bytes should call
+/// [`Serializer::serialize_bytes`] in their [`Serialize`] implementation.
+pub fn to_bytes<T: ?Sized + Serialize>(value: &T) -> Result<Vec<u8>, Error> {
     let mut serializer = RLPSerializer::new();
     value.serialize(&mut serializer)?;
 
     Ok(serializer.output)
 }
 
+/// Write the length prefix of a byte array or a sequence of `length` bytes.
+fn write_length(output: &mut Vec<u8>, length: usize, offset: u8) {
+    if length < 56 {
+        output.push(offset + length as u8);
+    } else {
+        let mut length_be = length.to_min_be();
+        output.push(offset + 55 + length_be.len() as u8);
+        output.append(&mut length_be);
+    }
+}
+
 pub struct RLPSerializer {
     pub output: Vec<u8>,
 }
@@ -25,98 +48,190 @@ impl RLPSerializer {
     }
 }
 
-struct RLPSequenceSerializer<'a> {
+/// Serializer for sequences, collecting the encoded elements before the length prefix of the
+/// whole sequence is known.
+pub struct RLPSequenceSerializer<'a> {
     source_serializer: &'a mut RLPSerializer,
-    sequence: Vec<Vec<u8>>,
+    sequence: Vec<u8>,
+
+    /// Encoded key of a map entry, waiting for its value.
+    key: Option<Vec<u8>>,
 }
 
 impl<'a> RLPSequenceSerializer<'a> {
-    fn new(serializer: &'a mut RLPSerializer) -> RLPSequenceSerializer {
+    fn new(serializer: &'a mut RLPSerializer) -> RLPSequenceSerializer<'a> {
         RLPSequenceSerializer {
             source_serializer: serializer,
             sequence: vec![],
+            key: None,
         }
     }
+
+    fn push<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.sequence.append(&mut to_bytes(value)?);
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<(), Error> {
+        let output = &mut self.source_serializer.output;
+        write_length(output, self.sequence.len(), SEQUENCE_OFFSET);
+        output.append(&mut self.sequence);
+
+        Ok(())
+    }
+}
+
+impl<'a> SerializeSeq for RLPSequenceSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
 }
 
-impl<'a> SerializeSeq for &'a mut RLPSequenceSerializer<'a> {
+impl<'a> SerializeTuple for RLPSequenceSerializer<'a> {
     type Ok = ();
-    type Error = crate::error::Error;
+    type Error = Error;
 
     fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
-        self.sequence.push(to_bytes(value)?);
-        Ok(())
+        self.push(value)
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        let total_length = self.sequence.iter().map(|element| element.len()).sum();
-
-        if total_length < 56 {
-            self.source_serializer
-                .output
-                .push((192 + total_length) as u8);
-        } else {
-            let mut total_length_be = total_length.to_min_be();
-
-            self.source_serializer
-                .output
-                .push((247 + total_length_be.len()) as u8);
-            self.source_serializer.output.append(total_length_be);
+        self.finish()
+    }
+}
+
+impl<'a> SerializeTupleStruct for RLPSequenceSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+/// Structs are serialized as sequences of their fields, in declaration order. Field names are
+/// not part of the encoding.
+impl<'a> SerializeStruct for RLPSequenceSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+/// Maps are serialized as sequences of `[key, value]` sequences.
+impl<'a> SerializeMap for RLPSequenceSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
+        match self.key {
+            None => {
+                self.key = Some(to_bytes(key)?);
+                Ok(())
+            }
+            Some(_) => Err(Error::CallingSerializeKeyTwice),
         }
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let mut entry = self
+            .key
+            .take()
+            .ok_or(Error::CallingSerializeValueWithoutKey)?;
+        entry.append(&mut to_bytes(value)?);
 
-        self.sequence
-            .iter_mut()
-            .for_each(|element| self.source_serializer.output.append(element));
+        write_length(&mut self.sequence, entry.len(), SEQUENCE_OFFSET);
+        self.sequence.append(&mut entry);
 
         Ok(())
     }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
 }
 
-impl<'a> SerializeTuple for &'a mut RLPSequenceSerializer<'a> {
+impl<'a> SerializeTupleVariant for RLPSequenceSerializer<'a> {
     type Ok = ();
-    type Error = crate::error::Error;
+    type Error = Error;
 
-    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
-        // TODO ?????
-        (self as dyn SerializeSeq).serialize_element(value)
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, _value: &T) -> Result<(), Self::Error> {
+        Err(Error::UnsupportedType(UnsupportedType::TupleVariant))
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        // TODO ????
-        (self as dyn SerializeSeq).end()
+        Err(Error::UnsupportedType(UnsupportedType::TupleVariant))
+    }
+}
+
+impl<'a> SerializeStructVariant for RLPSequenceSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        _key: &'static str,
+        _value: &T,
+    ) -> Result<(), Self::Error> {
+        Err(Error::UnsupportedType(UnsupportedType::StructVariant))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Err(Error::UnsupportedType(UnsupportedType::StructVariant))
     }
 }
 
 impl<'a> Serializer for &'a mut RLPSerializer {
     type Ok = ();
-    type Error = crate::error::Error;
+    type Error = Error;
 
-    type SerializeSeq = Self;
-    type SerializeTuple = Self;
-    type SerializeTupleStruct = Self;
-    type SerializeTupleVariant = Self;
-    type SerializeMap = Self;
-    type SerializeStruct = Self;
-    type SerializeStructVariant = Self;
+    type SerializeSeq = RLPSequenceSerializer<'a>;
+    type SerializeTuple = RLPSequenceSerializer<'a>;
+    type SerializeTupleStruct = RLPSequenceSerializer<'a>;
+    type SerializeTupleVariant = RLPSequenceSerializer<'a>;
+    type SerializeMap = RLPSequenceSerializer<'a>;
+    type SerializeStruct = RLPSequenceSerializer<'a>;
+    type SerializeStructVariant = RLPSequenceSerializer<'a>;
 
     fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
         self.serialize_u8(v as u8)
     }
 
     fn serialize_i8(self, _: i8) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedDataType(UnsupportedType::I8))
+        Err(Error::UnsupportedType(UnsupportedType::I8))
     }
 
     fn serialize_i16(self, _: i16) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedDataType(UnsupportedType::I16))
+        Err(Error::UnsupportedType(UnsupportedType::I16))
     }
 
     fn serialize_i32(self, _: i32) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedDataType(UnsupportedType::I32))
+        Err(Error::UnsupportedType(UnsupportedType::I32))
     }
 
     fn serialize_i64(self, _: i64) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedDataType(UnsupportedType::I64))
+        Err(Error::UnsupportedType(UnsupportedType::I64))
     }
 
     fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
@@ -135,12 +250,16 @@ impl<'a> Serializer for &'a mut RLPSerializer {
         self.serialize_bytes(v.to_min_be().as_slice())
     }
 
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
+        self.serialize_bytes(v.to_min_be().as_slice())
+    }
+
     fn serialize_f32(self, _: f32) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedDataType(UnsupportedType::F32))
+        Err(Error::UnsupportedType(UnsupportedType::F32))
     }
 
     fn serialize_f64(self, _: f64) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedDataType(UnsupportedType::F64))
+        Err(Error::UnsupportedType(UnsupportedType::F64))
     }
 
     fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
@@ -153,94 +272,86 @@ impl<'a> Serializer for &'a mut RLPSerializer {
     }
 
     fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
-        if (v.len() == 1) && (v[0] < 128) {
-            self.output.extend(v);
-        } else if v.len() < 56 {
-            self.output.push((128 + v.len()) as u8);
-            self.output.extend(v);
-        } else {
-            let mut len_be_bytes = v.len().to_min_be();
-            self.output.push((183 + len_be_bytes.len()) as u8);
-            self.output.append(&mut len_be_bytes);
-            self.output.extend(v);
+        // A single byte smaller than 128 is its own encoding.
+        if !((v.len() == 1) && (v[0] < 128)) {
+            write_length(&mut self.output, v.len(), BYTE_ARRAY_OFFSET);
         }
+        self.output.extend(v);
 
         Ok(())
     }
 
     fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedDataType(UnsupportedType::Option))
+        Err(Error::UnsupportedType(UnsupportedType::Option))
     }
 
-    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedDataType(UnsupportedType::Option))
+    fn serialize_some<T: ?Sized + Serialize>(self, _value: &T) -> Result<Self::Ok, Self::Error> {
+        Err(Error::UnsupportedType(UnsupportedType::Option))
     }
 
     fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedDataType(UnsupportedType::Unit))
+        Err(Error::UnsupportedType(UnsupportedType::Unit))
     }
 
-    fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedDataType(UnsupportedType::UnitStruct))
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Err(Error::UnsupportedType(UnsupportedType::UnitStruct))
     }
 
     fn serialize_unit_variant(
         self,
-        name: &'static str,
-        variant_index: u32,
-        variant: &'static str,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
     ) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedDataType(UnsupportedType::UnitVariant))
+        Err(Error::UnsupportedType(UnsupportedType::UnitVariant))
     }
 
     fn serialize_newtype_struct<T: ?Sized + Serialize>(
         self,
-        name: &'static str,
-        value: &T,
+        _name: &'static str,
+        _value: &T,
     ) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedDataType(UnsupportedType::NewTypeStruct))
+        Err(Error::UnsupportedType(UnsupportedType::NewTypeStruct))
     }
 
     fn serialize_newtype_variant<T: ?Sized + Serialize>(
         self,
-        name: &'static str,
-        variant_index: u32,
-        variant: &'static str,
-        value: &T,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
     ) -> Result<Self::Ok, Self::Error> {
-        Err(Error::UnsupportedDataType(UnsupportedType::NewTypeVariant))
+        Err(Error::UnsupportedType(UnsupportedType::NewTypeVariant))
     }
 
     fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
-        self.items = Some(vec![]);
-        Ok(self)
+        Ok(RLPSequenceSerializer::new(self))
     }
 
     fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
-        self.items = Some(vec![]);
-        Ok(self)
+        Ok(RLPSequenceSerializer::new(self))
     }
 
     fn serialize_tuple_struct(
         self,
-        name: &'static str,
-        len: usize,
+        _name: &'static str,
+        _len: usize,
     ) -> Result<Self::SerializeTupleStruct, Self::Error> {
-        Err(Error::UnsupportedDataType(UnsupportedType::TupleStruct))
+        Ok(RLPSequenceSerializer::new(self))
     }
 
     fn serialize_tuple_variant(
         self,
-        name: &'static str,
-        variant_index: u32,
-        variant: &'static str,
-        len: usize,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
     ) -> Result<Self::SerializeTupleVariant, Self::Error> {
-        Err(Error::UnsupportedDataType(UnsupportedType::TupleVariant))
+        Err(Error::UnsupportedType(UnsupportedType::TupleVariant))
     }
 
     fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
-        Ok(self)
+        Ok(RLPSequenceSerializer::new(self))
     }
 
     fn serialize_struct(
@@ -248,166 +359,26 @@ impl<'a> Serializer for &'a mut RLPSerializer {
         _name: &'static str,
         _len: usize,
     ) -> Result<Self::SerializeStruct, Self::Error> {
-        Ok(self)
+        Ok(RLPSequenceSerializer::new(self))
     }
 
     fn serialize_struct_variant(
         self,
-        name: &'static str,
-        variant_index: u32,
-        variant: &'static str,
-        len: usize,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
     ) -> Result<Self::SerializeStructVariant, Self::Error> {
-        Err(Error::UnsupportedDataType(UnsupportedType::StructVariant))
+        Err(Error::UnsupportedType(UnsupportedType::StructVariant))
     }
 
     fn collect_str<T: ?Sized + Display>(self, value: &T) -> Result<Self::Ok, Self::Error> {
-        // TODO
-        unimplemented!()
-    }
-}
-
-impl<'a> SerializeSeq for &'a mut RLPSerializer {
-    type Ok = ();
-    type Error = crate::error::Error;
-
-    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
-        // TODO: is this optimized?
-
-        let mut serialized = to_bytes(value)?;
-        self.output.append(&mut serialized);
-        Ok(())
-    }
-
-    fn end(self) -> Result<Self::Ok, Self::Error> {
-        // TODO: empty list?
-
-        if self.output.len() < 56 {
-            self.output.insert(0, (192 + self.output.len()) as u8);
-        } else {
-            // TODO: need to shorten this.
-            let output_len_be = self.output.len().to_be_bytes();
-            self.output.insert(0, (247 + output_len_be.len()) as u8);
-            // TODO: insert output_len_be at top.
-        }
-
-        Ok(())
-    }
-}
-
-impl<'a> SerializeTuple for &'a mut RLPSerializer {
-    type Ok = ();
-    type Error = crate::error::Error;
-
-    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
-        // TODO: is this optimized?
-
-        let mut serialized = to_bytes(value)?;
-        self.output.append(&mut serialized);
-        Ok(())
-    }
-
-    fn end(self) -> Result<Self::Ok, Self::Error> {
-        // TODO: empty list?
-
-        if self.output.len() < 56 {
-            self.output.insert(0, (192 + self.output.len()) as u8);
-        } else {
-            // TODO: need to shorten this.
-            let output_len_be = self.output.len().to_be_bytes();
-            self.output.insert(0, (247 + output_len_be.len()) as u8);
-            // TODO: insert output_len_be at top.
-        }
-
-        Ok(())
-    }
-}
-
-impl<'a> SerializeMap for &'a mut RLPSerializer {
-    type Ok = ();
-    type Error = Error;
-
-    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
-        match self.key {
-            None => {
-                self.key = Some(to_bytes(key)?);
-                Ok(())
-            }
-            Some(_) => Err(Error::CallingSerializeKeyTwice),
-        }
-    }
-
-    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
-        match self.key {
-            None => Err(Error::CallingSerializeValueWithoutKey),
-            Some(&key) => self.items.push(to_bytes([key, to_bytes(value)])),
-            // TODO: ????
-            _ => {}
-        }
+        self.serialize_str(&value.to_string())
     }
 
-    fn end(self) -> Result<Self::Ok, Self::Error> {
-        // TODO: end here.
-        unimplemented!()
-    }
-}
-
-impl<'a> SerializeStruct for &'a mut RLPSerializer {
-    type Ok = ();
-    type Error = Error;
-
-    fn serialize_field<T: ?Sized + Serialize>(
-        &mut self,
-        key: &'static str,
-        value: &T,
-    ) -> Result<(), Self::Error> {
-        unimplemented!()
-    }
-
-    fn end(self) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
-    }
-}
-
-impl<'a> SerializeTupleStruct for &'a mut RLPSerializer {
-    type Ok = ();
-    type Error = Error;
-
-    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
-        unimplemented!()
-    }
-
-    fn end(self) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
-    }
-}
-
-impl<'a> SerializeTupleVariant for &'a mut RLPSerializer {
-    type Ok = ();
-    type Error = Error;
-
-    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
-        unimplemented!()
-    }
-
-    fn end(self) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
-    }
-}
-
-impl<'a> SerializeStructVariant for &'a mut RLPSerializer {
-    type Ok = ();
-    type Error = Error;
-
-    fn serialize_field<T: ?Sized + Serialize>(
-        &mut self,
-        key: &'static str,
-        value: &T,
-    ) -> Result<(), Self::Error> {
-        unimplemented!()
-    }
-
-    fn end(self) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
+    /// RLP is a binary format, so types with a compact binary form, such as hashes, should use it
+    /// instead of a string.
+    fn is_human_readable(&self) -> bool {
+        false
     }
 }
@@ -0,0 +1,4 @@
+extern crate peko_rlp;
+
+mod test_de;
+mod test_ser;
@@ -0,0 +1,36 @@
+use hex_literal::hex;
+
+use peko_rlp::{Error, RLPDeserializer};
+use serde::Deserialize;
+
+macro_rules! test_de {
+    ($name: ident, $type: ty, $encoded: expr, $value: expr) => {
+        #[test]
+        fn $name() {
+            let mut deserializer = RLPDeserializer::from_bytes(&$encoded).unwrap();
+            assert_eq!(
+                <$type>::deserialize(&mut deserializer),
+                $value,
+                "decoding for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+test_de!(test_bool_false, bool, hex!("80"), Ok(false));
+test_de!(test_bool_true, bool, hex!("01"), Ok(true));
+test_de!(
+    test_bool_invalid,
+    bool,
+    hex!("02"),
+    Err(Error::InvalidInput)
+);
+test_de!(test_bool_list, bool, hex!("c0"), Err(Error::NotByteArray));
+test_de!(test_integer_15, u8, hex!("0f"), Ok(15));
+test_de!(
+    test_integer_overflow,
+    u8,
+    hex!("820400"),
+    Err(Error::OverflowedIntegerForType)
+);
@@ -0,0 +1,56 @@
+use hex_literal::hex;
+
+use peko_rlp::to_bytes;
+
+macro_rules! test_ser {
+    ($name: ident, $value: expr, $encoded: expr) => {
+        #[test]
+        fn $name() {
+            assert_eq!(
+                to_bytes(&$value).unwrap(),
+                $encoded.to_vec(),
+                "encoding for {} does not match",
+                stringify!($name)
+            );
+        }
+    };
+}
+
+// Examples from the RLP specification: https://eth.wiki/fundamentals/rlp
+test_ser!(test_string, "dog", hex!("83646f67"));
+test_ser!(test_list, ("cat", "dog"), hex!("c88363617483646f67"));
+test_ser!(test_empty_string, "", hex!("80"));
+test_ser!(test_empty_list, Vec::<u8>::new(), hex!("c0"));
+test_ser!(test_integer_zero, 0u64, hex!("80"));
+test_ser!(test_integer_15, 15u8, hex!("0f"));
+test_ser!(test_integer_1024, 1024u16, hex!("820400"));
+test_ser!(
+    test_long_string,
+    "Lorem ipsum dolor sit amet, consectetur adipisicing elit",
+    hex!(
+        "b8384c6f72656d20697073756d20646f6c6f722073697420616d65742c20636f6e7365637465747572206164"
+        "697069736963696e6720656c6974"
+    )
+);
+
+test_ser!(test_bool, (false, true), hex!("c28001"));
+test_ser!(
+    test_nested_list,
+    vec![vec![1u8, 2], vec![]],
+    hex!("c4c20102c0")
+);
+
+#[test]
+fn test_long_list() {
+    let list = vec![0x7fu8; 56];
+    let mut encoded = hex!("f838").to_vec();
+    encoded.extend_from_slice(&list);
+
+    assert_eq!(to_bytes(&list).unwrap(), encoded);
+}
+
+#[test]
+fn test_unsupported_type() {
+    assert!(to_bytes(&-1i32).is_err());
+    assert!(to_bytes(&Some(1u8)).is_err());
+}